@@ -2,7 +2,8 @@ use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 
 use axum::{Json, middleware::from_fn, routing::get};
-use shield::{Shield, ShieldOptions};
+use shield::{RateLimitOptions, Shield, ShieldOptions};
+use shield_api_key::{ApiKeyMethod, ApiKeyOptions};
 use shield_axum::{AuthRoutes, ShieldLayer, auth_required};
 use shield_email::{EmailMethod, EmailOptions, TracingSender};
 use shield_memory::{MemoryStorage, User};
@@ -36,6 +37,7 @@ async fn main() {
     let shield = Shield::new(
         storage.clone(),
         vec![
+            Arc::new(ApiKeyMethod::new(ApiKeyOptions::default(), storage.clone())),
             Arc::new(EmailMethod::new(
                 EmailOptions::builder()
                     .secret("secret")
@@ -44,7 +46,7 @@ async fn main() {
                 storage.clone(),
             )),
             Arc::new(
-                OidcMethod::new(storage)
+                OidcMethod::new(storage.clone())
                     .with_providers([Keycloak::builder(
                         "keycloak",
                         "http://localhost:18080/realms/Shield",
@@ -67,7 +69,10 @@ async fn main() {
             ),
         ],
         ShieldOptions::default(),
-    );
+    )
+    .with_session_registry(storage.clone())
+    .with_audit_storage(storage.clone())
+    .with_rate_limiter(RateLimitOptions::default(), storage);
     let shield_layer = ShieldLayer::new(shield.clone());
 
     // Initialize API router
@@ -91,7 +96,10 @@ async fn main() {
     // Start app
     info!("listening on http://{}", &addr);
     let listener = TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, router.into_make_service())
-        .await
-        .unwrap();
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }
@@ -53,7 +53,7 @@ async fn main() -> std::io::Result<()> {
                     storage.clone(),
                 )),
                 Arc::new(
-                    OidcMethod::new(storage).with_providers([Keycloak::builder(
+                    OidcMethod::new(storage.clone()).with_providers([Keycloak::builder(
                         "keycloak",
                         "http://localhost:18080/realms/Shield",
                         "client1",
@@ -63,7 +63,8 @@ async fn main() -> std::io::Result<()> {
                 ),
             ],
             ShieldOptions::default(),
-        );
+        )
+        .with_session_registry(storage);
         let shield_middleware = ShieldMiddleware::new(shield.clone());
 
         // Initialize app
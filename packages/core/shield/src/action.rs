@@ -25,6 +25,12 @@ pub trait Action: Send + Sync {
 
     async fn forms(&self) -> Result<Vec<Form>, ShieldError>;
 
+    /// Whether the action can be called with a token restricted to scopes, e.g. an API key. Enable
+    /// only for actions which check the scopes of the authentication themselves.
+    fn allows_scoped_tokens(&self) -> bool {
+        false
+    }
+
     async fn call(&self, session: &BaseSession, request: Request) -> Result<Response, ShieldError>;
 }
 
@@ -75,6 +81,24 @@ pub trait MethodAction<P: Provider, S>: ErasedMethodAction + Send + Sync {
 
     async fn forms(&self, provider: P) -> Result<Vec<Form>, ShieldError>;
 
+    /// Identifier of the account the request attempts to sign in to, e.g. an email address, which
+    /// failed attempts are limited for.
+    fn rate_limit_identifier(&self, _request: &Request) -> Option<String> {
+        None
+    }
+
+    /// Whether the action is protected against cross-site request forgery. Disable for actions which
+    /// are called by other sites, e.g. callbacks posted by an identity provider.
+    fn csrf_protected(&self) -> bool {
+        true
+    }
+
+    /// Whether the action can be called with a token restricted to scopes, e.g. an API key. Enable
+    /// only for actions which check the scopes of the authentication themselves.
+    fn allows_scoped_tokens(&self) -> bool {
+        false
+    }
+
     async fn call(
         &self,
         provider: P,
@@ -107,6 +131,12 @@ pub trait ErasedMethodAction: Send + Sync {
         provider: Box<dyn Any + Send + Sync>,
     ) -> Result<Vec<Form>, ShieldError>;
 
+    fn erased_rate_limit_identifier(&self, request: &Request) -> Option<String>;
+
+    fn erased_csrf_protected(&self) -> bool;
+
+    fn erased_allows_scoped_tokens(&self) -> bool;
+
     async fn erased_call(
         &self,
         provider: Box<dyn Any + Send + Sync>,
@@ -163,6 +193,18 @@ macro_rules! erased_method_action {
                 self.forms(*provider.downcast().expect("Provider should be downcast")).await
             }
 
+            fn erased_rate_limit_identifier(&self, request: &$crate::Request) -> Option<String> {
+                self.rate_limit_identifier(request)
+            }
+
+            fn erased_csrf_protected(&self) -> bool {
+                self.csrf_protected()
+            }
+
+            fn erased_allows_scoped_tokens(&self) -> bool {
+                self.allows_scoped_tokens()
+            }
+
             async fn erased_call(
                 &self,
                 provider: Box<dyn std::any::Any + Send + Sync>,
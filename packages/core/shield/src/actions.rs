@@ -1,9 +1,19 @@
+mod connections;
+mod revoke_other_sessions;
+mod revoke_session;
+mod sessions;
 mod sign_in;
 mod sign_in_callback;
 mod sign_out;
 mod sign_up;
+mod unlink_connection;
 
+pub use connections::*;
+pub use revoke_other_sessions::*;
+pub use revoke_session::*;
+pub use sessions::*;
 pub use sign_in::*;
 pub use sign_in_callback::*;
 pub use sign_out::*;
 pub use sign_up::*;
+pub use unlink_connection::*;
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ordered_hash_map::OrderedHashMap;
+
+use crate::{
+    action::Action,
+    connection::list_user_connections,
+    error::{ConfigurationError, ShieldError},
+    form::Form,
+    method::ErasedMethod,
+    request::{Request, RequestMethod},
+    response::{Response, ResponseType},
+    session::BaseSession,
+};
+
+const ACTION_ID: &str = "connections";
+const ACTION_NAME: &str = "Connections";
+
+pub struct ConnectionsAction {
+    methods: Arc<OrderedHashMap<String, Arc<dyn ErasedMethod>>>,
+}
+
+impl ConnectionsAction {
+    pub(crate) fn new(methods: Arc<OrderedHashMap<String, Arc<dyn ErasedMethod>>>) -> Self {
+        Self { methods }
+    }
+}
+
+#[async_trait]
+impl Action for ConnectionsAction {
+    fn id(&self) -> &'static str {
+        ACTION_ID
+    }
+
+    fn name(&self) -> &'static str {
+        ACTION_NAME
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "List connections"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "List the connections of the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Get
+    }
+
+    async fn forms(&self) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        session: &BaseSession,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let connections = list_user_connections(&self.methods, &authentication.user_id).await?;
+
+        Ok(Response::new(ResponseType::Data(
+            serde_json::to_value(connections)
+                .map_err(|err| ConfigurationError::Invalid(err.to_string()))?,
+        )))
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    action::Action,
+    error::ShieldError,
+    form::{Form, Input, InputType, InputTypeSubmit, InputValue},
+    request::{Request, RequestMethod},
+    response::{Response, ResponseType},
+    session::BaseSession,
+    session_registry::{SessionRegistry, revoke_other_user_sessions},
+};
+
+const ACTION_ID: &str = "revoke-other-sessions";
+const ACTION_NAME: &str = "Sign out other sessions";
+
+pub struct RevokeOtherSessionsAction {
+    session_registry: Arc<dyn SessionRegistry>,
+}
+
+impl RevokeOtherSessionsAction {
+    pub(crate) fn new(session_registry: Arc<dyn SessionRegistry>) -> Self {
+        Self { session_registry }
+    }
+}
+
+#[async_trait]
+impl Action for RevokeOtherSessionsAction {
+    fn id(&self) -> &'static str {
+        ACTION_ID
+    }
+
+    fn name(&self) -> &'static str {
+        ACTION_NAME
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Revoke other sessions"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Revoke all sessions of the current user, except the current session."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![Input {
+                name: "submit".to_owned(),
+                label: None,
+                r#type: InputType::Submit(InputTypeSubmit {}),
+                value: Some(InputValue::String {
+                    value: self.name().to_owned(),
+                }),
+                addon_start: None,
+                addon_end: None,
+            }],
+        }])
+    }
+
+    async fn call(&self, session: &BaseSession, request: Request) -> Result<Response, ShieldError> {
+        let authentication = session
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        revoke_other_user_sessions(
+            &*self.session_registry,
+            &request.hooks,
+            &authentication.user_id,
+            authentication.session_id.as_deref(),
+        )
+        .await?;
+
+        Ok(Response::new(ResponseType::Default))
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{
+    action::Action,
+    error::ShieldError,
+    form::{Form, Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue},
+    request::{Request, RequestMethod},
+    response::{Response, ResponseType},
+    session::{BaseSession, SessionAction},
+    session_registry::{SessionRegistry, revoke_user_session},
+};
+
+const ACTION_ID: &str = "revoke-session";
+const ACTION_NAME: &str = "Revoke session";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeSessionData {
+    pub session_id: String,
+}
+
+pub struct RevokeSessionAction {
+    session_registry: Arc<dyn SessionRegistry>,
+}
+
+impl RevokeSessionAction {
+    pub(crate) fn new(session_registry: Arc<dyn SessionRegistry>) -> Self {
+        Self { session_registry }
+    }
+}
+
+#[async_trait]
+impl Action for RevokeSessionAction {
+    fn id(&self) -> &'static str {
+        ACTION_ID
+    }
+
+    fn name(&self) -> &'static str {
+        ACTION_NAME
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Revoke session"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Revoke a session of the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "sessionId".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Query {
+                        key: "sessionId".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit {}),
+                    value: Some(InputValue::String {
+                        value: self.name().to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(&self, session: &BaseSession, request: Request) -> Result<Response, ShieldError> {
+        let authentication = session
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let data = serde_json::from_value::<RevokeSessionData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        revoke_user_session(
+            &*self.session_registry,
+            &request.hooks,
+            &authentication.user_id,
+            &data.session_id,
+        )
+        .await?;
+
+        let response = Response::new(ResponseType::Default);
+
+        Ok(
+            if authentication.session_id.as_ref() == Some(&data.session_id) {
+                response.session_action(SessionAction::unauthenticate())
+            } else {
+                response
+            },
+        )
+    }
+}
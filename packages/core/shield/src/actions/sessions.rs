@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    action::Action,
+    error::{ConfigurationError, ShieldError},
+    form::Form,
+    request::{Request, RequestMethod},
+    response::{Response, ResponseType},
+    session::BaseSession,
+    session_registry::{SessionRegistry, list_user_sessions},
+};
+
+const ACTION_ID: &str = "sessions";
+const ACTION_NAME: &str = "Sessions";
+
+pub struct SessionsAction {
+    session_registry: Arc<dyn SessionRegistry>,
+}
+
+impl SessionsAction {
+    pub(crate) fn new(session_registry: Arc<dyn SessionRegistry>) -> Self {
+        Self { session_registry }
+    }
+}
+
+#[async_trait]
+impl Action for SessionsAction {
+    fn id(&self) -> &'static str {
+        ACTION_ID
+    }
+
+    fn name(&self) -> &'static str {
+        ACTION_NAME
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "List sessions"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "List the active sessions of the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Get
+    }
+
+    async fn forms(&self) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        session: &BaseSession,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let sessions = list_user_sessions(
+            &*self.session_registry,
+            &authentication.user_id,
+            authentication.session_id.as_deref(),
+        )
+        .await?;
+
+        Ok(Response::new(ResponseType::Data(
+            serde_json::to_value(sessions)
+                .map_err(|err| ConfigurationError::Invalid(err.to_string()))?,
+        )))
+    }
+}
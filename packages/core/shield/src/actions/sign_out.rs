@@ -14,6 +14,16 @@ const ACTION_NAME: &str = "Sign out";
 
 pub struct SignOutAction;
 
+impl SignOutAction {
+    pub fn id() -> String {
+        ACTION_ID.to_owned()
+    }
+
+    pub fn name() -> String {
+        ACTION_NAME.to_owned()
+    }
+}
+
 #[async_trait]
 impl Action for SignOutAction {
     fn id(&self) -> &'static str {
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ordered_hash_map::OrderedHashMap;
+use serde::Deserialize;
+
+use crate::{
+    action::Action,
+    connection::unlink_user_connection,
+    error::ShieldError,
+    form::{Form, Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue},
+    method::ErasedMethod,
+    request::{Request, RequestMethod},
+    response::{Response, ResponseType},
+    session::BaseSession,
+};
+
+const ACTION_ID: &str = "unlink-connection";
+const ACTION_NAME: &str = "Unlink connection";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlinkConnectionData {
+    pub method_id: String,
+    pub connection_id: String,
+}
+
+pub struct UnlinkConnectionAction {
+    methods: Arc<OrderedHashMap<String, Arc<dyn ErasedMethod>>>,
+}
+
+impl UnlinkConnectionAction {
+    pub(crate) fn new(methods: Arc<OrderedHashMap<String, Arc<dyn ErasedMethod>>>) -> Self {
+        Self { methods }
+    }
+}
+
+#[async_trait]
+impl Action for UnlinkConnectionAction {
+    fn id(&self) -> &'static str {
+        ACTION_ID
+    }
+
+    fn name(&self) -> &'static str {
+        ACTION_NAME
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Unlink connection"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Unlink a connection of the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "methodId".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Query {
+                        key: "methodId".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "connectionId".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Query {
+                        key: "connectionId".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit {}),
+                    value: Some(InputValue::String {
+                        value: self.name().to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(&self, session: &BaseSession, request: Request) -> Result<Response, ShieldError> {
+        let authentication = session
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let data = serde_json::from_value::<UnlinkConnectionData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        unlink_user_connection(
+            &self.methods,
+            &request.hooks,
+            &authentication.user_id,
+            &data.method_id,
+            &data.connection_id,
+        )
+        .await?;
+
+        Ok(Response::new(ResponseType::Default))
+    }
+}
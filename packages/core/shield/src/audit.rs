@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    error::{ShieldError, StorageError},
+    hook::{ConnectionEvent, Hook, SessionEvent, SignInEvent, SignInFailedEvent, SignOutEvent},
+    session::SessionClient,
+};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditEventType {
+    SignIn,
+    SignInFailed,
+    SignOut,
+    ConnectionLinked,
+    ConnectionUnlinked,
+    SessionRevoked,
+}
+
+/// Authentication event recorded in the [`AuditStorage`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub id: String,
+    pub event_type: AuditEventType,
+    pub user_id: Option<String>,
+    pub method_id: Option<String>,
+    pub provider_id: Option<String>,
+    /// Reason of a failed sign in.
+    pub reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateAuditEntry {
+    pub event_type: AuditEventType,
+    pub user_id: Option<String>,
+    pub method_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// Filter for audit entries. Fields which are `None` match any entry.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuditFilter {
+    pub user_id: Option<String>,
+    /// Start of the time range, inclusive.
+    pub from: Option<DateTime<Utc>>,
+    /// End of the time range, exclusive.
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl AuditFilter {
+    pub fn user(user_id: &str) -> Self {
+        Self {
+            user_id: Some(user_id.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.user_id
+            .as_ref()
+            .is_none_or(|user_id| Some(user_id) == entry.user_id.as_ref())
+            && self.from.is_none_or(|from| entry.created_at >= from)
+            && self.to.is_none_or(|to| entry.created_at < to)
+    }
+}
+
+#[async_trait]
+pub trait AuditStorage: Send + Sync {
+    async fn create_audit_entry(&self, entry: CreateAuditEntry)
+    -> Result<AuditEntry, StorageError>;
+
+    /// Audit entries matching the filter, ordered from newest to oldest.
+    async fn audit_entries(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>, StorageError>;
+}
+
+/// Hook which records the events of a request in the audit storage.
+///
+/// The events have already happened when they are recorded, e.g. the session is signed in, so an entry which can not
+/// be recorded is logged instead of failing the request.
+pub(crate) struct AuditHook {
+    storage: Arc<dyn AuditStorage>,
+    client: SessionClient,
+}
+
+impl AuditHook {
+    pub(crate) fn new(storage: Arc<dyn AuditStorage>, client: SessionClient) -> Self {
+        Self { storage, client }
+    }
+
+    async fn create_entry(
+        &self,
+        event_type: AuditEventType,
+        user_id: Option<&str>,
+        method_id: &str,
+        provider_id: Option<&str>,
+        reason: Option<&str>,
+    ) {
+        if let Err(err) = self
+            .storage
+            .create_audit_entry(CreateAuditEntry {
+                event_type,
+                user_id: user_id.map(ToOwned::to_owned),
+                method_id: Some(method_id.to_owned()),
+                provider_id: provider_id.map(ToOwned::to_owned),
+                reason: reason.map(ToOwned::to_owned),
+                ip_address: self.client.ip_address.clone(),
+                user_agent: self.client.user_agent.clone(),
+            })
+            .await
+        {
+            warn!("Audit entry for `{event_type:?}` could not be recorded: {err}");
+        }
+    }
+}
+
+#[async_trait]
+impl Hook for AuditHook {
+    async fn after_sign_in(&self, event: &SignInEvent) -> Result<(), ShieldError> {
+        self.create_entry(
+            AuditEventType::SignIn,
+            Some(&event.user_id),
+            &event.method_id,
+            event.provider_id.as_deref(),
+            None,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn sign_in_failed(&self, event: &SignInFailedEvent) -> Result<(), ShieldError> {
+        self.create_entry(
+            AuditEventType::SignInFailed,
+            event.user_id.as_deref(),
+            &event.method_id,
+            event.provider_id.as_deref(),
+            Some(&event.reason),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn after_sign_out(&self, event: &SignOutEvent) -> Result<(), ShieldError> {
+        self.create_entry(
+            AuditEventType::SignOut,
+            Some(&event.user_id),
+            &event.method_id,
+            event.provider_id.as_deref(),
+            None,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn connection_linked(&self, event: &ConnectionEvent) -> Result<(), ShieldError> {
+        self.create_entry(
+            AuditEventType::ConnectionLinked,
+            Some(&event.user_id),
+            &event.method_id,
+            event.provider_id.as_deref(),
+            None,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn connection_unlinked(&self, event: &ConnectionEvent) -> Result<(), ShieldError> {
+        self.create_entry(
+            AuditEventType::ConnectionUnlinked,
+            Some(&event.user_id),
+            &event.method_id,
+            event.provider_id.as_deref(),
+            None,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn session_revoked(&self, event: &SessionEvent) -> Result<(), ShieldError> {
+        self.create_entry(
+            AuditEventType::SessionRevoked,
+            Some(&event.user_id),
+            &event.method_id,
+            event.provider_id.as_deref(),
+            None,
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+
+    use super::{AuditEntry, AuditEventType, AuditFilter};
+
+    #[test]
+    fn test_filter_matches() {
+        let now = Utc::now();
+        let entry = AuditEntry {
+            id: "entry".to_owned(),
+            event_type: AuditEventType::SignIn,
+            user_id: Some("user".to_owned()),
+            method_id: Some("credentials".to_owned()),
+            provider_id: None,
+            reason: None,
+            ip_address: None,
+            user_agent: None,
+            created_at: now,
+        };
+
+        assert!(AuditFilter::default().matches(&entry));
+        assert!(AuditFilter::user("user").matches(&entry));
+        assert!(!AuditFilter::user("other").matches(&entry));
+        assert!(
+            AuditFilter {
+                from: Some(now),
+                to: Some(now + TimeDelta::minutes(1)),
+                ..AuditFilter::user("user")
+            }
+            .matches(&entry)
+        );
+        assert!(
+            !AuditFilter {
+                to: Some(now),
+                ..Default::default()
+            }
+            .matches(&entry)
+        );
+        assert!(
+            !AuditFilter {
+                from: Some(now + TimeDelta::seconds(1)),
+                ..Default::default()
+            }
+            .matches(&entry)
+        );
+    }
+}
@@ -0,0 +1,592 @@
+use std::{
+    fmt::{self, Debug},
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+pub use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use jsonwebtoken::{Header, Validation, decode, encode};
+use rand::distr::{Alphanumeric, SampleString};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+
+use crate::{
+    error::{ConfigurationError, SessionError, ShieldError, StorageError},
+    session::{Authentication, AuthenticationFactor, SessionData, SessionStorage},
+};
+
+#[derive(Builder, Clone)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct BearerOptions {
+    /// Key to sign access tokens with.
+    pub(crate) encoding_key: EncodingKey,
+
+    /// Keys to verify access tokens with. Multiple keys allow rotating the signing key.
+    #[builder(with = FromIterator::from_iter)]
+    pub(crate) decoding_keys: Vec<DecodingKey>,
+
+    #[builder(default = Algorithm::HS256)]
+    pub(crate) algorithm: Algorithm,
+
+    /// Key ID (`kid` header) of the signing key.
+    pub(crate) key_id: Option<String>,
+
+    pub(crate) issuer: Option<String>,
+
+    pub(crate) audience: Option<String>,
+
+    #[builder(default = TimeDelta::minutes(15))]
+    pub(crate) access_token_expires_in: TimeDelta,
+
+    #[builder(default = TimeDelta::days(30))]
+    pub(crate) refresh_token_expires_in: TimeDelta,
+}
+
+impl Debug for BearerOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BearerOptions")
+            .field("algorithm", &self.algorithm)
+            .field("key_id", &self.key_id)
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .field("access_token_expires_in", &self.access_token_expires_in)
+            .field("refresh_token_expires_in", &self.refresh_token_expires_in)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshToken {
+    pub id: String,
+    /// Hash of the refresh token, the token itself is never stored.
+    pub token_hash: String,
+    /// Refresh tokens rotated from the same sign-in share a family, which is revoked entirely
+    /// when a rotated refresh token is used again.
+    pub family_id: String,
+    pub user_id: String,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    /// Method IDs of the authentication factors.
+    pub factors: Vec<String>,
+    pub expired_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateRefreshToken {
+    pub token_hash: String,
+    pub family_id: String,
+    pub user_id: String,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub factors: Vec<String>,
+    pub expired_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait RefreshTokenStorage: Send + Sync {
+    async fn refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<RefreshToken>, StorageError>;
+
+    async fn create_refresh_token(
+        &self,
+        token: CreateRefreshToken,
+    ) -> Result<RefreshToken, StorageError>;
+
+    /// Mark the refresh token as rotated, unless it has been rotated already.
+    ///
+    /// Returns whether the refresh token was rotated. This must be a single conditional update, so concurrent
+    /// refreshes with the same token can not both succeed.
+    async fn rotate_refresh_token(
+        &self,
+        refresh_token_id: &str,
+        rotated_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError>;
+
+    async fn delete_refresh_token_family(&self, family_id: &str) -> Result<(), StorageError>;
+
+    async fn delete_user_refresh_tokens(&self, user_id: &str) -> Result<(), StorageError>;
+}
+
+/// Claims of a signed access token.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccessTokenClaims {
+    pub sub: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
+    pub iat: i64,
+    pub exp: i64,
+    pub jti: String,
+    /// Refresh token family the access token was issued for.
+    pub sid: String,
+    /// Method IDs of the authentication factors.
+    pub amr: Vec<String>,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct BearerTokens {
+    pub access_token: String,
+    pub token_type: String,
+    /// Lifetime of the access token in seconds.
+    pub expires_in: i64,
+    pub refresh_token: String,
+}
+
+#[derive(Clone)]
+pub(crate) struct BearerTokenManager {
+    options: BearerOptions,
+    storage: Arc<dyn RefreshTokenStorage>,
+}
+
+impl BearerTokenManager {
+    pub(crate) fn new(options: BearerOptions, storage: Arc<dyn RefreshTokenStorage>) -> Self {
+        Self { options, storage }
+    }
+
+    pub(crate) async fn issue(
+        &self,
+        authentication: &Authentication,
+    ) -> Result<BearerTokens, ShieldError> {
+        let mut factors = authentication
+            .factors
+            .iter()
+            .map(|factor| factor.method_id.clone())
+            .collect::<Vec<_>>();
+        factors.dedup();
+
+        let family_id = Alphanumeric.sample_string(&mut rand::rng(), 32);
+
+        self.issue_refresh_token(CreateRefreshToken {
+            token_hash: String::new(),
+            family_id,
+            user_id: authentication.user_id.clone(),
+            method_id: authentication.method_id.clone(),
+            provider_id: authentication.provider_id.clone(),
+            factors,
+            expired_at: Utc::now(),
+        })
+        .await
+    }
+
+    /// Rotate the refresh token, revoking its family if it has been used before.
+    pub(crate) async fn refresh(&self, refresh_token: &str) -> Result<BearerTokens, ShieldError> {
+        let token = self
+            .storage
+            .refresh_token_by_hash(&hash_refresh_token(refresh_token))
+            .await?
+            .ok_or(ShieldError::Unauthorized)?;
+
+        if token.rotated_at.is_some() || token.expired_at < Utc::now() {
+            self.storage
+                .delete_refresh_token_family(&token.family_id)
+                .await?;
+
+            return Err(ShieldError::Unauthorized);
+        }
+
+        // Losing a race with a concurrent refresh is treated the same as reuse.
+        if !self
+            .storage
+            .rotate_refresh_token(&token.id, Utc::now())
+            .await?
+        {
+            self.storage
+                .delete_refresh_token_family(&token.family_id)
+                .await?;
+
+            return Err(ShieldError::Unauthorized);
+        }
+
+        self.issue_refresh_token(CreateRefreshToken {
+            token_hash: String::new(),
+            family_id: token.family_id,
+            user_id: token.user_id,
+            method_id: token.method_id,
+            provider_id: token.provider_id,
+            factors: token.factors,
+            expired_at: Utc::now(),
+        })
+        .await
+    }
+
+    pub(crate) async fn revoke(&self, refresh_token: &str) -> Result<(), ShieldError> {
+        if let Some(token) = self
+            .storage
+            .refresh_token_by_hash(&hash_refresh_token(refresh_token))
+            .await?
+        {
+            self.storage
+                .delete_refresh_token_family(&token.family_id)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn revoke_user(&self, user_id: &str) -> Result<(), ShieldError> {
+        self.storage.delete_user_refresh_tokens(user_id).await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn refresh_token_user_id(
+        &self,
+        refresh_token: &str,
+    ) -> Result<Option<String>, ShieldError> {
+        Ok(self
+            .storage
+            .refresh_token_by_hash(&hash_refresh_token(refresh_token))
+            .await?
+            .map(|token| token.user_id))
+    }
+
+    pub(crate) fn verify(&self, access_token: &str) -> Result<AccessTokenClaims, ShieldError> {
+        let mut validation = Validation::new(self.options.algorithm);
+        validation.set_required_spec_claims(&["exp", "sub"]);
+        validation.validate_aud = self.options.audience.is_some();
+        if let Some(issuer) = &self.options.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        if let Some(audience) = &self.options.audience {
+            validation.set_audience(&[audience]);
+        }
+
+        self.options
+            .decoding_keys
+            .iter()
+            .find_map(|decoding_key| {
+                decode::<AccessTokenClaims>(access_token, decoding_key, &validation).ok()
+            })
+            .map(|token_data| token_data.claims)
+            .ok_or(ShieldError::Unauthorized)
+    }
+
+    async fn issue_refresh_token(
+        &self,
+        mut token: CreateRefreshToken,
+    ) -> Result<BearerTokens, ShieldError> {
+        let now = Utc::now();
+        let refresh_token = Alphanumeric.sample_string(&mut rand::rng(), 48);
+
+        token.token_hash = hash_refresh_token(&refresh_token);
+        token.expired_at = now + self.options.refresh_token_expires_in;
+
+        let token = self.storage.create_refresh_token(token).await?;
+
+        let claims = AccessTokenClaims {
+            sub: token.user_id,
+            iss: self.options.issuer.clone(),
+            aud: self.options.audience.clone(),
+            iat: now.timestamp(),
+            exp: (now + self.options.access_token_expires_in).timestamp(),
+            jti: Alphanumeric.sample_string(&mut rand::rng(), 32),
+            sid: token.family_id,
+            amr: token.factors,
+            method_id: token.method_id,
+            provider_id: token.provider_id,
+        };
+
+        let mut header = Header::new(self.options.algorithm);
+        header.kid = self.options.key_id.clone();
+
+        let access_token = encode(&header, &claims, &self.options.encoding_key)
+            .map_err(|err| ConfigurationError::Invalid(err.to_string()))?;
+
+        Ok(BearerTokens {
+            access_token,
+            token_type: "Bearer".to_owned(),
+            expires_in: self.options.access_token_expires_in.num_seconds(),
+            refresh_token,
+        })
+    }
+}
+
+fn hash_refresh_token(refresh_token: &str) -> String {
+    hex::encode(Sha3_256::digest(refresh_token))
+}
+
+/// Session for a request with a token, which only lives for the duration of the request.
+pub(crate) struct BearerSessionStorage {
+    session_data: Arc<Mutex<SessionData>>,
+}
+
+impl From<AccessTokenClaims> for Authentication {
+    fn from(claims: AccessTokenClaims) -> Self {
+        let authenticated_at = DateTime::from_timestamp(claims.iat, 0).unwrap_or_default();
+
+        Authentication {
+            factors: claims
+                .amr
+                .into_iter()
+                .map(|method_id| AuthenticationFactor {
+                    method_id,
+                    provider_id: None,
+                    authenticated_at,
+                })
+                .collect(),
+            method_id: claims.method_id,
+            provider_id: claims.provider_id,
+            user_id: claims.sub,
+            session_id: None,
+            session_registered_at: None,
+            provider_session_id: None,
+            scopes: None,
+            method_only: false,
+        }
+    }
+}
+
+impl BearerSessionStorage {
+    pub(crate) fn new(authentication: Authentication) -> Self {
+        let mut session_data = SessionData::default();
+        session_data.base.authentication = Some(authentication);
+
+        Self {
+            session_data: Arc::new(Mutex::new(session_data)),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionStorage for BearerSessionStorage {
+    fn data(&self) -> Arc<Mutex<SessionData>> {
+        self.session_data.clone()
+    }
+
+    fn is_persistent(&self) -> bool {
+        false
+    }
+
+    async fn update(&self) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn renew(&self) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn purge(&self) -> Result<(), SessionError> {
+        let mut session_data = self
+            .session_data
+            .lock()
+            .map_err(|err| SessionError::Lock(err.to_string()))?;
+        *session_data = SessionData::default();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+
+    use super::{
+        BearerOptions, BearerTokenManager, CreateRefreshToken, DecodingKey, EncodingKey,
+        RefreshToken, RefreshTokenStorage,
+    };
+    use crate::{
+        error::{ShieldError, StorageError},
+        session::Authentication,
+    };
+
+    #[derive(Clone, Default)]
+    pub(crate) struct TestRefreshTokenStorage {
+        tokens: Arc<Mutex<Vec<RefreshToken>>>,
+    }
+
+    impl TestRefreshTokenStorage {
+        pub(crate) fn tokens(&self) -> Vec<RefreshToken> {
+            self.tokens.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl RefreshTokenStorage for TestRefreshTokenStorage {
+        async fn refresh_token_by_hash(
+            &self,
+            token_hash: &str,
+        ) -> Result<Option<RefreshToken>, StorageError> {
+            // Yield, so concurrent refreshes both read the token before either rotates it.
+            tokio::task::yield_now().await;
+
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|token| token.token_hash == token_hash)
+                .cloned())
+        }
+
+        async fn create_refresh_token(
+            &self,
+            token: CreateRefreshToken,
+        ) -> Result<RefreshToken, StorageError> {
+            let mut tokens = self.tokens.lock().unwrap();
+
+            let token = RefreshToken {
+                id: tokens.len().to_string(),
+                token_hash: token.token_hash,
+                family_id: token.family_id,
+                user_id: token.user_id,
+                method_id: token.method_id,
+                provider_id: token.provider_id,
+                factors: token.factors,
+                expired_at: token.expired_at,
+                rotated_at: None,
+            };
+            tokens.push(token.clone());
+
+            Ok(token)
+        }
+
+        async fn rotate_refresh_token(
+            &self,
+            refresh_token_id: &str,
+            rotated_at: DateTime<Utc>,
+        ) -> Result<bool, StorageError> {
+            let mut tokens = self.tokens.lock().unwrap();
+
+            match tokens
+                .iter_mut()
+                .find(|token| token.id == refresh_token_id && token.rotated_at.is_none())
+            {
+                Some(token) => {
+                    token.rotated_at = Some(rotated_at);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn delete_refresh_token_family(&self, family_id: &str) -> Result<(), StorageError> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .retain(|token| token.family_id != family_id);
+
+            Ok(())
+        }
+
+        async fn delete_user_refresh_tokens(&self, user_id: &str) -> Result<(), StorageError> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .retain(|token| token.user_id != user_id);
+
+            Ok(())
+        }
+    }
+
+    pub(crate) fn options() -> BearerOptions {
+        BearerOptions::builder()
+            .encoding_key(EncodingKey::from_secret(b"secret"))
+            .decoding_keys([DecodingKey::from_secret(b"secret")])
+            .issuer("shield")
+            .build()
+    }
+
+    fn manager() -> BearerTokenManager {
+        BearerTokenManager::new(options(), Arc::new(TestRefreshTokenStorage::default()))
+    }
+
+    fn authentication() -> Authentication {
+        serde_json::from_str(
+            r#"{"method_id":"credentials","provider_id":null,"user_id":"user","factors":[]}"#,
+        )
+        .expect("Authentication should deserialize.")
+    }
+
+    #[tokio::test]
+    async fn test_issue_and_verify() -> Result<(), ShieldError> {
+        let manager = manager();
+        let tokens = manager.issue(&authentication()).await?;
+
+        let claims = manager.verify(&tokens.access_token)?;
+        assert_eq!("user", claims.sub);
+        assert_eq!("credentials", claims.method_id);
+        assert_eq!(Some("shield".to_owned()), claims.iss);
+
+        assert!(matches!(
+            manager.verify("invalid"),
+            Err(ShieldError::Unauthorized)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotation_and_reuse() -> Result<(), ShieldError> {
+        let manager = manager();
+        let tokens = manager.issue(&authentication()).await?;
+
+        let rotated = manager.refresh(&tokens.refresh_token).await?;
+        assert_ne!(tokens.refresh_token, rotated.refresh_token);
+
+        // Reusing the rotated refresh token revokes the whole family.
+        assert!(matches!(
+            manager.refresh(&tokens.refresh_token).await,
+            Err(ShieldError::Unauthorized)
+        ));
+        assert!(matches!(
+            manager.refresh(&rotated.refresh_token).await,
+            Err(ShieldError::Unauthorized)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_refresh() -> Result<(), ShieldError> {
+        let manager = manager();
+        let tokens = manager.issue(&authentication()).await?;
+
+        let (first, second) = tokio::join!(
+            manager.refresh(&tokens.refresh_token),
+            manager.refresh(&tokens.refresh_token)
+        );
+
+        // Both refreshes read the unrotated token, the one losing the rotation is treated as reuse
+        // and revokes the family.
+        assert!(first.is_ok() != second.is_ok());
+        for rotated in [first, second].into_iter().flatten() {
+            assert!(matches!(
+                manager.refresh(&rotated.refresh_token).await,
+                Err(ShieldError::Unauthorized)
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_revoke_user() -> Result<(), ShieldError> {
+        let manager = manager();
+        let first = manager.issue(&authentication()).await?;
+        let second = manager.issue(&authentication()).await?;
+
+        manager.revoke_user("user").await?;
+
+        for tokens in [first, second] {
+            assert!(matches!(
+                manager.refresh(&tokens.refresh_token).await,
+                Err(ShieldError::Unauthorized)
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use ordered_hash_map::OrderedHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{MethodError, ShieldError, StorageError},
+    hook::{ConnectionEvent, Hook, Hooks},
+    method::ErasedMethod,
+};
+
+/// Connection between a user and a method, e.g. a linked OAuth account or a passkey.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UserConnection {
+    pub id: String,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    /// Identifier of the user at the provider, e.g. an account ID or email address.
+    pub identifier: Option<String>,
+    /// Whether the user can sign in with this connection.
+    pub sign_in: bool,
+    /// Whether the connection can be unlinked by the user.
+    pub unlinkable: bool,
+}
+
+pub(crate) async fn list_user_connections(
+    methods: &OrderedHashMap<String, Arc<dyn ErasedMethod>>,
+    user_id: &str,
+) -> Result<Vec<UserConnection>, ShieldError> {
+    let mut connections = vec![];
+
+    for method in methods.values() {
+        connections.extend(method.erased_list_user_connections(user_id).await?);
+    }
+
+    Ok(connections)
+}
+
+pub(crate) async fn unlink_user_connection(
+    methods: &OrderedHashMap<String, Arc<dyn ErasedMethod>>,
+    hooks: &Hooks,
+    user_id: &str,
+    method_id: &str,
+    connection_id: &str,
+) -> Result<(), ShieldError> {
+    let method = methods
+        .get(method_id)
+        .ok_or(ShieldError::Method(MethodError::NotFound(
+            method_id.to_owned(),
+        )))?;
+
+    let connections = list_user_connections(methods, user_id).await?;
+
+    let connection = connections
+        .iter()
+        .find(|connection| connection.method_id == method_id && connection.id == connection_id)
+        .ok_or(ShieldError::Storage(StorageError::NotFound(
+            "Connection".to_owned(),
+            connection_id.to_owned(),
+        )))?;
+
+    if !connection.unlinkable {
+        return Err(ShieldError::Validation(
+            "This connection can not be unlinked.".to_owned(),
+        ));
+    }
+
+    if connection.sign_in
+        && !connections.iter().any(|other| {
+            other.sign_in && !(other.method_id == method_id && other.id == connection_id)
+        })
+    {
+        return Err(ShieldError::Validation(
+            "This connection is your last sign-in method and can not be unlinked.".to_owned(),
+        ));
+    }
+
+    method
+        .erased_unlink_user_connection(user_id, connection_id)
+        .await?;
+
+    hooks
+        .connection_unlinked(&ConnectionEvent {
+            method_id: connection.method_id.clone(),
+            provider_id: connection.provider_id.clone(),
+            user_id: user_id.to_owned(),
+            connection_id: connection.id.clone(),
+            identifier: connection.identifier.clone(),
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use ordered_hash_map::OrderedHashMap;
+
+    use crate::{
+        action::MethodAction,
+        error::ShieldError,
+        hook::Hooks,
+        method::{ErasedMethod, Method},
+        provider::Provider,
+    };
+
+    use super::{UserConnection, unlink_user_connection};
+
+    struct TestProvider;
+
+    impl Provider for TestProvider {
+        fn method_id(&self) -> String {
+            "test".to_owned()
+        }
+
+        fn id(&self) -> Option<String> {
+            None
+        }
+
+        fn name(&self) -> String {
+            "Test".to_owned()
+        }
+    }
+
+    struct TestMethod {
+        id: String,
+        connections: Mutex<Vec<UserConnection>>,
+    }
+
+    impl TestMethod {
+        fn new(id: &str, connections: Vec<(&str, bool, bool)>) -> Self {
+            Self {
+                id: id.to_owned(),
+                connections: Mutex::new(
+                    connections
+                        .into_iter()
+                        .map(|(connection_id, sign_in, unlinkable)| UserConnection {
+                            id: connection_id.to_owned(),
+                            method_id: id.to_owned(),
+                            provider_id: None,
+                            identifier: None,
+                            sign_in,
+                            unlinkable,
+                        })
+                        .collect(),
+                ),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Method for TestMethod {
+        type Provider = TestProvider;
+        type Connection = ();
+        type Session = ();
+
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+            vec![]
+        }
+
+        async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+            Ok(vec![TestProvider])
+        }
+
+        async fn user_connections(
+            &self,
+            _user_id: &str,
+            _provider_id: Option<&str>,
+        ) -> Result<Vec<Self::Connection>, ShieldError> {
+            Ok(vec![])
+        }
+
+        async fn list_user_connections(
+            &self,
+            _user_id: &str,
+        ) -> Result<Vec<UserConnection>, ShieldError> {
+            Ok(self.connections.lock().unwrap().clone())
+        }
+
+        async fn unlink_user_connection(
+            &self,
+            _user_id: &str,
+            connection_id: &str,
+        ) -> Result<(), ShieldError> {
+            self.connections
+                .lock()
+                .unwrap()
+                .retain(|connection| connection.id != connection_id);
+
+            Ok(())
+        }
+    }
+
+    crate::erased_method!(TestMethod);
+
+    fn methods(methods: Vec<TestMethod>) -> OrderedHashMap<String, Arc<dyn ErasedMethod>> {
+        let mut map = OrderedHashMap::<String, Arc<dyn ErasedMethod>>::new();
+        for method in methods {
+            map.insert(method.id(), Arc::new(method));
+        }
+        map
+    }
+
+    #[tokio::test]
+    async fn test_unlink_user_connection() {
+        let hooks = Hooks::default();
+        let methods = methods(vec![
+            TestMethod::new("credentials", vec![("password", true, false)]),
+            TestMethod::new(
+                "oauth",
+                vec![("github", true, true), ("google", true, true)],
+            ),
+        ]);
+
+        unlink_user_connection(&methods, &hooks, "user", "oauth", "github")
+            .await
+            .expect("Connection should be unlinked.");
+        unlink_user_connection(&methods, &hooks, "user", "oauth", "google")
+            .await
+            .expect(
+                "Connection should be unlinked, as the user can still sign in with credentials.",
+            );
+
+        assert!(matches!(
+            unlink_user_connection(&methods, &hooks, "user", "credentials", "password").await,
+            Err(ShieldError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unlink_last_sign_in_connection() {
+        let hooks = Hooks::default();
+        let methods = methods(vec![
+            TestMethod::new("totp", vec![("totp", false, true)]),
+            TestMethod::new("oauth", vec![("github", true, true)]),
+        ]);
+
+        assert!(matches!(
+            unlink_user_connection(&methods, &hooks, "user", "oauth", "github").await,
+            Err(ShieldError::Validation(_))
+        ));
+
+        unlink_user_connection(&methods, &hooks, "user", "totp", "totp")
+            .await
+            .expect("Connection without sign-in should be unlinked.");
+    }
+}
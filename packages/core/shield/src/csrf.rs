@@ -0,0 +1,303 @@
+use rand::distr::{Alphanumeric, SampleString};
+use serde_json::Value;
+
+use crate::{
+    error::{ActionError, ShieldError},
+    form::{Input, InputType, InputTypeHidden, InputValue},
+    request::{Request, RequestMethod},
+};
+
+/// Name of the hidden input which contains the CSRF token.
+pub const CSRF_TOKEN_INPUT_NAME: &str = "csrf_token";
+
+/// Header which contains the CSRF token, for clients which do not submit the action forms.
+pub const CSRF_TOKEN_HEADER_NAME: &str = "x-csrf-token";
+
+/// Protection against cross-site request forgery of actions called with an unsafe method, e.g.
+/// `POST`.
+///
+/// Requests of sessions without cookies, e.g. for bearer tokens, are not checked.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum CsrfProtection {
+    /// Require the per-session token, which is added as hidden input to every action form.
+    #[default]
+    Token,
+    /// Require the request to come from the same origin or a trusted origin, based on the
+    /// `Sec-Fetch-Site` and `Origin` headers. Intended for JSON clients which do not submit the
+    /// action forms.
+    Origin {
+        /// Origins which are allowed to call actions, e.g. `https://app.example.com`.
+        trusted_origins: Vec<String>,
+    },
+    Disabled,
+}
+
+pub(crate) fn generate_csrf_token() -> String {
+    Alphanumeric.sample_string(&mut rand::rng(), 32)
+}
+
+pub(crate) fn csrf_token_input(csrf_token: &str) -> Input {
+    Input {
+        name: CSRF_TOKEN_INPUT_NAME.to_owned(),
+        label: None,
+        r#type: InputType::Hidden(InputTypeHidden::default()),
+        value: Some(InputValue::String {
+            value: csrf_token.to_owned(),
+        }),
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+/// Verify that the request uses the method of the action.
+///
+/// `HEAD` requests are allowed for `GET` actions. Requests without a method, e.g. from server functions, are
+/// assumed to use the method of the action.
+pub(crate) fn verify_method(
+    action_id: &str,
+    action_method: &RequestMethod,
+    request: &Request,
+) -> Result<(), ShieldError> {
+    match &request.context.method {
+        Some(method)
+            if method != action_method
+                && !(*method == RequestMethod::Head && *action_method == RequestMethod::Get) =>
+        {
+            Err(ActionError::MethodNotAllowed(action_id.to_owned(), method.clone()).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Verify the request and remove the CSRF token from its form data.
+///
+/// Whether the request is checked depends on the method of the action, not the method of the request, so an unsafe
+/// action can't be called without a token by using a safe method. See [`verify_method`].
+pub(crate) fn verify_csrf(
+    protection: &CsrfProtection,
+    csrf_token: Option<&str>,
+    action_method: RequestMethod,
+    request: &mut Request,
+) -> Result<(), ShieldError> {
+    let submitted_token = match &mut request.form_data {
+        Value::Object(form_data) => match form_data.remove(CSRF_TOKEN_INPUT_NAME) {
+            Some(Value::String(token)) => Some(token),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    if matches!(
+        action_method,
+        RequestMethod::Get | RequestMethod::Head | RequestMethod::Options
+    ) {
+        return Ok(());
+    }
+
+    match protection {
+        CsrfProtection::Token => {
+            let submitted_token = submitted_token
+                .as_deref()
+                .or_else(|| request.context.header(CSRF_TOKEN_HEADER_NAME));
+
+            match (csrf_token, submitted_token) {
+                (Some(csrf_token), Some(submitted_token))
+                    if constant_time_eq(csrf_token.as_bytes(), submitted_token.as_bytes()) =>
+                {
+                    Ok(())
+                }
+                _ => Err(ShieldError::Forbidden("Invalid CSRF token.".to_owned())),
+            }
+        }
+        CsrfProtection::Origin { trusted_origins } => {
+            let origin = request.context.origin();
+
+            if let Some(origin) = origin
+                && trusted_origins
+                    .iter()
+                    .any(|trusted_origin| trusted_origin.trim_end_matches('/') == origin)
+            {
+                return Ok(());
+            }
+
+            // Browsers which send the `Sec-Fetch-Site` header tell whether the request is cross-site.
+            if let Some(fetch_site) = request.context.header("sec-fetch-site") {
+                return match fetch_site {
+                    "same-origin" | "none" => Ok(()),
+                    _ => Err(ShieldError::Forbidden("Cross-origin request.".to_owned())),
+                };
+            }
+
+            // Requests without an `Origin` header are not sent by a browser on behalf of another site.
+            let Some(origin) = origin else {
+                return Ok(());
+            };
+
+            let origin_host = origin.split_once("://").map(|(_, host)| host);
+            if origin_host.is_some() && origin_host == request.context.header("host") {
+                Ok(())
+            } else {
+                Err(ShieldError::Forbidden("Cross-origin request.".to_owned()))
+            }
+        }
+        CsrfProtection::Disabled => Ok(()),
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |result, (a, b)| result | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{CSRF_TOKEN_INPUT_NAME, CsrfProtection, verify_csrf, verify_method};
+    use crate::{
+        error::{ActionError, ShieldError},
+        request::{Request, RequestContext, RequestMethod},
+    };
+
+    fn request(method: RequestMethod, headers: &[(&str, &str)], form_data: Value) -> Request {
+        Request::new(Value::Null, form_data).with_context(RequestContext::new(
+            Some(method),
+            None,
+            headers
+                .iter()
+                .map(|(name, value)| ((*name).to_owned(), (*value).to_owned())),
+            &[],
+        ))
+    }
+
+    #[test]
+    fn test_token() {
+        let protection = CsrfProtection::Token;
+        let verify = |mut request: Request| {
+            verify_csrf(
+                &protection,
+                Some("token"),
+                RequestMethod::Post,
+                &mut request,
+            )
+            .map(|_| request.form_data)
+        };
+
+        assert_eq!(
+            json!({"email": "test@example.com"}),
+            verify(request(
+                RequestMethod::Post,
+                &[],
+                json!({CSRF_TOKEN_INPUT_NAME: "token", "email": "test@example.com"})
+            ))
+            .expect("Token should be valid.")
+        );
+        assert!(
+            verify(request(
+                RequestMethod::Post,
+                &[("X-CSRF-Token", "token")],
+                Value::Null
+            ))
+            .is_ok()
+        );
+        assert!(
+            verify_csrf(
+                &protection,
+                Some("token"),
+                RequestMethod::Get,
+                &mut request(RequestMethod::Get, &[], Value::Null)
+            )
+            .is_ok()
+        );
+        assert!(matches!(
+            verify(request(RequestMethod::Get, &[], Value::Null)),
+            Err(ShieldError::Forbidden(_))
+        ));
+        assert!(matches!(
+            verify(request(RequestMethod::Post, &[], Value::Null)),
+            Err(ShieldError::Forbidden(_))
+        ));
+        assert!(matches!(
+            verify(request(
+                RequestMethod::Post,
+                &[],
+                json!({CSRF_TOKEN_INPUT_NAME: "other"})
+            )),
+            Err(ShieldError::Forbidden(_))
+        ));
+        assert!(matches!(
+            verify_csrf(
+                &protection,
+                None,
+                RequestMethod::Post,
+                &mut request(RequestMethod::Post, &[], json!({CSRF_TOKEN_INPUT_NAME: ""}))
+            ),
+            Err(ShieldError::Forbidden(_))
+        ));
+    }
+
+    #[test]
+    fn test_method() {
+        let verify = |action_method: RequestMethod, method: RequestMethod| {
+            verify_method(
+                "sign-out",
+                &action_method,
+                &request(method, &[], Value::Null),
+            )
+        };
+
+        assert!(verify(RequestMethod::Post, RequestMethod::Post).is_ok());
+        assert!(verify(RequestMethod::Get, RequestMethod::Get).is_ok());
+        assert!(verify(RequestMethod::Get, RequestMethod::Head).is_ok());
+        assert!(matches!(
+            verify(RequestMethod::Post, RequestMethod::Get),
+            Err(ShieldError::Action(ActionError::MethodNotAllowed(
+                _,
+                RequestMethod::Get
+            )))
+        ));
+        assert!(matches!(
+            verify(RequestMethod::Post, RequestMethod::Head),
+            Err(ShieldError::Action(ActionError::MethodNotAllowed(_, _)))
+        ));
+        assert!(
+            verify_method(
+                "sign-out",
+                &RequestMethod::Post,
+                &Request::new(Value::Null, Value::Null)
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_origin() {
+        let protection = CsrfProtection::Origin {
+            trusted_origins: vec!["https://app.example.com/".to_owned()],
+        };
+        let verify = |headers: &[(&str, &str)]| {
+            verify_csrf(
+                &protection,
+                None,
+                RequestMethod::Post,
+                &mut request(RequestMethod::Post, headers, Value::Null),
+            )
+            .is_ok()
+        };
+
+        assert!(verify(&[]));
+        assert!(verify(&[("Sec-Fetch-Site", "same-origin")]));
+        assert!(!verify(&[("Sec-Fetch-Site", "cross-site")]));
+        assert!(verify(&[
+            ("Sec-Fetch-Site", "cross-site"),
+            ("Origin", "https://app.example.com")
+        ]));
+        assert!(verify(&[
+            ("Origin", "https://auth.example.com"),
+            ("Host", "auth.example.com")
+        ]));
+        assert!(!verify(&[
+            ("Origin", "https://evil.example"),
+            ("Host", "auth.example.com")
+        ]));
+    }
+}
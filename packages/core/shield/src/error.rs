@@ -1,5 +1,9 @@
+use chrono::TimeDelta;
+use serde::Serialize;
 use thiserror::Error;
 
+use crate::request::RequestMethod;
+
 #[derive(Debug, Error)]
 pub enum MethodError {
     #[error("method `{0}` not found")]
@@ -10,6 +14,8 @@ pub enum MethodError {
 pub enum ActionError {
     #[error("action `{0}` not found")]
     NotFound(String),
+    #[error("action `{0}` does not support method `{1}`")]
+    MethodNotAllowed(String, RequestMethod),
 }
 
 #[derive(Debug, Error)]
@@ -59,6 +65,30 @@ pub enum SessionError {
     Serialization(String),
 }
 
+/// Error of an OAuth 2.0 endpoint, which is returned to the client as JSON, see
+/// [RFC 6749 section 5.2](https://www.rfc-editor.org/rfc/rfc6749#section-5.2).
+#[derive(Debug, Error, Serialize)]
+#[error("{}", .error_description.as_deref().unwrap_or(.error))]
+pub struct OauthError {
+    pub error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_description: Option<String>,
+}
+
+impl OauthError {
+    pub fn new(error: &str, error_description: &str) -> Self {
+        Self {
+            error: error.to_owned(),
+            error_description: Some(error_description.to_owned()),
+        }
+    }
+
+    /// Whether the client failed to authenticate, which is reported with status 401 instead of 400.
+    pub fn is_invalid_client(&self) -> bool {
+        self.error == "invalid_client"
+    }
+}
+
 #[derive(Debug, Error)]
 
 pub enum ShieldError {
@@ -80,4 +110,28 @@ pub enum ShieldError {
     Validation(String),
     #[error("Unauthorized")]
     Unauthorized,
+    #[error("{0}")]
+    Forbidden(String),
+    #[error(transparent)]
+    Oauth(#[from] OauthError),
+    /// Too many failed attempts. Contains the time after which the attempt can be retried.
+    #[error("Too many attempts. Try again in {} seconds.", retry_after_seconds(.0))]
+    RateLimited(TimeDelta),
+}
+
+impl ShieldError {
+    /// Seconds after which a rate limited attempt can be retried, e.g. for a `Retry-After` header.
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            ShieldError::RateLimited(retry_after) => Some(retry_after_seconds(retry_after)),
+            _ => None,
+        }
+    }
+}
+
+fn retry_after_seconds(retry_after: &TimeDelta) -> u64 {
+    // Round up, so the attempt is not retried too early.
+    let seconds = retry_after.num_seconds() + i64::from(retry_after.subsec_nanos() > 0);
+
+    seconds.max(1) as u64
 }
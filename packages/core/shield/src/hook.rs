@@ -0,0 +1,441 @@
+use std::{fmt::Debug, future::Future, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::{
+    error::ShieldError, provider::Provider, session::Authentication,
+    session_registry::RegisteredSession, user::User,
+};
+
+#[derive(Clone, Debug)]
+pub struct SignInEvent {
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub user_id: String,
+}
+
+impl From<&Authentication> for SignInEvent {
+    fn from(authentication: &Authentication) -> Self {
+        Self {
+            method_id: authentication.method_id.clone(),
+            provider_id: authentication.provider_id.clone(),
+            user_id: authentication.user_id.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SignInFailedEvent {
+    pub action_id: String,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    /// User of a pending authentication, e.g. when the second factor is invalid.
+    pub user_id: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct SignUpEvent {
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+}
+
+impl SignUpEvent {
+    pub fn new(provider: &dyn Provider) -> Self {
+        Self {
+            method_id: provider.method_id(),
+            provider_id: provider.id(),
+            name: None,
+            email: None,
+            phone_number: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SignOutEvent {
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub user_id: String,
+}
+
+impl From<&Authentication> for SignOutEvent {
+    fn from(authentication: &Authentication) -> Self {
+        Self {
+            method_id: authentication.method_id.clone(),
+            provider_id: authentication.provider_id.clone(),
+            user_id: authentication.user_id.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UserEvent {
+    pub user_id: String,
+}
+
+/// Connection which is about to be linked to a user.
+#[derive(Clone, Debug)]
+pub struct ConnectionLinkEvent {
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub user_id: String,
+    /// Identifier of the user at the provider, e.g. the subject of an OpenID Connect provider.
+    pub identifier: Option<String>,
+}
+
+impl ConnectionLinkEvent {
+    pub fn new(provider: &dyn Provider, user_id: &str, identifier: Option<&str>) -> Self {
+        Self {
+            method_id: provider.method_id(),
+            provider_id: provider.id(),
+            user_id: user_id.to_owned(),
+            identifier: identifier.map(ToOwned::to_owned),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ConnectionEvent {
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub user_id: String,
+    pub connection_id: String,
+    /// Identifier of the user at the provider, e.g. the subject of an OpenID Connect provider.
+    pub identifier: Option<String>,
+}
+
+impl ConnectionEvent {
+    pub fn new(
+        provider: &dyn Provider,
+        user_id: &str,
+        connection_id: &str,
+        identifier: Option<&str>,
+    ) -> Self {
+        Self {
+            method_id: provider.method_id(),
+            provider_id: provider.id(),
+            user_id: user_id.to_owned(),
+            connection_id: connection_id.to_owned(),
+            identifier: identifier.map(ToOwned::to_owned),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SessionEvent {
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub user_id: String,
+    pub session_id: String,
+}
+
+impl From<&RegisteredSession> for SessionEvent {
+    fn from(session: &RegisteredSession) -> Self {
+        Self {
+            method_id: session.method_id.clone(),
+            provider_id: session.provider_id.clone(),
+            user_id: session.user_id.clone(),
+            session_id: session.id.clone(),
+        }
+    }
+}
+
+/// Hook into actions and user changes, registered with [`Shield::with_hook`](crate::Shield::with_hook).
+///
+/// The `before_*` functions can prevent the action by returning an error, which is returned to the client. The other
+/// functions observe changes which have already been made, so an error is returned to the client without undoing the
+/// change.
+#[async_trait]
+pub trait Hook: Send + Sync {
+    async fn before_sign_in(&self, _event: &SignInEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn after_sign_in(&self, _event: &SignInEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn sign_in_failed(&self, _event: &SignInFailedEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn before_sign_up(&self, _event: &SignUpEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn after_sign_up(&self, _event: &SignUpEvent, _user_id: &str) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn before_sign_out(&self, _event: &SignOutEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn after_sign_out(&self, _event: &SignOutEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn user_created(&self, _event: &UserEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn before_user_update(&self, _event: &UserEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn user_updated(&self, _event: &UserEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn before_user_delete(&self, _event: &UserEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn user_deleted(&self, _event: &UserEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn before_connection_link(
+        &self,
+        _event: &ConnectionLinkEvent,
+    ) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn connection_linked(&self, _event: &ConnectionEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn connection_unlinked(&self, _event: &ConnectionEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+
+    async fn session_revoked(&self, _event: &SessionEvent) -> Result<(), ShieldError> {
+        Ok(())
+    }
+}
+
+/// Hooks registered on [`Shield`](crate::Shield), called in the order they were added.
+///
+/// Methods receive the hooks in [`Request::hooks`](crate::Request::hooks).
+#[derive(Clone, Default)]
+pub struct Hooks {
+    hooks: Arc<Vec<Arc<dyn Hook>>>,
+}
+
+impl Hooks {
+    pub(crate) fn push(&mut self, hook: Arc<dyn Hook>) {
+        Arc::make_mut(&mut self.hooks).push(hook);
+    }
+
+    /// Add a hook which is called before the hooks added before it.
+    pub(crate) fn push_front(&mut self, hook: Arc<dyn Hook>) {
+        Arc::make_mut(&mut self.hooks).insert(0, hook);
+    }
+
+    /// Create a user for a sign up, calling the sign up and user created hooks around it.
+    pub async fn sign_up<U, E, F>(
+        &self,
+        event: SignUpEvent,
+        create_user: F,
+    ) -> Result<U, ShieldError>
+    where
+        U: User,
+        ShieldError: From<E>,
+        F: Future<Output = Result<U, E>> + Send,
+    {
+        self.before_sign_up(&event).await?;
+
+        let user = create_user.await?;
+        let user_id = user.id();
+
+        self.user_created(&UserEvent {
+            user_id: user_id.clone(),
+        })
+        .await?;
+        self.after_sign_up(&event, &user_id).await?;
+
+        Ok(user)
+    }
+}
+
+impl Debug for Hooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hooks")
+            .field("len", &self.hooks.len())
+            .finish()
+    }
+}
+
+macro_rules! call_hooks {
+    ($( $name:ident ( $( $arg:ident : $type:ty ),+ ) ),+ $(,)?) => {
+        #[async_trait]
+        impl Hook for Hooks {
+            $(
+                async fn $name(&self, $( $arg: $type ),+) -> Result<(), ShieldError> {
+                    for hook in self.hooks.iter() {
+                        hook.$name($( $arg ),+).await?;
+                    }
+
+                    Ok(())
+                }
+            )+
+        }
+    };
+}
+
+call_hooks!(
+    before_sign_in(event: &SignInEvent),
+    after_sign_in(event: &SignInEvent),
+    sign_in_failed(event: &SignInFailedEvent),
+    before_sign_up(event: &SignUpEvent),
+    after_sign_up(event: &SignUpEvent, user_id: &str),
+    before_sign_out(event: &SignOutEvent),
+    after_sign_out(event: &SignOutEvent),
+    user_created(event: &UserEvent),
+    before_user_update(event: &UserEvent),
+    user_updated(event: &UserEvent),
+    before_user_delete(event: &UserEvent),
+    user_deleted(event: &UserEvent),
+    before_connection_link(event: &ConnectionLinkEvent),
+    connection_linked(event: &ConnectionEvent),
+    connection_unlinked(event: &ConnectionEvent),
+    session_revoked(event: &SessionEvent),
+);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+
+    use super::{Hook, Hooks, SignUpEvent, UserEvent};
+    use crate::{
+        error::{ShieldError, StorageError},
+        user::{User, tests::TestUser},
+    };
+
+    struct RecordHook {
+        name: &'static str,
+        veto: bool,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Hook for RecordHook {
+        async fn before_sign_up(&self, event: &SignUpEvent) -> Result<(), ShieldError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}:before_sign_up", self.name));
+
+            if self.veto {
+                return Err(ShieldError::Validation(format!(
+                    "Email address `{}` is not allowed.",
+                    event.email.as_deref().unwrap_or_default()
+                )));
+            }
+
+            Ok(())
+        }
+
+        async fn after_sign_up(
+            &self,
+            _event: &SignUpEvent,
+            user_id: &str,
+        ) -> Result<(), ShieldError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}:after_sign_up:{user_id}", self.name));
+
+            Ok(())
+        }
+
+        async fn user_created(&self, event: &UserEvent) -> Result<(), ShieldError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}:user_created:{}", self.name, event.user_id));
+
+            Ok(())
+        }
+    }
+
+    fn sign_up_event() -> SignUpEvent {
+        SignUpEvent {
+            method_id: "email".to_owned(),
+            provider_id: None,
+            name: None,
+            email: Some("test@example.com".to_owned()),
+            phone_number: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_up() {
+        let calls = Arc::new(Mutex::new(vec![]));
+
+        let mut hooks = Hooks::default();
+        for name in ["a", "b"] {
+            hooks.push(Arc::new(RecordHook {
+                name,
+                veto: false,
+                calls: calls.clone(),
+            }));
+        }
+
+        let user = hooks
+            .sign_up(sign_up_event(), async {
+                Ok::<_, StorageError>(TestUser::new("user"))
+            })
+            .await
+            .expect("Sign up should succeed.");
+
+        assert_eq!("user", user.id());
+        assert_eq!(
+            vec![
+                "a:before_sign_up",
+                "b:before_sign_up",
+                "a:user_created:user",
+                "b:user_created:user",
+                "a:after_sign_up:user",
+                "b:after_sign_up:user",
+            ],
+            *calls.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_up_veto() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let created = Arc::new(Mutex::new(false));
+
+        let mut hooks = Hooks::default();
+        hooks.push(Arc::new(RecordHook {
+            name: "a",
+            veto: true,
+            calls: calls.clone(),
+        }));
+        hooks.push(Arc::new(RecordHook {
+            name: "b",
+            veto: false,
+            calls: calls.clone(),
+        }));
+
+        let result = hooks
+            .sign_up(sign_up_event(), async {
+                *created.lock().unwrap() = true;
+                Ok::<_, StorageError>(TestUser::new("user"))
+            })
+            .await;
+
+        assert!(matches!(result, Err(ShieldError::Validation(_))));
+        assert!(!*created.lock().unwrap());
+        assert_eq!(vec!["a:before_sign_up"], *calls.lock().unwrap());
+    }
+}
@@ -1,14 +1,22 @@
 mod action;
 mod actions;
+mod audit;
+mod bearer;
+mod connection;
+mod csrf;
 mod error;
 mod form;
+mod hook;
 mod method;
 mod options;
 mod path;
 mod provider;
+mod rate_limit;
+mod refresh;
 mod request;
 mod response;
 mod session;
+mod session_registry;
 mod shield;
 mod shield_dyn;
 mod storage;
@@ -16,15 +24,23 @@ mod user;
 
 pub use action::*;
 pub use actions::*;
+pub use audit::*;
+pub use bearer::*;
+pub use connection::*;
+pub use csrf::*;
 pub use error::*;
 pub use form::*;
+pub use hook::*;
 pub use method::*;
 pub use options::*;
 pub use path::*;
 pub use provider::*;
+pub use rate_limit::*;
+pub use refresh::*;
 pub use request::*;
 pub use response::*;
 pub use session::*;
+pub use session_registry::*;
 pub use shield::*;
 pub use shield_dyn::*;
 pub use storage::*;
@@ -5,8 +5,10 @@ use serde::{Serialize, de::DeserializeOwned};
 
 use crate::{
     action::{ErasedMethodAction, MethodAction},
+    connection::UserConnection,
     error::{SessionError, ShieldError},
     provider::Provider,
+    session::Authentication,
 };
 
 #[async_trait]
@@ -46,6 +48,38 @@ pub trait Method: Send + Sync {
         user: &str,
         provider_id: Option<&str>,
     ) -> Result<Vec<Self::Connection>, ShieldError>;
+
+    /// Connections of the user in a serializable form, used to manage connected accounts.
+    async fn list_user_connections(
+        &self,
+        _user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        Ok(vec![])
+    }
+
+    /// Remove a connection of the user, e.g. by deleting it from storage and revoking its tokens.
+    async fn unlink_user_connection(
+        &self,
+        _user_id: &str,
+        _connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        Err(ShieldError::Validation(
+            "This connection can not be unlinked.".to_owned(),
+        ))
+    }
+
+    /// Action the user has to complete after signing in with another method, if any.
+    async fn second_factor_action_id(&self, _user_id: &str) -> Result<Option<String>, ShieldError> {
+        Ok(None)
+    }
+
+    /// Authenticate a request with a token from the `Authorization` header, e.g. an API key.
+    async fn authenticate_token(
+        &self,
+        _token: &str,
+    ) -> Result<Option<Authentication>, ShieldError> {
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -71,6 +105,27 @@ pub trait ErasedMethod: Send + Sync {
         provider_id: Option<&str>,
     ) -> Result<Vec<Box<dyn Any + Send + Sync>>, ShieldError>;
 
+    async fn erased_list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError>;
+
+    async fn erased_unlink_user_connection(
+        &self,
+        user_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError>;
+
+    async fn erased_second_factor_action_id(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<String>, ShieldError>;
+
+    async fn erased_authenticate_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<Authentication>, ShieldError>;
+
     fn erased_deserialize_session(
         &self,
         value: Option<&str>,
@@ -134,6 +189,35 @@ macro_rules! erased_method {
                     .collect())
             }
 
+            async fn erased_list_user_connections(
+                &self,
+                user_id: &str,
+            ) -> Result<Vec<$crate::UserConnection>, $crate::ShieldError> {
+                self.list_user_connections(user_id).await
+            }
+
+            async fn erased_unlink_user_connection(
+                &self,
+                user_id: &str,
+                connection_id: &str,
+            ) -> Result<(), $crate::ShieldError> {
+                self.unlink_user_connection(user_id, connection_id).await
+            }
+
+            async fn erased_second_factor_action_id(
+                &self,
+                user_id: &str,
+            ) -> Result<Option<String>, $crate::ShieldError> {
+                self.second_factor_action_id(user_id).await
+            }
+
+            async fn erased_authenticate_token(
+                &self,
+                token: &str,
+            ) -> Result<Option<$crate::Authentication>, $crate::ShieldError> {
+                self.authenticate_token(token).await
+            }
+
             fn erased_deserialize_session(
                 &self,
                 value: Option<&str>
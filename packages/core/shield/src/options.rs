@@ -1,8 +1,37 @@
+use std::net::IpAddr;
+
 use bon::Builder;
 
+use crate::{csrf::CsrfProtection, session::AssuranceLevel};
+
 #[derive(Builder, Clone, Debug)]
 #[builder(on(String, into), state_mod(vis = "pub(crate)"))]
-pub struct ShieldOptions {}
+pub struct ShieldOptions {
+    /// Assurance level required for [`Shield::user`](crate::Shield::user) to return the user.
+    #[builder(default)]
+    pub(crate) assurance_level: AssuranceLevel,
+
+    /// Addresses of reverse proxies which are trusted to set the `X-Forwarded-For` header.
+    #[builder(default, with = FromIterator::from_iter)]
+    pub(crate) trusted_proxies: Vec<IpAddr>,
+
+    #[builder(default)]
+    pub(crate) csrf_protection: CsrfProtection,
+}
+
+impl ShieldOptions {
+    pub fn assurance_level(&self) -> AssuranceLevel {
+        self.assurance_level
+    }
+
+    pub fn trusted_proxies(&self) -> &[IpAddr] {
+        &self.trusted_proxies
+    }
+
+    pub fn csrf_protection(&self) -> &CsrfProtection {
+        &self.csrf_protection
+    }
+}
 
 impl Default for ShieldOptions {
     fn default() -> Self {
@@ -27,3 +27,21 @@ pub struct MethodActionPathParams {
     /// ID of provider (optional).
     pub provider_id: Option<String>,
 }
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionPathParams {
+    /// ID of the method.
+    pub method_id: String,
+    /// ID of the connection.
+    pub connection_id: String,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPathParams {
+    /// ID of the session.
+    pub session_id: String,
+}
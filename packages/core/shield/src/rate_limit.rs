@@ -0,0 +1,464 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::error::{ShieldError, StorageError};
+
+#[derive(Builder, Clone, Debug)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct RateLimitOptions {
+    /// Time window in which failed attempts are counted.
+    #[builder(default = TimeDelta::minutes(15))]
+    pub(crate) window: TimeDelta,
+
+    /// Failed attempts per IP address and method in the window, after which attempts are refused
+    /// until the window ends.
+    #[builder(default = 50)]
+    pub(crate) max_attempts_per_ip: u32,
+
+    /// Failed attempts per account and method in the window, after which the account is locked.
+    #[builder(default = 10)]
+    pub(crate) max_attempts_per_account: u32,
+
+    #[builder(default = TimeDelta::minutes(15))]
+    pub(crate) lockout_duration: TimeDelta,
+
+    /// Failed attempts after which each attempt is delayed. The delay doubles with every failed
+    /// attempt, up to the maximum delay.
+    #[builder(default = 3)]
+    pub(crate) delay_after: u32,
+
+    #[builder(default = TimeDelta::seconds(1))]
+    pub(crate) delay: TimeDelta,
+
+    #[builder(default = TimeDelta::minutes(1))]
+    pub(crate) max_delay: TimeDelta,
+}
+
+impl Default for RateLimitOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Key which failed attempts are counted for.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum RateLimitKey {
+    Ip {
+        method_id: String,
+        ip_address: String,
+    },
+    /// Account identifier, e.g. an email address or a user ID.
+    Account {
+        method_id: String,
+        identifier: String,
+    },
+}
+
+impl fmt::Display for RateLimitKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitKey::Ip {
+                method_id,
+                ip_address,
+            } => write!(f, "ip:{method_id}:{ip_address}"),
+            RateLimitKey::Account {
+                method_id,
+                identifier,
+            } => write!(f, "account:{method_id}:{}", identifier.to_lowercase()),
+        }
+    }
+}
+
+/// Failed attempts of a [`RateLimitKey`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimitEntry {
+    pub key: String,
+    pub attempts: u32,
+    pub window_started_at: DateTime<Utc>,
+    pub last_attempted_at: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait RateLimitStorage: Send + Sync {
+    async fn rate_limit_entry(&self, key: &str) -> Result<Option<RateLimitEntry>, StorageError>;
+
+    /// Count a failed attempt, returning the updated entry.
+    ///
+    /// Starts a new window with a single attempt if there is no entry or its window has ended, otherwise increments
+    /// the attempts. This must be a single atomic upsert or update, so concurrent failed attempts are all counted.
+    async fn record_failed_attempt(
+        &self,
+        key: &str,
+        now: DateTime<Utc>,
+        window: TimeDelta,
+    ) -> Result<RateLimitEntry, StorageError>;
+
+    /// Create the entry, or replace the entry with the same key.
+    async fn save_rate_limit_entry(&self, entry: RateLimitEntry) -> Result<(), StorageError>;
+
+    async fn delete_rate_limit_entry(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// Limits failed sign-in attempts, registered with [`Shield::with_rate_limiter`](crate::Shield::with_rate_limiter).
+#[derive(Clone)]
+pub struct RateLimiter {
+    options: RateLimitOptions,
+    storage: Arc<dyn RateLimitStorage>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(options: RateLimitOptions, storage: Arc<dyn RateLimitStorage>) -> Self {
+        Self { options, storage }
+    }
+
+    /// Refuse the attempt if any of the keys is locked or delayed.
+    pub async fn check(&self, keys: &[RateLimitKey]) -> Result<(), ShieldError> {
+        let now = Utc::now();
+
+        for key in keys {
+            if let Some(entry) = self.storage.rate_limit_entry(&key.to_string()).await?
+                && let Some(retry_after) = retry_after(&self.options, key, &entry, now)
+            {
+                return Err(ShieldError::RateLimited(retry_after));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Count a failed attempt for the keys.
+    pub async fn fail(&self, keys: &[RateLimitKey]) -> Result<(), ShieldError> {
+        let now = Utc::now();
+
+        for key in keys {
+            let entry = self
+                .storage
+                .record_failed_attempt(&key.to_string(), now, self.options.window)
+                .await?;
+
+            if let Some(entry) = locked_entry(&self.options, key, entry, now) {
+                self.storage.save_rate_limit_entry(entry).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clear the failed attempts of the accounts after a successful attempt.
+    ///
+    /// Failed attempts per IP address are kept, so they limit attempts across accounts.
+    pub async fn succeed(&self, keys: &[RateLimitKey]) -> Result<(), ShieldError> {
+        for key in keys {
+            if matches!(key, RateLimitKey::Account { .. }) {
+                self.storage
+                    .delete_rate_limit_entry(&key.to_string())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn retry_after(
+    options: &RateLimitOptions,
+    key: &RateLimitKey,
+    entry: &RateLimitEntry,
+    now: DateTime<Utc>,
+) -> Option<TimeDelta> {
+    if let Some(locked_until) = entry.locked_until
+        && now < locked_until
+    {
+        return Some(locked_until - now);
+    }
+
+    let window_ends_at = entry.window_started_at + options.window;
+    if now >= window_ends_at {
+        return None;
+    }
+
+    if matches!(key, RateLimitKey::Ip { .. }) && entry.attempts >= options.max_attempts_per_ip {
+        return Some(window_ends_at - now);
+    }
+
+    if entry.attempts > options.delay_after {
+        let delay = (0..entry.attempts - options.delay_after - 1)
+            .try_fold(options.delay, |delay, _| delay.checked_mul(2))
+            .map_or(options.max_delay, |delay| delay.min(options.max_delay));
+
+        let delayed_until = entry.last_attempted_at + delay;
+        if now < delayed_until {
+            return Some(delayed_until - now);
+        }
+    }
+
+    None
+}
+
+/// Lock the account once its failed attempts reach the maximum.
+fn locked_entry(
+    options: &RateLimitOptions,
+    key: &RateLimitKey,
+    entry: RateLimitEntry,
+    now: DateTime<Utc>,
+) -> Option<RateLimitEntry> {
+    (matches!(key, RateLimitKey::Account { .. })
+        && entry.attempts >= options.max_attempts_per_account)
+        .then(|| RateLimitEntry {
+            // Start counting again once the lockout ends.
+            attempts: 0,
+            window_started_at: now,
+            locked_until: Some(now + options.lockout_duration),
+            ..entry
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    use crate::error::{ShieldError, StorageError};
+
+    use super::{
+        RateLimitEntry, RateLimitKey, RateLimitOptions, RateLimitStorage, RateLimiter,
+        locked_entry, retry_after,
+    };
+
+    #[derive(Default)]
+    struct TestRateLimitStorage {
+        entries: Mutex<Vec<RateLimitEntry>>,
+    }
+
+    #[async_trait]
+    impl RateLimitStorage for TestRateLimitStorage {
+        async fn rate_limit_entry(
+            &self,
+            key: &str,
+        ) -> Result<Option<RateLimitEntry>, StorageError> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|entry| entry.key == key)
+                .cloned())
+        }
+
+        async fn record_failed_attempt(
+            &self,
+            key: &str,
+            now: DateTime<Utc>,
+            window: TimeDelta,
+        ) -> Result<RateLimitEntry, StorageError> {
+            // Let concurrent attempts interleave, like a round trip to a database.
+            tokio::task::yield_now().await;
+
+            let mut entries = self.entries.lock().unwrap();
+            let entry = failed_attempt(
+                entries.iter().find(|entry| entry.key == key).cloned(),
+                key,
+                now,
+                window,
+            );
+
+            entries.retain(|entry| entry.key != key);
+            entries.push(entry.clone());
+
+            Ok(entry)
+        }
+
+        async fn save_rate_limit_entry(&self, entry: RateLimitEntry) -> Result<(), StorageError> {
+            let mut entries = self.entries.lock().unwrap();
+
+            entries.retain(|e| e.key != entry.key);
+            entries.push(entry);
+
+            Ok(())
+        }
+
+        async fn delete_rate_limit_entry(&self, key: &str) -> Result<(), StorageError> {
+            self.entries
+                .lock()
+                .unwrap()
+                .retain(|entry| entry.key != key);
+
+            Ok(())
+        }
+    }
+
+    /// Count a failed attempt, like [`RateLimitStorage::record_failed_attempt`].
+    fn failed_attempt(
+        entry: Option<RateLimitEntry>,
+        key: &str,
+        now: DateTime<Utc>,
+        window: TimeDelta,
+    ) -> RateLimitEntry {
+        match entry {
+            Some(entry) if now < entry.window_started_at + window => RateLimitEntry {
+                attempts: entry.attempts + 1,
+                last_attempted_at: now,
+                ..entry
+            },
+            _ => RateLimitEntry {
+                key: key.to_owned(),
+                attempts: 1,
+                window_started_at: now,
+                last_attempted_at: now,
+                locked_until: None,
+            },
+        }
+    }
+
+    /// Count a failed attempt, like [`RateLimiter::fail`].
+    fn failed_entry(
+        options: &RateLimitOptions,
+        key: &RateLimitKey,
+        entry: Option<RateLimitEntry>,
+        now: DateTime<Utc>,
+    ) -> RateLimitEntry {
+        let entry = failed_attempt(entry, &key.to_string(), now, options.window);
+
+        locked_entry(options, key, entry.clone(), now).unwrap_or(entry)
+    }
+
+    fn ip_key() -> RateLimitKey {
+        RateLimitKey::Ip {
+            method_id: "credentials".to_owned(),
+            ip_address: "192.0.2.1".to_owned(),
+        }
+    }
+
+    fn account_key() -> RateLimitKey {
+        RateLimitKey::Account {
+            method_id: "credentials".to_owned(),
+            identifier: "Test@Example.com".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_key() {
+        assert_eq!("ip:credentials:192.0.2.1", ip_key().to_string());
+        assert_eq!(
+            "account:credentials:test@example.com",
+            account_key().to_string()
+        );
+    }
+
+    #[test]
+    fn test_progressive_delay() {
+        let options = RateLimitOptions::builder()
+            .delay_after(2)
+            .delay(TimeDelta::seconds(1))
+            .max_delay(TimeDelta::seconds(3))
+            .build();
+        let key = ip_key();
+        let now = Utc::now();
+
+        let mut entry = None;
+        let mut delays = vec![];
+        for _ in 0..5 {
+            let failed = failed_entry(&options, &key, entry, now);
+            delays.push(retry_after(&options, &key, &failed, now));
+            entry = Some(failed);
+        }
+
+        assert_eq!(
+            vec![
+                None,
+                None,
+                Some(TimeDelta::seconds(1)),
+                Some(TimeDelta::seconds(2)),
+                Some(TimeDelta::seconds(3)),
+            ],
+            delays
+        );
+        assert_eq!(
+            None,
+            retry_after(
+                &options,
+                &key,
+                &entry.expect("Entry should exist."),
+                now + TimeDelta::seconds(3)
+            )
+        );
+    }
+
+    #[test]
+    fn test_ip_max_attempts() {
+        let options = RateLimitOptions::builder()
+            .max_attempts_per_ip(2)
+            .delay_after(10)
+            .window(TimeDelta::minutes(5))
+            .build();
+        let key = ip_key();
+        let now = Utc::now();
+
+        let entry = failed_entry(&options, &key, None, now);
+        assert_eq!(None, retry_after(&options, &key, &entry, now));
+
+        let entry = failed_entry(&options, &key, Some(entry), now);
+        assert_eq!(
+            Some(TimeDelta::minutes(4)),
+            retry_after(&options, &key, &entry, now + TimeDelta::minutes(1))
+        );
+        assert_eq!(
+            None,
+            retry_after(&options, &key, &entry, now + TimeDelta::minutes(5))
+        );
+    }
+
+    #[test]
+    fn test_account_lockout() {
+        let options = RateLimitOptions::builder()
+            .max_attempts_per_account(2)
+            .delay_after(10)
+            .lockout_duration(TimeDelta::minutes(10))
+            .build();
+        let key = account_key();
+        let now = Utc::now();
+
+        let entry = failed_entry(&options, &key, None, now);
+        assert_eq!(None, entry.locked_until);
+
+        let entry = failed_entry(&options, &key, Some(entry), now);
+        assert_eq!(Some(now + TimeDelta::minutes(10)), entry.locked_until);
+        assert_eq!(0, entry.attempts);
+        assert_eq!(
+            Some(TimeDelta::minutes(10)),
+            retry_after(&options, &key, &entry, now)
+        );
+        assert_eq!(
+            None,
+            retry_after(&options, &key, &entry, now + TimeDelta::minutes(10))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_fail() -> Result<(), ShieldError> {
+        let options = RateLimitOptions::builder()
+            .max_attempts_per_account(2)
+            .delay_after(10)
+            .build();
+        let rate_limiter = RateLimiter::new(options, Arc::new(TestRateLimitStorage::default()));
+        let keys = [account_key()];
+
+        // Both failed attempts are counted, so together they lock the account.
+        let (first, second) = tokio::join!(rate_limiter.fail(&keys), rate_limiter.fail(&keys));
+        first?;
+        second?;
+
+        assert!(matches!(
+            rate_limiter.check(&keys).await,
+            Err(ShieldError::RateLimited(_))
+        ));
+
+        Ok(())
+    }
+}
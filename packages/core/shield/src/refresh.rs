@@ -0,0 +1,125 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+
+use crate::error::{ShieldError, StorageError};
+
+/// Access tokens expiring within this margin are refreshed.
+const EXPIRY_MARGIN: TimeDelta = TimeDelta::seconds(60);
+
+/// Refreshes access tokens of connections, coalescing concurrent refreshes of the same connection.
+#[derive(Clone, Default)]
+pub struct TokenRefresher {
+    locks: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+impl TokenRefresher {
+    /// Whether an access token expiring at the given time should be refreshed.
+    pub fn is_expired(expired_at: Option<DateTime<FixedOffset>>) -> bool {
+        expired_at.is_some_and(|expired_at| expired_at <= Utc::now() + EXPIRY_MARGIN)
+    }
+
+    /// Run the refresh of the connection, after concurrent refreshes of the same connection have completed.
+    ///
+    /// The refresh should read the connection again, as another request might have refreshed it while waiting.
+    pub async fn refresh<T, F: Future<Output = Result<T, ShieldError>>>(
+        &self,
+        connection_id: &str,
+        refresh: F,
+    ) -> Result<T, ShieldError> {
+        let lock = self
+            .locks
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .entry(connection_id.to_owned())
+            .or_default()
+            .clone();
+
+        let result = {
+            let _guard = lock.lock().await;
+
+            refresh.await
+        };
+
+        let mut locks = self
+            .locks
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+        if Arc::strong_count(&lock) == 2 {
+            locks.remove(connection_id);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use chrono::{TimeDelta, Utc};
+
+    use crate::error::ShieldError;
+
+    use super::TokenRefresher;
+
+    #[test]
+    fn test_is_expired() {
+        let now = Utc::now();
+
+        assert!(!TokenRefresher::is_expired(None));
+        assert!(!TokenRefresher::is_expired(Some(
+            (now + TimeDelta::minutes(5)).into()
+        )));
+        assert!(TokenRefresher::is_expired(Some(
+            (now + TimeDelta::seconds(30)).into()
+        )));
+        assert!(TokenRefresher::is_expired(Some(
+            (now - TimeDelta::minutes(5)).into()
+        )));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_refresh() -> Result<(), ShieldError> {
+        let token_refresher = TokenRefresher::default();
+        let refreshes = Arc::new(AtomicUsize::new(0));
+
+        let tasks = (0..8)
+            .map(|_| {
+                let token_refresher = token_refresher.clone();
+                let refreshes = refreshes.clone();
+
+                tokio::spawn(async move {
+                    token_refresher
+                        .refresh("connection", async {
+                            // Only the first refresh finds the token expired, like re-reading the connection.
+                            if refreshes.load(Ordering::SeqCst) > 0 {
+                                return Ok("access");
+                            }
+
+                            tokio::task::yield_now().await;
+                            refreshes.fetch_add(1, Ordering::SeqCst);
+
+                            Ok("access")
+                        })
+                        .await
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for task in tasks {
+            assert_eq!("access", task.await.expect("task should complete")?);
+        }
+
+        assert_eq!(1, refreshes.load(Ordering::SeqCst));
+        assert!(token_refresher.locks.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+}
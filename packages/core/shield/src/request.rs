@@ -1,7 +1,11 @@
+use std::{fmt, net::IpAddr};
+
 use serde_json::Value;
 #[cfg(feature = "utoipa")]
 use utoipa::openapi::HttpMethod;
 
+use crate::{hook::Hooks, session::SessionClient};
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum RequestMethod {
     Get,
@@ -14,6 +18,38 @@ pub enum RequestMethod {
     Trace,
 }
 
+impl RequestMethod {
+    /// Parse an HTTP method name, e.g. `POST`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "GET" => Some(Self::Get),
+            "POST" => Some(Self::Post),
+            "PUT" => Some(Self::Put),
+            "DELETE" => Some(Self::Delete),
+            "OPTIONS" => Some(Self::Options),
+            "HEAD" => Some(Self::Head),
+            "PATCH" => Some(Self::Patch),
+            "TRACE" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RequestMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Delete => "DELETE",
+            Self::Options => "OPTIONS",
+            Self::Head => "HEAD",
+            Self::Patch => "PATCH",
+            Self::Trace => "TRACE",
+        })
+    }
+}
+
 #[cfg(feature = "utoipa")]
 impl From<RequestMethod> for HttpMethod {
     fn from(value: RequestMethod) -> Self {
@@ -34,4 +70,202 @@ impl From<RequestMethod> for HttpMethod {
 pub struct Request {
     pub query: Value,
     pub form_data: Value,
+    pub context: RequestContext,
+    /// Hooks registered on [`Shield`](crate::Shield), set when the request is called.
+    pub hooks: Hooks,
+}
+
+impl Request {
+    pub fn new(query: Value, form_data: Value) -> Self {
+        Self {
+            query,
+            form_data,
+            context: RequestContext::default(),
+            hooks: Hooks::default(),
+        }
+    }
+
+    pub fn with_context(mut self, context: RequestContext) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// Client context of a [`Request`], populated by the integrations.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestContext {
+    pub method: Option<RequestMethod>,
+    /// Client IP address, resolved from the `X-Forwarded-For` header behind trusted proxies.
+    pub ip_address: Option<IpAddr>,
+    /// Headers with lowercase names, in the order they were received.
+    pub headers: Vec<(String, String)>,
+}
+
+impl RequestContext {
+    /// Create the context of a request received from the peer address.
+    ///
+    /// The `X-Forwarded-For` header is only used if the peer address is one of the trusted proxies.
+    pub fn new(
+        method: Option<RequestMethod>,
+        peer_address: Option<IpAddr>,
+        headers: impl IntoIterator<Item = (String, String)>,
+        trusted_proxies: &[IpAddr],
+    ) -> Self {
+        let headers = headers
+            .into_iter()
+            .map(|(name, value)| (name.to_ascii_lowercase(), value))
+            .collect::<Vec<_>>();
+
+        let ip_address = peer_address.map(|peer_address| {
+            if !trusted_proxies.contains(&peer_address) {
+                return peer_address;
+            }
+
+            // Each proxy appends the address it received the request from, so the client is the
+            // last address which is not a trusted proxy.
+            let forwarded_for = headers
+                .iter()
+                .filter(|(name, _)| name == "x-forwarded-for")
+                .flat_map(|(_, value)| value.split(','))
+                .map(|address| address.trim().parse::<IpAddr>().ok())
+                .collect::<Vec<_>>();
+
+            let mut ip_address = peer_address;
+            for address in forwarded_for.into_iter().rev() {
+                match address {
+                    Some(address) => {
+                        ip_address = address;
+
+                        if !trusted_proxies.contains(&address) {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            }
+            ip_address
+        });
+
+        Self {
+            method,
+            ip_address,
+            headers,
+        }
+    }
+
+    /// First value of the header, with a case-insensitive name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn user_agent(&self) -> Option<&str> {
+        self.header("user-agent")
+    }
+
+    pub fn accept_language(&self) -> Option<&str> {
+        self.header("accept-language")
+    }
+
+    pub fn origin(&self) -> Option<&str> {
+        self.header("origin")
+    }
+
+    /// Cookies from the `Cookie` headers, as name and value pairs.
+    pub fn cookies(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.headers
+            .iter()
+            .filter(|(name, _)| name == "cookie")
+            .flat_map(|(_, value)| value.split(';'))
+            .filter_map(|cookie| cookie.split_once('='))
+            .map(|(name, value)| (name.trim(), value.trim()))
+    }
+
+    pub fn cookie(&self, name: &str) -> Option<&str> {
+        self.cookies()
+            .find(|(cookie_name, _)| *cookie_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Client recorded in the session.
+    pub fn client(&self) -> SessionClient {
+        SessionClient {
+            ip_address: self.ip_address.map(|ip_address| ip_address.to_string()),
+            user_agent: self.user_agent().map(ToOwned::to_owned),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use super::{RequestContext, RequestMethod};
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().expect("Address should be valid.")
+    }
+
+    fn context(
+        peer_address: &str,
+        forwarded_for: &str,
+        trusted_proxies: &[IpAddr],
+    ) -> RequestContext {
+        RequestContext::new(
+            Some(RequestMethod::Post),
+            Some(ip(peer_address)),
+            [("X-Forwarded-For".to_owned(), forwarded_for.to_owned())],
+            trusted_proxies,
+        )
+    }
+
+    #[test]
+    fn test_forwarded_for() {
+        let proxies = [ip("10.0.0.1"), ip("10.0.0.2")];
+
+        assert_eq!(
+            Some(ip("192.0.2.1")),
+            context("192.0.2.1", "198.51.100.1", &proxies).ip_address,
+            "untrusted peer should ignore header"
+        );
+        assert_eq!(
+            Some(ip("198.51.100.1")),
+            context("10.0.0.1", "198.51.100.1", &proxies).ip_address
+        );
+        assert_eq!(
+            Some(ip("198.51.100.2")),
+            context("10.0.0.1", "198.51.100.1, 198.51.100.2, 10.0.0.2", &proxies).ip_address,
+            "spoofed addresses before the last untrusted address should be ignored"
+        );
+        assert_eq!(
+            Some(ip("10.0.0.2")),
+            context("10.0.0.1", "invalid, 10.0.0.2", &proxies).ip_address
+        );
+    }
+
+    #[test]
+    fn test_headers() {
+        let context = RequestContext::new(
+            Some(RequestMethod::Get),
+            None,
+            [
+                ("User-Agent".to_owned(), "Test".to_owned()),
+                ("Cookie".to_owned(), "a=1; b=2".to_owned()),
+                ("Cookie".to_owned(), "c=3".to_owned()),
+            ],
+            &[],
+        );
+
+        assert_eq!(Some("Test"), context.user_agent());
+        assert_eq!(Some("Test"), context.header("USER-AGENT"));
+        assert_eq!(None, context.origin());
+        assert_eq!(
+            vec![("a", "1"), ("b", "2"), ("c", "3")],
+            context.cookies().collect::<Vec<_>>()
+        );
+        assert_eq!(Some("2"), context.cookie("b"));
+        assert_eq!(None, context.client().ip_address);
+    }
 }
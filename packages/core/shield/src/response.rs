@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::SessionAction;
 
@@ -34,4 +35,5 @@ pub enum ResponseType {
     Default,
     Redirect(String),
     RedirectToAction { action_id: String },
+    Data(Value),
 }
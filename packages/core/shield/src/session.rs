@@ -4,14 +4,23 @@ use std::{
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
-use crate::{error::SessionError, provider::Provider, user::User};
+use crate::{
+    error::SessionError, provider::Provider, session_registry::RegisteredSessionFilter, user::User,
+};
 
 #[async_trait]
 pub trait SessionStorage: Send + Sync {
     fn data(&self) -> Arc<Mutex<SessionData>>;
 
+    /// Whether the session is kept between requests. Sessions which are not kept, e.g. for bearer
+    /// tokens, are not registered in the session registry.
+    fn is_persistent(&self) -> bool {
+        true
+    }
+
     async fn update(&self) -> Result<(), SessionError>;
 
     async fn renew(&self) -> Result<(), SessionError>;
@@ -20,30 +29,56 @@ pub trait SessionStorage: Send + Sync {
 }
 
 #[derive(Clone)]
-pub struct Session(Arc<dyn SessionStorage>);
+pub struct Session {
+    storage: Arc<dyn SessionStorage>,
+    client: SessionClient,
+}
 
 impl Session {
     pub fn new<S: SessionStorage + 'static>(storage: S) -> Self {
-        Session(Arc::new(storage))
+        Session {
+            storage: Arc::new(storage),
+            client: SessionClient::default(),
+        }
+    }
+
+    pub fn with_client(mut self, client: SessionClient) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn client(&self) -> &SessionClient {
+        &self.client
     }
 
     pub fn data(&self) -> Arc<Mutex<SessionData>> {
-        self.0.data()
+        self.storage.data()
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.storage.is_persistent()
     }
 
     pub async fn update(&self) -> Result<(), SessionError> {
-        self.0.update().await
+        self.storage.update().await
     }
 
     pub async fn renew(&self) -> Result<(), SessionError> {
-        self.0.renew().await
+        self.storage.renew().await
     }
 
     pub async fn purge(&self) -> Result<(), SessionError> {
-        self.0.purge().await
+        self.storage.purge().await
     }
 }
 
+/// Client of the current request, recorded in the [`SessionRegistry`](crate::SessionRegistry).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionClient {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SessionData {
     pub base: BaseSession,
@@ -94,6 +129,11 @@ pub struct MethodSession<'a, S> {
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BaseSession {
     pub authentication: Option<Authentication>,
+    /// Authentication that is waiting for another factor before the user is signed in.
+    pub pending_authentication: Option<Authentication>,
+    /// Token which protects actions against cross-site request forgery.
+    #[serde(default)]
+    pub csrf_token: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -101,6 +141,126 @@ pub struct Authentication {
     pub method_id: String,
     pub provider_id: Option<String>,
     pub user_id: String,
+    #[serde(default)]
+    pub factors: Vec<AuthenticationFactor>,
+    /// ID of the session in the [`SessionRegistry`](crate::SessionRegistry), if one is configured.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// When the session was added to the [`SessionRegistry`](crate::SessionRegistry).
+    #[serde(default)]
+    pub session_registered_at: Option<DateTime<Utc>>,
+    /// Session ID at the provider, e.g. the OpenID Connect `sid` claim.
+    #[serde(default)]
+    pub provider_session_id: Option<String>,
+    /// Scopes the authentication is restricted to, e.g. for API keys. `None` is unrestricted.
+    #[serde(default)]
+    pub scopes: Option<Vec<String>>,
+    /// Whether the authentication is only valid for actions of its method, e.g. for access tokens issued to
+    /// third-party clients. It does not authenticate a user for the application.
+    #[serde(default)]
+    pub method_only: bool,
+}
+
+impl Authentication {
+    fn new(
+        method_id: &str,
+        provider_id: Option<&str>,
+        user_id: &str,
+        provider_session_id: Option<&str>,
+    ) -> Self {
+        Self {
+            method_id: method_id.to_owned(),
+            provider_id: provider_id.map(ToOwned::to_owned),
+            user_id: user_id.to_owned(),
+            factors: vec![AuthenticationFactor::new(method_id, provider_id)],
+            session_id: None,
+            session_registered_at: None,
+            provider_session_id: provider_session_id.map(ToOwned::to_owned),
+            scopes: None,
+            method_only: false,
+        }
+    }
+
+    /// Authentication of a request with a token, e.g. an API key, which may be restricted to scopes.
+    pub fn for_token(
+        method_id: &str,
+        provider_id: Option<&str>,
+        user_id: &str,
+        scopes: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            scopes,
+            ..Self::new(method_id, provider_id, user_id, None)
+        }
+    }
+
+    /// Authentication of a request with a token which is only valid for actions of the method, e.g. an
+    /// access token issued to a third-party client.
+    pub fn for_method_token(
+        method_id: &str,
+        provider_id: Option<&str>,
+        user_id: &str,
+        scopes: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            method_only: true,
+            ..Self::for_token(method_id, provider_id, user_id, scopes)
+        }
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes
+            .as_ref()
+            .is_none_or(|scopes| scopes.iter().any(|s| s == scope))
+    }
+
+    pub fn has_factor(&self, method_id: &str) -> bool {
+        self.factors
+            .iter()
+            .any(|factor| factor.method_id == method_id)
+    }
+
+    pub fn assurance_level(&self) -> AssuranceLevel {
+        let mut method_ids = self
+            .factors
+            .iter()
+            .map(|factor| factor.method_id.as_str())
+            .collect::<Vec<_>>();
+        method_ids.sort_unstable();
+        method_ids.dedup();
+
+        if method_ids.len() > 1 {
+            AssuranceLevel::MultiFactor
+        } else {
+            AssuranceLevel::SingleFactor
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AuthenticationFactor {
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub authenticated_at: DateTime<Utc>,
+}
+
+impl AuthenticationFactor {
+    fn new(method_id: &str, provider_id: Option<&str>) -> Self {
+        Self {
+            method_id: method_id.to_owned(),
+            provider_id: provider_id.map(ToOwned::to_owned),
+            authenticated_at: Utc::now(),
+        }
+    }
+}
+
+/// Level of assurance provided by the factors of an authentication.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AssuranceLevel {
+    #[default]
+    SingleFactor,
+    MultiFactor,
 }
 
 #[derive(Clone, Debug)]
@@ -109,8 +269,24 @@ pub enum SessionAction {
         method_id: String,
         provider_id: Option<String>,
         user_id: String,
+        provider_session_id: Option<String>,
+    },
+    PendingAuthentication {
+        method_id: String,
+        provider_id: Option<String>,
+        user_id: String,
+        provider_session_id: Option<String>,
+    },
+    /// Add a factor to the pending authentication, completing it, or to the current authentication.
+    AddFactor {
+        method_id: String,
+        provider_id: Option<String>,
     },
     Unauthenticate,
+    /// Terminate other sessions matching the filter, using the [`SessionRegistry`](crate::SessionRegistry).
+    DestroySessions {
+        filter: RegisteredSessionFilter,
+    },
     MethodData {
         method_id: String,
         value: String,
@@ -123,6 +299,37 @@ impl SessionAction {
             method_id: provider.method_id(),
             provider_id: provider.id(),
             user_id: user.id(),
+            provider_session_id: None,
+        }
+    }
+
+    /// Authenticate with a session at the provider, so it can be terminated when the provider signs out.
+    pub fn authenticate_with_provider_session<U: User>(
+        provider: &dyn Provider,
+        user: U,
+        provider_session_id: Option<String>,
+    ) -> Self {
+        Self::Authenticate {
+            method_id: provider.method_id(),
+            provider_id: provider.id(),
+            user_id: user.id(),
+            provider_session_id,
+        }
+    }
+
+    pub fn pending_authentication<U: User>(provider: &dyn Provider, user: U) -> Self {
+        Self::PendingAuthentication {
+            method_id: provider.method_id(),
+            provider_id: provider.id(),
+            user_id: user.id(),
+            provider_session_id: None,
+        }
+    }
+
+    pub fn add_factor(provider: &dyn Provider) -> Self {
+        Self::AddFactor {
+            method_id: provider.method_id(),
+            provider_id: provider.id(),
         }
     }
 
@@ -130,6 +337,10 @@ impl SessionAction {
         Self::Unauthenticate
     }
 
+    pub fn destroy_sessions(filter: RegisteredSessionFilter) -> Self {
+        Self::DestroySessions { filter }
+    }
+
     pub fn method_data<T: Serialize>(
         provider: &dyn Provider,
         value: T,
@@ -149,6 +360,32 @@ impl SessionAction {
                 method_id,
                 provider_id,
                 user_id,
+                provider_session_id,
+            } => {
+                session.renew().await?;
+
+                {
+                    let session_data = session.data();
+                    let mut session_data = session_data
+                        .lock()
+                        .map_err(|err| SessionError::Lock(err.to_string()))?;
+
+                    session_data.base.authentication = Some(Authentication::new(
+                        method_id,
+                        provider_id.as_deref(),
+                        user_id,
+                        provider_session_id.as_deref(),
+                    ));
+                    session_data.base.pending_authentication = None;
+                }
+
+                session.update().await?;
+            }
+            Self::PendingAuthentication {
+                method_id,
+                provider_id,
+                user_id,
+                provider_session_id,
             } => {
                 session.renew().await?;
 
@@ -158,11 +395,39 @@ impl SessionAction {
                         .lock()
                         .map_err(|err| SessionError::Lock(err.to_string()))?;
 
-                    session_data.base.authentication = Some(Authentication {
-                        method_id: method_id.clone(),
-                        provider_id: provider_id.clone(),
-                        user_id: user_id.clone(),
-                    });
+                    session_data.base.authentication = None;
+                    session_data.base.pending_authentication = Some(Authentication::new(
+                        method_id,
+                        provider_id.as_deref(),
+                        user_id,
+                        provider_session_id.as_deref(),
+                    ));
+                }
+
+                session.update().await?;
+            }
+            Self::AddFactor {
+                method_id,
+                provider_id,
+            } => {
+                session.renew().await?;
+
+                {
+                    let session_data = session.data();
+                    let mut session_data = session_data
+                        .lock()
+                        .map_err(|err| SessionError::Lock(err.to_string()))?;
+
+                    let factor = AuthenticationFactor::new(method_id, provider_id.as_deref());
+
+                    if let Some(mut authentication) =
+                        session_data.base.pending_authentication.take()
+                    {
+                        authentication.factors.push(factor);
+                        session_data.base.authentication = Some(authentication);
+                    } else if let Some(authentication) = &mut session_data.base.authentication {
+                        authentication.factors.push(factor);
+                    }
                 }
 
                 session.update().await?;
@@ -170,6 +435,9 @@ impl SessionAction {
             Self::Unauthenticate => {
                 session.purge().await?;
             }
+            Self::DestroySessions { .. } => {
+                // Handled by `Shield`, which has access to the session registry.
+            }
             Self::MethodData { method_id, value } => {
                 {
                     let session_data = session.data();
@@ -187,3 +455,48 @@ impl SessionAction {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AssuranceLevel, Authentication, AuthenticationFactor};
+
+    #[test]
+    fn test_assurance_level() {
+        let mut authentication = Authentication::new("credentials", None, "user", None);
+        assert_eq!(
+            AssuranceLevel::SingleFactor,
+            authentication.assurance_level()
+        );
+
+        authentication
+            .factors
+            .push(AuthenticationFactor::new("credentials", None));
+        assert_eq!(
+            AssuranceLevel::SingleFactor,
+            authentication.assurance_level()
+        );
+
+        authentication
+            .factors
+            .push(AuthenticationFactor::new("totp", None));
+        assert!(authentication.has_factor("totp"));
+        assert_eq!(
+            AssuranceLevel::MultiFactor,
+            authentication.assurance_level()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_without_factors() {
+        let authentication: Authentication = serde_json::from_str(
+            r#"{"method_id":"credentials","provider_id":null,"user_id":"user"}"#,
+        )
+        .expect("Authentication should deserialize.");
+
+        assert!(authentication.factors.is_empty());
+        assert_eq!(
+            AssuranceLevel::SingleFactor,
+            authentication.assurance_level()
+        );
+    }
+}
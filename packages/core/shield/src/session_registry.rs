@@ -0,0 +1,316 @@
+use std::cmp::Reverse;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{SessionError, ShieldError, StorageError},
+    hook::{Hook, Hooks, SessionEvent},
+};
+
+/// Session known on the server, so it can be terminated independently of the session backend.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RegisteredSession {
+    pub id: String,
+    pub user_id: String,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    /// Session ID at the provider, e.g. the OpenID Connect `sid` claim.
+    pub provider_session_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl RegisteredSession {
+    /// Short description of the device, derived from the user agent, e.g. `Firefox on Linux`.
+    pub fn device(&self) -> Option<String> {
+        self.user_agent.as_deref().map(device_name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateRegisteredSession {
+    pub user_id: String,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub provider_session_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateRegisteredSession {
+    pub id: String,
+    pub ip_address: Option<Option<String>>,
+    pub user_agent: Option<Option<String>>,
+    pub last_seen_at: Option<DateTime<Utc>>,
+}
+
+/// Filter for registered sessions. Fields which are `None` match any session.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RegisteredSessionFilter {
+    pub user_id: Option<String>,
+    pub method_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub provider_session_id: Option<String>,
+}
+
+impl RegisteredSessionFilter {
+    pub fn user(user_id: &str) -> Self {
+        Self {
+            user_id: Some(user_id.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn matches(&self, session: &RegisteredSession) -> bool {
+        self.user_id
+            .as_ref()
+            .is_none_or(|user_id| *user_id == session.user_id)
+            && self
+                .method_id
+                .as_ref()
+                .is_none_or(|method_id| *method_id == session.method_id)
+            && self
+                .provider_id
+                .as_ref()
+                .is_none_or(|provider_id| Some(provider_id) == session.provider_id.as_ref())
+            && self
+                .provider_session_id
+                .as_ref()
+                .is_none_or(|provider_session_id| {
+                    Some(provider_session_id) == session.provider_session_id.as_ref()
+                })
+    }
+}
+
+#[async_trait]
+pub trait SessionRegistry: Send + Sync {
+    /// Time since which the registry knows about sessions, if it does not keep them across restarts, e.g. in memory.
+    ///
+    /// Sessions registered before are registered again when they are used, instead of being purged.
+    fn registered_since(&self) -> Option<DateTime<Utc>> {
+        None
+    }
+
+    async fn registered_session_by_id(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<RegisteredSession>, SessionError>;
+
+    async fn registered_sessions(
+        &self,
+        filter: &RegisteredSessionFilter,
+    ) -> Result<Vec<RegisteredSession>, SessionError>;
+
+    async fn create_registered_session(
+        &self,
+        session: CreateRegisteredSession,
+    ) -> Result<RegisteredSession, SessionError>;
+
+    async fn update_registered_session(
+        &self,
+        session: UpdateRegisteredSession,
+    ) -> Result<RegisteredSession, SessionError>;
+
+    async fn delete_registered_session(&self, session_id: &str) -> Result<(), SessionError>;
+}
+
+/// Session of a user in a serializable form, used to manage active sessions.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UserSession {
+    pub id: String,
+    pub method_id: String,
+    pub provider_id: Option<String>,
+    pub device: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// Whether this is the session of the current request.
+    pub current: bool,
+}
+
+pub(crate) async fn list_user_sessions(
+    session_registry: &dyn SessionRegistry,
+    user_id: &str,
+    current_session_id: Option<&str>,
+) -> Result<Vec<UserSession>, ShieldError> {
+    let mut sessions = session_registry
+        .registered_sessions(&RegisteredSessionFilter::user(user_id))
+        .await?
+        .into_iter()
+        .map(|session| UserSession {
+            device: session.device(),
+            current: Some(session.id.as_str()) == current_session_id,
+            id: session.id,
+            method_id: session.method_id,
+            provider_id: session.provider_id,
+            ip_address: session.ip_address,
+            user_agent: session.user_agent,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+        })
+        .collect::<Vec<_>>();
+
+    sessions.sort_by_key(|session| Reverse(session.last_seen_at));
+
+    Ok(sessions)
+}
+
+pub(crate) async fn revoke_user_session(
+    session_registry: &dyn SessionRegistry,
+    hooks: &Hooks,
+    user_id: &str,
+    session_id: &str,
+) -> Result<(), ShieldError> {
+    let session = session_registry
+        .registered_session_by_id(session_id)
+        .await?
+        .filter(|session| session.user_id == user_id)
+        .ok_or(ShieldError::Storage(StorageError::NotFound(
+            "Session".to_owned(),
+            session_id.to_owned(),
+        )))?;
+
+    revoke_session(session_registry, hooks, &session).await
+}
+
+pub(crate) async fn revoke_other_user_sessions(
+    session_registry: &dyn SessionRegistry,
+    hooks: &Hooks,
+    user_id: &str,
+    current_session_id: Option<&str>,
+) -> Result<(), ShieldError> {
+    for session in session_registry
+        .registered_sessions(&RegisteredSessionFilter::user(user_id))
+        .await?
+    {
+        if Some(session.id.as_str()) != current_session_id {
+            revoke_session(session_registry, hooks, &session).await?;
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn revoke_session(
+    session_registry: &dyn SessionRegistry,
+    hooks: &Hooks,
+    session: &RegisteredSession,
+) -> Result<(), ShieldError> {
+    session_registry
+        .delete_registered_session(&session.id)
+        .await?;
+
+    hooks.session_revoked(&SessionEvent::from(session)).await
+}
+
+fn device_name(user_agent: &str) -> String {
+    // Order matters, as user agents mention the engines they are compatible with.
+    const BROWSERS: [(&str, &str); 5] = [
+        ("Edg/", "Edge"),
+        ("OPR/", "Opera"),
+        ("Firefox/", "Firefox"),
+        ("Chrome/", "Chrome"),
+        ("Safari/", "Safari"),
+    ];
+    const SYSTEMS: [(&str, &str); 7] = [
+        ("Android", "Android"),
+        ("iPhone", "iOS"),
+        ("iPad", "iPadOS"),
+        ("Windows", "Windows"),
+        ("Mac OS X", "macOS"),
+        ("CrOS", "ChromeOS"),
+        ("Linux", "Linux"),
+    ];
+
+    let browser = BROWSERS
+        .iter()
+        .find(|(pattern, _)| user_agent.contains(pattern))
+        .map(|(_, name)| *name);
+    let system = SYSTEMS
+        .iter()
+        .find(|(pattern, _)| user_agent.contains(pattern))
+        .map(|(_, name)| *name);
+
+    match (browser, system) {
+        (Some(browser), Some(system)) => format!("{browser} on {system}"),
+        (Some(name), None) | (None, Some(name)) => name.to_owned(),
+        (None, None) => "Unknown device".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::{RegisteredSession, RegisteredSessionFilter, device_name};
+
+    #[test]
+    fn test_filter_matches() {
+        let session = RegisteredSession {
+            id: "session".to_owned(),
+            user_id: "user".to_owned(),
+            method_id: "oidc".to_owned(),
+            provider_id: Some("keycloak".to_owned()),
+            provider_session_id: Some("sid".to_owned()),
+            ip_address: None,
+            user_agent: None,
+            created_at: Utc::now(),
+            last_seen_at: Utc::now(),
+        };
+
+        assert!(RegisteredSessionFilter::default().matches(&session));
+        assert!(RegisteredSessionFilter::user("user").matches(&session));
+        assert!(!RegisteredSessionFilter::user("other").matches(&session));
+        assert!(
+            RegisteredSessionFilter {
+                method_id: Some("oidc".to_owned()),
+                provider_id: Some("keycloak".to_owned()),
+                provider_session_id: Some("sid".to_owned()),
+                ..Default::default()
+            }
+            .matches(&session)
+        );
+        assert!(
+            !RegisteredSessionFilter {
+                provider_session_id: Some("other".to_owned()),
+                ..Default::default()
+            }
+            .matches(&session)
+        );
+    }
+
+    #[test]
+    fn test_device_name() {
+        assert_eq!(
+            "Firefox on Linux",
+            device_name("Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0")
+        );
+        assert_eq!(
+            "Edge on Windows",
+            device_name(
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36 Edg/129.0.0.0"
+            )
+        );
+        assert_eq!(
+            "Chrome on Android",
+            device_name(
+                "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Mobile Safari/537.36"
+            )
+        );
+        assert_eq!(
+            "Safari on iOS",
+            device_name(
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 18_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.0 Mobile/15E148 Safari/604.1"
+            )
+        );
+        assert_eq!("Unknown device", device_name("curl/8.10.1"));
+    }
+}
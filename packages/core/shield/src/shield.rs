@@ -1,5 +1,7 @@
 use std::{any::Any, collections::HashMap, sync::Arc};
 
+use chrono::{TimeDelta, Utc};
+
 #[cfg(feature = "utoipa")]
 use convert_case::{Case, Casing};
 use ordered_hash_map::OrderedHashMap;
@@ -17,22 +19,47 @@ use utoipa::{
 use crate::path::{ActionPathParams, MethodActionPathParams};
 use crate::{
     action::{Action, ActionForms, ActionMethodForm, ActionProviderForm},
-    actions::SignOutAction,
-    error::{ActionError, MethodError, ProviderError, SessionError, ShieldError},
+    actions::{
+        ConnectionsAction, RevokeOtherSessionsAction, RevokeSessionAction, SessionsAction,
+        SignInAction, SignInCallbackAction, SignOutAction, UnlinkConnectionAction,
+    },
+    audit::{AuditEntry, AuditFilter, AuditHook, AuditStorage},
+    bearer::{
+        BearerOptions, BearerSessionStorage, BearerTokenManager, BearerTokens, RefreshTokenStorage,
+    },
+    connection::{self, UserConnection},
+    csrf::{self, CsrfProtection},
+    error::{
+        ActionError, ConfigurationError, MethodError, ProviderError, SessionError, ShieldError,
+    },
+    hook::{Hook, Hooks, SignInEvent, SignInFailedEvent, SignOutEvent, UserEvent},
     method::ErasedMethod,
     options::ShieldOptions,
-    request::Request,
-    response::ResponseType,
-    session::Session,
+    rate_limit::{RateLimitKey, RateLimitOptions, RateLimitStorage, RateLimiter},
+    request::{Request, RequestMethod},
+    response::{Response, ResponseType},
+    session::{AssuranceLevel, Authentication, BaseSession, Session, SessionAction, SessionClient},
+    session_registry::{
+        self, CreateRegisteredSession, RegisteredSessionFilter, SessionRegistry,
+        UpdateRegisteredSession, UserSession,
+    },
     storage::Storage,
     user::User,
 };
 
+/// Interval after which the last seen time of a registered session is updated.
+const LAST_SEEN_INTERVAL: TimeDelta = TimeDelta::minutes(1);
+
 #[derive(Clone)]
 pub struct Shield<U: User> {
     storage: Arc<dyn Storage<U>>,
     actions: Arc<HashMap<String, Arc<dyn Action>>>,
     methods: Arc<OrderedHashMap<String, Arc<dyn ErasedMethod>>>,
+    session_registry: Option<Arc<dyn SessionRegistry>>,
+    bearer_tokens: Option<BearerTokenManager>,
+    audit_storage: Option<Arc<dyn AuditStorage>>,
+    rate_limiter: Option<RateLimiter>,
+    hooks: Hooks,
     options: ShieldOptions,
 }
 
@@ -41,7 +68,18 @@ impl<U: User> Shield<U> {
     where
         S: Storage<U> + 'static,
     {
-        let actions: [Arc<dyn Action>; 1] = [Arc::new(SignOutAction)];
+        let methods: Arc<OrderedHashMap<String, Arc<dyn ErasedMethod>>> = Arc::new(
+            methods
+                .into_iter()
+                .map(|method| (method.erased_id(), method))
+                .collect(),
+        );
+
+        let actions: [Arc<dyn Action>; 3] = [
+            Arc::new(SignOutAction),
+            Arc::new(ConnectionsAction::new(methods.clone())),
+            Arc::new(UnlinkConnectionAction::new(methods.clone())),
+        ];
 
         // TOOD: Check for duplicate action and method IDs.
 
@@ -53,24 +91,94 @@ impl<U: User> Shield<U> {
                     .map(|action| (action.id().to_owned(), action))
                     .collect(),
             ),
-            methods: Arc::new(
-                methods
-                    .into_iter()
-                    .map(|method| (method.erased_id(), method))
-                    .collect(),
-            ),
+            methods,
+            session_registry: None,
+            bearer_tokens: None,
+            audit_storage: None,
+            rate_limiter: None,
+            hooks: Hooks::default(),
             options,
         }
     }
 
+    /// Register sessions on the server, so they can be terminated from outside the session itself.
+    pub fn with_session_registry<R: SessionRegistry + 'static>(mut self, registry: R) -> Self {
+        let registry: Arc<dyn SessionRegistry> = Arc::new(registry);
+
+        let actions: [Arc<dyn Action>; 3] = [
+            Arc::new(SessionsAction::new(registry.clone())),
+            Arc::new(RevokeSessionAction::new(registry.clone())),
+            Arc::new(RevokeOtherSessionsAction::new(registry.clone())),
+        ];
+        Arc::make_mut(&mut self.actions).extend(
+            actions
+                .into_iter()
+                .map(|action| (action.id().to_owned(), action)),
+        );
+
+        self.session_registry = Some(registry);
+        self
+    }
+
+    /// Issue signed access tokens with rotating refresh tokens, for clients without cookie sessions.
+    pub fn with_bearer_tokens<S: RefreshTokenStorage + 'static>(
+        mut self,
+        options: BearerOptions,
+        storage: S,
+    ) -> Self {
+        self.bearer_tokens = Some(BearerTokenManager::new(options, Arc::new(storage)));
+        self
+    }
+
+    /// Record sign-ins, sign-outs, connection changes and session revocations in an audit log.
+    ///
+    /// Entries are recorded after the events, so an entry which can not be recorded is logged instead of failing the
+    /// request.
+    pub fn with_audit_storage<S: AuditStorage + 'static>(mut self, storage: S) -> Self {
+        self.audit_storage = Some(Arc::new(storage));
+        self
+    }
+
+    /// Limit failed sign-in attempts per IP address and account.
+    pub fn with_rate_limiter<S: RateLimitStorage + 'static>(
+        mut self,
+        options: RateLimitOptions,
+        storage: S,
+    ) -> Self {
+        self.rate_limiter = Some(RateLimiter::new(options, Arc::new(storage)));
+        self
+    }
+
+    /// Add a hook, which is called after the hooks added before it.
+    pub fn with_hook<H: Hook + 'static>(mut self, hook: H) -> Self {
+        self.hooks.push(Arc::new(hook));
+        self
+    }
+
     pub fn storage(&self) -> &dyn Storage<U> {
         &*self.storage
     }
 
+    pub fn session_registry(&self) -> Option<&dyn SessionRegistry> {
+        self.session_registry.as_deref()
+    }
+
+    pub fn audit_storage(&self) -> Option<&dyn AuditStorage> {
+        self.audit_storage.as_deref()
+    }
+
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
     pub fn options(&self) -> &ShieldOptions {
         &self.options
     }
 
+    pub fn hooks(&self) -> &Hooks {
+        &self.hooks
+    }
+
     pub fn action_by_id(&self, action_id: &str) -> Option<&dyn Action> {
         self.actions.get(action_id).map(|v| &**v)
     }
@@ -95,6 +203,13 @@ impl<U: User> Shield<U> {
         action_id: &str,
         session: Session,
     ) -> Result<ActionForms, ShieldError> {
+        let csrf_token = match self.options.csrf_protection {
+            CsrfProtection::Token if session.is_persistent() => {
+                Some(self.csrf_token(&session).await?)
+            }
+            _ => None,
+        };
+
         let mut action_name = None::<String>;
         let mut forms = vec![];
         let mut method_forms = vec![];
@@ -152,6 +267,17 @@ impl<U: User> Shield<U> {
             });
         }
 
+        if let Some(csrf_token) = csrf_token {
+            for form in forms.iter_mut().chain(
+                method_forms
+                    .iter_mut()
+                    .flat_map(|method_form| &mut method_form.provider_forms)
+                    .map(|provider_form| &mut provider_form.form),
+            ) {
+                form.inputs.push(csrf::csrf_token_input(&csrf_token));
+            }
+        }
+
         Ok(ActionForms {
             id: action_id.to_owned(),
             name: action_name.unwrap_or(action_id.to_owned()),
@@ -164,7 +290,7 @@ impl<U: User> Shield<U> {
         &self,
         action_id: &str,
         session: Session,
-        request: Request,
+        mut request: Request,
     ) -> Result<ResponseType, ShieldError> {
         let action =
             self.action_by_id(action_id)
@@ -172,31 +298,193 @@ impl<U: User> Shield<U> {
                     action_id.to_owned(),
                 )))?;
 
-        let base_session = {
+        csrf::verify_method(action_id, &action.method(), &request)?;
+        verify_token_method(&session, None)?;
+        if !action.allows_scoped_tokens() {
+            verify_unscoped(&session)?;
+        }
+        self.verify_csrf(&session, action.method(), &mut request)?;
+
+        let base_session = base_session(&session)?;
+
+        let hooks = self.request_hooks(session.client());
+
+        request.hooks = hooks.clone();
+        let response = action.call(&base_session, request).await?;
+
+        self.call_session_actions(&session, response, &hooks).await
+    }
+
+    pub async fn call_method(
+        &self,
+        action_id: &str,
+        method_id: &str,
+        provider_id: Option<&str>,
+        session: Session,
+        mut request: Request,
+    ) -> Result<ResponseType, ShieldError> {
+        if let Some(action) = self
+            .method_by_id(method_id)
+            .and_then(|method| method.erased_action_by_id(action_id))
+        {
+            csrf::verify_method(action_id, &action.erased_method(), &request)?;
+            verify_token_method(&session, Some(method_id))?;
+
+            if !action.erased_allows_scoped_tokens() {
+                verify_unscoped(&session)?;
+            }
+
+            if action.erased_csrf_protected() {
+                self.verify_csrf(&session, action.erased_method(), &mut request)?;
+            }
+        }
+
+        let hooks = self.request_hooks(session.client());
+        let base_session = base_session(&session)?;
+
+        let sign_in = action_id == SignInAction::id()
+            || action_id == SignInCallbackAction::id()
+            || base_session.pending_authentication.is_some();
+
+        let rate_limiter = self.rate_limiter.as_ref().filter(|_| sign_in);
+        let rate_limit_keys = match rate_limiter {
+            Some(_) => {
+                self.rate_limit_keys(action_id, method_id, &session, &base_session, &request)
+            }
+            None => vec![],
+        };
+
+        let result = async {
+            if let Some(rate_limiter) = rate_limiter {
+                rate_limiter.check(&rate_limit_keys).await?;
+            }
+
+            self.call_method_with_hooks(
+                action_id,
+                method_id,
+                provider_id,
+                &session,
+                request,
+                &hooks,
+            )
+            .await
+        }
+        .await;
+
+        if let Some(rate_limiter) = rate_limiter {
+            match &result {
+                Ok(_) => rate_limiter.succeed(&rate_limit_keys).await?,
+                Err(ShieldError::RateLimited(_)) => {}
+                Err(_) => rate_limiter.fail(&rate_limit_keys).await?,
+            }
+        }
+
+        if let Err(err) = &result
+            && sign_in
+            // The failure of the action is returned instead of the failure of a hook.
+            && let Err(hook_err) = hooks
+                .sign_in_failed(&SignInFailedEvent {
+                    action_id: action_id.to_owned(),
+                    method_id: method_id.to_owned(),
+                    provider_id: provider_id.map(ToOwned::to_owned),
+                    user_id: base_session
+                        .pending_authentication
+                        .map(|authentication| authentication.user_id),
+                    reason: err.to_string(),
+                })
+                .await
+        {
+            warn!("Sign in failed hook failed: {hook_err}");
+        }
+
+        result
+    }
+
+    /// CSRF token of the session, which is generated if the session does not have one yet.
+    pub async fn csrf_token(&self, session: &Session) -> Result<String, ShieldError> {
+        let csrf_token = {
             let session_data = session.data();
-            let session_data = session_data
+            let mut session_data = session_data
                 .lock()
                 .map_err(|err| SessionError::Lock(err.to_string()))?;
 
-            session_data.base.clone()
+            if let Some(csrf_token) = &session_data.base.csrf_token {
+                return Ok(csrf_token.clone());
+            }
+
+            let csrf_token = csrf::generate_csrf_token();
+            session_data.base.csrf_token = Some(csrf_token.clone());
+            csrf_token
         };
 
-        let response = action.call(&base_session, request).await?;
+        session.update().await?;
 
-        for session_action in &response.session_actions {
-            session_action.call(&session).await?;
+        Ok(csrf_token)
+    }
+
+    fn verify_csrf(
+        &self,
+        session: &Session,
+        action_method: RequestMethod,
+        request: &mut Request,
+    ) -> Result<(), ShieldError> {
+        // Sessions without cookies, e.g. for bearer tokens, can't be used by another site.
+        let protection = match session.is_persistent() {
+            true => &self.options.csrf_protection,
+            false => &CsrfProtection::Disabled,
+        };
+
+        csrf::verify_csrf(
+            protection,
+            base_session(session)?.csrf_token.as_deref(),
+            action_method,
+            request,
+        )
+    }
+
+    fn rate_limit_keys(
+        &self,
+        action_id: &str,
+        method_id: &str,
+        session: &Session,
+        base_session: &BaseSession,
+        request: &Request,
+    ) -> Vec<RateLimitKey> {
+        let mut keys = vec![];
+
+        if let Some(ip_address) = &session.client().ip_address {
+            keys.push(RateLimitKey::Ip {
+                method_id: method_id.to_owned(),
+                ip_address: ip_address.clone(),
+            });
+        }
+
+        let identifier = match &base_session.pending_authentication {
+            Some(authentication) => Some(authentication.user_id.clone()),
+            None => self
+                .method_by_id(method_id)
+                .and_then(|method| method.erased_action_by_id(action_id))
+                .and_then(|action| action.erased_rate_limit_identifier(request)),
+        };
+
+        if let Some(identifier) = identifier {
+            keys.push(RateLimitKey::Account {
+                method_id: method_id.to_owned(),
+                identifier,
+            });
         }
 
-        Ok(response.r#type)
+        keys
     }
 
-    pub async fn call_method(
+    async fn call_method_with_hooks(
         &self,
         action_id: &str,
         method_id: &str,
         provider_id: Option<&str>,
-        session: Session,
-        request: Request,
+        session: &Session,
+        mut request: Request,
+        hooks: &Hooks,
     ) -> Result<ResponseType, ShieldError> {
         let method =
             self.method_by_id(method_id)
@@ -230,28 +518,352 @@ impl<U: User> Shield<U> {
             )
         };
 
+        request.hooks = hooks.clone();
         let response = action
             .erased_call(provider, &base_session, &*method_session, request)
             .await?;
 
+        self.call_session_actions(session, response, hooks).await
+    }
+
+    /// Hooks for a request, including the audit hook if an audit storage is configured.
+    fn request_hooks(&self, client: &SessionClient) -> Hooks {
+        let mut hooks = self.hooks.clone();
+
+        if let Some(audit_storage) = &self.audit_storage {
+            // Record the event before other hooks observe it, so a failing hook does not hide it.
+            hooks.push_front(Arc::new(AuditHook::new(
+                audit_storage.clone(),
+                client.clone(),
+            )));
+        }
+
+        hooks
+    }
+
+    async fn call_session_actions(
+        &self,
+        session: &Session,
+        response: Response,
+        hooks: &Hooks,
+    ) -> Result<ResponseType, ShieldError> {
+        let mut response_type = response.r#type;
+
+        // Call the before hooks first, so a hook prevents all session actions of the response.
         for session_action in &response.session_actions {
-            session_action.call(&session).await?;
+            match session_action {
+                SessionAction::Authenticate {
+                    method_id,
+                    provider_id,
+                    user_id,
+                    ..
+                } => {
+                    hooks
+                        .before_sign_in(&SignInEvent {
+                            method_id: method_id.clone(),
+                            provider_id: provider_id.clone(),
+                            user_id: user_id.clone(),
+                        })
+                        .await?;
+                }
+                SessionAction::Unauthenticate => {
+                    if let Some(authentication) = authentication(session)? {
+                        hooks
+                            .before_sign_out(&SignOutEvent::from(&authentication))
+                            .await?;
+                    }
+                }
+                _ => {}
+            }
         }
 
-        Ok(response.r#type)
+        for session_action in response.session_actions {
+            let session_action = match session_action {
+                SessionAction::Authenticate {
+                    method_id,
+                    provider_id,
+                    user_id,
+                    provider_session_id,
+                } => match self.second_factor_action_id(&method_id, &user_id).await? {
+                    Some(action_id) => {
+                        response_type = ResponseType::RedirectToAction { action_id };
+
+                        SessionAction::PendingAuthentication {
+                            method_id,
+                            provider_id,
+                            user_id,
+                            provider_session_id,
+                        }
+                    }
+                    None => SessionAction::Authenticate {
+                        method_id,
+                        provider_id,
+                        user_id,
+                        provider_session_id,
+                    },
+                },
+                session_action => session_action,
+            };
+
+            match &session_action {
+                SessionAction::Authenticate { .. }
+                | SessionAction::PendingAuthentication { .. }
+                | SessionAction::Unauthenticate => {
+                    self.unregister_session(session).await?;
+                }
+                SessionAction::DestroySessions { filter } => {
+                    let current_session_id = registered_session_id(session)?;
+                    self.destroy_sessions_with_hooks(hooks, filter, current_session_id.as_deref())
+                        .await?;
+                }
+                _ => {}
+            }
+
+            let previous_authentication = authentication(session)?;
+
+            session_action.call(session).await?;
+
+            if matches!(
+                session_action,
+                SessionAction::Authenticate { .. } | SessionAction::AddFactor { .. }
+            ) {
+                self.register_session(session).await?;
+            }
+
+            let signed_in = match &session_action {
+                SessionAction::Authenticate { .. } => true,
+                // Adding a factor to a pending authentication completes the sign in.
+                SessionAction::AddFactor { .. } => previous_authentication.is_none(),
+                _ => false,
+            };
+
+            if signed_in && let Some(authentication) = authentication(session)? {
+                hooks
+                    .after_sign_in(&SignInEvent::from(&authentication))
+                    .await?;
+            }
+
+            if matches!(session_action, SessionAction::Unauthenticate)
+                && let Some(authentication) = previous_authentication
+            {
+                hooks
+                    .after_sign_out(&SignOutEvent::from(&authentication))
+                    .await?;
+            }
+        }
+
+        Ok(response_type)
     }
 
-    pub async fn user(&self, session: &Session) -> Result<Option<U>, ShieldError> {
-        let authentication = {
+    /// Add the authentication of the session to the session registry, if it is not registered yet.
+    async fn register_session(&self, session: &Session) -> Result<(), ShieldError> {
+        let Some(session_registry) = &self.session_registry else {
+            return Ok(());
+        };
+        if !session.is_persistent() {
+            return Ok(());
+        }
+
+        let Some(authentication) = authentication(session)? else {
+            return Ok(());
+        };
+        if authentication.session_id.is_some() {
+            return Ok(());
+        }
+
+        let registered_session = session_registry
+            .create_registered_session(CreateRegisteredSession {
+                user_id: authentication.user_id,
+                method_id: authentication.method_id,
+                provider_id: authentication.provider_id,
+                provider_session_id: authentication.provider_session_id,
+                ip_address: session.client().ip_address.clone(),
+                user_agent: session.client().user_agent.clone(),
+            })
+            .await?;
+
+        {
             let session_data = session.data();
-            let session_data = session_data
+            let mut session_data = session_data
                 .lock()
                 .map_err(|err| SessionError::Lock(err.to_string()))?;
 
-            session_data.base.authentication.clone()
+            if let Some(authentication) = &mut session_data.base.authentication {
+                authentication.session_id = Some(registered_session.id);
+                authentication.session_registered_at = Some(registered_session.created_at);
+            }
+        }
+
+        session.update().await?;
+
+        Ok(())
+    }
+
+    async fn unregister_session(&self, session: &Session) -> Result<(), ShieldError> {
+        if let Some(session_registry) = &self.session_registry
+            && let Some(session_id) = registered_session_id(session)?
+        {
+            session_registry
+                .delete_registered_session(&session_id)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Terminate all sessions matching the filter, except the given session.
+    ///
+    /// The sessions are removed from the session registry and purged the next time they are used. If the filter only
+    /// matches a user, all refresh tokens of the user are revoked as well.
+    pub async fn destroy_sessions(
+        &self,
+        filter: &RegisteredSessionFilter,
+        except_session_id: Option<&str>,
+    ) -> Result<(), ShieldError> {
+        self.destroy_sessions_with_hooks(
+            &self.request_hooks(&SessionClient::default()),
+            filter,
+            except_session_id,
+        )
+        .await
+    }
+
+    async fn destroy_sessions_with_hooks(
+        &self,
+        hooks: &Hooks,
+        filter: &RegisteredSessionFilter,
+        except_session_id: Option<&str>,
+    ) -> Result<(), ShieldError> {
+        // Refresh tokens are not tied to a registered session, so they are revoked for the user as a whole.
+        if let Some(bearer_tokens) = &self.bearer_tokens
+            && let RegisteredSessionFilter {
+                user_id: Some(user_id),
+                method_id: None,
+                provider_id: None,
+                provider_session_id: None,
+            } = filter
+        {
+            bearer_tokens.revoke_user(user_id).await?;
+        }
+
+        let Some(session_registry) = &self.session_registry else {
+            warn!("Sessions can not be destroyed without a session registry.");
+            return Ok(());
         };
 
+        for registered_session in session_registry.registered_sessions(filter).await? {
+            if Some(registered_session.id.as_str()) == except_session_id {
+                continue;
+            }
+
+            session_registry::revoke_session(&**session_registry, hooks, &registered_session)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn second_factor_action_id(
+        &self,
+        method_id: &str,
+        user_id: &str,
+    ) -> Result<Option<String>, ShieldError> {
+        for (id, method) in self.methods.iter() {
+            if id == method_id {
+                continue;
+            }
+
+            if let Some(action_id) = method.erased_second_factor_action_id(user_id).await? {
+                return Ok(Some(action_id));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn user(&self, session: &Session) -> Result<Option<U>, ShieldError> {
+        self.user_with_assurance_level(session, self.options.assurance_level)
+            .await
+    }
+
+    /// Get the authenticated user, if the authentication provides at least the given assurance level.
+    pub async fn user_with_assurance_level(
+        &self,
+        session: &Session,
+        assurance_level: AssuranceLevel,
+    ) -> Result<Option<U>, ShieldError> {
+        let authentication = authentication(session)?;
+
+        if let Some(session_registry) = &self.session_registry
+            && session.is_persistent()
+            && let Some(authentication) = &authentication
+        {
+            match &authentication.session_id {
+                Some(session_id) => match session_registry
+                    .registered_session_by_id(session_id)
+                    .await?
+                {
+                    Some(registered_session) => {
+                        let now = Utc::now();
+                        if now - registered_session.last_seen_at > LAST_SEEN_INTERVAL {
+                            let client = session.client();
+
+                            session_registry
+                                .update_registered_session(UpdateRegisteredSession {
+                                    id: registered_session.id,
+                                    ip_address: client
+                                        .ip_address
+                                        .is_some()
+                                        .then(|| client.ip_address.clone()),
+                                    user_agent: client
+                                        .user_agent
+                                        .is_some()
+                                        .then(|| client.user_agent.clone()),
+                                    last_seen_at: Some(now),
+                                })
+                                .await?;
+                        }
+                    }
+                    // A registry which is not persistent loses its sessions on restart, so sessions registered
+                    // before are registered again instead of being treated as terminated.
+                    None if session_registry.registered_since().is_some_and(
+                        |registered_since| {
+                            authentication
+                                .session_registered_at
+                                .is_none_or(|registered_at| registered_at < registered_since)
+                        },
+                    ) =>
+                    {
+                        {
+                            let session_data = session.data();
+                            let mut session_data = session_data
+                                .lock()
+                                .map_err(|err| SessionError::Lock(err.to_string()))?;
+
+                            if let Some(authentication) = &mut session_data.base.authentication {
+                                authentication.session_id = None;
+                                authentication.session_registered_at = None;
+                            }
+                        }
+
+                        self.register_session(session).await?;
+                    }
+                    None => {
+                        session.purge().await?;
+                        return Ok(None);
+                    }
+                },
+                None => self.register_session(session).await?,
+            }
+        }
+
         match authentication {
+            Some(authentication)
+                if authentication.method_only
+                    || authentication.assurance_level() < assurance_level =>
+            {
+                Ok(None)
+            }
             Some(authentication) => {
                 if self
                     .provider_by_id(
@@ -277,6 +889,16 @@ impl<U: User> Shield<U> {
         }
     }
 
+    /// Delete the user, calling the user delete hooks around it.
+    pub async fn delete_user(&self, user: &U) -> Result<(), ShieldError> {
+        let hooks = self.request_hooks(&SessionClient::default());
+        let event = UserEvent { user_id: user.id() };
+
+        hooks.before_user_delete(&event).await?;
+        self.storage.delete_user(&event.user_id).await?;
+        hooks.user_deleted(&event).await
+    }
+
     pub async fn user_connections<C: 'static>(
         &self,
         user: &U,
@@ -303,6 +925,183 @@ impl<U: User> Shield<U> {
             .collect())
     }
 
+    /// List the connections of the user across all methods.
+    pub async fn list_user_connections(
+        &self,
+        user: &U,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        connection::list_user_connections(&self.methods, &user.id()).await
+    }
+
+    /// Unlink a connection of the user, unless it is the last one the user can sign in with.
+    pub async fn unlink_user_connection(
+        &self,
+        user: &U,
+        session: &Session,
+        method_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        connection::unlink_user_connection(
+            &self.methods,
+            &self.request_hooks(session.client()),
+            &user.id(),
+            method_id,
+            connection_id,
+        )
+        .await
+    }
+
+    /// List the active sessions of the user, if a session registry is configured.
+    pub async fn list_user_sessions(
+        &self,
+        user: &U,
+        session: &Session,
+    ) -> Result<Vec<UserSession>, ShieldError> {
+        session_registry::list_user_sessions(
+            self.required_session_registry()?,
+            &user.id(),
+            registered_session_id(session)?.as_deref(),
+        )
+        .await
+    }
+
+    /// Revoke a session of the user. Revoking the current session signs the user out.
+    pub async fn revoke_user_session(
+        &self,
+        user: &U,
+        session: &Session,
+        session_id: &str,
+    ) -> Result<(), ShieldError> {
+        session_registry::revoke_user_session(
+            self.required_session_registry()?,
+            &self.request_hooks(session.client()),
+            &user.id(),
+            session_id,
+        )
+        .await?;
+
+        if registered_session_id(session)?.as_deref() == Some(session_id) {
+            session.purge().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Revoke all sessions of the user, except the current session.
+    pub async fn revoke_other_user_sessions(
+        &self,
+        user: &U,
+        session: &Session,
+    ) -> Result<(), ShieldError> {
+        session_registry::revoke_other_user_sessions(
+            self.required_session_registry()?,
+            &self.request_hooks(session.client()),
+            &user.id(),
+            registered_session_id(session)?.as_deref(),
+        )
+        .await
+    }
+
+    /// List the audit entries matching the filter, if an audit storage is configured.
+    pub async fn audit_entries(
+        &self,
+        filter: &AuditFilter,
+    ) -> Result<Vec<AuditEntry>, ShieldError> {
+        Ok(self.required_audit_storage()?.audit_entries(filter).await?)
+    }
+
+    fn required_audit_storage(&self) -> Result<&dyn AuditStorage, ConfigurationError> {
+        self.audit_storage()
+            .ok_or_else(|| ConfigurationError::Missing("audit storage".to_owned()))
+    }
+
+    fn required_session_registry(&self) -> Result<&dyn SessionRegistry, ConfigurationError> {
+        self.session_registry()
+            .ok_or_else(|| ConfigurationError::Missing("session registry".to_owned()))
+    }
+
+    /// Issue an access token and refresh token for the authenticated user of the session.
+    ///
+    /// Only sessions signed in with a method can be exchanged for tokens. Sessions authenticated with a token, e.g. an
+    /// access token or a scoped API key, are rejected, so tokens can not outlive or widen the token they came from.
+    pub async fn issue_bearer_tokens(
+        &self,
+        session: &Session,
+    ) -> Result<BearerTokens, ShieldError> {
+        let bearer_tokens = self.required_bearer_tokens()?;
+
+        if !session.is_persistent() {
+            return Err(ShieldError::Unauthorized);
+        }
+        let authentication = authentication(session)?.ok_or(ShieldError::Unauthorized)?;
+        if authentication.scopes.is_some() {
+            return Err(ShieldError::Unauthorized);
+        }
+
+        if self.user(session).await?.is_none() {
+            return Err(ShieldError::Unauthorized);
+        }
+
+        bearer_tokens.issue(&authentication).await
+    }
+
+    /// Exchange a refresh token for a new access token and refresh token.
+    ///
+    /// Refresh tokens can be used once. Using a refresh token again revokes all tokens issued since the sign-in.
+    pub async fn refresh_bearer_tokens(
+        &self,
+        refresh_token: &str,
+    ) -> Result<BearerTokens, ShieldError> {
+        let bearer_tokens = self.required_bearer_tokens()?;
+
+        if let Some(user_id) = bearer_tokens.refresh_token_user_id(refresh_token).await?
+            && self.storage.user_by_id(&user_id).await?.is_none()
+        {
+            bearer_tokens.revoke(refresh_token).await?;
+            return Err(ShieldError::Unauthorized);
+        }
+
+        bearer_tokens.refresh(refresh_token).await
+    }
+
+    /// Revoke a refresh token and all refresh tokens issued since the same sign-in.
+    pub async fn revoke_bearer_tokens(&self, refresh_token: &str) -> Result<(), ShieldError> {
+        self.required_bearer_tokens()?.revoke(refresh_token).await
+    }
+
+    /// Create a session for the request from an access token, without a cookie session.
+    pub fn bearer_session(&self, access_token: &str) -> Result<Session, ShieldError> {
+        let claims = self.required_bearer_tokens()?.verify(access_token)?;
+
+        Ok(Session::new(BearerSessionStorage::new(claims.into())))
+    }
+
+    /// Create a session for the request from a token in the `Authorization` header, which is either
+    /// an access token or a token of a method, e.g. an API key.
+    pub async fn token_session(&self, token: &str) -> Result<Option<Session>, ShieldError> {
+        if let Some(bearer_tokens) = &self.bearer_tokens
+            && let Ok(claims) = bearer_tokens.verify(token)
+        {
+            return Ok(Some(Session::new(BearerSessionStorage::new(claims.into()))));
+        }
+
+        for method in self.methods.values() {
+            if let Some(authentication) = method.erased_authenticate_token(token).await? {
+                return Ok(Some(Session::new(BearerSessionStorage::new(
+                    authentication,
+                ))));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn required_bearer_tokens(&self) -> Result<&BearerTokenManager, ConfigurationError> {
+        self.bearer_tokens
+            .as_ref()
+            .ok_or_else(|| ConfigurationError::Missing("bearer tokens".to_owned()))
+    }
+
     #[cfg(feature = "utoipa")]
     pub fn openapi(&self) -> OpenApi {
         use utoipa::openapi::Response;
@@ -374,14 +1173,269 @@ impl<U: User> Shield<U> {
     }
 }
 
+fn authentication(session: &Session) -> Result<Option<Authentication>, SessionError> {
+    let session_data = session.data();
+    let session_data = session_data
+        .lock()
+        .map_err(|err| SessionError::Lock(err.to_string()))?;
+
+    Ok(session_data.base.authentication.clone())
+}
+
+fn base_session(session: &Session) -> Result<BaseSession, SessionError> {
+    let session_data = session.data();
+    let session_data = session_data
+        .lock()
+        .map_err(|err| SessionError::Lock(err.to_string()))?;
+
+    Ok(session_data.base.clone())
+}
+
+fn registered_session_id(session: &Session) -> Result<Option<String>, SessionError> {
+    Ok(authentication(session)?.and_then(|authentication| authentication.session_id))
+}
+
+/// Tokens which are only valid for their method, e.g. access tokens issued to third-party clients, can only call
+/// actions of that method.
+fn verify_token_method(session: &Session, method_id: Option<&str>) -> Result<(), ShieldError> {
+    if authentication(session)?.is_some_and(|authentication| {
+        authentication.method_only && method_id != Some(authentication.method_id.as_str())
+    }) {
+        return Err(ShieldError::Forbidden(
+            "This action can not be called with a token of another method.".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Tokens restricted to scopes, e.g. API keys, can only call actions which check the scopes themselves.
+fn verify_unscoped(session: &Session) -> Result<(), ShieldError> {
+    if authentication(session)?.is_some_and(|authentication| authentication.scopes.is_some()) {
+        return Err(ShieldError::Forbidden(
+            "This action can not be called with a scoped token.".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, TimeDelta, Utc};
+    use serde_json::{Value, json};
+
     use crate::{
+        action::MethodAction,
+        audit::{AuditEntry, AuditEventType, AuditFilter, AuditStorage, CreateAuditEntry},
+        bearer::{
+            BearerSessionStorage,
+            tests::{TestRefreshTokenStorage, options},
+        },
+        csrf::{CSRF_TOKEN_INPUT_NAME, csrf_token_input},
+        error::{ActionError, SessionError, ShieldError, StorageError},
+        form::Form,
+        hook::{Hook, SignInFailedEvent, SignOutEvent, UserEvent},
+        method::Method,
         options::ShieldOptions,
+        provider::Provider,
+        request::{Request, RequestMethod},
+        response::{Response, ResponseType},
+        session::{
+            Authentication, MethodSession, Session, SessionClient, SessionData, SessionStorage,
+        },
+        session_registry::{
+            CreateRegisteredSession, RegisteredSession, RegisteredSessionFilter, SessionRegistry,
+            UpdateRegisteredSession,
+        },
         storage::tests::{TEST_STORAGE_ID, TestStorage},
+        user::tests::TestUser,
     };
 
-    use super::Shield;
+    use super::{Shield, authentication};
+
+    #[derive(Default)]
+    struct SignOutHook {
+        veto: bool,
+        signed_out: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Hook for SignOutHook {
+        async fn before_sign_out(&self, _event: &SignOutEvent) -> Result<(), ShieldError> {
+            if self.veto {
+                return Err(ShieldError::Unauthorized);
+            }
+
+            Ok(())
+        }
+
+        async fn after_sign_out(&self, event: &SignOutEvent) -> Result<(), ShieldError> {
+            self.signed_out.lock().unwrap().push(event.user_id.clone());
+
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct TestAuditStorage {
+        entries: Arc<Mutex<Vec<AuditEntry>>>,
+    }
+
+    #[async_trait]
+    impl AuditStorage for TestAuditStorage {
+        async fn create_audit_entry(
+            &self,
+            entry: CreateAuditEntry,
+        ) -> Result<AuditEntry, StorageError> {
+            let mut entries = self.entries.lock().unwrap();
+
+            let entry = AuditEntry {
+                id: entries.len().to_string(),
+                event_type: entry.event_type,
+                user_id: entry.user_id,
+                method_id: entry.method_id,
+                provider_id: entry.provider_id,
+                reason: entry.reason,
+                ip_address: entry.ip_address,
+                user_agent: entry.user_agent,
+                created_at: Utc::now(),
+            };
+            entries.push(entry.clone());
+
+            Ok(entry)
+        }
+
+        async fn audit_entries(
+            &self,
+            filter: &AuditFilter,
+        ) -> Result<Vec<AuditEntry>, StorageError> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|entry| filter.matches(entry))
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Default)]
+    struct TestSessionStorage {
+        session_data: Arc<Mutex<SessionData>>,
+    }
+
+    #[async_trait]
+    impl SessionStorage for TestSessionStorage {
+        fn data(&self) -> Arc<Mutex<SessionData>> {
+            self.session_data.clone()
+        }
+
+        async fn update(&self) -> Result<(), SessionError> {
+            Ok(())
+        }
+
+        async fn renew(&self) -> Result<(), SessionError> {
+            Ok(())
+        }
+
+        async fn purge(&self) -> Result<(), SessionError> {
+            *self.session_data.lock().unwrap() = SessionData::default();
+
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct TestSessionRegistry {
+        registered_since: Option<DateTime<Utc>>,
+        sessions: Arc<Mutex<Vec<RegisteredSession>>>,
+    }
+
+    #[async_trait]
+    impl SessionRegistry for TestSessionRegistry {
+        fn registered_since(&self) -> Option<DateTime<Utc>> {
+            self.registered_since
+        }
+
+        async fn registered_session_by_id(
+            &self,
+            session_id: &str,
+        ) -> Result<Option<RegisteredSession>, SessionError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|session| session.id == session_id)
+                .cloned())
+        }
+
+        async fn registered_sessions(
+            &self,
+            filter: &RegisteredSessionFilter,
+        ) -> Result<Vec<RegisteredSession>, SessionError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|session| filter.matches(session))
+                .cloned()
+                .collect())
+        }
+
+        async fn create_registered_session(
+            &self,
+            session: CreateRegisteredSession,
+        ) -> Result<RegisteredSession, SessionError> {
+            let mut sessions = self.sessions.lock().unwrap();
+
+            let session = RegisteredSession {
+                id: sessions.len().to_string(),
+                user_id: session.user_id,
+                method_id: session.method_id,
+                provider_id: session.provider_id,
+                provider_session_id: session.provider_session_id,
+                ip_address: session.ip_address,
+                user_agent: session.user_agent,
+                created_at: Utc::now(),
+                last_seen_at: Utc::now(),
+            };
+            sessions.push(session.clone());
+
+            Ok(session)
+        }
+
+        async fn update_registered_session(
+            &self,
+            _session: UpdateRegisteredSession,
+        ) -> Result<RegisteredSession, SessionError> {
+            todo!("update_registered_session")
+        }
+
+        async fn delete_registered_session(&self, session_id: &str) -> Result<(), SessionError> {
+            self.sessions
+                .lock()
+                .unwrap()
+                .retain(|session| session.id != session_id);
+
+            Ok(())
+        }
+    }
+
+    fn session() -> Session {
+        Session::new(BearerSessionStorage::new(Authentication::for_token(
+            "credentials",
+            None,
+            "user",
+            None,
+        )))
+    }
 
     #[test]
     fn test_storage() {
@@ -389,4 +1443,542 @@ mod tests {
 
         assert_eq!(TEST_STORAGE_ID, shield.storage().id());
     }
+
+    #[tokio::test]
+    async fn test_sign_out_hooks() {
+        let signed_out = Arc::new(Mutex::new(vec![]));
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+            .with_hook(SignOutHook {
+                veto: false,
+                signed_out: signed_out.clone(),
+            });
+
+        let session = session();
+        shield
+            .call(
+                "sign-out",
+                session.clone(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await
+            .expect("Sign out should succeed.");
+
+        assert!(authentication(&session).unwrap().is_none());
+        assert_eq!(vec!["user"], *signed_out.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_sign_out_hooks_veto() {
+        let signed_out = Arc::new(Mutex::new(vec![]));
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+            .with_hook(SignOutHook {
+                veto: true,
+                signed_out: signed_out.clone(),
+            });
+
+        let session = session();
+        let result = shield
+            .call(
+                "sign-out",
+                session.clone(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ShieldError::Unauthorized)));
+        assert!(authentication(&session).unwrap().is_some());
+        assert!(signed_out.lock().unwrap().is_empty());
+    }
+
+    struct SignInFailedHook;
+
+    #[async_trait]
+    impl Hook for SignInFailedHook {
+        async fn sign_in_failed(&self, _event: &SignInFailedEvent) -> Result<(), ShieldError> {
+            Err(StorageError::Engine("Audit storage is unavailable.".to_owned()).into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_in_failed_hook_error() {
+        let shield = Shield::new(
+            TestStorage::default(),
+            vec![Arc::new(ScopedMethod)],
+            ShieldOptions::default(),
+        )
+        .with_hook(SignInFailedHook);
+
+        let result = shield
+            .call_method(
+                "sign-in",
+                "scoped",
+                None,
+                session(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await;
+
+        // The failure of the sign in is returned instead of the failure of the hook.
+        assert!(matches!(
+            result,
+            Err(ShieldError::Action(ActionError::NotFound(_)))
+        ));
+    }
+
+    struct FailingAuditStorage;
+
+    #[async_trait]
+    impl AuditStorage for FailingAuditStorage {
+        async fn create_audit_entry(
+            &self,
+            _entry: CreateAuditEntry,
+        ) -> Result<AuditEntry, StorageError> {
+            Err(StorageError::Engine(
+                "Audit storage is unavailable.".to_owned(),
+            ))
+        }
+
+        async fn audit_entries(
+            &self,
+            _filter: &AuditFilter,
+        ) -> Result<Vec<AuditEntry>, StorageError> {
+            Ok(vec![])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_storage_error() {
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+            .with_audit_storage(FailingAuditStorage);
+
+        // The session is already signed out when the audit entry is recorded, so the request succeeds.
+        let session = session();
+        shield
+            .call(
+                "sign-out",
+                session.clone(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await
+            .expect("Sign out should succeed.");
+
+        assert!(authentication(&session).unwrap().is_none());
+    }
+
+    struct UserDeleteHook;
+
+    #[async_trait]
+    impl Hook for UserDeleteHook {
+        async fn before_user_delete(&self, _event: &UserEvent) -> Result<(), ShieldError> {
+            Err(ShieldError::Forbidden(
+                "Users can not be deleted.".to_owned(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_veto() {
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+            .with_hook(UserDeleteHook);
+
+        // The hook prevents the user from being deleted in the storage.
+        let result = shield.delete_user(&TestUser::new("user")).await;
+
+        assert!(matches!(result, Err(ShieldError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_audit_sign_out() {
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+            .with_audit_storage(TestAuditStorage::default());
+
+        let session = session().with_client(SessionClient {
+            ip_address: Some("192.0.2.1".to_owned()),
+            user_agent: Some("curl/8.10.1".to_owned()),
+        });
+        shield
+            .call("sign-out", session, Request::new(Value::Null, Value::Null))
+            .await
+            .expect("Sign out should succeed.");
+
+        let entries = shield
+            .audit_entries(&AuditFilter::user("user"))
+            .await
+            .expect("Audit entries should be listed.");
+
+        assert_eq!(1, entries.len());
+        assert_eq!(AuditEventType::SignOut, entries[0].event_type);
+        assert_eq!(Some("credentials"), entries[0].method_id.as_deref());
+        assert_eq!(Some("192.0.2.1"), entries[0].ip_address.as_deref());
+        assert_eq!(Some("curl/8.10.1"), entries[0].user_agent.as_deref());
+
+        assert!(
+            shield
+                .audit_entries(&AuditFilter::user("other"))
+                .await
+                .expect("Audit entries should be listed.")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_csrf_token() {
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default());
+        let session = Session::new(TestSessionStorage::default());
+
+        let forms = shield
+            .action_forms("sign-out", session.clone())
+            .await
+            .expect("Action forms should be returned.");
+        let csrf_token = shield
+            .csrf_token(&session)
+            .await
+            .expect("CSRF token should exist.");
+
+        assert_eq!(
+            Some(&csrf_token_input(&csrf_token)),
+            forms.forms[0].inputs.last()
+        );
+
+        let result = shield
+            .call(
+                "sign-out",
+                session.clone(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ShieldError::Forbidden(_))));
+
+        shield
+            .call(
+                "sign-out",
+                session,
+                Request::new(Value::Null, json!({ CSRF_TOKEN_INPUT_NAME: csrf_token })),
+            )
+            .await
+            .expect("Sign out should succeed.");
+    }
+
+    struct ScopedProvider;
+
+    impl Provider for ScopedProvider {
+        fn method_id(&self) -> String {
+            "scoped".to_owned()
+        }
+
+        fn id(&self) -> Option<String> {
+            None
+        }
+
+        fn name(&self) -> String {
+            "Scoped".to_owned()
+        }
+    }
+
+    struct ScopedAction {
+        id: &'static str,
+        allows_scoped_tokens: bool,
+    }
+
+    #[async_trait]
+    impl MethodAction<ScopedProvider, ()> for ScopedAction {
+        fn id(&self) -> String {
+            self.id.to_owned()
+        }
+
+        fn name(&self) -> String {
+            self.id.to_owned()
+        }
+
+        fn openapi_summary(&self) -> &'static str {
+            "Scoped"
+        }
+
+        fn openapi_description(&self) -> &'static str {
+            "Scoped"
+        }
+
+        fn method(&self) -> RequestMethod {
+            RequestMethod::Post
+        }
+
+        fn allows_scoped_tokens(&self) -> bool {
+            self.allows_scoped_tokens
+        }
+
+        async fn forms(&self, _provider: ScopedProvider) -> Result<Vec<Form>, ShieldError> {
+            Ok(vec![])
+        }
+
+        async fn call(
+            &self,
+            _provider: ScopedProvider,
+            _session: &MethodSession<()>,
+            _request: Request,
+        ) -> Result<Response, ShieldError> {
+            Ok(Response::new(ResponseType::Default))
+        }
+    }
+
+    crate::erased_method_action!(ScopedAction);
+
+    struct ScopedMethod;
+
+    #[async_trait]
+    impl Method for ScopedMethod {
+        type Provider = ScopedProvider;
+        type Connection = ();
+        type Session = ();
+
+        fn id(&self) -> String {
+            "scoped".to_owned()
+        }
+
+        fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+            vec![
+                Box::new(ScopedAction {
+                    id: "unscoped",
+                    allows_scoped_tokens: false,
+                }),
+                Box::new(ScopedAction {
+                    id: "scoped",
+                    allows_scoped_tokens: true,
+                }),
+            ]
+        }
+
+        async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+            Ok(vec![ScopedProvider])
+        }
+
+        async fn user_connections(
+            &self,
+            _user_id: &str,
+            _provider_id: Option<&str>,
+        ) -> Result<Vec<Self::Connection>, ShieldError> {
+            Ok(vec![])
+        }
+    }
+
+    crate::erased_method!(ScopedMethod);
+
+    #[tokio::test]
+    async fn test_scoped_token_actions() {
+        let shield = Shield::new(
+            TestStorage::default(),
+            vec![Arc::new(ScopedMethod)],
+            ShieldOptions::default(),
+        )
+        .with_session_registry(TestSessionRegistry::default());
+
+        let scoped_session = || {
+            Session::new(BearerSessionStorage::new(Authentication::for_token(
+                "api-key",
+                None,
+                "user",
+                Some(vec!["read".to_owned()]),
+            )))
+        };
+
+        for action_id in [
+            "sign-out",
+            "unlink-connection",
+            "revoke-session",
+            "revoke-other-sessions",
+        ] {
+            let result = shield
+                .call(
+                    action_id,
+                    scoped_session(),
+                    Request::new(Value::Null, Value::Null),
+                )
+                .await;
+
+            assert!(
+                matches!(result, Err(ShieldError::Forbidden(_))),
+                "{action_id} should be forbidden"
+            );
+        }
+
+        let result = shield
+            .call_method(
+                "unscoped",
+                "scoped",
+                None,
+                scoped_session(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await;
+        assert!(matches!(result, Err(ShieldError::Forbidden(_))));
+
+        shield
+            .call_method(
+                "scoped",
+                "scoped",
+                None,
+                scoped_session(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await
+            .expect("Action should allow scoped tokens.");
+
+        // Tokens which are not restricted to scopes can call all actions.
+        shield
+            .call_method(
+                "unscoped",
+                "scoped",
+                None,
+                session(),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await
+            .expect("Action should allow unscoped tokens.");
+    }
+
+    #[tokio::test]
+    async fn test_method_token_actions() {
+        let shield = Shield::new(
+            TestStorage::default(),
+            vec![Arc::new(ScopedMethod)],
+            ShieldOptions::default(),
+        );
+
+        let method_session = |method_id: &str| {
+            Session::new(BearerSessionStorage::new(Authentication::for_method_token(
+                method_id,
+                None,
+                "user",
+                Some(vec!["read".to_owned()]),
+            )))
+        };
+
+        // Method tokens do not authenticate a user for the application.
+        assert!(
+            shield
+                .user(&method_session("scoped"))
+                .await
+                .expect("User should be loaded.")
+                .is_none()
+        );
+
+        let result = shield
+            .call(
+                "sign-out",
+                method_session("scoped"),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await;
+        assert!(matches!(result, Err(ShieldError::Forbidden(_))));
+
+        let result = shield
+            .call_method(
+                "scoped",
+                "scoped",
+                None,
+                method_session("other"),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await;
+        assert!(matches!(result, Err(ShieldError::Forbidden(_))));
+
+        shield
+            .call_method(
+                "scoped",
+                "scoped",
+                None,
+                method_session("scoped"),
+                Request::new(Value::Null, Value::Null),
+            )
+            .await
+            .expect("Action should allow tokens of its method.");
+    }
+
+    #[tokio::test]
+    async fn test_destroy_sessions_revokes_refresh_tokens() {
+        let storage = TestRefreshTokenStorage::default();
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+            .with_bearer_tokens(options(), storage.clone());
+
+        let bearer_tokens = shield.required_bearer_tokens().unwrap();
+        for user_id in ["user", "user", "other"] {
+            bearer_tokens
+                .issue(&Authentication::for_token(
+                    "credentials",
+                    None,
+                    user_id,
+                    None,
+                ))
+                .await
+                .expect("Bearer tokens should be issued.");
+        }
+
+        shield
+            .destroy_sessions(&RegisteredSessionFilter::user("user"), None)
+            .await
+            .expect("Sessions should be destroyed.");
+
+        let tokens = storage.tokens();
+        assert_eq!(1, tokens.len());
+        assert_eq!("other", tokens[0].user_id);
+    }
+
+    #[tokio::test]
+    async fn test_issue_bearer_tokens_rejects_token_sessions() {
+        let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+            .with_bearer_tokens(options(), TestRefreshTokenStorage::default());
+
+        // Session of a request with an access token or API key.
+        let result = shield.issue_bearer_tokens(&session()).await;
+        assert!(matches!(result, Err(ShieldError::Unauthorized)));
+
+        // Scoped authentication in a cookie session.
+        let session = Session::new(TestSessionStorage::default());
+        session.data().lock().unwrap().base.authentication = Some(Authentication::for_token(
+            "api-key",
+            None,
+            "user",
+            Some(vec!["read".to_owned()]),
+        ));
+        let result = shield.issue_bearer_tokens(&session).await;
+        assert!(matches!(result, Err(ShieldError::Unauthorized)));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_registered_session() {
+        let now = Utc::now();
+
+        // A registry which is not persistent only knows about sessions registered since it was created.
+        for (registered_since, registered_again) in [
+            (None, false),
+            (Some(now - TimeDelta::hours(2)), false),
+            (Some(now), true),
+        ] {
+            let registry = TestSessionRegistry {
+                registered_since,
+                ..Default::default()
+            };
+            let shield = Shield::new(TestStorage::default(), vec![], ShieldOptions::default())
+                .with_session_registry(registry.clone());
+
+            let mut authentication = Authentication::for_token("credentials", None, "user", None);
+            authentication.session_id = Some("unknown".to_owned());
+            authentication.session_registered_at = Some(now - TimeDelta::hours(1));
+
+            let session = Session::new(TestSessionStorage::default());
+            session.data().lock().unwrap().base.authentication = Some(authentication);
+
+            // The user is not found either way, as there are no methods.
+            shield
+                .user(&session)
+                .await
+                .expect("User should be checked.");
+
+            assert_eq!(
+                registered_again,
+                !registry.sessions.lock().unwrap().is_empty(),
+                "registered since {registered_since:?}"
+            );
+        }
+    }
 }
@@ -14,6 +14,11 @@ pub trait User: Debug + Send + Sync {
 
     async fn email_addresses(&self) -> Result<Vec<EmailAddress>, StorageError>;
 
+    /// Phone numbers of the user, empty for storages without phone number support.
+    async fn phone_numbers(&self) -> Result<Vec<PhoneNumber>, StorageError> {
+        Ok(vec![])
+    }
+
     fn additional(&self) -> Option<impl Serialize>;
 }
 
@@ -66,6 +71,29 @@ pub struct UpdateEmailAddress {
     pub verified_at: Option<Option<DateTime<FixedOffset>>>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneNumber {
+    pub id: String,
+    /// Phone number in E.164 format, e.g. `+31612345678`.
+    pub phone_number: String,
+    pub is_primary: bool,
+    pub is_verified: bool,
+    #[serde(skip)]
+    pub verified_at: Option<DateTime<FixedOffset>>,
+    #[serde(skip)]
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreatePhoneNumber {
+    pub phone_number: String,
+    pub is_primary: bool,
+    pub is_verified: bool,
+    pub verified_at: Option<DateTime<FixedOffset>>,
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use async_trait::async_trait;
@@ -81,6 +109,15 @@ pub(crate) mod tests {
         name: Option<String>,
     }
 
+    impl TestUser {
+        pub fn new(id: &str) -> Self {
+            Self {
+                id: id.to_owned(),
+                name: None,
+            }
+        }
+    }
+
     #[async_trait]
     impl User for TestUser {
         fn id(&self) -> String {
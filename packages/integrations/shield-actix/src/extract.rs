@@ -2,7 +2,7 @@ use actix_utils::future::{Ready, ready};
 use actix_web::{
     Error, FromRequest, HttpMessage, HttpRequest, dev::Payload, error::ErrorInternalServerError,
 };
-use shield::{Session, Shield, User};
+use shield::{RequestContext, Session, Shield, User};
 
 pub struct ExtractShield<U: User>(pub Shield<U>);
 
@@ -42,6 +42,25 @@ impl FromRequest for ExtractSession {
     }
 }
 
+pub struct ExtractRequestContext(pub RequestContext);
+
+impl FromRequest for ExtractRequestContext {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(
+            req.extensions()
+                .get::<RequestContext>()
+                .cloned()
+                .map(ExtractRequestContext)
+                .ok_or(ErrorInternalServerError(
+                    "Can't extract Shield request context. Is `ShieldTransform` enabled?",
+                )),
+        )
+    }
+}
+
 pub struct ExtractUser<U: User>(pub Option<U>);
 
 impl<U: User + Clone + 'static> FromRequest for ExtractUser<U> {
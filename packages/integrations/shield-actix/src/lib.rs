@@ -1,7 +1,9 @@
 mod extract;
 mod service;
+mod session;
 mod transform;
 
 pub use extract::*;
 pub use service::*;
+pub use session::*;
 pub use transform::*;
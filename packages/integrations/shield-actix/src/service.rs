@@ -1,36 +1,128 @@
-use std::task::{Context, Poll};
+use std::{
+    future::Future,
+    net::IpAddr,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+};
 
+use actix_session::SessionExt;
 use actix_web::{
     Error, HttpMessage,
     dev::{Service, ServiceRequest, ServiceResponse},
+    error::{ErrorInternalServerError, ErrorUnauthorized},
+    http::header::AUTHORIZATION,
 };
-use shield::{Shield, User};
+use shield::{RequestContext, RequestMethod, Session, Shield, ShieldError, User};
+
+use crate::session::ActixSessionStorage;
 
 pub struct ShieldService<S, U: User> {
-    inner: S,
+    inner: Rc<S>,
     shield: Shield<U>,
+    session_key: &'static str,
 }
 
 impl<S, U: User> ShieldService<S, U> {
-    pub fn new(inner: S, shield: Shield<U>) -> Self {
-        Self { inner, shield }
+    pub fn new(inner: S, shield: Shield<U>, session_key: &'static str) -> Self {
+        Self {
+            inner: Rc::new(inner),
+            shield,
+            session_key,
+        }
     }
 }
 
 impl<S, U: User + Clone + 'static, ResBody> Service<ServiceRequest> for ShieldService<S, U>
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<ResBody>, Error = Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<ResBody>, Error = Error> + 'static,
 {
     type Response = ServiceResponse<ResBody>;
     type Error = Error;
-    type Future = S::Future;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
 
     fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         self.inner.poll_ready(ctx)
     }
 
     fn call(&self, req: ServiceRequest) -> Self::Future {
-        req.extensions_mut().insert(self.shield.clone());
-        self.inner.call(req)
+        let inner = self.inner.clone();
+        let shield = self.shield.clone();
+        let session_key = self.session_key;
+
+        Box::pin(async move {
+            let context = request_context(&req, shield.options().trusted_proxies());
+
+            // Requests with an access token or API key use a session without cookies. An invalid token is rejected
+            // instead of falling back to the cookie session.
+            let token_session = match bearer_token(&req) {
+                Some(token) => match shield.token_session(token).await {
+                    Ok(Some(token_session)) => Some(token_session),
+                    Ok(None) | Err(ShieldError::Unauthorized) => {
+                        return Err(ErrorUnauthorized("Unauthorized"));
+                    }
+                    Err(err) => return Err(ErrorInternalServerError(err)),
+                },
+                None => None,
+            };
+
+            let (session, session_storage) = match token_session {
+                Some(token_session) => (token_session, None),
+                None => {
+                    let session_storage =
+                        ActixSessionStorage::load(&req.get_session(), session_key)
+                            .map_err(ErrorInternalServerError)?;
+
+                    (Session::new(session_storage.clone()), Some(session_storage))
+                }
+            };
+            let session = session.with_client(context.client());
+
+            let user = shield
+                .user(&session)
+                .await
+                .map_err(ErrorInternalServerError)?;
+
+            req.extensions_mut().insert(shield);
+            req.extensions_mut().insert(context);
+            req.extensions_mut().insert(session);
+            req.extensions_mut().insert(user);
+
+            let response = inner.call(req).await?;
+
+            if let Some(session_storage) = session_storage {
+                session_storage
+                    .apply(&response.request().get_session())
+                    .map_err(ErrorInternalServerError)?;
+            }
+
+            Ok(response)
+        })
     }
 }
+
+fn request_context(req: &ServiceRequest, trusted_proxies: &[IpAddr]) -> RequestContext {
+    RequestContext::new(
+        RequestMethod::from_name(req.method().as_str()),
+        req.peer_addr().map(|peer_addr| peer_addr.ip()),
+        req.headers().iter().filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.as_str().to_owned(), value.to_owned()))
+        }),
+        trusted_proxies,
+    )
+}
+
+fn bearer_token(req: &ServiceRequest) -> Option<&str> {
+    req.headers()
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .and_then(|authorization| {
+            authorization
+                .strip_prefix("Bearer ")
+                .or_else(|| authorization.strip_prefix("bearer "))
+        })
+        .map(str::trim)
+}
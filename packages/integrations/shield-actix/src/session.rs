@@ -0,0 +1,118 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use shield::{SessionData, SessionError, SessionStorage};
+
+#[derive(Clone, Debug, Default)]
+struct SessionChanges {
+    updated: bool,
+    renewed: bool,
+    purged: bool,
+}
+
+/// Session storage backed by an Actix session.
+///
+/// Actix sessions are not `Send`, so changes are recorded during the request and applied to the
+/// Actix session afterwards with [`ActixSessionStorage::apply`].
+#[derive(Clone, Debug)]
+pub struct ActixSessionStorage {
+    session_key: &'static str,
+    session_data: Arc<Mutex<SessionData>>,
+    changes: Arc<Mutex<SessionChanges>>,
+}
+
+impl ActixSessionStorage {
+    pub fn load(
+        session: &actix_session::Session,
+        session_key: &'static str,
+    ) -> Result<Self, SessionError> {
+        let data = session
+            .get::<SessionData>(session_key)
+            .map_err(|err| SessionError::Engine(err.to_string()))?
+            .unwrap_or_default();
+
+        Ok(Self {
+            session_key,
+            session_data: Arc::new(Mutex::new(data)),
+            changes: Arc::new(Mutex::new(SessionChanges::default())),
+        })
+    }
+
+    pub fn apply(&self, session: &actix_session::Session) -> Result<(), SessionError> {
+        let changes = self
+            .changes
+            .lock()
+            .map_err(|err| SessionError::Lock(err.to_string()))?
+            .clone();
+
+        if changes.purged && !changes.updated {
+            session.purge();
+            return Ok(());
+        }
+
+        // A purged Actix session ignores new values, so clear it instead.
+        if changes.purged {
+            session.clear();
+        }
+        if changes.purged || changes.renewed {
+            session.renew();
+        }
+
+        if changes.updated {
+            let data = self
+                .session_data
+                .lock()
+                .map_err(|err| SessionError::Lock(err.to_string()))?
+                .clone();
+
+            session
+                .insert(self.session_key, data)
+                .map_err(|err| SessionError::Engine(err.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    fn changes(&self) -> Result<std::sync::MutexGuard<'_, SessionChanges>, SessionError> {
+        self.changes
+            .lock()
+            .map_err(|err| SessionError::Lock(err.to_string()))
+    }
+}
+
+#[async_trait]
+impl SessionStorage for ActixSessionStorage {
+    fn data(&self) -> Arc<Mutex<SessionData>> {
+        self.session_data.clone()
+    }
+
+    async fn update(&self) -> Result<(), SessionError> {
+        self.changes()?.updated = true;
+
+        Ok(())
+    }
+
+    async fn renew(&self) -> Result<(), SessionError> {
+        self.changes()?.renewed = true;
+
+        Ok(())
+    }
+
+    async fn purge(&self) -> Result<(), SessionError> {
+        {
+            let mut changes = self.changes()?;
+            changes.purged = true;
+            changes.updated = false;
+        }
+
+        {
+            let mut session_data = self
+                .session_data
+                .lock()
+                .map_err(|err| SessionError::Lock(err.to_string()))?;
+            *session_data = SessionData::default();
+        }
+
+        Ok(())
+    }
+}
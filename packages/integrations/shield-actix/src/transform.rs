@@ -7,23 +7,33 @@ use shield::{Shield, User};
 
 use crate::service::ShieldService;
 
+pub const SESSION_KEY: &str = "shield";
+
 // Actix uses a `Middleware` suffix instead of a `Transform` suffix, despite the trait being called `Transform`.
 // Export both names so users can choose.
 pub type ShieldMiddleware<U> = ShieldTransform<U>;
 
 pub struct ShieldTransform<U: User> {
     shield: Shield<U>,
+    session_key: &'static str,
 }
 
 impl<U: User> ShieldTransform<U> {
     pub fn new(shield: Shield<U>) -> Self {
-        Self { shield }
+        Self::new_with_session_key(shield, SESSION_KEY)
+    }
+
+    pub fn new_with_session_key(shield: Shield<U>, session_key: &'static str) -> Self {
+        Self {
+            shield,
+            session_key,
+        }
     }
 }
 
 impl<S, U: User + Clone + 'static, ResBody> Transform<S, ServiceRequest> for ShieldTransform<U>
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<ResBody>, Error = Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<ResBody>, Error = Error> + 'static,
 {
     type Response = ServiceResponse<ResBody>;
     type Error = Error;
@@ -32,6 +42,10 @@ where
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
     fn new_transform(&self, inner: S) -> Self::Future {
-        ready(Ok(ShieldService::new(inner, self.shield.clone())))
+        ready(Ok(ShieldService::new(
+            inner,
+            self.shield.clone(),
+            self.session_key,
+        )))
     }
 }
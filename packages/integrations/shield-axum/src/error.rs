@@ -1,6 +1,9 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{
+        StatusCode,
+        header::{CACHE_CONTROL, RETRY_AFTER},
+    },
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
@@ -49,6 +52,7 @@ impl IntoResponse for RouteError {
             },
             ShieldError::Action(action_error) => match action_error {
                 ActionError::NotFound(_) => StatusCode::NOT_FOUND,
+                ActionError::MethodNotAllowed(_, _) => StatusCode::METHOD_NOT_ALLOWED,
             },
             ShieldError::Provider(provider_error) => match provider_error {
                 ProviderError::Missing => StatusCode::BAD_REQUEST,
@@ -65,9 +69,33 @@ impl IntoResponse for RouteError {
             ShieldError::Request(_) => StatusCode::INTERNAL_SERVER_ERROR,
             ShieldError::Validation(_) => StatusCode::BAD_REQUEST,
             ShieldError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ShieldError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ShieldError::Oauth(oauth_error) => match oauth_error.is_invalid_client() {
+                true => StatusCode::UNAUTHORIZED,
+                false => StatusCode::BAD_REQUEST,
+            },
+            ShieldError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
         };
 
-        (status_code, Json(ErrorBody::new(status_code, self.0))).into_response()
+        // OAuth clients expect the error format of RFC 6749 instead of the error body.
+        if let ShieldError::Oauth(oauth_error) = self.0 {
+            return (
+                status_code,
+                [(CACHE_CONTROL, "no-store")],
+                Json(oauth_error),
+            )
+                .into_response();
+        }
+
+        match self.0.retry_after() {
+            Some(retry_after) => (
+                status_code,
+                [(RETRY_AFTER, retry_after.to_string())],
+                Json(ErrorBody::new(status_code, self.0)),
+            )
+                .into_response(),
+            None => (status_code, Json(ErrorBody::new(status_code, self.0))).into_response(),
+        }
     }
 }
 
@@ -1,5 +1,8 @@
 use axum::{extract::FromRequestParts, http::request::Parts};
-use shield::{ConfigurationError, Session, Shield, ShieldError, User};
+use shield::{
+    AssuranceLevel, ConfigurationError, RequestContext, Session, SessionError, Shield, ShieldError,
+    User,
+};
 
 use crate::error::RouteError;
 
@@ -39,6 +42,24 @@ impl<S: Send + Sync> FromRequestParts<S> for ExtractSession {
     }
 }
 
+pub struct ExtractRequestContext(pub RequestContext);
+
+impl<S: Send + Sync> FromRequestParts<S> for ExtractRequestContext {
+    type Rejection = RouteError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<RequestContext>()
+            .cloned()
+            .map(ExtractRequestContext)
+            .ok_or(ShieldError::Configuration(ConfigurationError::Invalid(
+                "Can't extract Shield request context. Is `ShieldLayer` enabled?".to_owned(),
+            )))
+            .map_err(RouteError::from)
+    }
+}
+
 pub struct ExtractUser<U: User>(pub Option<U>);
 
 impl<S: Send + Sync, U: User + Clone + 'static> FromRequestParts<S> for ExtractUser<U> {
@@ -57,21 +78,50 @@ impl<S: Send + Sync, U: User + Clone + 'static> FromRequestParts<S> for ExtractU
     }
 }
 
-pub struct UserRequired<U: User>(pub U);
+/// Extract the authenticated user, rejecting the request if there is none.
+///
+/// The user is only available if the authentication meets the assurance level configured in the
+/// Shield options. Set `MULTI_FACTOR` to require a multi-factor authentication for this route.
+pub struct UserRequired<U: User, const MULTI_FACTOR: bool = false>(pub U);
+
+pub type MultiFactorUserRequired<U> = UserRequired<U, true>;
 
-impl<S: Send + Sync, U: User + Clone + 'static> FromRequestParts<S> for UserRequired<U> {
+impl<S: Send + Sync, U: User + Clone + 'static, const MULTI_FACTOR: bool> FromRequestParts<S>
+    for UserRequired<U, MULTI_FACTOR>
+{
     type Rejection = RouteError;
 
-    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        parts
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let user = parts
             .extensions
             .get::<Option<U>>()
             .cloned()
             .ok_or(ShieldError::Configuration(ConfigurationError::Invalid(
                 "Can't extract Shield user. Is `ShieldLayer` enabled?".to_owned(),
             )))
-            .and_then(|user| user.ok_or(ShieldError::Unauthorized))
-            .map(UserRequired)
-            .map_err(RouteError::from)
+            .and_then(|user| user.ok_or(ShieldError::Unauthorized))?;
+
+        if MULTI_FACTOR {
+            let ExtractSession(session) = ExtractSession::from_request_parts(parts, state).await?;
+
+            let assurance_level = {
+                let session_data = session.data();
+                let session_data = session_data
+                    .lock()
+                    .map_err(|err| ShieldError::from(SessionError::Lock(err.to_string())))?;
+
+                session_data
+                    .base
+                    .authentication
+                    .as_ref()
+                    .map(|authentication| authentication.assurance_level())
+            };
+
+            if assurance_level < Some(AssuranceLevel::MultiFactor) {
+                return Err(ShieldError::Unauthorized.into());
+            }
+        }
+
+        Ok(UserRequired(user))
     }
 }
@@ -1,8 +1,6 @@
-#[cfg(feature = "utoipa")]
-use axum::routing::post;
 use axum::{
     Router,
-    routing::{any, get},
+    routing::{any, delete, get, post},
 };
 use shield::{Shield, User};
 #[cfg(feature = "utoipa")]
@@ -14,7 +12,22 @@ use crate::routes::*;
 
 #[cfg(feature = "utoipa")]
 #[cfg_attr(feature = "utoipa", derive(utoipa::OpenApi))]
-#[cfg_attr(feature = "utoipa", openapi(paths(action, forms, user)))]
+#[cfg_attr(
+    feature = "utoipa",
+    openapi(paths(
+        action,
+        connections,
+        forms,
+        issue_token,
+        refresh_token,
+        revoke_other_sessions,
+        revoke_session,
+        revoke_token,
+        sessions,
+        unlink_connection,
+        user
+    ))
+)]
 struct BaseOpenApi;
 
 pub struct AuthRoutes<U: User> {
@@ -30,6 +43,19 @@ impl<U: Clone + User + 'static> AuthRoutes<U> {
     pub fn router<S: Clone + Send + Sync + 'static>(&self) -> Router<S> {
         Router::new()
             .route("/user", get(user::<U>))
+            .route("/connections", get(connections::<U>))
+            .route(
+                "/connections/{methodId}/{connectionId}",
+                delete(unlink_connection::<U>),
+            )
+            .route(
+                "/sessions",
+                get(sessions::<U>).delete(revoke_other_sessions::<U>),
+            )
+            .route("/sessions/{sessionId}", delete(revoke_session::<U>))
+            .route("/token", post(issue_token::<U>))
+            .route("/token/refresh", post(refresh_token::<U>))
+            .route("/token/revoke", post(revoke_token::<U>))
             .route("/forms/{actionId}", get(forms::<U>))
             .route("/{actionId}", any(action::<U>))
             .route("/{actionId}/{methodId}", any(method_action::<U>))
@@ -43,6 +69,19 @@ impl<U: Clone + User + 'static> AuthRoutes<U> {
     pub fn openapi_router<S: Clone + Send + Sync + 'static>(&self) -> OpenApiRouter<S> {
         OpenApiRouter::with_openapi(BaseOpenApi::openapi().merge_from(self.shield.openapi()))
             .route("/user", get(user::<U>))
+            .route("/connections", get(connections::<U>))
+            .route(
+                "/connections/{methodId}/{connectionId}",
+                delete(unlink_connection::<U>),
+            )
+            .route(
+                "/sessions",
+                get(sessions::<U>).delete(revoke_other_sessions::<U>),
+            )
+            .route("/sessions/{sessionId}", delete(revoke_session::<U>))
+            .route("/token", post(issue_token::<U>))
+            .route("/token/refresh", post(refresh_token::<U>))
+            .route("/token/revoke", post(revoke_token::<U>))
             .route("/forms/{actionId}", get(forms::<U>))
             .route("/{actionId}", get(action::<U>))
             .route("/{actionId}", post(action::<U>))
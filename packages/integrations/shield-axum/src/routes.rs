@@ -1,7 +1,13 @@
 mod action;
+mod connections;
 mod forms;
+mod sessions;
+mod token;
 mod user;
 
 pub use action::*;
+pub use connections::*;
 pub use forms::*;
+pub use sessions::*;
+pub use token::*;
 pub use user::*;
@@ -1,7 +1,7 @@
 use axum::{
-    Form,
+    Form, Json,
     extract::{Path, Query},
-    http::StatusCode,
+    http::{StatusCode, header::CACHE_CONTROL},
     response::{IntoResponse, Redirect, Response},
 };
 use serde_json::Value;
@@ -9,7 +9,7 @@ use shield::{ActionPathParams, MethodActionPathParams, Request, ResponseType, Us
 
 #[cfg(feature = "utoipa")]
 use crate::error::ErrorBody;
-use crate::{ExtractSession, ExtractShield, RouteError};
+use crate::{ExtractRequestContext, ExtractSession, ExtractShield, RouteError};
 
 #[cfg_attr(
     feature = "utoipa",
@@ -26,7 +26,10 @@ use crate::{ExtractSession, ExtractShield, RouteError};
         ),
         responses(
             (status = NO_CONTENT, description = "Success."),
+            (status = OK, description = "Data."),
             (status = SEE_OTHER, description = "Redirect."),
+            (status = FORBIDDEN, description = "Cross-site request forgery.", body = ErrorBody),
+            (status = METHOD_NOT_ALLOWED, description = "Method not allowed.", body = ErrorBody),
             (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
         )
     )
@@ -35,13 +38,16 @@ pub async fn action<U: User>(
     Path(ActionPathParams { action_id }): Path<ActionPathParams>,
     ExtractShield(shield): ExtractShield<U>,
     ExtractSession(session): ExtractSession,
+    ExtractRequestContext(context): ExtractRequestContext,
     Query(query): Query<Value>,
     Form(form_data): Form<Value>,
 ) -> Result<Response, RouteError> {
-    // TODO: Check if this action supports the HTTP method (GET/POST)?
-
     let response = shield
-        .call(&action_id, session, Request { query, form_data })
+        .call(
+            &action_id,
+            session,
+            Request::new(query, form_data).with_context(context),
+        )
         .await?;
 
     Ok(match response {
@@ -51,6 +57,7 @@ pub async fn action<U: User>(
             // TODO: Use actual frontend prefix instead of hardcoded `/auth`.
             Redirect::to(&format!("/auth/{action_id}")).into_response()
         }
+        ResponseType::Data(data) => data_response(data),
     })
 }
 
@@ -69,7 +76,10 @@ pub async fn action<U: User>(
         ),
         responses(
             (status = NO_CONTENT, description = "Success."),
+            (status = OK, description = "Data."),
             (status = SEE_OTHER, description = "Redirect."),
+            (status = FORBIDDEN, description = "Cross-site request forgery.", body = ErrorBody),
+            (status = METHOD_NOT_ALLOWED, description = "Method not allowed.", body = ErrorBody),
             (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
         )
     )
@@ -83,18 +93,17 @@ pub async fn method_action<U: User>(
     }): Path<MethodActionPathParams>,
     ExtractShield(shield): ExtractShield<U>,
     ExtractSession(session): ExtractSession,
+    ExtractRequestContext(context): ExtractRequestContext,
     Query(query): Query<Value>,
     Form(form_data): Form<Value>,
 ) -> Result<Response, RouteError> {
-    // TODO: Check if this action supports the HTTP method (GET/POST)?
-
     let response = shield
         .call_method(
             &action_id,
             &method_id,
             provider_id.as_deref(),
             session,
-            Request { query, form_data },
+            Request::new(query, form_data).with_context(context),
         )
         .await?;
 
@@ -105,5 +114,12 @@ pub async fn method_action<U: User>(
             // TODO: Use actual frontend prefix instead of hardcoded `/auth`.
             Redirect::to(&format!("/auth/{action_id}")).into_response()
         }
+        ResponseType::Data(data) => data_response(data),
     })
 }
+
+/// Data of an action is specific to the session and may contain tokens, e.g. of the OAuth token endpoint, so it must
+/// not be cached (RFC 6749 section 5.1).
+fn data_response(data: Value) -> Response {
+    ([(CACHE_CONTROL, "no-store")], Json(data)).into_response()
+}
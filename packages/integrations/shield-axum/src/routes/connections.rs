@@ -0,0 +1,66 @@
+use axum::{Json, extract::Path, http::StatusCode};
+use shield::{ConnectionPathParams, User, UserConnection};
+
+#[cfg(feature = "utoipa")]
+use crate::error::ErrorBody;
+use crate::{ExtractSession, ExtractShield, RouteError, extract::UserRequired};
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        get,
+        path = "/connections",
+        operation_id = "getConnections",
+        summary = "Get connections",
+        description = "Get the connections of the current user account.",
+        tags = ["auth"],
+        responses(
+            (status = OK, description = "The connections of the current user account.", body = Vec<UserConnection>),
+            (status = UNAUTHORIZED, description = "No account signed in.", body = ErrorBody),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn connections<U: User + Clone + 'static>(
+    ExtractShield(shield): ExtractShield<U>,
+    UserRequired(user): UserRequired<U>,
+) -> Result<Json<Vec<UserConnection>>, RouteError> {
+    Ok(Json(shield.list_user_connections(&user).await?))
+}
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        delete,
+        path = "/connections/{methodId}/{connectionId}",
+        operation_id = "unlinkConnection",
+        summary = "Unlink connection",
+        description = "Unlink a connection of the current user account.",
+        tags = ["auth"],
+        params(
+            ConnectionPathParams
+        ),
+        responses(
+            (status = NO_CONTENT, description = "Success."),
+            (status = BAD_REQUEST, description = "Connection can not be unlinked.", body = ErrorBody),
+            (status = UNAUTHORIZED, description = "No account signed in.", body = ErrorBody),
+            (status = NOT_FOUND, description = "Connection not found.", body = ErrorBody),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn unlink_connection<U: User + Clone + 'static>(
+    Path(ConnectionPathParams {
+        method_id,
+        connection_id,
+    }): Path<ConnectionPathParams>,
+    ExtractShield(shield): ExtractShield<U>,
+    ExtractSession(session): ExtractSession,
+    UserRequired(user): UserRequired<U>,
+) -> Result<StatusCode, RouteError> {
+    shield
+        .unlink_user_connection(&user, &session, &method_id, &connection_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,89 @@
+use axum::{Json, extract::Path, http::StatusCode};
+use shield::{SessionPathParams, User, UserSession};
+
+#[cfg(feature = "utoipa")]
+use crate::error::ErrorBody;
+use crate::{ExtractSession, ExtractShield, RouteError, extract::UserRequired};
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        get,
+        path = "/sessions",
+        operation_id = "getSessions",
+        summary = "Get sessions",
+        description = "Get the active sessions of the current user account.",
+        tags = ["auth"],
+        responses(
+            (status = OK, description = "The active sessions of the current user account.", body = Vec<UserSession>),
+            (status = UNAUTHORIZED, description = "No account signed in.", body = ErrorBody),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn sessions<U: User + Clone + 'static>(
+    ExtractShield(shield): ExtractShield<U>,
+    ExtractSession(session): ExtractSession,
+    UserRequired(user): UserRequired<U>,
+) -> Result<Json<Vec<UserSession>>, RouteError> {
+    Ok(Json(shield.list_user_sessions(&user, &session).await?))
+}
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        delete,
+        path = "/sessions/{sessionId}",
+        operation_id = "revokeSession",
+        summary = "Revoke session",
+        description = "Revoke a session of the current user account. Revoking the current session signs out.",
+        tags = ["auth"],
+        params(
+            SessionPathParams
+        ),
+        responses(
+            (status = NO_CONTENT, description = "Success."),
+            (status = UNAUTHORIZED, description = "No account signed in.", body = ErrorBody),
+            (status = NOT_FOUND, description = "Session not found.", body = ErrorBody),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn revoke_session<U: User + Clone + 'static>(
+    Path(SessionPathParams { session_id }): Path<SessionPathParams>,
+    ExtractShield(shield): ExtractShield<U>,
+    ExtractSession(session): ExtractSession,
+    UserRequired(user): UserRequired<U>,
+) -> Result<StatusCode, RouteError> {
+    shield
+        .revoke_user_session(&user, &session, &session_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        delete,
+        path = "/sessions",
+        operation_id = "revokeOtherSessions",
+        summary = "Revoke other sessions",
+        description = "Revoke all sessions of the current user account, except the current session.",
+        tags = ["auth"],
+        responses(
+            (status = NO_CONTENT, description = "Success."),
+            (status = UNAUTHORIZED, description = "No account signed in.", body = ErrorBody),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn revoke_other_sessions<U: User + Clone + 'static>(
+    ExtractShield(shield): ExtractShield<U>,
+    ExtractSession(session): ExtractSession,
+    UserRequired(user): UserRequired<U>,
+) -> Result<StatusCode, RouteError> {
+    shield.revoke_other_user_sessions(&user, &session).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
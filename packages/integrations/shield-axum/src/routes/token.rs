@@ -0,0 +1,97 @@
+use axum::{
+    Json,
+    http::{StatusCode, header::CACHE_CONTROL},
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use shield::{BearerTokens, User};
+
+#[cfg(feature = "utoipa")]
+use crate::error::ErrorBody;
+use crate::{ExtractSession, ExtractShield, RouteError};
+
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshTokenBody {
+    pub refresh_token: String,
+}
+
+/// Tokens must not be cached (RFC 6749 section 5.1).
+fn tokens_response(tokens: BearerTokens) -> Response {
+    ([(CACHE_CONTROL, "no-store")], Json(tokens)).into_response()
+}
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        post,
+        path = "/token",
+        operation_id = "issueToken",
+        summary = "Issue token",
+        description = "Issue an access token and refresh token for the current user account.",
+        tags = ["auth"],
+        responses(
+            (status = OK, description = "Access token and refresh token.", body = BearerTokens),
+            (status = UNAUTHORIZED, description = "No account signed in.", body = ErrorBody),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn issue_token<U: User + Clone + 'static>(
+    ExtractShield(shield): ExtractShield<U>,
+    ExtractSession(session): ExtractSession,
+) -> Result<Response, RouteError> {
+    Ok(tokens_response(shield.issue_bearer_tokens(&session).await?))
+}
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        post,
+        path = "/token/refresh",
+        operation_id = "refreshToken",
+        summary = "Refresh token",
+        description = "Exchange a refresh token for a new access token and refresh token.",
+        tags = ["auth"],
+        request_body = RefreshTokenBody,
+        responses(
+            (status = OK, description = "Access token and refresh token.", body = BearerTokens),
+            (status = UNAUTHORIZED, description = "Invalid refresh token.", body = ErrorBody),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn refresh_token<U: User + Clone + 'static>(
+    ExtractShield(shield): ExtractShield<U>,
+    Json(body): Json<RefreshTokenBody>,
+) -> Result<Response, RouteError> {
+    Ok(tokens_response(
+        shield.refresh_bearer_tokens(&body.refresh_token).await?,
+    ))
+}
+
+#[cfg_attr(
+    feature = "utoipa",
+    utoipa::path(
+        post,
+        path = "/token/revoke",
+        operation_id = "revokeToken",
+        summary = "Revoke token",
+        description = "Revoke a refresh token and all refresh tokens issued since the same sign-in.",
+        tags = ["auth"],
+        request_body = RefreshTokenBody,
+        responses(
+            (status = NO_CONTENT, description = "Success."),
+            (status = INTERNAL_SERVER_ERROR, description = "Internal server error.", body = ErrorBody),
+        )
+    )
+)]
+pub async fn revoke_token<U: User + Clone + 'static>(
+    ExtractShield(shield): ExtractShield<U>,
+    Json(body): Json<RefreshTokenBody>,
+) -> Result<StatusCode, RouteError> {
+    shield.revoke_bearer_tokens(&body.refresh_token).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
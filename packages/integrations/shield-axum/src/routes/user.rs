@@ -1,7 +1,7 @@
 use axum::Json;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use shield::{ConfigurationError, EmailAddress, ShieldError, User};
+use shield::{ConfigurationError, EmailAddress, PhoneNumber, ShieldError, User};
 
 #[cfg(feature = "utoipa")]
 use crate::error::ErrorBody;
@@ -15,17 +15,20 @@ pub struct UserBody {
     id: String,
     name: Option<String>,
     email_addresses: Vec<EmailAddress>,
+    phone_numbers: Vec<PhoneNumber>,
     additional: Value,
 }
 
 impl UserBody {
     async fn new<U: User>(user: U) -> Result<Self, ShieldError> {
         let email_addresses = user.email_addresses().await?;
+        let phone_numbers = user.phone_numbers().await?;
 
         Ok(Self {
             id: user.id(),
             name: user.name(),
             email_addresses,
+            phone_numbers,
             additional: serde_json::to_value(user.additional()).map_err(|err| {
                 ConfigurationError::Invalid(format!(
                     "additional user data is not serializable: {err}"
@@ -2,8 +2,8 @@ use std::marker::PhantomData;
 
 use anyhow::{Result, anyhow};
 use dioxus_server::http::Extensions;
-use shield::{Session, Shield, ShieldDyn, User};
-use shield_axum::{ExtractSession, ExtractShield};
+use shield::{RequestContext, Session, Shield, ShieldDyn, User};
+use shield_axum::{ExtractRequestContext, ExtractSession, ExtractShield};
 use shield_dioxus::{DioxusIntegration, DioxusIntegrationDyn};
 
 pub struct AxumDioxusIntegration<U: User>(PhantomData<U>);
@@ -40,4 +40,14 @@ impl<U: User + Clone + 'static> DioxusIntegration for AxumDioxusIntegration<U> {
 
         Ok(session)
     }
+
+    fn extract_request_context(&self, extensions: &Extensions) -> Result<RequestContext> {
+        let ExtractRequestContext(context) = extensions
+            .get::<RequestContext>()
+            .cloned()
+            .map(ExtractRequestContext)
+            .ok_or_else(|| anyhow!("Request context should be extracted"))?;
+
+        Ok(context)
+    }
 }
@@ -2,12 +2,14 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use dioxus::fullstack::http::Extensions;
-use shield::{Session, ShieldDyn};
+use shield::{RequestContext, Session, ShieldDyn};
 
 pub trait DioxusIntegration: Send + Sync {
     fn extract_shield(&self, extensions: &Extensions) -> Result<ShieldDyn>;
 
     fn extract_session(&self, extensions: &Extensions) -> Result<Session>;
+
+    fn extract_request_context(&self, extensions: &Extensions) -> Result<RequestContext>;
 }
 
 #[derive(Clone)]
@@ -25,4 +27,8 @@ impl DioxusIntegrationDyn {
     pub fn extract_session(&self, extensions: &Extensions) -> Result<Session> {
         self.0.extract_session(extensions)
     }
+
+    pub fn extract_request_context(&self, extensions: &Extensions) -> Result<RequestContext> {
+        self.0.extract_request_context(extensions)
+    }
 }
@@ -72,15 +72,13 @@ pub async fn call(
         .ok_or_else(|| anyhow!("Dioxus Shield integration should be extracted."))?;
     let shield = integration.extract_shield(&parts.extensions)?;
     let session = integration.extract_session(&parts.extensions)?;
+    let context = integration.extract_request_context(&parts.extensions)?;
 
     let response = shield
         .call(
             &action_id,
             session,
-            Request {
-                query: Value::Null,
-                form_data: data,
-            },
+            Request::new(Value::Null, data).with_context(context),
         )
         .await
         .context("Failed to call Shield action.")?;
@@ -110,6 +108,7 @@ pub async fn call_method(
         .ok_or_else(|| anyhow!("Dioxus Shield integration should be extracted."))?;
     let shield = integration.extract_shield(&parts.extensions)?;
     let session = integration.extract_session(&parts.extensions)?;
+    let context = integration.extract_request_context(&parts.extensions)?;
 
     let response = shield
         .call_method(
@@ -117,10 +116,7 @@ pub async fn call_method(
             &method_id,
             provider_id.as_deref(),
             session,
-            Request {
-                query: Value::Null,
-                form_data: data,
-            },
+            Request::new(Value::Null, data).with_context(context),
         )
         .await
         .context("Failed to call Shield method action.")?;
@@ -3,8 +3,8 @@ use std::{marker::PhantomData, sync::Arc};
 use async_trait::async_trait;
 use leptos::prelude::provide_context;
 use leptos_actix::{extract, redirect};
-use shield::{Session, ShieldDyn, User};
-use shield_actix::{ExtractSession, ExtractShield, ExtractUser};
+use shield::{RequestContext, Session, ShieldDyn, User};
+use shield_actix::{ExtractRequestContext, ExtractSession, ExtractShield, ExtractUser};
 use shield_leptos::{LeptosIntegration, LeptosUser};
 
 pub struct ActixLeptosIntegration<U: User>(PhantomData<U>);
@@ -31,6 +31,14 @@ impl<U: User + Clone + 'static> LeptosIntegration for ActixLeptosIntegration<U>
         session
     }
 
+    async fn extract_request_context(&self) -> RequestContext {
+        let ExtractRequestContext(context) = extract()
+            .await
+            .expect("Request context should be extracted");
+
+        context
+    }
+
     async fn extract_user(&self) -> Option<LeptosUser> {
         let ExtractUser(user) = extract::<ExtractUser<U>>()
             .await
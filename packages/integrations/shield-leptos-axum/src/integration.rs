@@ -3,8 +3,8 @@ use std::{marker::PhantomData, sync::Arc};
 use async_trait::async_trait;
 use leptos::prelude::provide_context;
 use leptos_axum::{extract, redirect};
-use shield::{Session, ShieldDyn, User};
-use shield_axum::{ExtractSession, ExtractShield, ExtractUser};
+use shield::{RequestContext, Session, ShieldDyn, User};
+use shield_axum::{ExtractRequestContext, ExtractSession, ExtractShield, ExtractUser};
 use shield_leptos::{LeptosIntegration, LeptosUser};
 
 pub struct AxumLeptosIntegration<U: User>(PhantomData<U>);
@@ -31,6 +31,14 @@ impl<U: User + Clone + 'static> LeptosIntegration for AxumLeptosIntegration<U> {
         session
     }
 
+    async fn extract_request_context(&self) -> RequestContext {
+        let ExtractRequestContext(context) = extract()
+            .await
+            .expect("Request context should be extracted");
+
+        context
+    }
+
     async fn extract_user(&self) -> Option<LeptosUser> {
         let ExtractUser(user) = extract::<ExtractUser<U>>()
             .await
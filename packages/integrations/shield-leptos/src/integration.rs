@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use shield::{Session, ShieldDyn, User};
+use shield::{RequestContext, Session, ShieldDyn, User};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LeptosUser {
@@ -23,6 +23,8 @@ pub trait LeptosIntegration: Send + Sync {
 
     async fn extract_session(&self) -> Session;
 
+    async fn extract_request_context(&self) -> RequestContext;
+
     async fn extract_user(&self) -> Option<LeptosUser>;
 
     fn redirect(&self, path: &str);
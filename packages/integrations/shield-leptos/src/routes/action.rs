@@ -53,7 +53,7 @@ pub async fn call(
     action_id: String,
     // TODO: Would be nice if this argument could fill up with all unknown keys instead of setting name to `data[...]`.
     data: Value,
-) -> Result<(), ServerFnError> {
+) -> Result<Option<Value>, ServerFnError> {
     use serde_json::Value;
     use shield::{Request, ResponseType};
 
@@ -62,6 +62,7 @@ pub async fn call(
     let integration = expect_server_integration();
     let shield = integration.extract_shield().await;
     let session = integration.extract_session().await;
+    let context = integration.extract_request_context().await;
 
     tracing::info!("call data {data:#?}");
 
@@ -69,10 +70,7 @@ pub async fn call(
         .call(
             &action_id,
             session,
-            Request {
-                query: Value::Null,
-                form_data: data,
-            },
+            Request::new(Value::Null, data).with_context(context),
         )
         .await?;
 
@@ -85,9 +83,10 @@ pub async fn call(
             // TODO: Use actual router prefix instead of hardcoded `/auth`.
             integration.redirect(&format!("/auth/{action_id}"));
         }
+        ResponseType::Data(data) => return Ok(Some(data)),
     }
 
-    Ok(())
+    Ok(None)
 }
 
 #[server]
@@ -97,7 +96,7 @@ pub async fn call_method(
     provider_id: Option<String>,
     // TODO: Would be nice if this argument could fill up with all unknown keys instead of setting name to `data[...]`.
     data: Value,
-) -> Result<(), ServerFnError> {
+) -> Result<Option<Value>, ServerFnError> {
     use serde_json::Value;
     use shield::{Request, ResponseType};
 
@@ -106,6 +105,7 @@ pub async fn call_method(
     let integration = expect_server_integration();
     let shield = integration.extract_shield().await;
     let session = integration.extract_session().await;
+    let context = integration.extract_request_context().await;
 
     tracing::info!("call method data {data:#?}");
 
@@ -115,10 +115,7 @@ pub async fn call_method(
             &method_id,
             provider_id.as_deref(),
             session,
-            Request {
-                query: Value::Null,
-                form_data: data,
-            },
+            Request::new(Value::Null, data).with_context(context),
         )
         .await?;
 
@@ -131,7 +128,8 @@ pub async fn call_method(
             // TODO: Use actual router prefix instead of hardcoded `/auth`.
             integration.redirect(&format!("/auth/{action_id}"));
         }
+        ResponseType::Data(data) => return Ok(Some(data)),
     }
 
-    Ok(())
+    Ok(None)
 }
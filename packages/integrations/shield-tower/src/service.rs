@@ -1,11 +1,12 @@
 use std::{
     future::Future,
+    net::IpAddr,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use http::{Request, Response};
-use shield::{Session, Shield, User};
+use http::{Request, Response, header::AUTHORIZATION};
+use shield::{RequestContext, RequestMethod, Session, Shield, ShieldError, User};
 use tower_service::Service;
 
 use crate::session::TowerSessionStorage;
@@ -31,6 +32,12 @@ impl<S, U: User> ShieldService<S, U> {
         *response.status_mut() = http::StatusCode::INTERNAL_SERVER_ERROR;
         response
     }
+
+    fn unauthorized<ResBody: Default>() -> Response<ResBody> {
+        let mut response = Response::default();
+        *response.status_mut() = http::StatusCode::UNAUTHORIZED;
+        response
+    }
 }
 
 impl<S, U: User + Clone + 'static, ReqBody, ResBody> Service<Request<ReqBody>>
@@ -60,19 +67,36 @@ where
         let session_key = self.session_key;
 
         Box::pin(async move {
-            let session = match req.extensions().get::<tower_sessions::Session>() {
-                Some(session) => session,
-                None => {
-                    return Ok(Self::internal_server_error());
-                }
-            };
+            let context = request_context(&req, shield.options().trusted_proxies());
 
-            let session_storage =
-                match TowerSessionStorage::load(session.clone(), session_key).await {
-                    Ok(session_storage) => session_storage,
+            // Requests with an access token or API key use a session without cookies. An invalid token is rejected
+            // instead of falling back to the cookie session.
+            let token_session = match bearer_token(&req) {
+                Some(token) => match shield.token_session(token).await {
+                    Ok(Some(token_session)) => Some(token_session),
+                    Ok(None) | Err(ShieldError::Unauthorized) => return Ok(Self::unauthorized()),
                     Err(_err) => return Ok(Self::internal_server_error()),
-                };
-            let shield_session = Session::new(session_storage);
+                },
+                None => None,
+            };
+
+            let shield_session = match token_session {
+                Some(token_session) => token_session,
+                None => {
+                    let session = match req.extensions().get::<tower_sessions::Session>() {
+                        Some(session) => session,
+                        None => {
+                            return Ok(Self::internal_server_error());
+                        }
+                    };
+
+                    match TowerSessionStorage::load(session.clone(), session_key).await {
+                        Ok(session_storage) => Session::new(session_storage),
+                        Err(_err) => return Ok(Self::internal_server_error()),
+                    }
+                }
+            }
+            .with_client(context.client());
 
             let user = match shield.user(&shield_session).await {
                 Ok(user) => user,
@@ -80,6 +104,7 @@ where
             };
 
             req.extensions_mut().insert(shield);
+            req.extensions_mut().insert(context);
             req.extensions_mut().insert(shield_session);
             req.extensions_mut().insert(user);
 
@@ -87,3 +112,37 @@ where
         })
     }
 }
+
+fn request_context<B>(req: &Request<B>, trusted_proxies: &[IpAddr]) -> RequestContext {
+    #[cfg(feature = "axum")]
+    let peer_address = req
+        .extensions()
+        .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
+        .map(|connect_info| connect_info.ip());
+    #[cfg(not(feature = "axum"))]
+    let peer_address = None;
+
+    RequestContext::new(
+        RequestMethod::from_name(req.method().as_str()),
+        peer_address,
+        req.headers().iter().filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.as_str().to_owned(), value.to_owned()))
+        }),
+        trusted_proxies,
+    )
+}
+
+fn bearer_token<B>(req: &Request<B>) -> Option<&str> {
+    req.headers()
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .and_then(|authorization| {
+            authorization
+                .strip_prefix("Bearer ")
+                .or_else(|| authorization.strip_prefix("bearer "))
+        })
+        .map(str::trim)
+}
@@ -0,0 +1,7 @@
+mod api_keys;
+mod create;
+mod revoke;
+
+pub use api_keys::*;
+pub use create::*;
+pub use revoke::*;
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError,
+    User, erased_method_action,
+};
+
+use crate::{
+    api_key::UserApiKey, method::managing_user_id, provider::ApiKeyProvider, storage::ApiKeyStorage,
+};
+
+pub const API_KEYS_ACTION_ID: &str = "api-keys";
+const API_KEYS_ACTION_NAME: &str = "API keys";
+
+pub struct ApiKeysAction<U: User> {
+    storage: Arc<dyn ApiKeyStorage<U>>,
+}
+
+impl<U: User> ApiKeysAction<U> {
+    pub fn new(storage: Arc<dyn ApiKeyStorage<U>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<ApiKeyProvider, ()> for ApiKeysAction<U> {
+    fn id(&self) -> String {
+        API_KEYS_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        API_KEYS_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Get API keys"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Get the API keys of the current user, without the keys themselves."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Get
+    }
+
+    fn condition(
+        &self,
+        _provider: &ApiKeyProvider,
+        session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(managing_user_id(session).is_ok())
+    }
+
+    async fn forms(&self, _provider: ApiKeyProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        _provider: ApiKeyProvider,
+        session: &MethodSession<()>,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        let user_id = managing_user_id(session)?;
+
+        let mut api_keys = self
+            .storage
+            .user_api_keys(user_id)
+            .await?
+            .into_iter()
+            .map(UserApiKey::from)
+            .collect::<Vec<_>>();
+        api_keys.sort_by_key(|api_key| api_key.created_at);
+
+        let api_keys = serde_json::to_value(api_keys)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(Response::new(ResponseType::Data(api_keys)))
+    }
+}
+
+erased_method_action!(ApiKeysAction, <U: User>);
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime, Utc};
+use serde::Deserialize;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError,
+    User, erased_method_action,
+};
+
+use crate::{
+    api_key::{CreateApiKey, CreatedApiKey, api_key_prefix, generate_api_key, hash_api_key},
+    form::{expires_at_input, name_input, scopes_input, submit_input},
+    method::managing_user_id,
+    options::ApiKeyOptions,
+    provider::ApiKeyProvider,
+    storage::ApiKeyStorage,
+};
+
+pub const CREATE_ACTION_ID: &str = "create-api-key";
+const CREATE_ACTION_NAME: &str = "Create API key";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateData {
+    name: String,
+    #[serde(default)]
+    scopes: Option<String>,
+    #[serde(default)]
+    expires_at: Option<String>,
+}
+
+pub struct ApiKeyCreateAction<U: User> {
+    options: ApiKeyOptions,
+    storage: Arc<dyn ApiKeyStorage<U>>,
+}
+
+impl<U: User> ApiKeyCreateAction<U> {
+    pub fn new(options: ApiKeyOptions, storage: Arc<dyn ApiKeyStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<ApiKeyProvider, ()> for ApiKeyCreateAction<U> {
+    fn id(&self) -> String {
+        CREATE_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        CREATE_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Create API key"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Create an API key for the current user. The key is only returned once."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &ApiKeyProvider,
+        session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(managing_user_id(session).is_ok())
+    }
+
+    async fn forms(&self, _provider: ApiKeyProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                name_input(),
+                scopes_input(),
+                expires_at_input(self.options.max_expires_in.is_some()),
+                submit_input("Create API key"),
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: ApiKeyProvider,
+        session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let user_id = managing_user_id(session)?;
+
+        let data = serde_json::from_value::<CreateData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let name = data.name.trim();
+        if name.is_empty() {
+            return Err(ShieldError::Validation("Name is required.".to_owned()));
+        }
+
+        let mut scopes = data
+            .scopes
+            .unwrap_or_default()
+            .split([' ', ','])
+            .filter(|scope| !scope.is_empty())
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>();
+        scopes.sort_unstable();
+        scopes.dedup();
+
+        if let Some(allowed_scopes) = &self.options.scopes
+            && let Some(scope) = scopes.iter().find(|scope| !allowed_scopes.contains(scope))
+        {
+            return Err(ShieldError::Validation(format!(
+                "Scope `{scope}` is not allowed."
+            )));
+        }
+
+        let now = Utc::now();
+        let expired_at = data
+            .expires_at
+            .filter(|expires_at| !expires_at.is_empty())
+            .map(|expires_at| {
+                NaiveDate::parse_from_str(&expires_at, "%Y-%m-%d")
+                    .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+                    .map_err(|err| {
+                        ShieldError::Validation(format!("Invalid expiration date: {err}"))
+                    })
+            })
+            .transpose()?;
+
+        if expired_at.is_some_and(|expired_at| expired_at <= now) {
+            return Err(ShieldError::Validation(
+                "Expiration date must be in the future.".to_owned(),
+            ));
+        }
+        if let Some(max_expires_in) = self.options.max_expires_in
+            && expired_at.is_none_or(|expired_at| expired_at > now + max_expires_in)
+        {
+            return Err(ShieldError::Validation(format!(
+                "Expiration date must be within {} days.",
+                max_expires_in.num_days()
+            )));
+        }
+
+        let key = generate_api_key(&self.options);
+
+        let api_key = self
+            .storage
+            .create_api_key(CreateApiKey {
+                name: name.to_owned(),
+                key_prefix: api_key_prefix(&self.options, &key),
+                key_hash: hash_api_key(&key),
+                scopes,
+                expired_at: expired_at.map(Into::into),
+                user_id: user_id.to_owned(),
+            })
+            .await?;
+
+        let created_api_key = serde_json::to_value(CreatedApiKey {
+            api_key: api_key.into(),
+            key,
+        })
+        .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(Response::new(ResponseType::Data(created_api_key)))
+    }
+}
+
+erased_method_action!(ApiKeyCreateAction, <U: User>);
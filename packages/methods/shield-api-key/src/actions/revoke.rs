@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError,
+    StorageError, User, erased_method_action,
+};
+
+use crate::{
+    form::{api_key_id_input, submit_input},
+    method::managing_user_id,
+    provider::ApiKeyProvider,
+    storage::ApiKeyStorage,
+};
+
+pub const REVOKE_ACTION_ID: &str = "revoke-api-key";
+const REVOKE_ACTION_NAME: &str = "Revoke API key";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RevokeData {
+    api_key_id: String,
+}
+
+pub struct ApiKeyRevokeAction<U: User> {
+    storage: Arc<dyn ApiKeyStorage<U>>,
+}
+
+impl<U: User> ApiKeyRevokeAction<U> {
+    pub fn new(storage: Arc<dyn ApiKeyStorage<U>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<ApiKeyProvider, ()> for ApiKeyRevokeAction<U> {
+    fn id(&self) -> String {
+        REVOKE_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        REVOKE_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Revoke API key"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Revoke an API key of the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &ApiKeyProvider,
+        session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(managing_user_id(session).is_ok())
+    }
+
+    async fn forms(&self, _provider: ApiKeyProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![api_key_id_input(), submit_input("Revoke API key")],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: ApiKeyProvider,
+        session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let user_id = managing_user_id(session)?;
+
+        let data = serde_json::from_value::<RevokeData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        // Keys of other users are reported as missing, to not reveal their existence.
+        if !self
+            .storage
+            .user_api_keys(user_id)
+            .await?
+            .iter()
+            .any(|api_key| api_key.id == data.api_key_id)
+        {
+            return Err(StorageError::NotFound("ApiKey".to_owned(), data.api_key_id).into());
+        }
+
+        self.storage.delete_api_key(&data.api_key_id).await?;
+
+        Ok(Response::new(ResponseType::Default))
+    }
+}
+
+erased_method_action!(ApiKeyRevokeAction, <U: User>);
@@ -0,0 +1,115 @@
+use chrono::{DateTime, FixedOffset};
+use rand::distr::{Alphanumeric, SampleString};
+use serde::Serialize;
+use sha3::{Digest, Sha3_256};
+
+use crate::options::ApiKeyOptions;
+
+#[derive(Clone, Debug)]
+pub struct ApiKey {
+    pub id: String,
+    pub name: String,
+    /// Start of the key, to recognize it without storing the key itself.
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub expired_at: Option<DateTime<FixedOffset>>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateApiKey {
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub expired_at: Option<DateTime<FixedOffset>>,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateApiKey {
+    pub id: String,
+    pub last_used_at: Option<Option<DateTime<FixedOffset>>>,
+}
+
+/// API key of a user in a serializable form, used to manage API keys.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserApiKey {
+    pub id: String,
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<String>,
+    pub expired_at: Option<DateTime<FixedOffset>>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<ApiKey> for UserApiKey {
+    fn from(value: ApiKey) -> Self {
+        UserApiKey {
+            id: value.id,
+            name: value.name,
+            key_prefix: value.key_prefix,
+            scopes: value.scopes,
+            expired_at: value.expired_at,
+            last_used_at: value.last_used_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+/// Newly created API key, the only time the key is available in plain text.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedApiKey {
+    #[serde(flatten)]
+    pub api_key: UserApiKey,
+    pub key: String,
+}
+
+const API_KEY_LENGTH: usize = 40;
+const API_KEY_VISIBLE_LENGTH: usize = 4;
+
+pub(crate) fn generate_api_key(options: &ApiKeyOptions) -> String {
+    format!(
+        "{}{}",
+        options.prefix,
+        Alphanumeric.sample_string(&mut rand::rng(), API_KEY_LENGTH)
+    )
+}
+
+/// Prefix and first characters of the key, shown to recognize the key.
+pub(crate) fn api_key_prefix(options: &ApiKeyOptions, key: &str) -> String {
+    key.chars()
+        .take(options.prefix.chars().count() + API_KEY_VISIBLE_LENGTH)
+        .collect()
+}
+
+/// API keys are random, so a fast hash is sufficient.
+pub(crate) fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha3_256::digest(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{api_key_prefix, generate_api_key, hash_api_key};
+    use crate::options::ApiKeyOptions;
+
+    #[test]
+    fn test_api_key() {
+        let options = ApiKeyOptions::builder().prefix("test_").build();
+        let key = generate_api_key(&options);
+
+        assert!(key.starts_with("test_"));
+        assert_eq!(key.len(), 45);
+        assert_eq!(api_key_prefix(&options, &key), key[..9]);
+        assert_ne!(
+            hash_api_key(&key),
+            hash_api_key(&generate_api_key(&options))
+        );
+    }
+}
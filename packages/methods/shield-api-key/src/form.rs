@@ -0,0 +1,72 @@
+use shield::{
+    Input, InputType, InputTypeDate, InputTypeHidden, InputTypeSubmit, InputTypeText, InputValue,
+};
+
+pub(crate) fn name_input() -> Input {
+    Input {
+        name: "name".to_owned(),
+        label: Some("Name".to_owned()),
+        r#type: InputType::Text(InputTypeText {
+            placeholder: Some("Name".to_owned()),
+            required: Some(true),
+            ..Default::default()
+        }),
+        value: None,
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+pub(crate) fn scopes_input() -> Input {
+    Input {
+        name: "scopes".to_owned(),
+        label: Some("Scopes".to_owned()),
+        r#type: InputType::Text(InputTypeText {
+            placeholder: Some("Scopes separated by spaces".to_owned()),
+            ..Default::default()
+        }),
+        value: None,
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+pub(crate) fn expires_at_input(required: bool) -> Input {
+    Input {
+        name: "expiresAt".to_owned(),
+        label: Some("Expiration date".to_owned()),
+        r#type: InputType::Date(InputTypeDate {
+            required: Some(required),
+            ..Default::default()
+        }),
+        value: None,
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+pub(crate) fn api_key_id_input() -> Input {
+    Input {
+        name: "apiKeyId".to_owned(),
+        label: None,
+        r#type: InputType::Hidden(InputTypeHidden::default()),
+        value: Some(InputValue::Query {
+            key: "apiKeyId".to_owned(),
+        }),
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+pub(crate) fn submit_input(value: &str) -> Input {
+    Input {
+        name: "submit".to_owned(),
+        label: None,
+        r#type: InputType::Submit(InputTypeSubmit::default()),
+        value: Some(InputValue::String {
+            value: value.to_owned(),
+        }),
+        addon_start: None,
+        addon_end: None,
+    }
+}
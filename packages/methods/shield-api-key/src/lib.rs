@@ -0,0 +1,13 @@
+mod actions;
+mod api_key;
+mod form;
+mod method;
+mod options;
+mod provider;
+mod storage;
+
+pub use api_key::{ApiKey, CreateApiKey, CreatedApiKey, UpdateApiKey, UserApiKey};
+pub use method::*;
+pub use options::*;
+pub use provider::*;
+pub use storage::*;
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use shield::{
+    Authentication, Method, MethodAction, MethodSession, ShieldError, User, erased_method,
+};
+
+use crate::{
+    actions::{ApiKeyCreateAction, ApiKeyRevokeAction, ApiKeysAction},
+    api_key::{ApiKey, UpdateApiKey, hash_api_key},
+    options::ApiKeyOptions,
+    provider::ApiKeyProvider,
+    storage::ApiKeyStorage,
+};
+
+pub const API_KEY_METHOD_ID: &str = "api-key";
+
+/// Interval after which the last used time of an API key is updated.
+const LAST_USED_INTERVAL: TimeDelta = TimeDelta::minutes(1);
+
+pub struct ApiKeyMethod<U: User> {
+    options: ApiKeyOptions,
+    storage: Arc<dyn ApiKeyStorage<U>>,
+}
+
+impl<U: User> ApiKeyMethod<U> {
+    pub fn new<S: ApiKeyStorage<U> + 'static>(options: ApiKeyOptions, storage: S) -> Self {
+        Self {
+            options,
+            storage: Arc::new(storage),
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> Method for ApiKeyMethod<U> {
+    type Provider = ApiKeyProvider;
+    type Connection = ApiKey;
+    type Session = ();
+
+    fn id(&self) -> String {
+        API_KEY_METHOD_ID.to_owned()
+    }
+
+    fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+        vec![
+            Box::new(ApiKeysAction::new(self.storage.clone())),
+            Box::new(ApiKeyCreateAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(ApiKeyRevokeAction::new(self.storage.clone())),
+        ]
+    }
+
+    async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+        Ok(vec![ApiKeyProvider])
+    }
+
+    async fn user_connections(
+        &self,
+        user_id: &str,
+        _provider_id: Option<&str>,
+    ) -> Result<Vec<Self::Connection>, ShieldError> {
+        Ok(self.storage.user_api_keys(user_id).await?)
+    }
+
+    async fn authenticate_token(&self, token: &str) -> Result<Option<Authentication>, ShieldError> {
+        if !token.starts_with(&self.options.prefix) {
+            return Ok(None);
+        }
+
+        let Some(api_key) = self.storage.api_key_by_hash(&hash_api_key(token)).await? else {
+            return Ok(None);
+        };
+
+        let now = Utc::now();
+        if api_key
+            .expired_at
+            .is_some_and(|expired_at| expired_at <= now)
+        {
+            return Ok(None);
+        }
+
+        if api_key
+            .last_used_at
+            .is_none_or(|last_used_at| now - last_used_at.to_utc() > LAST_USED_INTERVAL)
+        {
+            self.storage
+                .update_api_key(UpdateApiKey {
+                    id: api_key.id.clone(),
+                    last_used_at: Some(Some(now.into())),
+                })
+                .await?;
+        }
+
+        Ok(Some(Authentication::for_token(
+            API_KEY_METHOD_ID,
+            None,
+            &api_key.user_id,
+            Some(api_key.scopes),
+        )))
+    }
+}
+
+erased_method!(ApiKeyMethod, <U: User>);
+
+/// User which can manage API keys. Requests authenticated with an API key can not manage API keys.
+pub(crate) fn managing_user_id<'a>(session: &'a MethodSession<()>) -> Result<&'a str, ShieldError> {
+    let authentication = session
+        .base
+        .authentication
+        .as_ref()
+        .ok_or(ShieldError::Unauthorized)?;
+
+    if authentication.method_id == API_KEY_METHOD_ID {
+        return Err(ShieldError::Unauthorized);
+    }
+
+    Ok(&authentication.user_id)
+}
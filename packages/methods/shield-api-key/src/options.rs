@@ -0,0 +1,23 @@
+use bon::Builder;
+use chrono::TimeDelta;
+
+#[derive(Builder, Clone, Debug)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct ApiKeyOptions {
+    /// Prefix of generated keys, which makes them recognizable, e.g. for secret scanning.
+    #[builder(default = "shield_")]
+    pub(crate) prefix: String,
+
+    /// Scopes keys can be restricted to. Any scope is allowed if not set.
+    #[builder(with = FromIterator::from_iter)]
+    pub(crate) scopes: Option<Vec<String>>,
+
+    /// Longest lifetime of a key. Keys without an expiry date are rejected if set.
+    pub(crate) max_expires_in: Option<TimeDelta>,
+}
+
+impl Default for ApiKeyOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
@@ -0,0 +1,19 @@
+use shield::Provider;
+
+use crate::method::API_KEY_METHOD_ID;
+
+pub struct ApiKeyProvider;
+
+impl Provider for ApiKeyProvider {
+    fn method_id(&self) -> String {
+        API_KEY_METHOD_ID.to_owned()
+    }
+
+    fn id(&self) -> Option<String> {
+        None
+    }
+
+    fn name(&self) -> String {
+        "API keys".to_owned()
+    }
+}
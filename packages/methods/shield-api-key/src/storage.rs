@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use shield::{Storage, StorageError, User};
+
+use crate::api_key::{ApiKey, CreateApiKey, UpdateApiKey};
+
+#[async_trait]
+pub trait ApiKeyStorage<U: User>: Storage<U> + Sync {
+    async fn api_key_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, StorageError>;
+
+    async fn user_api_keys(&self, user_id: &str) -> Result<Vec<ApiKey>, StorageError>;
+
+    async fn create_api_key(&self, api_key: CreateApiKey) -> Result<ApiKey, StorageError>;
+
+    async fn update_api_key(&self, api_key: UpdateApiKey) -> Result<ApiKey, StorageError>;
+
+    async fn delete_api_key(&self, api_key_id: &str) -> Result<(), StorageError>;
+}
@@ -1,3 +1,11 @@
+mod forgot_password;
+mod reset_password;
 mod sign_in;
+mod sign_up;
+mod verify_email;
 
+pub use forgot_password::*;
+pub use reset_password::*;
 pub use sign_in::*;
+pub use sign_up::*;
+pub use verify_email::*;
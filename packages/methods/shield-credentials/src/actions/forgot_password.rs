@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use rand::distr::{Alphanumeric, SampleString};
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeEmail, InputTypeSubmit, InputValue, MethodAction,
+    MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError, User,
+    erased_method_action,
+};
+
+use crate::{
+    password::PasswordManager,
+    provider::CredentialsProvider,
+    token::{CreatePasswordResetToken, hash_token},
+};
+
+pub const FORGOT_PASSWORD_ACTION_ID: &str = "forgot-password";
+const FORGOT_PASSWORD_ACTION_NAME: &str = "Forgot password";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForgotPasswordData {
+    pub email: String,
+}
+
+pub struct CredentialsForgotPasswordAction<U: User> {
+    password: PasswordManager<U>,
+}
+
+impl<U: User> CredentialsForgotPasswordAction<U> {
+    pub(crate) fn new(password: PasswordManager<U>) -> Self {
+        Self { password }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<CredentialsProvider, ()>
+    for CredentialsForgotPasswordAction<U>
+{
+    fn id(&self) -> String {
+        FORGOT_PASSWORD_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        FORGOT_PASSWORD_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Forgot password"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Request a password reset token by email."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self, _provider: CredentialsProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "email".to_owned(),
+                    label: Some("Email address".to_owned()),
+                    r#type: InputType::Email(InputTypeEmail {
+                        autocomplete: Some("email".to_owned()),
+                        placeholder: Some("Email address".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: "Send reset link".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: CredentialsProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<ForgotPasswordData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let options = &self.password.options;
+        let storage = &self.password.storage;
+
+        let secret = options.secret()?;
+        let sender = options.sender()?;
+
+        storage.delete_expired_password_reset_tokens().await?;
+
+        let email = data.email.trim().to_lowercase();
+
+        // Respond the same way for unknown accounts, so email addresses can not be enumerated.
+        let Some(user) = storage.user_by_email(&email).await? else {
+            return Ok(Response::new(ResponseType::Default));
+        };
+        if storage.user_password(&user.id()).await?.is_none() {
+            return Ok(Response::new(ResponseType::Default));
+        }
+
+        let token = Alphanumeric.sample_string(&mut rand::rng(), 32);
+
+        let password_reset_token = storage
+            .create_password_reset_token(CreatePasswordResetToken {
+                token: hash_token(&token, secret),
+                expired_at: (Utc::now() + options.password_reset_expires_in).into(),
+                user_id: user.id(),
+            })
+            .await?;
+
+        sender
+            .send_password_reset(&email, &token, password_reset_token.expired_at)
+            .await?;
+
+        Ok(Response::new(ResponseType::Default))
+    }
+}
+
+erased_method_action!(CredentialsForgotPasswordAction, <U: User>);
@@ -0,0 +1,324 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeHidden, InputTypePassword, InputTypeSubmit, InputValue,
+    MethodAction, MethodSession, RegisteredSessionFilter, Request, RequestMethod, Response,
+    ResponseType, SessionAction, ShieldError, User, erased_method_action,
+};
+
+use crate::{password::PasswordManager, provider::CredentialsProvider, token::hash_token};
+
+pub const RESET_PASSWORD_ACTION_ID: &str = "reset-password";
+const RESET_PASSWORD_ACTION_NAME: &str = "Reset password";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetPasswordData {
+    pub token: String,
+    pub password: String,
+    pub password_confirmation: String,
+}
+
+/// Reset the password with a token sent by the forgot password action.
+///
+/// Changing the password signs the user out of all sessions and revokes all refresh tokens of the user. Other sessions
+/// can only be terminated if a session registry is configured with `Shield::with_session_registry`, otherwise they stay
+/// signed in until they expire.
+pub struct CredentialsResetPasswordAction<U: User> {
+    password: PasswordManager<U>,
+}
+
+impl<U: User> CredentialsResetPasswordAction<U> {
+    pub(crate) fn new(password: PasswordManager<U>) -> Self {
+        Self { password }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<CredentialsProvider, ()>
+    for CredentialsResetPasswordAction<U>
+{
+    fn id(&self) -> String {
+        RESET_PASSWORD_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        RESET_PASSWORD_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Reset password"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Reset password with a password reset token."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self, _provider: CredentialsProvider) -> Result<Vec<Form>, ShieldError> {
+        let minlength = Some(self.password.options.policy.min_length.to_string());
+        let maxlength = Some(self.password.options.policy.max_length.to_string());
+
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "token".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden {
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: Some(InputValue::Query {
+                        key: "token".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "password".to_owned(),
+                    label: Some("New password".to_owned()),
+                    r#type: InputType::Password(InputTypePassword {
+                        autocomplete: Some("new-password".to_owned()),
+                        minlength: minlength.clone(),
+                        maxlength: maxlength.clone(),
+                        placeholder: Some("New password".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "passwordConfirmation".to_owned(),
+                    label: Some("Confirm new password".to_owned()),
+                    r#type: InputType::Password(InputTypePassword {
+                        autocomplete: Some("new-password".to_owned()),
+                        minlength,
+                        maxlength,
+                        placeholder: Some("Confirm new password".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: "Reset password".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: CredentialsProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<ResetPasswordData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let options = &self.password.options;
+        let storage = &self.password.storage;
+
+        if data.password != data.password_confirmation {
+            return Err(ShieldError::Validation(
+                "Passwords do not match.".to_owned(),
+            ));
+        }
+        options.policy.validate(&data.password)?;
+
+        let password_reset_token = storage
+            .password_reset_token(&hash_token(&data.token, options.secret()?))
+            .await?
+            .ok_or_else(|| ShieldError::Validation("Password reset token not found.".to_owned()))?;
+
+        // Tokens are single-use, so delete it before changing the password. Only the request which deleted the
+        // token can change the password, as concurrent requests may have found the same token.
+        if !storage
+            .delete_password_reset_token(&password_reset_token.id)
+            .await?
+        {
+            return Err(ShieldError::Validation(
+                "Password reset token not found.".to_owned(),
+            ));
+        }
+
+        self.password
+            .set_password(&password_reset_token.user_id, None, &data.password)
+            .await?;
+
+        // Sign out everywhere, as the old password may have been compromised.
+        Ok(Response::new(ResponseType::Redirect(
+            options.password_reset_redirect.clone(),
+        ))
+        .session_action(SessionAction::unauthenticate())
+        .session_action(SessionAction::destroy_sessions(
+            RegisteredSessionFilter::user(&password_reset_token.user_id),
+        )))
+    }
+}
+
+erased_method_action!(CredentialsResetPasswordAction, <U: User>);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use secrecy::ExposeSecret;
+    use serde_json::json;
+    use shield::{
+        BaseSession, MethodAction, MethodSession, Request, SessionAction, ShieldError, Storage,
+        User,
+    };
+
+    use crate::{
+        actions::{CredentialsForgotPasswordAction, CredentialsSignUpAction},
+        email_password::EmailPasswordCredentials,
+        options::PasswordOptions,
+        password::PasswordManager,
+        provider::CredentialsProvider,
+        sender::tests::TestSender,
+        storage::tests::{TestStorage, TestUser},
+    };
+
+    use super::CredentialsResetPasswordAction;
+
+    #[tokio::test]
+    async fn reset_password() -> Result<(), ShieldError> {
+        let storage = Arc::new(TestStorage::default());
+        let sender = TestSender::default();
+        let password = PasswordManager::<TestUser>::new(
+            PasswordOptions::builder()
+                .memory_cost(1024)
+                .time_cost(1)
+                .secret("secret")
+                .sender(sender.clone())
+                .build(),
+            storage.clone(),
+        );
+
+        let base = BaseSession::default();
+        let session = MethodSession {
+            base: &base,
+            method: &(),
+        };
+
+        CredentialsSignUpAction::new(
+            Arc::new(EmailPasswordCredentials::default()),
+            password.clone(),
+        )
+        .call(
+            CredentialsProvider,
+            &session,
+            Request::new(
+                json!({}),
+                json!({
+                    "email": "alice@example.com",
+                    "password": "correct horse battery staple",
+                    "passwordConfirmation": "correct horse battery staple",
+                }),
+            ),
+        )
+        .await?;
+        let user = storage.user_by_email("alice@example.com").await?.unwrap();
+        let old_password_hash = storage.user_passwords()[0]
+            .password_hash
+            .expose_secret()
+            .to_owned();
+
+        CredentialsForgotPasswordAction::new(password.clone())
+            .call(
+                CredentialsProvider,
+                &session,
+                Request::new(json!({}), json!({ "email": "alice@example.com" })),
+            )
+            .await?;
+        let token = sender.token().expect("password reset token should be sent");
+
+        let action = CredentialsResetPasswordAction::new(password.clone());
+        let reset = |token: &str| {
+            action.call(
+                CredentialsProvider,
+                &session,
+                Request::new(
+                    json!({}),
+                    json!({
+                        "token": token,
+                        "password": "battery staple correct horse",
+                        "passwordConfirmation": "battery staple correct horse",
+                    }),
+                ),
+            )
+        };
+
+        assert!(
+            reset("incorrect")
+                .await
+                .is_err_and(|err| err.to_string().contains("token not found"))
+        );
+
+        let response = reset(&token).await?;
+
+        let password_hash = storage.user_passwords()[0]
+            .password_hash
+            .expose_secret()
+            .to_owned();
+        assert_ne!(old_password_hash, password_hash);
+        assert!(
+            password
+                .options
+                .verify_password("battery staple correct horse", &password_hash)
+                .await?
+        );
+
+        // All sessions of the user are terminated, including the current one.
+        assert!(matches!(
+            response.session_actions[0],
+            SessionAction::Unauthenticate
+        ));
+        assert!(matches!(
+            &response.session_actions[1],
+            SessionAction::DestroySessions { filter }
+                if filter.user_id == Some(user.id())
+                    && filter.method_id.is_none()
+                    && filter.provider_id.is_none()
+        ));
+
+        // Tokens are single-use.
+        assert!(storage.password_reset_tokens().is_empty());
+        assert!(
+            reset(&token)
+                .await
+                .is_err_and(|err| err.to_string().contains("token not found"))
+        );
+
+        // Concurrent resets with the same token can not both succeed.
+        CredentialsForgotPasswordAction::new(password.clone())
+            .call(
+                CredentialsProvider,
+                &session,
+                Request::new(json!({}), json!({ "email": "alice@example.com" })),
+            )
+            .await?;
+        let token = sender.token().expect("password reset token should be sent");
+
+        let (first, second) = tokio::join!(reset(&token), reset(&token));
+        assert!(first.is_ok() != second.is_ok());
+        assert!(storage.password_reset_tokens().is_empty());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{pin::Pin, sync::Arc};
 
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
@@ -7,15 +7,24 @@ use shield::{
     SessionAction, ShieldError, SignInAction, User, erased_method_action,
 };
 
-use crate::{credentials::Credentials, provider::CredentialsProvider};
+use crate::{credentials::Credentials, password::PasswordManager, provider::CredentialsProvider};
 
 pub struct CredentialsSignInAction<U: User, D: DeserializeOwned> {
     credentials: Arc<dyn Credentials<U, D>>,
+    password: Option<PasswordManager<U>>,
 }
 
 impl<U: User, D: DeserializeOwned> CredentialsSignInAction<U, D> {
     pub fn new(credentials: Arc<dyn Credentials<U, D>>) -> Self {
-        Self { credentials }
+        Self {
+            credentials,
+            password: None,
+        }
+    }
+
+    pub(crate) fn with_password(mut self, password: Option<PasswordManager<U>>) -> Self {
+        self.password = password;
+        self
     }
 }
 
@@ -47,16 +56,30 @@ impl<U: User + 'static, D: DeserializeOwned + 'static> MethodAction<CredentialsP
         Ok(vec![self.credentials.form()])
     }
 
+    fn rate_limit_identifier(&self, request: &Request) -> Option<String> {
+        serde_json::from_value(request.form_data.clone())
+            .ok()
+            .and_then(|data| self.credentials.identifier(&data))
+    }
+
     async fn call(
         &self,
         provider: CredentialsProvider,
         _session: &MethodSession<()>,
         request: Request,
     ) -> Result<Response, ShieldError> {
-        let data = serde_json::from_value(request.form_data)
-            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+        // Form data is not required to be `Send`, so it must be dropped before awaiting.
+        let sign_in: Pin<Box<dyn Future<Output = Result<U, ShieldError>> + Send + '_>> = {
+            let data = serde_json::from_value(request.form_data)
+                .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+            match (&self.password, self.credentials.password_data(&data)) {
+                (Some(password), Some(password_data)) => Box::pin(password.sign_in(password_data)),
+                _ => self.credentials.sign_in(data),
+            }
+        };
 
-        let user = self.credentials.sign_in(data).await?;
+        let user = sign_in.await?;
 
         Ok(Response::new(ResponseType::Default)
             .session_action(SessionAction::authenticate(&provider, user)))
@@ -64,3 +87,126 @@ impl<U: User + 'static, D: DeserializeOwned + 'static> MethodAction<CredentialsP
 }
 
 erased_method_action!(CredentialsSignInAction, <U: User, D: DeserializeOwned>);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use secrecy::ExposeSecret;
+    use serde_json::json;
+    use shield::{
+        BaseSession, CreateEmailAddress, CreateUser, MethodAction, MethodSession, Request,
+        SessionAction, ShieldError, Storage, User,
+    };
+
+    use crate::{
+        email_password::EmailPasswordCredentials,
+        options::PasswordOptions,
+        password::{CreateUserPassword, PasswordManager},
+        provider::CredentialsProvider,
+        storage::{
+            PasswordStorage,
+            tests::{TestStorage, TestUser},
+        },
+    };
+
+    use super::CredentialsSignInAction;
+
+    #[tokio::test]
+    async fn sign_in_rehashes_legacy_password() -> Result<(), ShieldError> {
+        let storage = Arc::new(TestStorage::default());
+        let user = storage
+            .create_user(
+                CreateUser { name: None },
+                CreateEmailAddress {
+                    email: "alice@example.com".to_owned(),
+                    is_primary: true,
+                    is_verified: true,
+                    verification_token: None,
+                    verification_token_expired_at: None,
+                    verified_at: None,
+                },
+            )
+            .await?;
+
+        let legacy_options = PasswordOptions::builder()
+            .memory_cost(1024)
+            .time_cost(1)
+            .build();
+        storage
+            .create_user_password(CreateUserPassword {
+                username: None,
+                password_hash: legacy_options.hash_password("correct horse").await?,
+                user_id: user.id(),
+            })
+            .await?;
+
+        let options = PasswordOptions::builder()
+            .memory_cost(2048)
+            .time_cost(1)
+            .build();
+        let action = CredentialsSignInAction::<TestUser, _>::new(Arc::new(
+            EmailPasswordCredentials::default(),
+        ))
+        .with_password(Some(PasswordManager::new(options.clone(), storage.clone())));
+
+        let base = BaseSession::default();
+        let session = MethodSession {
+            base: &base,
+            method: &(),
+        };
+        let sign_in = |password: &str| {
+            action.call(
+                CredentialsProvider,
+                &session,
+                Request::new(
+                    json!({}),
+                    json!({ "email": "alice@example.com", "password": password }),
+                ),
+            )
+        };
+
+        let legacy_hash = storage.user_passwords()[0]
+            .password_hash
+            .expose_secret()
+            .to_owned();
+
+        assert!(
+            sign_in("battery staple")
+                .await
+                .is_err_and(|err| err.to_string().contains("Incorrect email and password"))
+        );
+        assert_eq!(
+            storage.user_passwords()[0].password_hash.expose_secret(),
+            legacy_hash
+        );
+
+        let response = sign_in("correct horse").await?;
+        assert!(
+            response
+                .session_actions
+                .iter()
+                .any(|session_action| matches!(
+                    session_action,
+                    SessionAction::Authenticate { user_id, .. } if *user_id == user.id()
+                ))
+        );
+
+        let password_hash = storage.user_passwords()[0]
+            .password_hash
+            .expose_secret()
+            .to_owned();
+        assert_ne!(password_hash, legacy_hash);
+        assert!(!options.needs_rehash(&password_hash));
+        assert!(
+            options
+                .verify_password("correct horse", &password_hash)
+                .await?
+        );
+
+        // The new hash is used from now on.
+        sign_in("correct horse").await?;
+
+        Ok(())
+    }
+}
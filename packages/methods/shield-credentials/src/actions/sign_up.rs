@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use rand::distr::{Alphanumeric, SampleString};
+use serde::{Deserialize, de::DeserializeOwned};
+use shield::{
+    CreateEmailAddress, CreateUser, Form, Input, InputType, InputTypeEmail, InputTypePassword,
+    InputTypeText, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType,
+    SessionAction, ShieldError, SignUpAction, SignUpEvent, User, erased_method_action,
+};
+
+use crate::{
+    credentials::{Credentials, PasswordIdentifierType},
+    password::PasswordManager,
+    provider::CredentialsProvider,
+    token::hash_token,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignUpData {
+    pub email: String,
+    pub username: Option<String>,
+    pub password: String,
+    pub password_confirmation: String,
+}
+
+pub struct CredentialsSignUpAction<U: User, D: DeserializeOwned> {
+    credentials: Arc<dyn Credentials<U, D>>,
+    password: PasswordManager<U>,
+}
+
+impl<U: User, D: DeserializeOwned> CredentialsSignUpAction<U, D> {
+    pub(crate) fn new(
+        credentials: Arc<dyn Credentials<U, D>>,
+        password: PasswordManager<U>,
+    ) -> Self {
+        Self {
+            credentials,
+            password,
+        }
+    }
+
+    fn requires_username(&self) -> bool {
+        self.credentials.password_identifier_type() == Some(PasswordIdentifierType::Username)
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static, D: DeserializeOwned + 'static> MethodAction<CredentialsProvider, ()>
+    for CredentialsSignUpAction<U, D>
+{
+    fn id(&self) -> String {
+        SignUpAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignUpAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign up with credentials"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign up with credentials."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &CredentialsProvider,
+        session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.authentication.is_none())
+    }
+
+    async fn forms(&self, _provider: CredentialsProvider) -> Result<Vec<Form>, ShieldError> {
+        let mut inputs = vec![];
+
+        if self.requires_username() {
+            inputs.push(Input {
+                name: "username".to_owned(),
+                label: Some("Username".to_owned()),
+                r#type: InputType::Text(InputTypeText {
+                    autocomplete: Some("username".to_owned()),
+                    placeholder: Some("Username".to_owned()),
+                    required: Some(true),
+                    ..Default::default()
+                }),
+                value: None,
+                addon_start: None,
+                addon_end: None,
+            });
+        }
+
+        let minlength = Some(self.password.options.policy.min_length.to_string());
+        let maxlength = Some(self.password.options.policy.max_length.to_string());
+
+        inputs.extend([
+            Input {
+                name: "email".to_owned(),
+                label: Some("Email address".to_owned()),
+                r#type: InputType::Email(InputTypeEmail {
+                    autocomplete: Some("email".to_owned()),
+                    placeholder: Some("Email address".to_owned()),
+                    required: Some(true),
+                    ..Default::default()
+                }),
+                value: None,
+                addon_start: None,
+                addon_end: None,
+            },
+            Input {
+                name: "password".to_owned(),
+                label: Some("Password".to_owned()),
+                r#type: InputType::Password(InputTypePassword {
+                    autocomplete: Some("new-password".to_owned()),
+                    minlength: minlength.clone(),
+                    maxlength: maxlength.clone(),
+                    placeholder: Some("Password".to_owned()),
+                    required: Some(true),
+                    ..Default::default()
+                }),
+                value: None,
+                addon_start: None,
+                addon_end: None,
+            },
+            Input {
+                name: "passwordConfirmation".to_owned(),
+                label: Some("Confirm password".to_owned()),
+                r#type: InputType::Password(InputTypePassword {
+                    autocomplete: Some("new-password".to_owned()),
+                    minlength,
+                    maxlength,
+                    placeholder: Some("Confirm password".to_owned()),
+                    required: Some(true),
+                    ..Default::default()
+                }),
+                value: None,
+                addon_start: None,
+                addon_end: None,
+            },
+        ]);
+
+        Ok(vec![Form { inputs }])
+    }
+
+    async fn call(
+        &self,
+        provider: CredentialsProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignUpData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let options = &self.password.options;
+        let storage = &self.password.storage;
+
+        let email = data.email.trim().to_lowercase();
+        let username = data
+            .username
+            .map(|username| username.trim().to_owned())
+            .filter(|username| !username.is_empty());
+
+        if email.is_empty() {
+            return Err(ShieldError::Validation(
+                "Email address is required.".to_owned(),
+            ));
+        }
+        if username.is_none() && self.requires_username() {
+            return Err(ShieldError::Validation("Username is required.".to_owned()));
+        }
+        if data.password != data.password_confirmation {
+            return Err(ShieldError::Validation(
+                "Passwords do not match.".to_owned(),
+            ));
+        }
+        options.policy.validate(&data.password)?;
+
+        if storage.user_by_email(&email).await?.is_some() {
+            return Err(ShieldError::Validation(
+                "Email address is already used by another account.".to_owned(),
+            ));
+        }
+        if let Some(username) = &username
+            && storage.user_password_by_username(username).await?.is_some()
+        {
+            return Err(ShieldError::Validation(
+                "Username is already taken.".to_owned(),
+            ));
+        }
+
+        let (sender, token) = if options.email_verification {
+            (
+                Some(options.sender()?),
+                Some(Alphanumeric.sample_string(&mut rand::rng(), 32)),
+            )
+        } else {
+            (None, None)
+        };
+        let expired_at: Option<DateTime<FixedOffset>> = token
+            .as_ref()
+            .map(|_| (Utc::now() + options.email_verification_expires_in).into());
+
+        let verification_token = token
+            .as_deref()
+            .map(|token| options.secret().map(|secret| hash_token(token, secret)))
+            .transpose()?;
+
+        let user = request
+            .hooks
+            .sign_up(
+                SignUpEvent {
+                    email: Some(email.clone()),
+                    ..SignUpEvent::new(&provider)
+                },
+                async {
+                    let user = storage
+                        .create_user(
+                            CreateUser { name: None },
+                            CreateEmailAddress {
+                                email: email.clone(),
+                                is_primary: true,
+                                is_verified: false,
+                                verification_token,
+                                verification_token_expired_at: expired_at,
+                                verified_at: None,
+                            },
+                        )
+                        .await?;
+
+                    if let Err(err) = self
+                        .password
+                        .set_password(&user.id(), username, &data.password)
+                        .await
+                    {
+                        storage.delete_user(&user.id()).await?;
+
+                        return Err(err);
+                    }
+
+                    Ok(user)
+                },
+            )
+            .await?;
+
+        if let (Some(sender), Some(token), Some(expired_at)) = (sender, token, expired_at) {
+            sender
+                .send_email_verification(&email, &token, expired_at)
+                .await?;
+        }
+
+        Ok(Response::new(ResponseType::Default)
+            .session_action(SessionAction::authenticate(&provider, user)))
+    }
+}
+
+erased_method_action!(CredentialsSignUpAction, <U: User, D: DeserializeOwned>);
@@ -0,0 +1,198 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue, MethodAction,
+    MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError, User,
+    erased_method_action,
+};
+
+use crate::{password::PasswordManager, provider::CredentialsProvider, token::hash_token};
+
+pub const VERIFY_EMAIL_ACTION_ID: &str = "verify-email";
+const VERIFY_EMAIL_ACTION_NAME: &str = "Verify email";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyEmailData {
+    pub token: String,
+}
+
+pub struct CredentialsVerifyEmailAction<U: User> {
+    password: PasswordManager<U>,
+}
+
+impl<U: User> CredentialsVerifyEmailAction<U> {
+    pub(crate) fn new(password: PasswordManager<U>) -> Self {
+        Self { password }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<CredentialsProvider, ()> for CredentialsVerifyEmailAction<U> {
+    fn id(&self) -> String {
+        VERIFY_EMAIL_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        VERIFY_EMAIL_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Verify email"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Verify an email address with the token sent after sign up."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self, _provider: CredentialsProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "token".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden {
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: Some(InputValue::Query {
+                        key: "token".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: "Verify email address".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: CredentialsProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<VerifyEmailData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let options = &self.password.options;
+
+        if !self
+            .password
+            .storage
+            .verify_email_address(&hash_token(&data.token, options.secret()?))
+            .await?
+        {
+            return Err(ShieldError::Validation(
+                "Email verification token not found.".to_owned(),
+            ));
+        }
+
+        Ok(Response::new(ResponseType::Redirect(
+            options.email_verification_redirect.clone(),
+        )))
+    }
+}
+
+erased_method_action!(CredentialsVerifyEmailAction, <U: User>);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+    use shield::{BaseSession, MethodAction, MethodSession, Request, ShieldError, Storage};
+
+    use crate::{
+        actions::CredentialsSignUpAction,
+        email_password::EmailPasswordCredentials,
+        options::PasswordOptions,
+        password::PasswordManager,
+        provider::CredentialsProvider,
+        sender::tests::TestSender,
+        storage::tests::{TestStorage, TestUser},
+    };
+
+    use super::CredentialsVerifyEmailAction;
+
+    #[tokio::test]
+    async fn verify_email() -> Result<(), ShieldError> {
+        let storage = Arc::new(TestStorage::default());
+        let sender = TestSender::default();
+        let password = PasswordManager::<TestUser>::new(
+            PasswordOptions::builder()
+                .memory_cost(1024)
+                .time_cost(1)
+                .secret("secret")
+                .sender(sender.clone())
+                .email_verification(true)
+                .build(),
+            storage.clone(),
+        );
+
+        let base = BaseSession::default();
+        let session = MethodSession {
+            base: &base,
+            method: &(),
+        };
+
+        CredentialsSignUpAction::new(
+            Arc::new(EmailPasswordCredentials::default()),
+            password.clone(),
+        )
+        .call(
+            CredentialsProvider,
+            &session,
+            Request::new(
+                json!({}),
+                json!({
+                    "email": "alice@example.com",
+                    "password": "correct horse battery staple",
+                    "passwordConfirmation": "correct horse battery staple",
+                }),
+            ),
+        )
+        .await?;
+
+        let user = storage.user_by_email("alice@example.com").await?.unwrap();
+        assert!(!user.is_verified());
+        let token = sender.token().expect("verification token should be sent");
+
+        let action = CredentialsVerifyEmailAction::new(password);
+        let verify = |token: &str| {
+            action.call(
+                CredentialsProvider,
+                &session,
+                Request::new(json!({}), json!({ "token": token })),
+            )
+        };
+
+        assert!(
+            verify("incorrect")
+                .await
+                .is_err_and(|err| err.to_string().contains("token not found"))
+        );
+
+        verify(&token).await?;
+        let user = storage.user_by_email("alice@example.com").await?.unwrap();
+        assert!(user.is_verified());
+
+        // Tokens are single-use.
+        assert!(verify(&token).await.is_err());
+
+        Ok(())
+    }
+}
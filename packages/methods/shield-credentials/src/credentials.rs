@@ -2,9 +2,46 @@ use async_trait::async_trait;
 use serde::de::DeserializeOwned;
 use shield::{Form, ShieldError, User};
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PasswordIdentifierType {
+    Email,
+    Username,
+}
+
+#[derive(Clone, Debug)]
+pub enum PasswordIdentifier {
+    Email(String),
+    Username(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct PasswordData {
+    pub identifier: PasswordIdentifier,
+    pub password: String,
+}
+
 #[async_trait]
 pub trait Credentials<U: User, D: DeserializeOwned>: Send + Sync {
     fn form(&self) -> Form;
 
+    /// Identifier used to sign in, if Shield manages passwords.
+    fn password_identifier_type(&self) -> Option<PasswordIdentifierType> {
+        None
+    }
+
+    /// Identifier and password to verify against the stored hash, if Shield manages passwords.
+    fn password_data(&self, _data: &D) -> Option<PasswordData> {
+        None
+    }
+
+    /// Identifier of the account, e.g. an email address, which failed sign-in attempts are limited for.
+    fn identifier(&self, data: &D) -> Option<String> {
+        self.password_data(data)
+            .map(|password_data| match password_data.identifier {
+                PasswordIdentifier::Email(identifier)
+                | PasswordIdentifier::Username(identifier) => identifier,
+            })
+    }
+
     async fn sign_in(&self, data: D) -> Result<U, ShieldError>;
 }
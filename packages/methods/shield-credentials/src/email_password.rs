@@ -2,9 +2,12 @@ use std::{pin::Pin, sync::Arc};
 
 use async_trait::async_trait;
 use serde::Deserialize;
-use shield::{Form, Input, InputType, InputTypeEmail, InputTypePassword, ShieldError, User};
+use shield::{
+    ConfigurationError, Form, Input, InputType, InputTypeEmail, InputTypePassword, ShieldError,
+    User,
+};
 
-use crate::Credentials;
+use crate::{Credentials, PasswordData, PasswordIdentifier, PasswordIdentifierType};
 
 #[derive(Debug, Deserialize)]
 pub struct EmailPasswordData {
@@ -16,8 +19,10 @@ type SignInFn<U> = dyn Fn(EmailPasswordData) -> Pin<Box<dyn Future<Output = Resu
     + Send
     + Sync;
 
+/// Without a sign in function, passwords are verified against
+/// [`PasswordStorage`](crate::PasswordStorage).
 pub struct EmailPasswordCredentials<U: User> {
-    sign_in_fn: Arc<SignInFn<U>>,
+    sign_in_fn: Option<Arc<SignInFn<U>>>,
 }
 
 impl<U: User> EmailPasswordCredentials<U> {
@@ -31,11 +36,17 @@ impl<U: User> EmailPasswordCredentials<U> {
         + 'static,
     ) -> Self {
         Self {
-            sign_in_fn: Arc::new(sign_in_fn),
+            sign_in_fn: Some(Arc::new(sign_in_fn)),
         }
     }
 }
 
+impl<U: User> Default for EmailPasswordCredentials<U> {
+    fn default() -> Self {
+        Self { sign_in_fn: None }
+    }
+}
+
 #[async_trait]
 impl<U: User> Credentials<U, EmailPasswordData> for EmailPasswordCredentials<U> {
     fn form(&self) -> Form {
@@ -71,8 +82,28 @@ impl<U: User> Credentials<U, EmailPasswordData> for EmailPasswordCredentials<U>
         }
     }
 
+    fn password_identifier_type(&self) -> Option<PasswordIdentifierType> {
+        self.sign_in_fn
+            .is_none()
+            .then_some(PasswordIdentifierType::Email)
+    }
+
+    fn password_data(&self, data: &EmailPasswordData) -> Option<PasswordData> {
+        self.sign_in_fn.is_none().then(|| PasswordData {
+            identifier: PasswordIdentifier::Email(data.email.clone()),
+            password: data.password.clone(),
+        })
+    }
+
+    fn identifier(&self, data: &EmailPasswordData) -> Option<String> {
+        Some(data.email.clone())
+    }
+
     async fn sign_in(&self, data: EmailPasswordData) -> Result<U, ShieldError> {
-        (self.sign_in_fn)(data).await
+        match &self.sign_in_fn {
+            Some(sign_in_fn) => sign_in_fn(data).await,
+            None => Err(ConfigurationError::Missing("password storage".to_owned()).into()),
+        }
     }
 }
 
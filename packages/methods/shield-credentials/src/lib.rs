@@ -2,10 +2,22 @@ mod actions;
 mod credentials;
 mod email_password;
 mod method;
+mod options;
+mod password;
+mod policy;
 mod provider;
+mod sender;
+mod storage;
+mod token;
 mod username_password;
 
 pub use credentials::*;
 pub use email_password::*;
 pub use method::*;
+pub use options::*;
+pub use password::{CreateUserPassword, UpdateUserPassword, UserPassword};
+pub use policy::*;
+pub use sender::*;
+pub use storage::*;
+pub use token::{CreatePasswordResetToken, PasswordResetToken};
 pub use username_password::*;
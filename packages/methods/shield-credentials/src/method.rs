@@ -2,24 +2,65 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
-use shield::{Method, MethodAction, ShieldError, User, erased_method};
+use shield::{
+    ConfigurationError, Method, MethodAction, ShieldError, StorageError, User, UserConnection,
+    erased_method,
+};
 
 use crate::{
-    actions::CredentialsSignInAction, credentials::Credentials, provider::CredentialsProvider,
+    actions::{
+        CredentialsForgotPasswordAction, CredentialsResetPasswordAction, CredentialsSignInAction,
+        CredentialsSignUpAction, CredentialsVerifyEmailAction,
+    },
+    credentials::Credentials,
+    options::PasswordOptions,
+    password::{PasswordManager, UserPassword},
+    provider::CredentialsProvider,
+    storage::PasswordStorage,
 };
 
 pub const CREDENTIALS_METHOD_ID: &str = "credentials";
 
 pub struct CredentialsMethod<U: User, D: DeserializeOwned> {
     credentials: Arc<dyn Credentials<U, D>>,
+    password: Option<PasswordManager<U>>,
 }
 
 impl<U: User, D: DeserializeOwned> CredentialsMethod<U, D> {
     pub fn new<C: Credentials<U, D> + 'static>(credentials: C) -> Self {
         Self {
             credentials: Arc::new(credentials),
+            password: None,
+        }
+    }
+
+    pub fn with_password_storage<
+        C: Credentials<U, D> + 'static,
+        S: PasswordStorage<U> + 'static,
+    >(
+        credentials: C,
+        options: PasswordOptions,
+        storage: S,
+    ) -> Self {
+        Self {
+            credentials: Arc::new(credentials),
+            password: Some(PasswordManager::new(options, Arc::new(storage))),
         }
     }
+
+    /// Validate the password against the policy and store its hash for the user.
+    pub async fn set_user_password(
+        &self,
+        user_id: &str,
+        username: Option<String>,
+        password: &str,
+    ) -> Result<UserPassword, ShieldError> {
+        self.password
+            .as_ref()
+            .ok_or_else(|| ConfigurationError::Missing("password storage".to_owned()))?
+            .set_password(user_id, username, password)
+            .await
+    }
 }
 
 #[async_trait]
@@ -33,9 +74,34 @@ impl<U: User + 'static, D: DeserializeOwned + 'static> Method for CredentialsMet
     }
 
     fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
-        vec![Box::new(CredentialsSignInAction::new(
-            self.credentials.clone(),
-        ))]
+        let mut actions: Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> =
+            vec![Box::new(
+                CredentialsSignInAction::new(self.credentials.clone())
+                    .with_password(self.password.clone()),
+            )];
+
+        if let Some(password) = &self.password
+            && self.credentials.password_identifier_type().is_some()
+        {
+            actions.push(Box::new(CredentialsSignUpAction::new(
+                self.credentials.clone(),
+                password.clone(),
+            )));
+            actions.push(Box::new(CredentialsForgotPasswordAction::new(
+                password.clone(),
+            )));
+            actions.push(Box::new(CredentialsResetPasswordAction::new(
+                password.clone(),
+            )));
+
+            if password.options.email_verification {
+                actions.push(Box::new(CredentialsVerifyEmailAction::new(
+                    password.clone(),
+                )));
+            }
+        }
+
+        actions
     }
 
     async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
@@ -49,6 +115,130 @@ impl<U: User + 'static, D: DeserializeOwned + 'static> Method for CredentialsMet
     ) -> Result<Vec<Self::Connection>, ShieldError> {
         Ok(vec![])
     }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        // Credentials which are not verified against stored passwords, e.g. with a sign in function, are managed
+        // outside of Shield, so the user can always sign in with them and they can not be unlinked.
+        if self.credentials.password_identifier_type().is_none() {
+            return Ok(vec![UserConnection {
+                id: CREDENTIALS_METHOD_ID.to_owned(),
+                method_id: CREDENTIALS_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: None,
+                sign_in: true,
+                unlinkable: false,
+            }]);
+        }
+
+        let Some(password) = &self.password else {
+            return Ok(vec![]);
+        };
+
+        Ok(password
+            .storage
+            .user_password(user_id)
+            .await?
+            .into_iter()
+            .map(|user_password| UserConnection {
+                id: user_password.id,
+                method_id: CREDENTIALS_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: user_password.username,
+                sign_in: true,
+                unlinkable: true,
+            })
+            .collect())
+    }
+
+    async fn unlink_user_connection(
+        &self,
+        user_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        let password = self
+            .password
+            .as_ref()
+            .ok_or_else(|| ConfigurationError::Missing("password storage".to_owned()))?;
+
+        match password.storage.user_password(user_id).await? {
+            Some(user_password) if user_password.id == connection_id => Ok(password
+                .storage
+                .delete_user_password(&user_password.id)
+                .await?),
+            _ => Err(
+                StorageError::NotFound("UserPassword".to_owned(), connection_id.to_owned()).into(),
+            ),
+        }
+    }
 }
 
 erased_method!(CredentialsMethod, <U: User, D: DeserializeOwned>);
+
+#[cfg(test)]
+mod tests {
+    use shield::{Method, ShieldError, UserConnection};
+
+    use crate::{
+        email_password::{EmailPasswordCredentials, EmailPasswordData},
+        options::PasswordOptions,
+        storage::tests::{TestStorage, TestUser},
+    };
+
+    use super::{CREDENTIALS_METHOD_ID, CredentialsMethod};
+
+    #[tokio::test]
+    async fn list_user_connections() -> Result<(), ShieldError> {
+        let method = CredentialsMethod::<TestUser, EmailPasswordData>::with_password_storage(
+            EmailPasswordCredentials::default(),
+            PasswordOptions::builder()
+                .memory_cost(1024)
+                .time_cost(1)
+                .build(),
+            TestStorage::default(),
+        );
+
+        assert!(method.list_user_connections("1").await?.is_empty());
+
+        let user_password = method
+            .set_user_password("1", None, "correct horse battery staple")
+            .await?;
+
+        assert_eq!(
+            vec![UserConnection {
+                id: user_password.id,
+                method_id: CREDENTIALS_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: None,
+                sign_in: true,
+                unlinkable: true,
+            }],
+            method.list_user_connections("1").await?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_user_connections_with_sign_in_fn() -> Result<(), ShieldError> {
+        let method = CredentialsMethod::<TestUser, EmailPasswordData>::new(
+            EmailPasswordCredentials::new(|_| Box::pin(async { Err(ShieldError::Unauthorized) })),
+        );
+
+        assert_eq!(
+            vec![UserConnection {
+                id: CREDENTIALS_METHOD_ID.to_owned(),
+                method_id: CREDENTIALS_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: None,
+                sign_in: true,
+                unlinkable: false,
+            }],
+            method.list_user_connections("1").await?
+        );
+
+        Ok(())
+    }
+}
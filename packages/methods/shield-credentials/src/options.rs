@@ -0,0 +1,183 @@
+use argon2::{
+    Algorithm, Argon2, Params, Version,
+    password_hash::{Error, PasswordHasher, PasswordVerifier, phc::PasswordHash},
+};
+use std::sync::Arc;
+
+use bon::Builder;
+use chrono::TimeDelta;
+use secrecy::{ExposeSecret, SecretString};
+use shield::{ConfigurationError, ShieldError};
+
+use crate::{policy::PasswordPolicy, sender::Sender};
+
+#[derive(Builder, Clone)]
+#[builder(state_mod(vis = "pub(crate)"))]
+pub struct PasswordOptions {
+    /// Argon2id memory cost in KiB.
+    #[builder(default = Params::DEFAULT_M_COST)]
+    pub(crate) memory_cost: u32,
+
+    /// Argon2id number of iterations.
+    #[builder(default = Params::DEFAULT_T_COST)]
+    pub(crate) time_cost: u32,
+
+    /// Argon2id degree of parallelism.
+    #[builder(default = Params::DEFAULT_P_COST)]
+    pub(crate) parallelism: u32,
+
+    #[builder(default)]
+    pub(crate) policy: PasswordPolicy,
+
+    /// Secret used to hash tokens before they are stored.
+    #[builder(into)]
+    pub(crate) secret: Option<SecretString>,
+
+    #[builder(with = |sender: impl Sender + 'static| Arc::new(sender))]
+    pub(crate) sender: Option<Arc<dyn Sender>>,
+
+    /// Send an email verification token after sign up.
+    #[builder(default)]
+    pub(crate) email_verification: bool,
+
+    #[builder(default = TimeDelta::days(1))]
+    pub(crate) email_verification_expires_in: TimeDelta,
+
+    #[builder(default = "/", into)]
+    pub(crate) email_verification_redirect: String,
+
+    #[builder(default = TimeDelta::hours(1))]
+    pub(crate) password_reset_expires_in: TimeDelta,
+
+    #[builder(default = "/", into)]
+    pub(crate) password_reset_redirect: String,
+}
+
+impl PasswordOptions {
+    fn argon2(&self) -> Result<Argon2<'static>, ShieldError> {
+        let params = Params::new(self.memory_cost, self.time_cost, self.parallelism, None)
+            .map_err(|err| {
+                ConfigurationError::Invalid(format!("invalid Argon2 parameters: {err}"))
+            })?;
+
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+
+    pub(crate) fn secret(&self) -> Result<&SecretString, ShieldError> {
+        self.secret
+            .as_ref()
+            .ok_or_else(|| ConfigurationError::Missing("password secret".to_owned()).into())
+    }
+
+    pub(crate) fn sender(&self) -> Result<&dyn Sender, ShieldError> {
+        self.sender
+            .as_deref()
+            .ok_or_else(|| ConfigurationError::Missing("password sender".to_owned()).into())
+    }
+
+    pub(crate) async fn hash_password(&self, password: &str) -> Result<SecretString, ShieldError> {
+        let argon2 = self.argon2()?;
+        let password = SecretString::from(password);
+
+        // Argon2id is deliberately slow, so keep it off the async runtime.
+        tokio::task::spawn_blocking(move || {
+            argon2
+                .hash_password(password.expose_secret().as_bytes())
+                .map(|password_hash| password_hash.to_string().into())
+                .map_err(|err| ShieldError::Validation(err.to_string()))
+        })
+        .await
+        .map_err(|err| ShieldError::Validation(err.to_string()))?
+    }
+
+    pub(crate) async fn verify_password(
+        &self,
+        password: &str,
+        password_hash: &str,
+    ) -> Result<bool, ShieldError> {
+        let argon2 = self.argon2()?;
+        let password = SecretString::from(password);
+        let password_hash = SecretString::from(password_hash);
+
+        tokio::task::spawn_blocking(move || {
+            // Parameters are read from the hash, so older hashes still verify.
+            match argon2.verify_password(
+                password.expose_secret().as_bytes(),
+                password_hash.expose_secret(),
+            ) {
+                Ok(()) => Ok(true),
+                Err(Error::PasswordInvalid) => Ok(false),
+                Err(err) => Err(ShieldError::Validation(err.to_string())),
+            }
+        })
+        .await
+        .map_err(|err| ShieldError::Validation(err.to_string()))?
+    }
+
+    pub(crate) fn needs_rehash(&self, password_hash: &str) -> bool {
+        let Ok(password_hash) = PasswordHash::new(password_hash) else {
+            return true;
+        };
+        let Ok(params) = Params::try_from(&password_hash) else {
+            return true;
+        };
+
+        password_hash.algorithm != Algorithm::Argon2id.ident()
+            || password_hash.version != Some(Version::V0x13.into())
+            || params.m_cost() != self.memory_cost
+            || params.t_cost() != self.time_cost
+            || params.p_cost() != self.parallelism
+    }
+}
+
+impl Default for PasswordOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use secrecy::ExposeSecret;
+
+    use super::PasswordOptions;
+
+    #[tokio::test]
+    async fn password_hashing() {
+        let options = PasswordOptions::builder()
+            .memory_cost(1024)
+            .time_cost(1)
+            .build();
+
+        let password_hash = options.hash_password("correct horse").await.unwrap();
+        let password_hash = password_hash.expose_secret();
+
+        assert!(password_hash.starts_with("$argon2id$"));
+        assert!(
+            options
+                .verify_password("correct horse", password_hash)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !options
+                .verify_password("battery staple", password_hash)
+                .await
+                .unwrap()
+        );
+        assert!(!options.needs_rehash(password_hash));
+
+        let options = PasswordOptions::builder()
+            .memory_cost(2048)
+            .time_cost(1)
+            .build();
+
+        assert!(
+            options
+                .verify_password("correct horse", password_hash)
+                .await
+                .unwrap()
+        );
+        assert!(options.needs_rehash(password_hash));
+    }
+}
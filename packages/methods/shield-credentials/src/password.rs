@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use secrecy::{ExposeSecret, SecretString};
+use shield::{ShieldError, User};
+
+use crate::{
+    credentials::{PasswordData, PasswordIdentifier},
+    options::PasswordOptions,
+    storage::PasswordStorage,
+};
+
+#[derive(Clone, Debug)]
+pub struct UserPassword {
+    pub id: String,
+    pub username: Option<String>,
+    pub password_hash: SecretString,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateUserPassword {
+    pub username: Option<String>,
+    pub password_hash: SecretString,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateUserPassword {
+    pub id: String,
+    pub username: Option<Option<String>>,
+    pub password_hash: Option<SecretString>,
+}
+
+pub(crate) struct PasswordManager<U: User> {
+    pub(crate) options: PasswordOptions,
+    pub(crate) storage: Arc<dyn PasswordStorage<U>>,
+}
+
+impl<U: User> PasswordManager<U> {
+    pub(crate) fn new(options: PasswordOptions, storage: Arc<dyn PasswordStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+
+    pub(crate) async fn sign_in(&self, data: PasswordData) -> Result<U, ShieldError> {
+        let (user, user_password, message) = match data.identifier {
+            PasswordIdentifier::Email(email) => {
+                let user = self.storage.user_by_email(&email).await?;
+                let user_password = match &user {
+                    Some(user) => self.storage.user_password(&user.id()).await?,
+                    None => None,
+                };
+
+                (
+                    user,
+                    user_password,
+                    "Incorrect email and password combination.",
+                )
+            }
+            PasswordIdentifier::Username(username) => {
+                let user_password = self.storage.user_password_by_username(&username).await?;
+                let user = match &user_password {
+                    Some(user_password) => self.storage.user_by_id(&user_password.user_id).await?,
+                    None => None,
+                };
+
+                (
+                    user,
+                    user_password,
+                    "Incorrect username and password combination.",
+                )
+            }
+        };
+
+        let (Some(user), Some(user_password)) = (user, user_password) else {
+            // Hash anyway, so unknown accounts take as long as known ones.
+            self.options.hash_password(&data.password).await?;
+
+            return Err(ShieldError::Validation(message.to_owned()));
+        };
+
+        let password_hash = user_password.password_hash.expose_secret();
+        if !self
+            .options
+            .verify_password(&data.password, password_hash)
+            .await?
+        {
+            return Err(ShieldError::Validation(message.to_owned()));
+        }
+
+        if self.options.needs_rehash(password_hash) {
+            self.storage
+                .update_user_password(UpdateUserPassword {
+                    id: user_password.id,
+                    username: None,
+                    password_hash: Some(self.options.hash_password(&data.password).await?),
+                })
+                .await?;
+        }
+
+        Ok(user)
+    }
+
+    pub(crate) async fn set_password(
+        &self,
+        user_id: &str,
+        username: Option<String>,
+        password: &str,
+    ) -> Result<UserPassword, ShieldError> {
+        self.options.policy.validate(password)?;
+
+        let password_hash = self.options.hash_password(password).await?;
+
+        let user_password = match self.storage.user_password(user_id).await? {
+            Some(user_password) => {
+                self.storage
+                    .update_user_password(UpdateUserPassword {
+                        id: user_password.id,
+                        username: username.map(Some),
+                        password_hash: Some(password_hash),
+                    })
+                    .await?
+            }
+            None => {
+                self.storage
+                    .create_user_password(CreateUserPassword {
+                        username,
+                        password_hash,
+                        user_id: user_id.to_owned(),
+                    })
+                    .await?
+            }
+        };
+
+        Ok(user_password)
+    }
+}
+
+impl<U: User> Clone for PasswordManager<U> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+            storage: self.storage.clone(),
+        }
+    }
+}
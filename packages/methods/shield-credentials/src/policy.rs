@@ -0,0 +1,90 @@
+use bon::Builder;
+use shield::ShieldError;
+
+#[derive(Builder, Clone, Debug)]
+#[builder(state_mod(vis = "pub(crate)"))]
+pub struct PasswordPolicy {
+    #[builder(default = 8)]
+    pub(crate) min_length: usize,
+
+    #[builder(default = 128)]
+    pub(crate) max_length: usize,
+
+    #[builder(default)]
+    pub(crate) require_lowercase: bool,
+
+    #[builder(default)]
+    pub(crate) require_uppercase: bool,
+
+    #[builder(default)]
+    pub(crate) require_digit: bool,
+
+    #[builder(default)]
+    pub(crate) require_symbol: bool,
+}
+
+impl PasswordPolicy {
+    pub fn validate(&self, password: &str) -> Result<(), ShieldError> {
+        let length = password.chars().count();
+
+        if length < self.min_length {
+            return Err(ShieldError::Validation(format!(
+                "Password must be at least {} characters.",
+                self.min_length
+            )));
+        }
+        if length > self.max_length {
+            return Err(ShieldError::Validation(format!(
+                "Password must be at most {} characters.",
+                self.max_length
+            )));
+        }
+        if self.require_lowercase && !password.chars().any(char::is_lowercase) {
+            return Err(ShieldError::Validation(
+                "Password must contain a lowercase letter.".to_owned(),
+            ));
+        }
+        if self.require_uppercase && !password.chars().any(char::is_uppercase) {
+            return Err(ShieldError::Validation(
+                "Password must contain an uppercase letter.".to_owned(),
+            ));
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            return Err(ShieldError::Validation(
+                "Password must contain a digit.".to_owned(),
+            ));
+        }
+        if self.require_symbol && password.chars().all(char::is_alphanumeric) {
+            return Err(ShieldError::Validation(
+                "Password must contain a symbol.".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PasswordPolicy;
+
+    #[test]
+    fn password_policy() {
+        let policy = PasswordPolicy::builder()
+            .min_length(10)
+            .require_uppercase(true)
+            .require_digit(true)
+            .build();
+
+        assert!(policy.validate("Short1").is_err());
+        assert!(policy.validate("lowercase123").is_err());
+        assert!(policy.validate("NoDigitsHere").is_err());
+        assert!(policy.validate("Correct horse 1").is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use shield::ShieldError;
+
+#[async_trait]
+pub trait Sender: Send + Sync {
+    async fn send_email_verification(
+        &self,
+        email: &str,
+        token: &str,
+        expires_at: DateTime<FixedOffset>,
+    ) -> Result<(), ShieldError>;
+
+    async fn send_password_reset(
+        &self,
+        email: &str,
+        token: &str,
+        expires_at: DateTime<FixedOffset>,
+    ) -> Result<(), ShieldError>;
+}
+
+#[cfg(feature = "sender-tracing")]
+mod tracing {
+    use async_trait::async_trait;
+    use chrono::{DateTime, FixedOffset};
+    use shield::ShieldError;
+    use tracing::info;
+
+    use super::Sender;
+
+    pub struct TracingSender;
+
+    #[async_trait]
+    impl Sender for TracingSender {
+        async fn send_email_verification(
+            &self,
+            email: &str,
+            token: &str,
+            expires_at: DateTime<FixedOffset>,
+        ) -> Result<(), ShieldError> {
+            info!("Email verification token for `{email}` expires at `{expires_at}`:\n`{token}`");
+
+            Ok(())
+        }
+
+        async fn send_password_reset(
+            &self,
+            email: &str,
+            token: &str,
+            expires_at: DateTime<FixedOffset>,
+        ) -> Result<(), ShieldError> {
+            info!("Password reset token for `{email}` expires at `{expires_at}`:\n`{token}`");
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "sender-tracing")]
+pub use tracing::*;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, FixedOffset};
+    use shield::ShieldError;
+
+    use super::Sender;
+
+    /// Sender which keeps the last token, so tests can use it.
+    #[derive(Clone, Default)]
+    pub struct TestSender {
+        token: Arc<Mutex<Option<String>>>,
+    }
+
+    impl TestSender {
+        pub fn token(&self) -> Option<String> {
+            self.token.lock().expect("lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl Sender for TestSender {
+        async fn send_email_verification(
+            &self,
+            _email: &str,
+            token: &str,
+            _expires_at: DateTime<FixedOffset>,
+        ) -> Result<(), ShieldError> {
+            *self.token.lock().expect("lock") = Some(token.to_owned());
+
+            Ok(())
+        }
+
+        async fn send_password_reset(
+            &self,
+            _email: &str,
+            token: &str,
+            _expires_at: DateTime<FixedOffset>,
+        ) -> Result<(), ShieldError> {
+            *self.token.lock().expect("lock") = Some(token.to_owned());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+
+use shield::{Storage, StorageError, User};
+
+use crate::{
+    password::{CreateUserPassword, UpdateUserPassword, UserPassword},
+    token::{CreatePasswordResetToken, PasswordResetToken},
+};
+
+#[async_trait]
+pub trait PasswordStorage<U: User>: Storage<U> + Sync {
+    async fn user_password(&self, user_id: &str) -> Result<Option<UserPassword>, StorageError>;
+
+    async fn user_password_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<UserPassword>, StorageError>;
+
+    async fn create_user_password(
+        &self,
+        user_password: CreateUserPassword,
+    ) -> Result<UserPassword, StorageError>;
+
+    async fn update_user_password(
+        &self,
+        user_password: UpdateUserPassword,
+    ) -> Result<UserPassword, StorageError>;
+
+    async fn delete_user_password(&self, user_password_id: &str) -> Result<(), StorageError>;
+
+    async fn password_reset_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<PasswordResetToken>, StorageError>;
+
+    async fn create_password_reset_token(
+        &self,
+        password_reset_token: CreatePasswordResetToken,
+    ) -> Result<PasswordResetToken, StorageError>;
+
+    /// Delete a password reset token, consuming it.
+    ///
+    /// Returns whether the password reset token was deleted. This must be a single conditional delete, so
+    /// concurrent password resets with the same token can not both succeed.
+    async fn delete_password_reset_token(
+        &self,
+        password_reset_token_id: &str,
+    ) -> Result<bool, StorageError>;
+
+    async fn delete_expired_password_reset_tokens(&self) -> Result<(), StorageError>;
+
+    /// Verify the email address with the unexpired verification token and clear the token.
+    ///
+    /// Returns whether an email address was verified.
+    async fn verify_email_address(&self, verification_token: &str) -> Result<bool, StorageError>;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, FixedOffset, Utc};
+    use serde::{Deserialize, Serialize};
+    use shield::{
+        CreateEmailAddress, CreateUser, EmailAddress, Storage, StorageError, UpdateUser, User,
+    };
+
+    use crate::{
+        password::{CreateUserPassword, UpdateUserPassword, UserPassword},
+        token::{CreatePasswordResetToken, PasswordResetToken},
+    };
+
+    use super::PasswordStorage;
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct TestUser {
+        id: String,
+        email: String,
+        is_verified: bool,
+        #[serde(skip)]
+        verification_token: Option<String>,
+        #[serde(skip)]
+        verification_token_expired_at: Option<DateTime<FixedOffset>>,
+    }
+
+    impl TestUser {
+        pub fn is_verified(&self) -> bool {
+            self.is_verified
+        }
+    }
+
+    #[async_trait]
+    impl User for TestUser {
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn name(&self) -> Option<String> {
+            None
+        }
+
+        async fn email_addresses(&self) -> Result<Vec<EmailAddress>, StorageError> {
+            Ok(vec![])
+        }
+
+        fn additional(&self) -> Option<impl Serialize> {
+            None::<()>
+        }
+    }
+
+    #[derive(Default)]
+    pub struct TestStorage {
+        users: Mutex<Vec<TestUser>>,
+        user_passwords: Mutex<Vec<UserPassword>>,
+        password_reset_tokens: Mutex<Vec<PasswordResetToken>>,
+    }
+
+    impl TestStorage {
+        pub fn user_passwords(&self) -> Vec<UserPassword> {
+            self.user_passwords.lock().expect("lock").clone()
+        }
+
+        pub fn password_reset_tokens(&self) -> Vec<PasswordResetToken> {
+            self.password_reset_tokens.lock().expect("lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl Storage<TestUser> for TestStorage {
+        fn id(&self) -> String {
+            "test".to_owned()
+        }
+
+        async fn user_by_id(&self, user_id: &str) -> Result<Option<TestUser>, StorageError> {
+            Ok(self
+                .users
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|user| user.id == user_id)
+                .cloned())
+        }
+
+        async fn user_by_email(&self, email: &str) -> Result<Option<TestUser>, StorageError> {
+            Ok(self
+                .users
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|user| user.email == email)
+                .cloned())
+        }
+
+        async fn create_user(
+            &self,
+            _user: CreateUser,
+            email_address: CreateEmailAddress,
+        ) -> Result<TestUser, StorageError> {
+            let mut users = self.users.lock().expect("lock");
+            let user = TestUser {
+                id: (users.len() + 1).to_string(),
+                email: email_address.email,
+                is_verified: email_address.is_verified,
+                verification_token: email_address.verification_token,
+                verification_token_expired_at: email_address.verification_token_expired_at,
+            };
+            users.push(user.clone());
+
+            Ok(user)
+        }
+
+        async fn update_user(&self, _user: UpdateUser) -> Result<TestUser, StorageError> {
+            todo!("update_user")
+        }
+
+        async fn delete_user(&self, user_id: &str) -> Result<(), StorageError> {
+            self.users
+                .lock()
+                .expect("lock")
+                .retain(|user| user.id != user_id);
+
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl PasswordStorage<TestUser> for TestStorage {
+        async fn user_password(&self, user_id: &str) -> Result<Option<UserPassword>, StorageError> {
+            Ok(self
+                .user_passwords()
+                .into_iter()
+                .find(|user_password| user_password.user_id == user_id))
+        }
+
+        async fn user_password_by_username(
+            &self,
+            username: &str,
+        ) -> Result<Option<UserPassword>, StorageError> {
+            Ok(self
+                .user_passwords()
+                .into_iter()
+                .find(|user_password| user_password.username.as_deref() == Some(username)))
+        }
+
+        async fn create_user_password(
+            &self,
+            user_password: CreateUserPassword,
+        ) -> Result<UserPassword, StorageError> {
+            let mut user_passwords = self.user_passwords.lock().expect("lock");
+            let user_password = UserPassword {
+                id: (user_passwords.len() + 1).to_string(),
+                username: user_password.username,
+                password_hash: user_password.password_hash,
+                user_id: user_password.user_id,
+            };
+            user_passwords.push(user_password.clone());
+
+            Ok(user_password)
+        }
+
+        async fn update_user_password(
+            &self,
+            update: UpdateUserPassword,
+        ) -> Result<UserPassword, StorageError> {
+            let mut user_passwords = self.user_passwords.lock().expect("lock");
+            let user_password = user_passwords
+                .iter_mut()
+                .find(|user_password| user_password.id == update.id)
+                .ok_or_else(|| StorageError::NotFound("UserPassword".to_owned(), update.id))?;
+
+            if let Some(username) = update.username {
+                user_password.username = username;
+            }
+            if let Some(password_hash) = update.password_hash {
+                user_password.password_hash = password_hash;
+            }
+
+            Ok(user_password.clone())
+        }
+
+        async fn delete_user_password(&self, user_password_id: &str) -> Result<(), StorageError> {
+            self.user_passwords
+                .lock()
+                .expect("lock")
+                .retain(|user_password| user_password.id != user_password_id);
+
+            Ok(())
+        }
+
+        async fn password_reset_token(
+            &self,
+            token: &str,
+        ) -> Result<Option<PasswordResetToken>, StorageError> {
+            Ok(self
+                .password_reset_tokens()
+                .into_iter()
+                .find(|password_reset_token| {
+                    password_reset_token.token == token
+                        && password_reset_token.expired_at > Utc::now()
+                }))
+        }
+
+        async fn create_password_reset_token(
+            &self,
+            password_reset_token: CreatePasswordResetToken,
+        ) -> Result<PasswordResetToken, StorageError> {
+            let mut password_reset_tokens = self.password_reset_tokens.lock().expect("lock");
+            let password_reset_token = PasswordResetToken {
+                id: (password_reset_tokens.len() + 1).to_string(),
+                token: password_reset_token.token,
+                expired_at: password_reset_token.expired_at,
+                user_id: password_reset_token.user_id,
+            };
+            password_reset_tokens.push(password_reset_token.clone());
+
+            Ok(password_reset_token)
+        }
+
+        async fn delete_password_reset_token(
+            &self,
+            password_reset_token_id: &str,
+        ) -> Result<bool, StorageError> {
+            let mut password_reset_tokens = self.password_reset_tokens.lock().expect("lock");
+
+            let length = password_reset_tokens.len();
+            password_reset_tokens
+                .retain(|password_reset_token| password_reset_token.id != password_reset_token_id);
+
+            Ok(password_reset_tokens.len() < length)
+        }
+
+        async fn delete_expired_password_reset_tokens(&self) -> Result<(), StorageError> {
+            Ok(())
+        }
+
+        async fn verify_email_address(
+            &self,
+            verification_token: &str,
+        ) -> Result<bool, StorageError> {
+            let mut users = self.users.lock().expect("lock");
+
+            let Some(user) = users.iter_mut().find(|user| {
+                user.verification_token.as_deref() == Some(verification_token)
+                    && user
+                        .verification_token_expired_at
+                        .is_some_and(|expired_at| expired_at > Utc::now())
+            }) else {
+                return Ok(false);
+            };
+
+            user.is_verified = true;
+            user.verification_token = None;
+            user.verification_token_expired_at = None;
+
+            Ok(true)
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, FixedOffset};
+use secrecy::{ExposeSecret, SecretString};
+use sha3::{Digest, Sha3_256};
+
+#[derive(Clone, Debug)]
+pub struct PasswordResetToken {
+    pub id: String,
+    pub token: String,
+    pub expired_at: DateTime<FixedOffset>,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreatePasswordResetToken {
+    pub token: String,
+    pub expired_at: DateTime<FixedOffset>,
+    pub user_id: String,
+}
+
+pub(crate) fn hash_token(token: &str, secret: &SecretString) -> String {
+    hex::encode(
+        Sha3_256::new()
+            .chain_update(token)
+            .chain_update(secret.expose_secret())
+            .finalize(),
+    )
+}
@@ -2,9 +2,11 @@ use std::{pin::Pin, sync::Arc};
 
 use async_trait::async_trait;
 use serde::Deserialize;
-use shield::{Form, Input, InputType, InputTypePassword, InputTypeText, ShieldError, User};
+use shield::{
+    ConfigurationError, Form, Input, InputType, InputTypePassword, InputTypeText, ShieldError, User,
+};
 
-use crate::Credentials;
+use crate::{Credentials, PasswordData, PasswordIdentifier, PasswordIdentifierType};
 
 #[derive(Debug, Deserialize)]
 pub struct UsernamePasswordData {
@@ -16,8 +18,10 @@ type SignInFn<U> = dyn Fn(UsernamePasswordData) -> Pin<Box<dyn Future<Output = R
     + Send
     + Sync;
 
+/// Without a sign in function, passwords are verified against
+/// [`PasswordStorage`](crate::PasswordStorage).
 pub struct UsernamePasswordCredentials<U: User> {
-    sign_in_fn: Arc<SignInFn<U>>,
+    sign_in_fn: Option<Arc<SignInFn<U>>>,
 }
 
 impl<U: User> UsernamePasswordCredentials<U> {
@@ -31,11 +35,17 @@ impl<U: User> UsernamePasswordCredentials<U> {
         + 'static,
     ) -> Self {
         Self {
-            sign_in_fn: Arc::new(sign_in_fn),
+            sign_in_fn: Some(Arc::new(sign_in_fn)),
         }
     }
 }
 
+impl<U: User> Default for UsernamePasswordCredentials<U> {
+    fn default() -> Self {
+        Self { sign_in_fn: None }
+    }
+}
+
 #[async_trait]
 impl<U: User> Credentials<U, UsernamePasswordData> for UsernamePasswordCredentials<U> {
     fn form(&self) -> Form {
@@ -71,8 +81,28 @@ impl<U: User> Credentials<U, UsernamePasswordData> for UsernamePasswordCredentia
         }
     }
 
+    fn password_identifier_type(&self) -> Option<PasswordIdentifierType> {
+        self.sign_in_fn
+            .is_none()
+            .then_some(PasswordIdentifierType::Username)
+    }
+
+    fn password_data(&self, data: &UsernamePasswordData) -> Option<PasswordData> {
+        self.sign_in_fn.is_none().then(|| PasswordData {
+            identifier: PasswordIdentifier::Username(data.username.clone()),
+            password: data.password.clone(),
+        })
+    }
+
+    fn identifier(&self, data: &UsernamePasswordData) -> Option<String> {
+        Some(data.username.clone())
+    }
+
     async fn sign_in(&self, data: UsernamePasswordData) -> Result<U, ShieldError> {
-        (self.sign_in_fn)(data).await
+        match &self.sign_in_fn {
+            Some(sign_in_fn) => sign_in_fn(data).await,
+            None => Err(ConfigurationError::Missing("password storage".to_owned()).into()),
+        }
     }
 }
 
@@ -6,7 +6,8 @@ use serde::Deserialize;
 use shield::{
     CreateEmailAddress, CreateUser, Form, Input, InputType, InputTypeEmail, InputTypeSubmit,
     InputTypeText, InputValue, MethodAction, MethodSession, Request, RequestMethod, Response,
-    ResponseType, SessionAction, ShieldError, SignInCallbackAction, User, erased_method_action,
+    ResponseType, SessionAction, ShieldError, SignInCallbackAction, SignUpEvent, User,
+    erased_method_action,
 };
 
 use crate::{
@@ -108,6 +109,12 @@ impl<U: User + 'static> MethodAction<EmailProvider, ()> for EmailSignInCallbackA
         }])
     }
 
+    fn rate_limit_identifier(&self, request: &Request) -> Option<String> {
+        serde_json::from_value::<SignInCallbackData>(request.form_data.clone())
+            .ok()
+            .map(|data| data.email.trim().to_lowercase())
+    }
+
     async fn call(
         &self,
         provider: EmailProvider,
@@ -135,17 +142,24 @@ impl<U: User + 'static> MethodAction<EmailProvider, ()> for EmailSignInCallbackA
         let user = match self.storage.user_by_email(&email_auth_token.email).await? {
             Some(user) => user,
             None => {
-                self.storage
-                    .create_user(
-                        CreateUser { name: None },
-                        CreateEmailAddress {
-                            email: email_auth_token.email,
-                            is_primary: true,
-                            is_verified: true,
-                            verification_token: None,
-                            verification_token_expired_at: None,
-                            verified_at: Some(Utc::now().into()),
+                request
+                    .hooks
+                    .sign_up(
+                        SignUpEvent {
+                            email: Some(email_auth_token.email.clone()),
+                            ..SignUpEvent::new(&provider)
                         },
+                        self.storage.create_user(
+                            CreateUser { name: None },
+                            CreateEmailAddress {
+                                email: email_auth_token.email,
+                                is_primary: true,
+                                is_verified: true,
+                                verification_token: None,
+                                verification_token_expired_at: None,
+                                verified_at: Some(Utc::now().into()),
+                            },
+                        ),
                     )
                     .await?
             }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use shield::{Method, MethodAction, ShieldError, User, erased_method};
+use shield::{Method, MethodAction, ShieldError, User, UserConnection, erased_method};
 
 use crate::{
     actions::{EmailSignInAction, EmailSignInCallbackAction},
@@ -60,6 +60,30 @@ impl<U: User + 'static> Method for EmailMethod<U> {
     ) -> Result<Vec<Self::Connection>, ShieldError> {
         Ok(vec![])
     }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        let Some(user) = self.storage.user_by_id(user_id).await? else {
+            return Ok(vec![]);
+        };
+
+        // Email addresses are managed on the user, so they are listed but can not be unlinked here.
+        Ok(user
+            .email_addresses()
+            .await?
+            .into_iter()
+            .map(|email_address| UserConnection {
+                id: email_address.id,
+                method_id: EMAIL_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: Some(email_address.email),
+                sign_in: true,
+                unlinkable: false,
+            })
+            .collect())
+    }
 }
 
 erased_method!(EmailMethod, <U: User>);
@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use serde_json::{Value, json};
+use shield::{
+    RateLimitOptions, Request, Session, SessionClient, SessionData, SessionError, SessionStorage,
+    Shield, ShieldError, ShieldOptions, SignInCallbackAction,
+};
+use shield_email::{EMAIL_METHOD_ID, EmailMethod, EmailOptions, Sender};
+use shield_memory::MemoryStorage;
+
+struct NoopSender;
+
+#[async_trait]
+impl Sender for NoopSender {
+    async fn send(
+        &self,
+        _email: &str,
+        _token: &str,
+        _expires_at: DateTime<FixedOffset>,
+    ) -> Result<(), ShieldError> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct TestSessionStorage {
+    data: Arc<Mutex<SessionData>>,
+}
+
+#[async_trait]
+impl SessionStorage for TestSessionStorage {
+    fn data(&self) -> Arc<Mutex<SessionData>> {
+        self.data.clone()
+    }
+
+    fn is_persistent(&self) -> bool {
+        false
+    }
+
+    async fn update(&self) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn renew(&self) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn purge(&self) -> Result<(), SessionError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn sign_in_callback_locks_account() {
+    let storage = MemoryStorage::new();
+    let shield = Shield::new(
+        storage.clone(),
+        vec![Arc::new(EmailMethod::new(
+            EmailOptions::builder()
+                .secret("secret")
+                .sender(NoopSender)
+                .build(),
+            storage.clone(),
+        ))],
+        ShieldOptions::default(),
+    )
+    .with_rate_limiter(
+        RateLimitOptions::builder()
+            .max_attempts_per_account(3)
+            .delay_after(10)
+            .build(),
+        storage,
+    );
+
+    // Every attempt comes from another IP address, so only the account limit applies.
+    let action_id = SignInCallbackAction::id();
+    let sign_in_callback = |ip_address: usize, email: &str| {
+        shield.call_method(
+            &action_id,
+            EMAIL_METHOD_ID,
+            None,
+            Session::new(TestSessionStorage::default()).with_client(SessionClient {
+                ip_address: Some(format!("192.0.2.{ip_address}")),
+                user_agent: None,
+            }),
+            Request::new(Value::Null, json!({ "email": email, "token": "incorrect" })),
+        )
+    };
+
+    for ip_address in 0..3 {
+        assert!(
+            sign_in_callback(ip_address, "alice@example.com")
+                .await
+                .is_err_and(|err| matches!(err, ShieldError::Validation(_)))
+        );
+    }
+
+    assert!(
+        sign_in_callback(3, " Alice@Example.com ")
+            .await
+            .is_err_and(|err| matches!(err, ShieldError::RateLimited(_)))
+    );
+    assert!(
+        sign_in_callback(4, "bob@example.com")
+            .await
+            .is_err_and(|err| matches!(err, ShieldError::Validation(_)))
+    );
+}
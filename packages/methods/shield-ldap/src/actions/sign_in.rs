@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use shield::{
+    ConnectionEvent, ConnectionLinkEvent, CreateEmailAddress, CreateUser, Form, Hook, Hooks, Input,
+    InputType, InputTypePassword, InputTypeText, MethodAction, MethodSession, Request,
+    RequestMethod, Response, ResponseType, SessionAction, ShieldError, SignInAction, SignUpEvent,
+    UpdateUser, User, UserEvent, erased_method_action,
+};
+
+use crate::{
+    connection::CreateLdapConnection,
+    directory::{LdapDirectory, LdapEntry},
+    provider::LdapProvider,
+    storage::LdapStorage,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SignInData {
+    pub username: String,
+    pub password: String,
+}
+
+pub struct LdapSignInAction<U: User> {
+    directory: LdapDirectory,
+    storage: Arc<dyn LdapStorage<U>>,
+}
+
+impl<U: User> LdapSignInAction<U> {
+    pub fn new(directory: LdapDirectory, storage: Arc<dyn LdapStorage<U>>) -> Self {
+        Self { directory, storage }
+    }
+
+    async fn create_user(
+        &self,
+        hooks: &Hooks,
+        provider: &LdapProvider,
+        entry: &LdapEntry,
+    ) -> Result<U, ShieldError> {
+        let Some(email) = &entry.email else {
+            return Err(ShieldError::Validation(
+                "Missing email address in LDAP entry.".to_owned(),
+            ));
+        };
+
+        let email_verified = self.directory.trust_email;
+
+        if let Some(user) = self.storage.user_by_email(email).await? {
+            if self.directory.link_verified_email
+                && email_verified
+                && user.email_addresses().await?.iter().any(|email_address| {
+                    email_address.is_verified && email_address.email.eq_ignore_ascii_case(email)
+                })
+            {
+                return Ok(user);
+            }
+
+            return Err(ShieldError::Validation(format!(
+                "\
+                Email address `{email}` is already used by another account. \
+                Please contact support for assistance.\
+                "
+            )));
+        }
+
+        hooks
+            .sign_up(
+                SignUpEvent {
+                    name: entry.name.clone(),
+                    email: Some(email.clone()),
+                    ..SignUpEvent::new(provider)
+                },
+                self.storage.create_user(
+                    CreateUser {
+                        name: entry.name.clone(),
+                    },
+                    CreateEmailAddress {
+                        email: email.clone(),
+                        is_primary: true,
+                        is_verified: email_verified,
+                        verification_token: None,
+                        verification_token_expired_at: None,
+                        verified_at: email_verified.then(|| Utc::now().into()),
+                    },
+                ),
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<LdapProvider, ()> for LdapSignInAction<U> {
+    fn id(&self) -> String {
+        SignInAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignInAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign in with LDAP"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign in with a username and password of the directory."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self, _provider: LdapProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "username".to_owned(),
+                    label: Some("Username".to_owned()),
+                    r#type: InputType::Text(InputTypeText {
+                        autocomplete: Some("username".to_owned()),
+                        placeholder: Some("Username".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "password".to_owned(),
+                    label: Some("Password".to_owned()),
+                    r#type: InputType::Password(InputTypePassword {
+                        autocomplete: Some("current-password".to_owned()),
+                        placeholder: Some("Password".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    fn rate_limit_identifier(&self, request: &Request) -> Option<String> {
+        serde_json::from_value::<SignInData>(request.form_data.clone())
+            .ok()
+            .map(|data| data.username)
+    }
+
+    async fn call(
+        &self,
+        provider: LdapProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignInData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let entry = self
+            .directory
+            .authenticate(&data.username, &data.password)
+            .await?
+            .ok_or_else(|| ShieldError::Validation("Incorrect username or password.".to_owned()))?;
+
+        let user = match self
+            .storage
+            .ldap_connection_by_identifier(&entry.identifier)
+            .await?
+        {
+            Some(connection) => {
+                request
+                    .hooks
+                    .before_user_update(&UserEvent {
+                        user_id: connection.user_id.clone(),
+                    })
+                    .await?;
+
+                let user = self
+                    .storage
+                    .update_user(UpdateUser {
+                        id: connection.user_id,
+                        name: entry.name.clone().map(Some),
+                    })
+                    .await?;
+
+                request
+                    .hooks
+                    .user_updated(&UserEvent { user_id: user.id() })
+                    .await?;
+
+                user
+            }
+            None => {
+                let user = self.create_user(&request.hooks, &provider, &entry).await?;
+
+                request
+                    .hooks
+                    .before_connection_link(&ConnectionLinkEvent::new(
+                        &provider,
+                        &user.id(),
+                        Some(&entry.identifier),
+                    ))
+                    .await?;
+
+                let connection = self
+                    .storage
+                    .create_ldap_connection(CreateLdapConnection {
+                        identifier: entry.identifier,
+                        user_id: user.id(),
+                    })
+                    .await?;
+
+                request
+                    .hooks
+                    .connection_linked(&ConnectionEvent::new(
+                        &provider,
+                        &connection.user_id,
+                        &connection.id,
+                        Some(&connection.identifier),
+                    ))
+                    .await?;
+
+                user
+            }
+        };
+
+        Ok(Response::new(ResponseType::Default)
+            .session_action(SessionAction::authenticate(&provider, user)))
+    }
+}
+
+erased_method_action!(LdapSignInAction, <U: User>);
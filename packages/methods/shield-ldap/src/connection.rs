@@ -0,0 +1,13 @@
+#[derive(Clone, Debug)]
+pub struct LdapConnection {
+    pub id: String,
+    /// Distinguished name or configured identifier attribute of the directory entry.
+    pub identifier: String,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateLdapConnection {
+    pub identifier: String,
+    pub user_id: String,
+}
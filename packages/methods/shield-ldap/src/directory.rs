@@ -0,0 +1,241 @@
+use std::{collections::HashMap, time::Duration};
+
+use bon::Builder;
+use ldap3::{
+    Ldap, LdapConnAsync, LdapConnSettings, LdapError, Scope, SearchEntry, SearchOptions,
+    ldap_escape,
+};
+use shield::{ConfigurationError, ShieldError};
+
+/// Result code of a failed bind, see RFC 4511.
+const INVALID_CREDENTIALS: u32 = 49;
+
+/// Directory to authenticate users against with a search and bind.
+#[derive(Builder, Clone, Debug)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct LdapDirectory {
+    /// URL of the directory server, e.g. `ldaps://ldap.example.com`.
+    pub url: String,
+    #[builder(default)]
+    pub starttls: bool,
+    #[builder(default = Duration::from_secs(10))]
+    pub timeout: Duration,
+    /// Distinguished name of the service account used to search for users. Searches are anonymous
+    /// if not set.
+    pub bind_dn: Option<String>,
+    pub bind_password: Option<String>,
+    pub base_dn: String,
+    /// Filter to find a user, `{username}` is replaced with the escaped username.
+    #[builder(default = "(&(objectClass=person)(uid={username}))".to_owned())]
+    pub user_filter: String,
+    /// Attribute identifying the user, e.g. `objectGUID`. The distinguished name is used if not
+    /// set, which changes when the entry is moved or renamed.
+    pub identifier_attribute: Option<String>,
+    #[builder(default = "displayName".to_owned())]
+    pub name_attribute: String,
+    #[builder(default = "mail".to_owned())]
+    pub email_attribute: String,
+    #[builder(default = "memberOf".to_owned())]
+    pub group_attribute: String,
+    /// Distinguished names of the groups allowed to sign in. All users are allowed if empty.
+    #[builder(default)]
+    pub allowed_groups: Vec<String>,
+    /// Treat email addresses from the directory as verified.
+    #[builder(default)]
+    pub trust_email: bool,
+    /// Link the directory entry to an existing user with the same verified email address, requires
+    /// `trust_email`.
+    #[builder(default)]
+    pub link_verified_email: bool,
+}
+
+/// Directory entry of an authenticated user.
+#[derive(Clone, Debug)]
+pub struct LdapEntry {
+    pub dn: String,
+    pub identifier: String,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub groups: Vec<String>,
+}
+
+impl LdapDirectory {
+    /// Search for the user and bind as the user to verify the password.
+    ///
+    /// Returns `None` if the user does not exist or the password is incorrect.
+    pub async fn authenticate(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<Option<LdapEntry>, ShieldError> {
+        // A simple bind without password is an unauthenticated bind, which succeeds for any DN.
+        if username.is_empty() || password.is_empty() {
+            return Ok(None);
+        }
+
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(self.timeout)
+            .set_starttls(self.starttls);
+        let (connection, mut ldap) = LdapConnAsync::with_settings(settings, &self.url)
+            .await
+            .map_err(request_error)?;
+        ldap3::drive!(connection);
+        ldap.with_timeout(self.timeout);
+
+        let result = self.search_and_bind(&mut ldap, username, password).await;
+        let _ = ldap.unbind().await;
+
+        let Some(entry) = result? else {
+            return Ok(None);
+        };
+
+        if !self.allowed_groups.is_empty()
+            && !entry.groups.iter().any(|group| {
+                self.allowed_groups
+                    .iter()
+                    .any(|allowed_group| allowed_group.eq_ignore_ascii_case(group))
+            })
+        {
+            return Err(ShieldError::Validation(
+                "Your account is not allowed to sign in.".to_owned(),
+            ));
+        }
+
+        Ok(Some(entry))
+    }
+
+    async fn search_and_bind(
+        &self,
+        ldap: &mut Ldap,
+        username: &str,
+        password: &str,
+    ) -> Result<Option<LdapEntry>, ShieldError> {
+        if let Some(bind_dn) = &self.bind_dn {
+            ldap.simple_bind(bind_dn, self.bind_password.as_deref().unwrap_or_default())
+                .await
+                .and_then(|result| result.success())
+                .map_err(request_error)?;
+        }
+
+        let filter = self
+            .user_filter
+            .replace("{username}", &ldap_escape(username));
+        let mut attributes = vec![
+            self.name_attribute.as_str(),
+            self.email_attribute.as_str(),
+            self.group_attribute.as_str(),
+        ];
+        if let Some(identifier_attribute) = &self.identifier_attribute {
+            attributes.push(identifier_attribute);
+        }
+
+        let (entries, _) = ldap
+            .with_search_options(SearchOptions::new().sizelimit(2))
+            .search(&self.base_dn, Scope::Subtree, &filter, attributes)
+            .await
+            .and_then(|result| result.success())
+            .map_err(request_error)?;
+
+        let mut entries = entries.into_iter();
+        let entry = match (entries.next(), entries.next()) {
+            (None, _) => return Ok(None),
+            (Some(entry), None) => SearchEntry::construct(entry),
+            (Some(_), Some(_)) => {
+                return Err(ConfigurationError::Invalid(
+                    "LDAP user filter matches multiple entries".to_owned(),
+                )
+                .into());
+            }
+        };
+
+        let result = ldap
+            .simple_bind(&entry.dn, password)
+            .await
+            .map_err(request_error)?;
+        if result.rc == INVALID_CREDENTIALS {
+            return Ok(None);
+        }
+        result.success().map_err(request_error)?;
+
+        self.entry(entry).map(Some)
+    }
+
+    fn entry(&self, entry: SearchEntry) -> Result<LdapEntry, ShieldError> {
+        let identifier = match &self.identifier_attribute {
+            Some(identifier_attribute) => {
+                identifier(&entry.attrs, &entry.bin_attrs, identifier_attribute).ok_or_else(
+                    || {
+                        ShieldError::Validation(format!(
+                            "Missing attribute `{identifier_attribute}` in LDAP entry."
+                        ))
+                    },
+                )?
+            }
+            None => entry.dn.clone(),
+        };
+
+        Ok(LdapEntry {
+            identifier,
+            name: first_value(&entry.attrs, &self.name_attribute),
+            email: first_value(&entry.attrs, &self.email_attribute),
+            groups: values(&entry.attrs, &self.group_attribute),
+            dn: entry.dn,
+        })
+    }
+}
+
+fn request_error(err: LdapError) -> ShieldError {
+    ShieldError::Request(err.to_string())
+}
+
+/// Attribute values, matching the attribute name case-insensitively like the directory does.
+fn values(attributes: &HashMap<String, Vec<String>>, name: &str) -> Vec<String> {
+    attributes
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, values)| values.clone())
+        .unwrap_or_default()
+}
+
+fn first_value(attributes: &HashMap<String, Vec<String>>, name: &str) -> Option<String> {
+    values(attributes, name)
+        .into_iter()
+        .find(|value| !value.is_empty())
+}
+
+fn identifier(
+    attributes: &HashMap<String, Vec<String>>,
+    binary_attributes: &HashMap<String, Vec<Vec<u8>>>,
+    name: &str,
+) -> Option<String> {
+    if let Some(value) = first_value(attributes, name) {
+        return Some(value);
+    }
+
+    let value = binary_attributes
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .and_then(|(_, values)| values.first())?;
+
+    Some(match <[u8; 16]>::try_from(value.as_slice()) {
+        // Active Directory GUIDs are stored with the first three groups in little endian.
+        Ok(guid) if name.eq_ignore_ascii_case("objectGUID") => format!(
+            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{}",
+            guid[3],
+            guid[2],
+            guid[1],
+            guid[0],
+            guid[5],
+            guid[4],
+            guid[7],
+            guid[6],
+            guid[8],
+            guid[9],
+            guid[10..]
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+        ),
+        _ => value.iter().map(|byte| format!("{byte:02x}")).collect(),
+    })
+}
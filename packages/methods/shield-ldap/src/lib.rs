@@ -0,0 +1,12 @@
+mod actions;
+mod connection;
+mod directory;
+mod method;
+mod provider;
+mod storage;
+
+pub use connection::*;
+pub use directory::*;
+pub use method::*;
+pub use provider::*;
+pub use storage::*;
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Method, MethodAction, ShieldError, StorageError, User, UserConnection, erased_method,
+};
+
+use crate::{
+    actions::LdapSignInAction, connection::LdapConnection, directory::LdapDirectory,
+    provider::LdapProvider, storage::LdapStorage,
+};
+
+pub const LDAP_METHOD_ID: &str = "ldap";
+
+pub struct LdapMethod<U: User> {
+    directory: LdapDirectory,
+    storage: Arc<dyn LdapStorage<U>>,
+}
+
+impl<U: User> LdapMethod<U> {
+    pub fn new<S: LdapStorage<U> + 'static>(directory: LdapDirectory, storage: S) -> Self {
+        Self {
+            directory,
+            storage: Arc::new(storage),
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> Method for LdapMethod<U> {
+    type Provider = LdapProvider;
+    type Connection = LdapConnection;
+    type Session = ();
+
+    fn id(&self) -> String {
+        LDAP_METHOD_ID.to_owned()
+    }
+
+    fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+        vec![Box::new(LdapSignInAction::new(
+            self.directory.clone(),
+            self.storage.clone(),
+        ))]
+    }
+
+    async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+        Ok(vec![LdapProvider])
+    }
+
+    async fn user_connections(
+        &self,
+        user_id: &str,
+        _provider_id: Option<&str>,
+    ) -> Result<Vec<Self::Connection>, ShieldError> {
+        Ok(self.storage.user_ldap_connections(user_id).await?)
+    }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        Ok(self
+            .storage
+            .user_ldap_connections(user_id)
+            .await?
+            .into_iter()
+            .map(|connection| UserConnection {
+                id: connection.id,
+                method_id: LDAP_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: Some(connection.identifier),
+                sign_in: true,
+                unlinkable: true,
+            })
+            .collect())
+    }
+
+    async fn unlink_user_connection(
+        &self,
+        user_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        let connection = self
+            .storage
+            .ldap_connection_by_id(connection_id)
+            .await?
+            .filter(|connection| connection.user_id == user_id)
+            .ok_or_else(|| {
+                StorageError::NotFound("LdapConnection".to_owned(), connection_id.to_owned())
+            })?;
+
+        Ok(self.storage.delete_ldap_connection(&connection.id).await?)
+    }
+}
+
+erased_method!(LdapMethod, <U: User>);
@@ -0,0 +1,19 @@
+use shield::Provider;
+
+use crate::method::LDAP_METHOD_ID;
+
+pub struct LdapProvider;
+
+impl Provider for LdapProvider {
+    fn method_id(&self) -> String {
+        LDAP_METHOD_ID.to_owned()
+    }
+
+    fn id(&self) -> Option<String> {
+        None
+    }
+
+    fn name(&self) -> String {
+        "LDAP".to_owned()
+    }
+}
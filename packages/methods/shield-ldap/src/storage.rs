@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+use shield::{Storage, StorageError, User};
+
+use crate::connection::{CreateLdapConnection, LdapConnection};
+
+#[async_trait]
+pub trait LdapStorage<U: User>: Storage<U> + Sync {
+    async fn ldap_connection_by_id(
+        &self,
+        connection_id: &str,
+    ) -> Result<Option<LdapConnection>, StorageError>;
+
+    async fn ldap_connection_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<Option<LdapConnection>, StorageError>;
+
+    async fn create_ldap_connection(
+        &self,
+        connection: CreateLdapConnection,
+    ) -> Result<LdapConnection, StorageError>;
+
+    async fn delete_ldap_connection(&self, connection_id: &str) -> Result<(), StorageError>;
+
+    async fn user_ldap_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<LdapConnection>, StorageError>;
+}
@@ -0,0 +1,396 @@
+//! Tests against an in-process LDAP server, which implements the simple bind and search operations
+//! of RFC 4511 for a fixed set of entries.
+
+use std::sync::Arc;
+
+use shield::ShieldError;
+use shield_ldap::LdapDirectory;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+const BASE_DN: &str = "dc=example,dc=com";
+const SERVICE_DN: &str = "cn=service,dc=example,dc=com";
+const SERVICE_PASSWORD: &str = "service-password";
+const STAFF_GROUP: &str = "cn=staff,ou=groups,dc=example,dc=com";
+
+/// Active Directory `objectGUID` of `{01020304-0506-0708-090a-0b0c0d0e0fff}`.
+const ALICE_GUID: [u8; 16] = [
+    0x04, 0x03, 0x02, 0x01, 0x06, 0x05, 0x08, 0x07, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0xff,
+];
+
+struct Entry {
+    dn: &'static str,
+    password: &'static str,
+    attributes: Vec<(&'static str, Vec<Vec<u8>>)>,
+}
+
+fn entries() -> Vec<Entry> {
+    vec![
+        Entry {
+            dn: SERVICE_DN,
+            password: SERVICE_PASSWORD,
+            attributes: vec![("objectClass", vec![b"applicationProcess".to_vec()])],
+        },
+        Entry {
+            dn: "uid=alice,ou=people,dc=example,dc=com",
+            password: "alice-password",
+            attributes: vec![
+                ("objectClass", vec![b"person".to_vec()]),
+                ("uid", vec![b"alice".to_vec()]),
+                ("displayName", vec![b"Alice".to_vec()]),
+                ("mail", vec![b"alice@example.com".to_vec()]),
+                ("memberOf", vec![STAFF_GROUP.as_bytes().to_vec()]),
+                ("objectGUID", vec![ALICE_GUID.to_vec()]),
+            ],
+        },
+        Entry {
+            dn: "uid=bob,ou=people,dc=example,dc=com",
+            password: "bob-password",
+            attributes: vec![
+                ("objectClass", vec![b"person".to_vec()]),
+                ("uid", vec![b"bob".to_vec()]),
+                ("mail", vec![b"bob@example.com".to_vec()]),
+            ],
+        },
+    ]
+}
+
+/// BER element with its tag and contents.
+struct Element {
+    tag: u8,
+    contents: Vec<u8>,
+}
+
+impl Element {
+    fn children(&self) -> Vec<Element> {
+        let mut children = vec![];
+        let mut contents = self.contents.as_slice();
+
+        while !contents.is_empty() {
+            let (element, length) = decode(contents).expect("element should be complete");
+            children.push(element);
+            contents = &contents[length..];
+        }
+
+        children
+    }
+
+    fn integer(&self) -> i64 {
+        self.contents
+            .iter()
+            .fold(0, |value, byte| (value << 8) | i64::from(*byte))
+    }
+}
+
+/// Decode an element, returning `None` if the input is incomplete.
+fn decode(input: &[u8]) -> Option<(Element, usize)> {
+    let tag = *input.first()?;
+    let first = *input.get(1)?;
+
+    let (length, offset) = if first < 0x80 {
+        (usize::from(first), 2)
+    } else {
+        let count = usize::from(first & 0x7f);
+        let bytes = input.get(2..2 + count)?;
+        (
+            bytes
+                .iter()
+                .fold(0, |length, byte| (length << 8) | usize::from(*byte)),
+            2 + count,
+        )
+    };
+
+    let contents = input.get(offset..offset + length)?.to_vec();
+    Some((Element { tag, contents }, offset + length))
+}
+
+fn encode(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut output = vec![tag];
+
+    if contents.len() < 0x80 {
+        output.push(contents.len() as u8);
+    } else {
+        let length = (contents.len() as u32).to_be_bytes();
+        let length = &length[length.iter().position(|byte| *byte != 0).unwrap_or(3)..];
+        output.push(0x80 | length.len() as u8);
+        output.extend_from_slice(length);
+    }
+
+    output.extend_from_slice(contents);
+    output
+}
+
+fn encode_integer(tag: u8, value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(7).min(7);
+    let mut contents = bytes[start..].to_vec();
+    if contents[0] & 0x80 != 0 {
+        contents.insert(0, 0);
+    }
+
+    encode(tag, &contents)
+}
+
+fn message(id: i64, operation: Vec<u8>) -> Vec<u8> {
+    encode(0x30, &[encode_integer(0x02, id), operation].concat())
+}
+
+fn result(tag: u8, code: i64) -> Vec<u8> {
+    encode(
+        tag,
+        &[
+            encode_integer(0x0a, code),
+            encode(0x04, b""),
+            encode(0x04, b""),
+        ]
+        .concat(),
+    )
+}
+
+fn attribute<'a>(entry: &'a Entry, name: &[u8]) -> Option<&'a Vec<Vec<u8>>> {
+    entry
+        .attributes
+        .iter()
+        .find(|(key, _)| key.as_bytes().eq_ignore_ascii_case(name))
+        .map(|(_, values)| values)
+}
+
+fn matches(entry: &Entry, filter: &Element) -> bool {
+    match filter.tag {
+        // and
+        0xa0 => filter
+            .children()
+            .iter()
+            .all(|filter| matches(entry, filter)),
+        // or
+        0xa1 => filter
+            .children()
+            .iter()
+            .any(|filter| matches(entry, filter)),
+        // not
+        0xa2 => !matches(entry, &filter.children()[0]),
+        // equalityMatch
+        0xa3 => {
+            let children = filter.children();
+            attribute(entry, &children[0].contents).is_some_and(|values| {
+                values
+                    .iter()
+                    .any(|value| value.eq_ignore_ascii_case(&children[1].contents))
+            })
+        }
+        // present
+        0x87 => attribute(entry, &filter.contents).is_some(),
+        tag => panic!("unsupported filter `{tag:#x}`"),
+    }
+}
+
+async fn serve(mut stream: TcpStream, entries: Arc<Vec<Entry>>) {
+    let mut buffer = vec![];
+    let mut bound = false;
+
+    loop {
+        let Some((message_element, length)) = decode(&buffer) else {
+            let mut chunk = [0; 4096];
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+            }
+            continue;
+        };
+        buffer.drain(..length);
+
+        let children = message_element.children();
+        let id = children[0].integer();
+        let operation = &children[1];
+
+        let mut response = vec![];
+        match operation.tag {
+            // BindRequest
+            0x60 => {
+                let fields = operation.children();
+                let dn = &fields[1].contents;
+                let password = &fields[2].contents;
+
+                bound = entries.iter().any(|entry| {
+                    entry.dn.as_bytes() == dn.as_slice()
+                        && !password.is_empty()
+                        && entry.password.as_bytes() == password.as_slice()
+                });
+
+                response.extend(message(id, result(0x61, if bound { 0 } else { 49 })));
+            }
+            // UnbindRequest
+            0x42 => return,
+            // SearchRequest
+            0x63 => {
+                // insufficientAccessRights for anonymous searches.
+                if !bound {
+                    response.extend(message(id, result(0x65, 50)));
+                } else {
+                    let fields = operation.children();
+                    let filter = &fields[6];
+                    let requested = fields[7]
+                        .children()
+                        .into_iter()
+                        .map(|attribute| attribute.contents)
+                        .collect::<Vec<_>>();
+
+                    for entry in entries
+                        .iter()
+                        .filter(|entry| entry.dn.ends_with(BASE_DN) && matches(entry, filter))
+                    {
+                        let attributes = entry
+                            .attributes
+                            .iter()
+                            .filter(|(key, _)| {
+                                requested
+                                    .iter()
+                                    .any(|name| key.as_bytes().eq_ignore_ascii_case(name))
+                            })
+                            .map(|(key, values)| {
+                                let values = values
+                                    .iter()
+                                    .flat_map(|value| encode(0x04, value))
+                                    .collect::<Vec<_>>();
+                                encode(
+                                    0x30,
+                                    &[encode(0x04, key.as_bytes()), encode(0x31, &values)].concat(),
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                            .concat();
+
+                        response.extend(message(
+                            id,
+                            encode(
+                                0x64,
+                                &[encode(0x04, entry.dn.as_bytes()), encode(0x30, &attributes)]
+                                    .concat(),
+                            ),
+                        ));
+                    }
+
+                    response.extend(message(id, result(0x65, 0)));
+                }
+            }
+            tag => panic!("unsupported operation `{tag:#x}`"),
+        }
+
+        if stream.write_all(&response).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Start a server and return a directory configured for it.
+async fn directory() -> LdapDirectory {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let entries = Arc::new(entries());
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(serve(stream, entries.clone()));
+        }
+    });
+
+    LdapDirectory::builder()
+        .url(format!("ldap://{address}"))
+        .bind_dn(SERVICE_DN)
+        .bind_password(SERVICE_PASSWORD)
+        .base_dn(BASE_DN)
+        .identifier_attribute("objectGUID")
+        .build()
+}
+
+#[tokio::test]
+async fn authenticate() {
+    let directory = directory().await;
+
+    let entry = directory
+        .authenticate("alice", "alice-password")
+        .await
+        .unwrap()
+        .expect("Alice should be authenticated.");
+
+    assert_eq!("uid=alice,ou=people,dc=example,dc=com", entry.dn);
+    assert_eq!("01020304-0506-0708-090a-0b0c0d0e0fff", entry.identifier);
+    assert_eq!(Some("Alice"), entry.name.as_deref());
+    assert_eq!(Some("alice@example.com"), entry.email.as_deref());
+    assert_eq!(vec![STAFF_GROUP.to_owned()], entry.groups);
+}
+
+#[tokio::test]
+async fn authenticate_invalid_credentials() {
+    let directory = directory().await;
+
+    for (username, password) in [
+        ("alice", "bob-password"),
+        ("alice", ""),
+        ("carol", "carol-password"),
+        // Filter injection matching any user.
+        ("*", "alice-password"),
+        ("alice)(uid=*", "alice-password"),
+    ] {
+        assert!(
+            directory
+                .authenticate(username, password)
+                .await
+                .unwrap()
+                .is_none(),
+            "`{username}` should not be authenticated."
+        );
+    }
+}
+
+#[tokio::test]
+async fn authenticate_allowed_groups() {
+    let directory = LdapDirectory {
+        allowed_groups: vec![STAFF_GROUP.to_uppercase()],
+        ..directory().await
+    };
+
+    assert!(
+        directory
+            .authenticate("alice", "alice-password")
+            .await
+            .unwrap()
+            .is_some()
+    );
+    assert!(matches!(
+        directory.authenticate("bob", "bob-password").await,
+        Err(ShieldError::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn authenticate_without_identifier_attribute() {
+    let directory = LdapDirectory {
+        identifier_attribute: None,
+        ..directory().await
+    };
+
+    let entry = directory
+        .authenticate("bob", "bob-password")
+        .await
+        .unwrap()
+        .expect("Bob should be authenticated.");
+
+    assert_eq!("uid=bob,ou=people,dc=example,dc=com", entry.identifier);
+    assert_eq!(None, entry.name);
+}
+
+#[tokio::test]
+async fn authenticate_invalid_service_account() {
+    let directory = LdapDirectory {
+        bind_password: Some("wrong-password".to_owned()),
+        ..directory().await
+    };
+
+    assert!(matches!(
+        directory.authenticate("alice", "alice-password").await,
+        Err(ShieldError::Request(_))
+    ));
+}
@@ -0,0 +1,11 @@
+mod authorize;
+mod jwks;
+mod openid_configuration;
+mod token;
+mod userinfo;
+
+pub use authorize::*;
+pub use jwks::*;
+pub use openid_configuration::*;
+pub use token::*;
+pub use userinfo::*;
@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use shield::{
+    Authentication, Form, MethodAction, MethodSession, Request, RequestMethod, Response,
+    ResponseType, ShieldError, User, erased_method_action,
+};
+use url::Url;
+
+use crate::{
+    client::OauthServerClient,
+    form::{authorization_inputs, deny_input, submit_input},
+    grant::CreateOauthServerAuthorizationCode,
+    options::OauthServerOptions,
+    provider::OauthServerProvider,
+    storage::OauthServerStorage,
+    token::{generate_token, hash_token},
+};
+
+pub const AUTHORIZE_ACTION_ID: &str = "authorize";
+const AUTHORIZE_ACTION_NAME: &str = "Authorize";
+
+#[derive(Debug, Deserialize)]
+struct AuthorizeData {
+    client_id: String,
+    redirect_uri: String,
+    response_type: Option<String>,
+    scope: Option<String>,
+    state: Option<String>,
+    code_challenge: Option<String>,
+    code_challenge_method: Option<String>,
+    nonce: Option<String>,
+    deny: Option<String>,
+}
+
+pub struct OauthServerAuthorizeAction<U: User> {
+    options: OauthServerOptions,
+    storage: Arc<dyn OauthServerStorage<U>>,
+}
+
+impl<U: User> OauthServerAuthorizeAction<U> {
+    pub fn new(options: OauthServerOptions, storage: Arc<dyn OauthServerStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+
+    fn redirect(
+        &self,
+        redirect_uri: &str,
+        state: Option<&str>,
+        parameters: &[(&str, &str)],
+    ) -> Result<Response, ShieldError> {
+        let mut url = Url::parse(redirect_uri)
+            .map_err(|err| ShieldError::Validation(format!("Invalid redirect URI: {err}")))?;
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            query_pairs.extend_pairs(parameters);
+            if let Some(state) = state {
+                query_pairs.append_pair("state", state);
+            }
+            // Identify the issuer to prevent mix-up attacks, see RFC 9207.
+            query_pairs.append_pair("iss", &self.options.issuer);
+        }
+
+        Ok(Response::new(ResponseType::Redirect(url.to_string())))
+    }
+
+    fn redirect_error(
+        &self,
+        redirect_uri: &str,
+        state: Option<&str>,
+        error: &str,
+        description: &str,
+    ) -> Result<Response, ShieldError> {
+        self.redirect(
+            redirect_uri,
+            state,
+            &[("error", error), ("error_description", description)],
+        )
+    }
+}
+
+/// Only sessions with full access can authorize clients, not requests with scoped tokens.
+fn session_authentication<'a>(
+    session: &'a MethodSession<()>,
+) -> Result<&'a Authentication, ShieldError> {
+    session
+        .base
+        .authentication
+        .as_ref()
+        .filter(|authentication| authentication.scopes.is_none())
+        .ok_or(ShieldError::Unauthorized)
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<OauthServerProvider, ()> for OauthServerAuthorizeAction<U> {
+    fn id(&self) -> String {
+        AUTHORIZE_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        AUTHORIZE_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Authorize client"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Grant or deny a client access to the current user, redirecting back to the client with an authorization code."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &OauthServerProvider,
+        session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session_authentication(session).is_ok())
+    }
+
+    async fn forms(&self, _provider: OauthServerProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![
+            Form {
+                inputs: authorization_inputs()
+                    .into_iter()
+                    .chain([submit_input("Allow")])
+                    .collect(),
+            },
+            Form {
+                inputs: authorization_inputs()
+                    .into_iter()
+                    .chain([deny_input(), submit_input("Deny")])
+                    .collect(),
+            },
+        ])
+    }
+
+    async fn call(
+        &self,
+        _provider: OauthServerProvider,
+        session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session_authentication(session)?;
+
+        let data = serde_json::from_value::<AuthorizeData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        // Without a valid client and redirect URI, errors can not be sent to the client.
+        let client = self
+            .storage
+            .oauth_server_client_by_client_id(&data.client_id)
+            .await?
+            .ok_or_else(|| ShieldError::Validation("Unknown client.".to_owned()))?;
+        if !client.redirect_uris.contains(&data.redirect_uri) {
+            return Err(ShieldError::Validation(
+                "Redirect URI is not registered for the client.".to_owned(),
+            ));
+        }
+
+        let redirect_uri = data.redirect_uri.as_str();
+        let state = data.state.as_deref();
+
+        if data.deny.is_some() {
+            return self.redirect_error(
+                redirect_uri,
+                state,
+                "access_denied",
+                "The user denied access.",
+            );
+        }
+        if data.response_type.as_deref() != Some("code") {
+            return self.redirect_error(
+                redirect_uri,
+                state,
+                "unsupported_response_type",
+                "Only the `code` response type is supported.",
+            );
+        }
+        let Some(code_challenge) = data
+            .code_challenge
+            .filter(|_| data.code_challenge_method.as_deref() == Some("S256"))
+        else {
+            return self.redirect_error(
+                redirect_uri,
+                state,
+                "invalid_request",
+                "A PKCE code challenge with the `S256` method is required.",
+            );
+        };
+
+        let scopes = requested_scopes(&client, data.scope.as_deref());
+        if let Some(scope) = scopes
+            .iter()
+            .find(|scope| !client.scopes.contains(scope) || !self.options.scopes.contains(scope))
+        {
+            return self.redirect_error(
+                redirect_uri,
+                state,
+                "invalid_scope",
+                &format!("Scope `{scope}` is not allowed for the client."),
+            );
+        }
+
+        let authenticated_at = authentication
+            .factors
+            .iter()
+            .map(|factor| factor.authenticated_at)
+            .max()
+            .unwrap_or_else(Utc::now);
+
+        let code = generate_token();
+
+        self.storage
+            .create_oauth_server_authorization_code(CreateOauthServerAuthorizationCode {
+                code_hash: hash_token(&code),
+                client_id: client.client_id,
+                user_id: authentication.user_id.clone(),
+                redirect_uri: data.redirect_uri.clone(),
+                scopes,
+                code_challenge,
+                nonce: data.nonce,
+                authenticated_at: authenticated_at.into(),
+                expired_at: (Utc::now() + self.options.authorization_code_expires_in).into(),
+            })
+            .await?;
+
+        self.redirect(redirect_uri, state, &[("code", &code)])
+    }
+}
+
+erased_method_action!(OauthServerAuthorizeAction, <U: User>);
+
+/// Scopes of the request, or all scopes of the client if none are requested.
+pub(crate) fn requested_scopes(client: &OauthServerClient, scope: Option<&str>) -> Vec<String> {
+    let mut scopes = match scope {
+        Some(scope) => scope
+            .split(' ')
+            .filter(|scope| !scope.is_empty())
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>(),
+        None => client.scopes.clone(),
+    };
+    scopes.dedup();
+
+    scopes
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use serde_json::json;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError,
+    erased_method_action,
+};
+
+use crate::{options::OauthServerOptions, provider::OauthServerProvider, token::public_jwk};
+
+pub const JWKS_ACTION_ID: &str = "jwks";
+const JWKS_ACTION_NAME: &str = "JSON Web Key Set";
+
+pub struct OauthServerJwksAction {
+    options: OauthServerOptions,
+}
+
+impl OauthServerJwksAction {
+    pub fn new(options: OauthServerOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[async_trait]
+impl MethodAction<OauthServerProvider, ()> for OauthServerJwksAction {
+    fn id(&self) -> String {
+        JWKS_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        JWKS_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Get JSON Web Key Set"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Get the public keys to verify tokens issued by the authorization server."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Get
+    }
+
+    fn condition(
+        &self,
+        _provider: &OauthServerProvider,
+        _session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(true)
+    }
+
+    async fn forms(&self, _provider: OauthServerProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        _provider: OauthServerProvider,
+        _session: &MethodSession<()>,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        Ok(Response::new(ResponseType::Data(json!({
+            "keys": [public_jwk(&self.options)?],
+        }))))
+    }
+}
+
+erased_method_action!(OauthServerJwksAction);
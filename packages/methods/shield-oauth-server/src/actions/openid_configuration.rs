@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError,
+    erased_method_action,
+};
+
+use crate::{
+    actions::{JWKS_ACTION_ID, TOKEN_ACTION_ID, USERINFO_ACTION_ID},
+    options::OauthServerOptions,
+    provider::OauthServerProvider,
+};
+
+pub const OPENID_CONFIGURATION_ACTION_ID: &str = "openid-configuration";
+const OPENID_CONFIGURATION_ACTION_NAME: &str = "OpenID configuration";
+
+pub struct OauthServerOpenidConfigurationAction {
+    options: OauthServerOptions,
+}
+
+impl OauthServerOpenidConfigurationAction {
+    pub fn new(options: OauthServerOptions) -> Self {
+        Self { options }
+    }
+}
+
+/// OpenID Connect discovery document of the authorization server.
+pub(crate) fn openid_configuration(options: &OauthServerOptions) -> Value {
+    json!({
+        "issuer": options.issuer,
+        "authorization_endpoint": options.authorization_endpoint,
+        "token_endpoint": options.endpoint(TOKEN_ACTION_ID),
+        "userinfo_endpoint": options.endpoint(USERINFO_ACTION_ID),
+        "jwks_uri": options.endpoint(JWKS_ACTION_ID),
+        "response_types_supported": ["code"],
+        "grant_types_supported": ["authorization_code", "refresh_token"],
+        "subject_types_supported": ["public"],
+        "id_token_signing_alg_values_supported": [options.algorithm],
+        "scopes_supported": options.scopes,
+        "token_endpoint_auth_methods_supported": ["client_secret_post", "none"],
+        "code_challenge_methods_supported": ["S256"],
+        "authorization_response_iss_parameter_supported": true,
+    })
+}
+
+#[async_trait]
+impl MethodAction<OauthServerProvider, ()> for OauthServerOpenidConfigurationAction {
+    fn id(&self) -> String {
+        OPENID_CONFIGURATION_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        OPENID_CONFIGURATION_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Get OpenID configuration"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Get the OpenID Connect discovery document, to be served at `/.well-known/openid-configuration` of the issuer."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Get
+    }
+
+    fn condition(
+        &self,
+        _provider: &OauthServerProvider,
+        _session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(true)
+    }
+
+    async fn forms(&self, _provider: OauthServerProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        _provider: OauthServerProvider,
+        _session: &MethodSession<()>,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        Ok(Response::new(ResponseType::Data(openid_configuration(
+            &self.options,
+        ))))
+    }
+}
+
+erased_method_action!(OauthServerOpenidConfigurationAction);
@@ -0,0 +1,284 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use shield::{
+    Form, MethodAction, MethodSession, OauthError, Request, RequestMethod, Response, ResponseType,
+    ShieldError, User, erased_method_action,
+};
+
+use crate::{
+    client::OauthServerClient,
+    options::OauthServerOptions,
+    provider::OauthServerProvider,
+    storage::OauthServerStorage,
+    token::{TokenGrant, hash_token, issue_tokens, verify_code_challenge},
+};
+
+pub const TOKEN_ACTION_ID: &str = "token";
+const TOKEN_ACTION_NAME: &str = "Token";
+
+#[derive(Debug, Deserialize)]
+struct TokenData {
+    grant_type: String,
+    code: Option<String>,
+    redirect_uri: Option<String>,
+    code_verifier: Option<String>,
+    refresh_token: Option<String>,
+    client_id: String,
+    client_secret: Option<String>,
+    scope: Option<String>,
+}
+
+pub struct OauthServerTokenAction<U: User> {
+    options: OauthServerOptions,
+    storage: Arc<dyn OauthServerStorage<U>>,
+}
+
+impl<U: User> OauthServerTokenAction<U> {
+    pub fn new(options: OauthServerOptions, storage: Arc<dyn OauthServerStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+
+    /// Authenticate the client. Public clients have no secret and rely on PKCE instead.
+    async fn client(&self, data: &TokenData) -> Result<OauthServerClient, ShieldError> {
+        let client = self
+            .storage
+            .oauth_server_client_by_client_id(&data.client_id)
+            .await?
+            .ok_or_else(invalid_client)?;
+
+        if let Some(client_secret_hash) = &client.client_secret_hash
+            && data.client_secret.as_deref().map(hash_token).as_ref() != Some(client_secret_hash)
+        {
+            return Err(invalid_client());
+        }
+
+        Ok(client)
+    }
+
+    async fn authorization_code_grant(
+        &self,
+        client: &OauthServerClient,
+        data: TokenData,
+    ) -> Result<TokenGrant, ShieldError> {
+        let code = data
+            .code
+            .ok_or_else(|| oauth_error("invalid_request", "Missing authorization code."))?;
+
+        let authorization_code = self
+            .storage
+            .oauth_server_authorization_code_by_hash(&hash_token(&code))
+            .await?
+            .filter(|authorization_code| authorization_code.client_id == client.client_id)
+            .ok_or_else(invalid_authorization_code)?;
+
+        // An authorization code being used again indicates it was stolen, so the refresh tokens issued
+        // for it are revoked. Losing a race with a concurrent request is treated the same as reuse.
+        if authorization_code.used_at.is_some()
+            || !self
+                .storage
+                .use_oauth_server_authorization_code(&authorization_code.id, Utc::now())
+                .await?
+        {
+            self.storage
+                .delete_oauth_server_refresh_token_family(&authorization_code.id)
+                .await?;
+
+            return Err(invalid_authorization_code());
+        }
+
+        if Some(&authorization_code.redirect_uri) != data.redirect_uri.as_ref()
+            || authorization_code.expired_at <= Utc::now()
+        {
+            return Err(invalid_authorization_code());
+        }
+
+        if !data.code_verifier.is_some_and(|code_verifier| {
+            verify_code_challenge(&code_verifier, &authorization_code.code_challenge)
+        }) {
+            return Err(oauth_error("invalid_grant", "Invalid code verifier."));
+        }
+
+        Ok(TokenGrant {
+            family_id: authorization_code.id,
+            user_id: authorization_code.user_id,
+            scopes: authorization_code.scopes,
+            nonce: authorization_code.nonce,
+            authenticated_at: authorization_code.authenticated_at,
+        })
+    }
+
+    async fn refresh_token_grant(
+        &self,
+        client: &OauthServerClient,
+        data: TokenData,
+    ) -> Result<TokenGrant, ShieldError> {
+        let refresh_token = data
+            .refresh_token
+            .ok_or_else(|| oauth_error("invalid_request", "Missing refresh token."))?;
+
+        let refresh_token = self
+            .storage
+            .oauth_server_refresh_token_by_hash(&hash_token(&refresh_token))
+            .await?
+            .filter(|refresh_token| refresh_token.client_id == client.client_id)
+            .ok_or_else(invalid_refresh_token)?;
+
+        // A rotated refresh token being used again indicates it was stolen, so the family is revoked.
+        if refresh_token.rotated_at.is_some() || refresh_token.expired_at <= Utc::now() {
+            self.storage
+                .delete_oauth_server_refresh_token_family(&refresh_token.family_id)
+                .await?;
+
+            return Err(invalid_refresh_token());
+        }
+
+        if self
+            .storage
+            .user_by_id(&refresh_token.user_id)
+            .await?
+            .is_none()
+        {
+            self.storage
+                .delete_oauth_server_refresh_token_family(&refresh_token.family_id)
+                .await?;
+
+            return Err(invalid_refresh_token());
+        }
+
+        // The requested scopes can only narrow down the original grant.
+        let scopes = match data.scope {
+            Some(scope) => {
+                let scopes = scope
+                    .split(' ')
+                    .filter(|scope| !scope.is_empty())
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>();
+
+                if let Some(scope) = scopes
+                    .iter()
+                    .find(|scope| !refresh_token.scopes.contains(scope))
+                {
+                    return Err(oauth_error(
+                        "invalid_scope",
+                        &format!("Scope `{scope}` was not granted."),
+                    ));
+                }
+
+                scopes
+            }
+            None => refresh_token.scopes,
+        };
+
+        // Losing a race with a concurrent request is treated the same as reuse.
+        if !self
+            .storage
+            .rotate_oauth_server_refresh_token(&refresh_token.id, Utc::now())
+            .await?
+        {
+            self.storage
+                .delete_oauth_server_refresh_token_family(&refresh_token.family_id)
+                .await?;
+
+            return Err(invalid_refresh_token());
+        }
+
+        Ok(TokenGrant {
+            family_id: refresh_token.family_id,
+            user_id: refresh_token.user_id,
+            scopes,
+            nonce: None,
+            authenticated_at: refresh_token.authenticated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<OauthServerProvider, ()> for OauthServerTokenAction<U> {
+    fn id(&self) -> String {
+        TOKEN_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        TOKEN_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Exchange token"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Exchange an authorization code or refresh token for an access token, refresh token and ID token."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    /// Clients call the token endpoint without the session of the user.
+    fn csrf_protected(&self) -> bool {
+        false
+    }
+
+    fn condition(
+        &self,
+        _provider: &OauthServerProvider,
+        _session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(true)
+    }
+
+    async fn forms(&self, _provider: OauthServerProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        _provider: OauthServerProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<TokenData>(request.form_data)
+            .map_err(|err| oauth_error("invalid_request", &err.to_string()))?;
+
+        let client = self.client(&data).await?;
+
+        let grant = match data.grant_type.as_str() {
+            "authorization_code" => self.authorization_code_grant(&client, data).await?,
+            "refresh_token" => self.refresh_token_grant(&client, data).await?,
+            grant_type => {
+                return Err(oauth_error(
+                    "unsupported_grant_type",
+                    &format!("Unsupported grant type `{grant_type}`."),
+                ));
+            }
+        };
+
+        let tokens = issue_tokens(&self.options, &*self.storage, &client, grant).await?;
+
+        let tokens =
+            serde_json::to_value(tokens).map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(Response::new(ResponseType::Data(tokens)))
+    }
+}
+
+erased_method_action!(OauthServerTokenAction, <U: User>);
+
+fn oauth_error(error: &str, description: &str) -> ShieldError {
+    OauthError::new(error, description).into()
+}
+
+fn invalid_client() -> ShieldError {
+    oauth_error("invalid_client", "Client authentication failed.")
+}
+
+fn invalid_authorization_code() -> ShieldError {
+    oauth_error("invalid_grant", "Invalid authorization code.")
+}
+
+fn invalid_refresh_token() -> ShieldError {
+    oauth_error("invalid_grant", "Invalid refresh token.")
+}
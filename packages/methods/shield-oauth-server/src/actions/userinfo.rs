@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::json;
+use shield::{
+    Authentication, Form, MethodAction, MethodSession, Request, RequestMethod, Response,
+    ResponseType, ShieldError, User, erased_method_action,
+};
+
+use crate::{
+    method::OAUTH_SERVER_METHOD_ID, provider::OauthServerProvider, storage::OauthServerStorage,
+    token::user_info,
+};
+
+pub const USERINFO_ACTION_ID: &str = "userinfo";
+const USERINFO_ACTION_NAME: &str = "User info";
+
+pub struct OauthServerUserinfoAction<U: User> {
+    storage: Arc<dyn OauthServerStorage<U>>,
+}
+
+impl<U: User> OauthServerUserinfoAction<U> {
+    pub fn new(storage: Arc<dyn OauthServerStorage<U>>) -> Self {
+        Self { storage }
+    }
+}
+
+/// Only access tokens issued by this server with the `openid` scope can access user info.
+fn access_token_authentication<'a>(
+    session: &'a MethodSession<()>,
+) -> Result<&'a Authentication, ShieldError> {
+    session
+        .base
+        .authentication
+        .as_ref()
+        .filter(|authentication| {
+            authentication.method_id == OAUTH_SERVER_METHOD_ID
+                && authentication.scopes.is_some()
+                && authentication.has_scope("openid")
+        })
+        .ok_or(ShieldError::Unauthorized)
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<OauthServerProvider, ()> for OauthServerUserinfoAction<U> {
+    fn id(&self) -> String {
+        USERINFO_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        USERINFO_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Get user info"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Get claims about the user of the access token, depending on the granted scopes."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Get
+    }
+
+    /// Claims are limited to the scopes granted to the access token.
+    fn allows_scoped_tokens(&self) -> bool {
+        true
+    }
+
+    fn condition(
+        &self,
+        _provider: &OauthServerProvider,
+        session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        Ok(access_token_authentication(session).is_ok())
+    }
+
+    async fn forms(&self, _provider: OauthServerProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        _provider: OauthServerProvider,
+        session: &MethodSession<()>,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = access_token_authentication(session)?;
+
+        let user_info = user_info(
+            &*self.storage,
+            &authentication.user_id,
+            authentication.scopes.as_deref().unwrap_or_default(),
+        )
+        .await?;
+
+        let mut value = serde_json::to_value(user_info)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+        value["sub"] = json!(authentication.user_id);
+
+        Ok(Response::new(ResponseType::Data(value)))
+    }
+}
+
+erased_method_action!(OauthServerUserinfoAction, <U: User>);
@@ -0,0 +1,37 @@
+use chrono::{DateTime, FixedOffset};
+
+/// Client application registered with the authorization server.
+#[derive(Clone, Debug)]
+pub struct OauthServerClient {
+    pub id: String,
+    pub client_id: String,
+    /// Hash of the client secret. Public clients, e.g. single-page apps, have no secret.
+    pub client_secret_hash: Option<String>,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    /// Scopes the client can request.
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl OauthServerClient {
+    pub fn is_confidential(&self) -> bool {
+        self.client_secret_hash.is_some()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateOauthServerClient {
+    pub client_id: String,
+    pub client_secret_hash: Option<String>,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    pub scopes: Vec<String>,
+}
+
+/// Newly registered client, the only time the client secret is available in plain text.
+#[derive(Clone, Debug)]
+pub struct RegisteredOauthServerClient {
+    pub client: OauthServerClient,
+    pub client_secret: Option<String>,
+}
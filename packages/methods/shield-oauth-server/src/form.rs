@@ -0,0 +1,55 @@
+use shield::{Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue};
+
+/// Parameters of the authorization request, passed from the query to the authorize action.
+const AUTHORIZATION_PARAMETERS: [&str; 8] = [
+    "client_id",
+    "redirect_uri",
+    "response_type",
+    "scope",
+    "state",
+    "code_challenge",
+    "code_challenge_method",
+    "nonce",
+];
+
+pub(crate) fn authorization_inputs() -> Vec<Input> {
+    AUTHORIZATION_PARAMETERS
+        .into_iter()
+        .map(|parameter| Input {
+            name: parameter.to_owned(),
+            label: None,
+            r#type: InputType::Hidden(InputTypeHidden::default()),
+            value: Some(InputValue::Query {
+                key: parameter.to_owned(),
+            }),
+            addon_start: None,
+            addon_end: None,
+        })
+        .collect()
+}
+
+pub(crate) fn deny_input() -> Input {
+    Input {
+        name: "deny".to_owned(),
+        label: None,
+        r#type: InputType::Hidden(InputTypeHidden::default()),
+        value: Some(InputValue::String {
+            value: "true".to_owned(),
+        }),
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+pub(crate) fn submit_input(value: &str) -> Input {
+    Input {
+        name: "submit".to_owned(),
+        label: None,
+        r#type: InputType::Submit(InputTypeSubmit::default()),
+        value: Some(InputValue::String {
+            value: value.to_owned(),
+        }),
+        addon_start: None,
+        addon_end: None,
+    }
+}
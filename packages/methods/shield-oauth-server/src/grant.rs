@@ -0,0 +1,59 @@
+use chrono::{DateTime, FixedOffset};
+
+#[derive(Clone, Debug)]
+pub struct OauthServerAuthorizationCode {
+    pub id: String,
+    pub code_hash: String,
+    pub client_id: String,
+    pub user_id: String,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    /// PKCE code challenge (`S256`).
+    pub code_challenge: String,
+    pub nonce: Option<String>,
+    pub authenticated_at: DateTime<FixedOffset>,
+    pub expired_at: DateTime<FixedOffset>,
+    /// Used authorization codes are kept until they expire, so the tokens issued for them can be
+    /// revoked when they are used again.
+    pub used_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateOauthServerAuthorizationCode {
+    pub code_hash: String,
+    pub client_id: String,
+    pub user_id: String,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub code_challenge: String,
+    pub nonce: Option<String>,
+    pub authenticated_at: DateTime<FixedOffset>,
+    pub expired_at: DateTime<FixedOffset>,
+}
+
+#[derive(Clone, Debug)]
+pub struct OauthServerRefreshToken {
+    pub id: String,
+    pub token_hash: String,
+    /// Refresh tokens rotated from the same authorization code share a family, identified by the ID
+    /// of the authorization code. The family is revoked entirely when a rotated refresh token or the
+    /// authorization code is used again.
+    pub family_id: String,
+    pub client_id: String,
+    pub user_id: String,
+    pub scopes: Vec<String>,
+    pub authenticated_at: DateTime<FixedOffset>,
+    pub expired_at: DateTime<FixedOffset>,
+    pub rotated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateOauthServerRefreshToken {
+    pub token_hash: String,
+    pub family_id: String,
+    pub client_id: String,
+    pub user_id: String,
+    pub scopes: Vec<String>,
+    pub authenticated_at: DateTime<FixedOffset>,
+    pub expired_at: DateTime<FixedOffset>,
+}
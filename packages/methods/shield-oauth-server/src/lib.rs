@@ -0,0 +1,20 @@
+mod actions;
+mod client;
+mod form;
+mod grant;
+mod method;
+mod options;
+mod provider;
+mod storage;
+mod token;
+
+pub use client::*;
+pub use grant::*;
+pub use method::*;
+pub use options::*;
+pub use provider::*;
+pub use storage::*;
+pub use token::{
+    OauthServerAccessTokenClaims, OauthServerIdTokenClaims, OauthServerTokenResponse,
+    OauthServerUserInfo,
+};
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use shield::{Authentication, Method, MethodAction, ShieldError, User, erased_method};
+
+use crate::{
+    actions::{
+        OauthServerAuthorizeAction, OauthServerJwksAction, OauthServerOpenidConfigurationAction,
+        OauthServerTokenAction, OauthServerUserinfoAction, openid_configuration,
+    },
+    client::{CreateOauthServerClient, RegisteredOauthServerClient},
+    options::OauthServerOptions,
+    provider::OauthServerProvider,
+    storage::OauthServerStorage,
+    token::{generate_token, hash_token, verify_access_token},
+};
+
+pub const OAUTH_SERVER_METHOD_ID: &str = "oauth-server";
+
+pub struct OauthServerMethod<U: User> {
+    options: OauthServerOptions,
+    storage: Arc<dyn OauthServerStorage<U>>,
+}
+
+impl<U: User> OauthServerMethod<U> {
+    pub fn new<S: OauthServerStorage<U> + 'static>(
+        options: OauthServerOptions,
+        storage: S,
+    ) -> Self {
+        Self {
+            options,
+            storage: Arc::new(storage),
+        }
+    }
+
+    /// Register a client application. Confidential clients receive a client secret, which is only
+    /// returned here.
+    pub async fn register_client(
+        &self,
+        name: &str,
+        redirect_uris: Vec<String>,
+        scopes: Vec<String>,
+        confidential: bool,
+    ) -> Result<RegisteredOauthServerClient, ShieldError> {
+        if let Some(scope) = scopes
+            .iter()
+            .find(|scope| !self.options.scopes.contains(scope))
+        {
+            return Err(ShieldError::Validation(format!(
+                "Scope `{scope}` is not supported."
+            )));
+        }
+
+        let client_secret = confidential.then(generate_token);
+
+        let client = self
+            .storage
+            .create_oauth_server_client(CreateOauthServerClient {
+                client_id: generate_token(),
+                client_secret_hash: client_secret.as_deref().map(hash_token),
+                name: name.to_owned(),
+                redirect_uris,
+                scopes,
+            })
+            .await?;
+
+        Ok(RegisteredOauthServerClient {
+            client,
+            client_secret,
+        })
+    }
+
+    pub async fn delete_client(&self, client_id: &str) -> Result<(), ShieldError> {
+        Ok(self.storage.delete_oauth_server_client(client_id).await?)
+    }
+
+    /// OpenID Connect discovery document, to be served at `/.well-known/openid-configuration` of
+    /// the issuer. Clients discover the server there, the `openid-configuration` action only
+    /// provides the same document under the Shield routes.
+    pub fn openid_configuration(&self) -> Value {
+        openid_configuration(&self.options)
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> Method for OauthServerMethod<U> {
+    type Provider = OauthServerProvider;
+    type Connection = ();
+    type Session = ();
+
+    fn id(&self) -> String {
+        OAUTH_SERVER_METHOD_ID.to_owned()
+    }
+
+    fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+        vec![
+            Box::new(OauthServerAuthorizeAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(OauthServerTokenAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(OauthServerUserinfoAction::new(self.storage.clone())),
+            Box::new(OauthServerOpenidConfigurationAction::new(
+                self.options.clone(),
+            )),
+            Box::new(OauthServerJwksAction::new(self.options.clone())),
+        ]
+    }
+
+    async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+        Ok(vec![OauthServerProvider])
+    }
+
+    async fn user_connections(
+        &self,
+        _user_id: &str,
+        _provider_id: Option<&str>,
+    ) -> Result<Vec<Self::Connection>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn authenticate_token(&self, token: &str) -> Result<Option<Authentication>, ShieldError> {
+        // Skip tokens of other methods, which are not JSON Web Tokens.
+        if token.split('.').count() != 3 {
+            return Ok(None);
+        }
+
+        let Ok(claims) = verify_access_token(&self.options, token) else {
+            return Ok(None);
+        };
+
+        // Access tokens are issued to third-party clients, so they are only valid for the user info endpoint and do
+        // not authenticate the user for the application.
+        Ok(Some(Authentication::for_method_token(
+            OAUTH_SERVER_METHOD_ID,
+            None,
+            &claims.sub,
+            Some(claims.scope.split(' ').map(ToOwned::to_owned).collect()),
+        )))
+    }
+}
+
+erased_method!(OauthServerMethod, <U: User>);
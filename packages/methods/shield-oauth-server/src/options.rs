@@ -0,0 +1,72 @@
+use std::fmt::{self, Debug};
+
+use bon::Builder;
+use chrono::TimeDelta;
+use jsonwebtoken::{Algorithm, EncodingKey};
+
+#[derive(Builder, Clone)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct OauthServerOptions {
+    /// Issuer identifier, e.g. `https://example.com`. Discovery is expected at
+    /// `{issuer}/.well-known/openid-configuration`.
+    pub(crate) issuer: String,
+
+    /// URL of the page rendering the forms of the authorize action, where clients send users to.
+    pub(crate) authorization_endpoint: String,
+
+    /// Base URL of the Shield routes, e.g. `https://example.com/api/auth`.
+    pub(crate) auth_url: String,
+
+    /// Private key to sign tokens with. Only asymmetric keys are supported, as the public key is
+    /// published in the JSON Web Key Set.
+    pub(crate) signing_key: EncodingKey,
+
+    #[builder(default = Algorithm::RS256)]
+    pub(crate) algorithm: Algorithm,
+
+    /// Key ID (`kid` header) of the signing key.
+    pub(crate) key_id: Option<String>,
+
+    #[builder(default = TimeDelta::minutes(1))]
+    pub(crate) authorization_code_expires_in: TimeDelta,
+
+    #[builder(default = TimeDelta::hours(1))]
+    pub(crate) access_token_expires_in: TimeDelta,
+
+    #[builder(default = TimeDelta::days(30))]
+    pub(crate) refresh_token_expires_in: TimeDelta,
+
+    /// Scopes clients can be registered with and request, published in the discovery document.
+    /// Claims are provided for the `openid`, `profile` and `email` scopes.
+    #[builder(default = vec!["openid".to_owned(), "profile".to_owned(), "email".to_owned()])]
+    pub(crate) scopes: Vec<String>,
+}
+
+impl OauthServerOptions {
+    pub(crate) fn endpoint(&self, action_id: &str) -> String {
+        format!(
+            "{}/{action_id}/{}",
+            self.auth_url.trim_end_matches('/'),
+            crate::method::OAUTH_SERVER_METHOD_ID
+        )
+    }
+}
+
+impl Debug for OauthServerOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OauthServerOptions")
+            .field("issuer", &self.issuer)
+            .field("authorization_endpoint", &self.authorization_endpoint)
+            .field("auth_url", &self.auth_url)
+            .field("algorithm", &self.algorithm)
+            .field("key_id", &self.key_id)
+            .field(
+                "authorization_code_expires_in",
+                &self.authorization_code_expires_in,
+            )
+            .field("access_token_expires_in", &self.access_token_expires_in)
+            .field("refresh_token_expires_in", &self.refresh_token_expires_in)
+            .field("scopes", &self.scopes)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,19 @@
+use shield::Provider;
+
+use crate::method::OAUTH_SERVER_METHOD_ID;
+
+pub struct OauthServerProvider;
+
+impl Provider for OauthServerProvider {
+    fn method_id(&self) -> String {
+        OAUTH_SERVER_METHOD_ID.to_owned()
+    }
+
+    fn id(&self) -> Option<String> {
+        None
+    }
+
+    fn name(&self) -> String {
+        "Authorization server".to_owned()
+    }
+}
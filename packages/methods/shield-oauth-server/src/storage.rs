@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shield::{Storage, StorageError, User};
+
+use crate::{
+    client::{CreateOauthServerClient, OauthServerClient},
+    grant::{
+        CreateOauthServerAuthorizationCode, CreateOauthServerRefreshToken,
+        OauthServerAuthorizationCode, OauthServerRefreshToken,
+    },
+};
+
+#[async_trait]
+pub trait OauthServerStorage<U: User>: Storage<U> + Sync {
+    async fn oauth_server_client_by_client_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<OauthServerClient>, StorageError>;
+
+    async fn create_oauth_server_client(
+        &self,
+        client: CreateOauthServerClient,
+    ) -> Result<OauthServerClient, StorageError>;
+
+    /// Delete the client with its authorization codes and refresh tokens.
+    async fn delete_oauth_server_client(&self, client_id: &str) -> Result<(), StorageError>;
+
+    async fn create_oauth_server_authorization_code(
+        &self,
+        authorization_code: CreateOauthServerAuthorizationCode,
+    ) -> Result<OauthServerAuthorizationCode, StorageError>;
+
+    async fn oauth_server_authorization_code_by_hash(
+        &self,
+        code_hash: &str,
+    ) -> Result<Option<OauthServerAuthorizationCode>, StorageError>;
+
+    /// Mark the authorization code as used, unless it has been used already.
+    ///
+    /// Returns whether the authorization code was marked as used. This must be a single conditional
+    /// update, so concurrent requests with the same authorization code can not both succeed.
+    async fn use_oauth_server_authorization_code(
+        &self,
+        authorization_code_id: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError>;
+
+    async fn oauth_server_refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<OauthServerRefreshToken>, StorageError>;
+
+    async fn create_oauth_server_refresh_token(
+        &self,
+        refresh_token: CreateOauthServerRefreshToken,
+    ) -> Result<OauthServerRefreshToken, StorageError>;
+
+    /// Mark the refresh token as rotated, unless it has been rotated already.
+    ///
+    /// Returns whether the refresh token was rotated. This must be a single conditional update, so
+    /// concurrent requests with the same refresh token can not both succeed.
+    async fn rotate_oauth_server_refresh_token(
+        &self,
+        refresh_token_id: &str,
+        rotated_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError>;
+
+    async fn delete_oauth_server_refresh_token_family(
+        &self,
+        family_id: &str,
+    ) -> Result<(), StorageError>;
+}
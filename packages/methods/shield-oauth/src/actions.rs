@@ -1,5 +1,7 @@
 mod sign_in;
 mod sign_in_callback;
+mod sign_out;
 
 pub use sign_in::*;
 pub use sign_in_callback::*;
+pub use sign_out::*;
@@ -1,17 +1,17 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::{DateTime, Duration, FixedOffset, Utc};
+use chrono::Utc;
 use oauth2::{
     AuthorizationCode, PkceCodeVerifier, TokenResponse, basic::BasicTokenResponse,
     url::form_urlencoded::parse,
 };
-use secrecy::SecretString;
 use serde_json::Value;
 use shield::{
-    ConfigurationError, CreateEmailAddress, CreateUser, Form, MethodAction, MethodSession, Request,
-    RequestMethod, Response, ResponseType, SessionAction, ShieldError, SignInCallbackAction,
-    UpdateUser, User, erased_method_action,
+    ConfigurationError, ConnectionEvent, ConnectionLinkEvent, CreateEmailAddress, CreateUser, Form,
+    Hook, Hooks, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType,
+    SessionAction, ShieldError, SignInCallbackAction, SignUpEvent, UpdateUser, User, UserEvent,
+    erased_method_action,
 };
 
 use crate::{
@@ -21,6 +21,7 @@ use crate::{
     provider::{OauthProvider, OauthProviderPkceCodeChallenge},
     session::OauthSession,
     storage::OauthStorage,
+    token::parse_token_response,
 };
 
 pub struct OauthSignInCallbackAction<U: User> {
@@ -35,56 +36,95 @@ impl<U: User> OauthSignInCallbackAction<U> {
 
     // TODO: Consider if there is a better location for the functions below.
 
-    async fn create_user(&self, email: Option<&str>, name: Option<&str>) -> Result<U, ShieldError> {
-        if let Some(email) = email {
-            match self.storage.user_by_email(email).await? {
-                Some(_) => Err(ShieldError::Validation(
-                    "\
+    async fn create_user(
+        &self,
+        hooks: &Hooks,
+        provider: &OauthProvider,
+        email: Option<&str>,
+        email_verified: bool,
+        name: Option<&str>,
+    ) -> Result<U, ShieldError> {
+        let Some(email) = email else {
+            return Err(ShieldError::Validation(
+                "Missing email address in OAuth user info.".to_owned(),
+            ));
+        };
+
+        if let Some(user) = self.storage.user_by_email(email).await? {
+            if self.options.link_verified_email
+                && email_verified
+                && user.email_addresses().await?.iter().any(|email_address| {
+                    email_address.is_verified && email_address.email.eq_ignore_ascii_case(email)
+                })
+            {
+                return Ok(user);
+            }
+
+            return Err(ShieldError::Validation(format!(
+                "\
                 Email address `{email}` is already used by another account. \
-                To link a new provider, sign in to with your exising account first. \
-                If this is not your account, please contact support for assistence.\
+                To link a new provider, sign in with your existing account first. \
+                If this is not your account, please contact support for assistance.\
                 "
-                    .to_owned(),
-                )),
-                None => Ok(self
-                    .storage
-                    .create_user(
-                        CreateUser {
-                            name: name.map(ToOwned::to_owned),
-                        },
-                        CreateEmailAddress {
-                            email: email.to_string(),
-                            is_primary: true,
-                            // TODO: from claim?
-                            is_verified: false,
-                            // TODO: generate if not verified
-                            verification_token: None,
-                            verification_token_expired_at: None,
-                            verified_at: None,
-                        },
-                    )
-                    .await?),
-            }
-        } else {
-            Err(ShieldError::Validation(
-                "Missing email address in OpenID Connect claims.".to_owned(),
-            ))
+            )));
         }
+
+        hooks
+            .sign_up(
+                SignUpEvent {
+                    name: name.map(ToOwned::to_owned),
+                    email: Some(email.to_owned()),
+                    ..SignUpEvent::new(provider)
+                },
+                self.storage.create_user(
+                    CreateUser {
+                        name: name.map(ToOwned::to_owned),
+                    },
+                    CreateEmailAddress {
+                        email: email.to_string(),
+                        is_primary: true,
+                        is_verified: email_verified,
+                        // TODO: generate if not verified
+                        verification_token: None,
+                        verification_token_expired_at: None,
+                        verified_at: email_verified.then(|| Utc::now().into()),
+                    },
+                ),
+            )
+            .await
     }
 
-    async fn update_user(&self, user_id: &str, name: Option<&str>) -> Result<U, ShieldError> {
-        self.storage
+    async fn update_user(
+        &self,
+        hooks: &Hooks,
+        user_id: &str,
+        name: Option<&str>,
+    ) -> Result<U, ShieldError> {
+        hooks
+            .before_user_update(&UserEvent {
+                user_id: user_id.to_owned(),
+            })
+            .await?;
+
+        let user = self
+            .storage
             .update_user(UpdateUser {
                 id: user_id.to_owned(),
                 name: name.map(ToOwned::to_owned).map(Some),
             })
-            .await
-            .map_err(ShieldError::Storage)
+            .await?;
+
+        hooks
+            .user_updated(&UserEvent { user_id: user.id() })
+            .await?;
+
+        Ok(user)
     }
 
     async fn create_oauth_connection(
         &self,
-        provider_id: String,
+        hooks: &Hooks,
+        provider: &OauthProvider,
         user_id: String,
         identifier: String,
         token_response: BasicTokenResponse,
@@ -92,7 +132,16 @@ impl<U: User> OauthSignInCallbackAction<U> {
         let (token_type, access_token, refresh_token, expired_at, scopes) =
             parse_token_response(token_response)?;
 
-        self.storage
+        hooks
+            .before_connection_link(&ConnectionLinkEvent::new(
+                provider,
+                &user_id,
+                Some(&identifier),
+            ))
+            .await?;
+
+        let connection = self
+            .storage
             .create_oauth_connection(CreateOauthConnection {
                 identifier,
                 token_type,
@@ -100,11 +149,21 @@ impl<U: User> OauthSignInCallbackAction<U> {
                 refresh_token,
                 expired_at,
                 scopes,
-                provider_id,
+                provider_id: provider.id.clone(),
                 user_id,
             })
-            .await
-            .map_err(ShieldError::Storage)
+            .await?;
+
+        hooks
+            .connection_linked(&ConnectionEvent::new(
+                provider,
+                &connection.user_id,
+                &connection.id,
+                Some(&connection.identifier),
+            ))
+            .await?;
+
+        Ok(connection)
     }
 
     async fn update_oauth_connection(
@@ -260,46 +319,89 @@ impl<U: User + 'static> MethodAction<OauthProvider, OauthSession> for OauthSignI
             None
         };
 
-        let (connection, user) = match self
+        let email_verified = value_by_path(user, "email_verified")
+            .ok()
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let connection = self
             .storage
             .oauth_connection_by_identifier(&provider.id, &identifier)
-            .await?
-        {
-            Some(connection) => {
+            .await?;
+
+        let (connection, user) = match (connection, &session.base.authentication) {
+            (Some(connection), Some(authentication)) => {
+                if connection.user_id != authentication.user_id {
+                    return Err(ShieldError::Validation(format!(
+                        "This {} account is already linked to another account.",
+                        provider.name
+                    )));
+                }
+
                 let connection = self
                     .update_oauth_connection(connection.id, token_response)
                     .await?;
 
-                let user = self.update_user(&connection.user_id, name).await?;
+                (connection, None)
+            }
+            (Some(connection), None) => {
+                let connection = self
+                    .update_oauth_connection(connection.id, token_response)
+                    .await?;
 
-                (connection, user)
+                let user = self
+                    .update_user(&request.hooks, &connection.user_id, name)
+                    .await?;
+
+                (connection, Some(user))
             }
-            None => {
-                let user = self.create_user(email, name).await?;
+            (None, Some(authentication)) => {
+                let connection = self
+                    .create_oauth_connection(
+                        &request.hooks,
+                        &provider,
+                        authentication.user_id.clone(),
+                        identifier.to_owned(),
+                        token_response,
+                    )
+                    .await?;
+
+                (connection, None)
+            }
+            (None, None) => {
+                let user = self
+                    .create_user(&request.hooks, &provider, email, email_verified, name)
+                    .await?;
 
                 let connection = self
                     .create_oauth_connection(
-                        provider.id.clone(),
+                        &request.hooks,
+                        &provider,
                         user.id(),
                         identifier.to_owned(),
                         token_response,
                     )
                     .await?;
 
-                (connection, user)
+                (connection, Some(user))
             }
         };
 
-        Ok(Response::new(ResponseType::Redirect(
+        let mut response = Response::new(ResponseType::Redirect(
             session
                 .method
                 .redirect_url
                 .as_ref()
                 .map(ToString::to_string)
                 .unwrap_or_else(|| self.options.sign_in_redirect.clone()),
-        ))
-        .session_action(SessionAction::authenticate(&provider, user))
-        .session_action(SessionAction::method_data(
+        ));
+
+        // Linking a connection to the current user keeps the existing authentication.
+        if let Some(user) = user {
+            response = response.session_action(SessionAction::authenticate(&provider, user));
+        }
+
+        Ok(response.session_action(SessionAction::method_data(
             &provider,
             OauthSession {
                 redirect_url: None,
@@ -313,38 +415,6 @@ impl<U: User + 'static> MethodAction<OauthProvider, OauthSession> for OauthSignI
 
 erased_method_action!(OauthSignInCallbackAction, <U: User>);
 
-type ParsedTokenResponse = (
-    String,
-    SecretString,
-    Option<SecretString>,
-    Option<DateTime<FixedOffset>>,
-    Option<Vec<String>>,
-);
-
-fn parse_token_response(
-    token_response: BasicTokenResponse,
-) -> Result<ParsedTokenResponse, ShieldError> {
-    Ok((
-        token_response.token_type().as_ref().to_string(),
-        token_response.access_token().secret().as_str().into(),
-        token_response
-            .refresh_token()
-            .map(|refresh_token| refresh_token.secret().as_str().into()),
-        match token_response.expires_in() {
-            Some(expires_in) => Some(
-                (Utc::now()
-                    + Duration::from_std(expires_in)
-                        .map_err(|err| ShieldError::Validation(err.to_string()))?)
-                .into(),
-            ),
-            None => None,
-        },
-        token_response
-            .scopes()
-            .map(|scopes| scopes.iter().map(|scope| scope.to_string()).collect()),
-    ))
-}
-
 fn value_by_path<'a>(data: &'a Value, path: &str) -> Result<&'a Value, ShieldError> {
     let mut data = data;
 
@@ -360,3 +430,242 @@ fn value_by_path<'a>(data: &'a Value, path: &str) -> Result<&'a Value, ShieldErr
 
     Ok(data)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Json, Router,
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+    use shield::{
+        Authentication, BaseSession, CreateEmailAddress, CreateUser, MethodAction, MethodSession,
+        Request, Response, SessionAction, ShieldError, Storage, User,
+    };
+
+    use crate::{
+        client::tests::serve,
+        options::OauthOptions,
+        provider::OauthProvider,
+        session::OauthSession,
+        storage::tests::{TestStorage, TestUser},
+    };
+
+    use super::OauthSignInCallbackAction;
+
+    async fn provider(user: Value) -> OauthProvider {
+        let url = serve(
+            Router::new()
+                .route(
+                    "/token",
+                    post(|| async {
+                        Json(json!({
+                            "access_token": "access",
+                            "token_type": "bearer",
+                            "expires_in": 3600,
+                            "refresh_token": "refresh",
+                        }))
+                    }),
+                )
+                .route("/user", get(move || async move { Json(user) })),
+        )
+        .await;
+
+        OauthProvider::builder()
+            .id("test")
+            .name("Test")
+            .client_id("client")
+            .token_url(format!("{url}/token"))
+            .user_url(format!("{url}/user"))
+            .build()
+    }
+
+    async fn callback(
+        storage: &TestStorage,
+        options: OauthOptions,
+        provider: OauthProvider,
+        authentication: Option<Authentication>,
+    ) -> Result<Response, ShieldError> {
+        let base = BaseSession {
+            authentication,
+            ..Default::default()
+        };
+        let method = OauthSession {
+            csrf: Some("state".to_owned()),
+            pkce_verifier: Some("verifier".to_owned()),
+            ..Default::default()
+        };
+
+        OauthSignInCallbackAction::<TestUser>::new(options, Arc::new(storage.clone()))
+            .call(
+                provider,
+                &MethodSession {
+                    base: &base,
+                    method: &method,
+                },
+                Request::new(json!({ "state": "state", "code": "code" }), json!({})),
+            )
+            .await
+    }
+
+    async fn create_user(storage: &TestStorage, email: &str, is_verified: bool) -> TestUser {
+        storage
+            .create_user(
+                CreateUser { name: None },
+                CreateEmailAddress {
+                    email: email.to_owned(),
+                    is_primary: true,
+                    is_verified,
+                    verification_token: None,
+                    verification_token_expired_at: None,
+                    verified_at: None,
+                },
+            )
+            .await
+            .expect("user should be created")
+    }
+
+    fn authentication(user: &TestUser) -> Authentication {
+        Authentication {
+            method_id: "credentials".to_owned(),
+            user_id: user.id(),
+            ..Default::default()
+        }
+    }
+
+    fn authenticated_user_id(response: &Response) -> Option<&str> {
+        response
+            .session_actions
+            .iter()
+            .find_map(|session_action| match session_action {
+                SessionAction::Authenticate { user_id, .. } => Some(user_id.as_str()),
+                _ => None,
+            })
+    }
+
+    #[tokio::test]
+    async fn link_connection_to_signed_in_user() -> Result<(), ShieldError> {
+        let storage = TestStorage::default();
+        let user = create_user(&storage, "alice@example.com", false).await;
+        let provider = provider(json!({ "id": 42, "email": "bob@example.com" })).await;
+
+        let response = callback(
+            &storage,
+            OauthOptions::default(),
+            provider.clone(),
+            Some(authentication(&user)),
+        )
+        .await?;
+
+        // Linking keeps the existing authentication.
+        assert_eq!(None, authenticated_user_id(&response));
+        assert_eq!(1, storage.users().len());
+
+        let connections = storage.connections();
+        assert_eq!(1, connections.len());
+        assert_eq!("42", connections[0].identifier);
+        assert_eq!(user.id(), connections[0].user_id);
+
+        // Signing in again updates the connection of the user.
+        let response = callback(&storage, OauthOptions::default(), provider, None).await?;
+
+        assert_eq!(Some(user.id().as_str()), authenticated_user_id(&response));
+        assert_eq!(1, storage.connections().len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reject_connection_linked_to_other_user() {
+        let storage = TestStorage::default();
+        let provider = provider(json!({ "id": 42, "email": "alice@example.com" })).await;
+
+        callback(&storage, OauthOptions::default(), provider.clone(), None)
+            .await
+            .expect("user should sign up");
+        let other_user = create_user(&storage, "bob@example.com", true).await;
+
+        assert!(
+            callback(
+                &storage,
+                OauthOptions::default(),
+                provider,
+                Some(authentication(&other_user)),
+            )
+            .await
+            .is_err_and(|err| err
+                .to_string()
+                .contains("already linked to another account"))
+        );
+        assert_eq!(1, storage.connections().len());
+    }
+
+    #[tokio::test]
+    async fn link_verified_email() -> Result<(), ShieldError> {
+        let storage = TestStorage::default();
+        let user = create_user(&storage, "alice@example.com", true).await;
+        let provider = provider(json!({
+            "id": 42,
+            "email": "Alice@example.com",
+            "email_verified": true,
+        }))
+        .await;
+
+        // Linking by email address is opt-in.
+        assert!(
+            callback(&storage, OauthOptions::default(), provider.clone(), None)
+                .await
+                .is_err_and(|err| err.to_string().contains("already used by another account"))
+        );
+        assert!(storage.connections().is_empty());
+
+        let response = callback(
+            &storage,
+            OauthOptions::builder().link_verified_email(true).build(),
+            provider,
+            None,
+        )
+        .await?;
+
+        assert_eq!(Some(user.id().as_str()), authenticated_user_id(&response));
+        assert_eq!(1, storage.users().len());
+        assert_eq!(user.id(), storage.connections()[0].user_id);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reject_unverified_email() {
+        let options = OauthOptions::builder().link_verified_email(true).build();
+
+        // The provider does not assert the email address is verified.
+        let storage = TestStorage::default();
+        create_user(&storage, "alice@example.com", true).await;
+        let unverified_provider = provider(json!({ "id": 42, "email": "alice@example.com" })).await;
+
+        assert!(
+            callback(&storage, options.clone(), unverified_provider, None)
+                .await
+                .is_err_and(|err| err.to_string().contains("already used by another account"))
+        );
+
+        // The existing user has not verified the email address.
+        let storage = TestStorage::default();
+        create_user(&storage, "alice@example.com", false).await;
+        let verified_provider = provider(json!({
+            "id": 42,
+            "email": "alice@example.com",
+            "email_verified": true,
+        }))
+        .await;
+
+        assert!(
+            callback(&storage, options, verified_provider, None)
+                .await
+                .is_err_and(|err| err.to_string().contains("already used by another account"))
+        );
+        assert!(storage.connections().is_empty());
+    }
+}
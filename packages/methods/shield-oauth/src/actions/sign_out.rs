@@ -0,0 +1,244 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue, MethodAction,
+    MethodSession, Provider, Request, RequestMethod, Response, ResponseType, SessionAction,
+    ShieldError, SignOutAction, User, erased_method_action,
+};
+use tracing::warn;
+use url::Url;
+
+use crate::{
+    method::OAUTH_METHOD_ID, options::OauthOptions, provider::OauthProvider,
+    revocation::revoke_oauth_connection, session::OauthSession, storage::OauthStorage,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignOutData {
+    pub redirect_origin: Url,
+    pub redirect_url: Option<String>,
+}
+
+pub struct OauthSignOutAction<U: User> {
+    options: OauthOptions,
+    storage: Arc<dyn OauthStorage<U>>,
+}
+
+impl<U: User> OauthSignOutAction<U> {
+    pub fn new(options: OauthOptions, storage: Arc<dyn OauthStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<OauthProvider, OauthSession> for OauthSignOutAction<U> {
+    fn id(&self) -> String {
+        SignOutAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignOutAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign out with OAuth"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign out with OAuth, revoking the tokens at the provider."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        provider: &OauthProvider,
+        session: &MethodSession<OauthSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session
+            .base
+            .authentication
+            .as_ref()
+            .is_some_and(|authentication| {
+                authentication.method_id == OAUTH_METHOD_ID
+                    && authentication.provider_id.as_ref() == Some(&provider.id)
+            }))
+    }
+
+    async fn forms(&self, provider: OauthProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "redirectOrigin".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Origin),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "redirectUrl".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Query {
+                        key: "redirectUrl".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: format!("Sign out of {}", provider.name()),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: OauthProvider,
+        session: &MethodSession<OauthSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignOutData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let redirect_url = self.options.redirect_url(
+            &data.redirect_origin,
+            data.redirect_url.as_deref(),
+            &self.options.sign_out_redirect,
+        )?;
+
+        let connection = match &session.method.oauth_connection_id {
+            Some(connection_id) => self.storage.oauth_connection_by_id(connection_id).await?,
+            None => None,
+        };
+
+        if let Some(connection) = &connection
+            && let Err(err) = revoke_oauth_connection(&provider, connection).await
+        {
+            // Failing to revoke the tokens should not prevent the user from signing out.
+            warn!("failed to revoke OAuth tokens: {err}");
+        }
+
+        Ok(
+            Response::new(ResponseType::Redirect(redirect_url.to_string()))
+                .session_action(SessionAction::unauthenticate()),
+        )
+    }
+}
+
+erased_method_action!(OauthSignOutAction, <U: User>);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+    use shield::{
+        BaseSession, MethodAction, MethodSession, Request, Response, ResponseType, SessionAction,
+        ShieldError,
+    };
+    use url::Url;
+
+    use crate::{
+        connection::CreateOauthConnection,
+        options::OauthOptions,
+        provider::OauthProvider,
+        session::OauthSession,
+        storage::{
+            OauthStorage,
+            tests::{TestStorage, TestUser},
+        },
+    };
+
+    use super::OauthSignOutAction;
+
+    async fn sign_out(options: OauthOptions) -> Result<Response, ShieldError> {
+        let provider = OauthProvider::builder()
+            .id("test")
+            .name("Test")
+            .client_id("client")
+            .user_url("https://127.0.0.1:1/user")
+            // Nothing listens on this port, so the revocation request fails.
+            .revocation_url("https://127.0.0.1:1/revoke")
+            .build();
+
+        let storage = TestStorage::default();
+        let connection = storage
+            .create_oauth_connection(CreateOauthConnection {
+                identifier: "identifier".to_owned(),
+                token_type: "bearer".to_owned(),
+                access_token: "access".into(),
+                refresh_token: Some("refresh".into()),
+                expired_at: None,
+                scopes: None,
+                provider_id: "test".to_owned(),
+                user_id: "1".to_owned(),
+            })
+            .await?;
+
+        let base = BaseSession::default();
+        let method = OauthSession {
+            oauth_connection_id: Some(connection.id),
+            ..Default::default()
+        };
+
+        OauthSignOutAction::<TestUser>::new(options, Arc::new(storage))
+            .call(
+                provider,
+                &MethodSession {
+                    base: &base,
+                    method: &method,
+                },
+                Request::new(
+                    json!({}),
+                    json!({
+                        "redirectOrigin": "https://app.example.com",
+                        "redirectUrl": "/signed-out",
+                    }),
+                ),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn sign_out_when_revocation_fails() -> Result<(), ShieldError> {
+        let response = sign_out(OauthOptions::default()).await?;
+
+        assert!(matches!(
+            response.r#type,
+            ResponseType::Redirect(url) if url == "https://app.example.com/signed-out"
+        ));
+        assert!(matches!(
+            response.session_actions.as_slice(),
+            [SessionAction::Unauthenticate]
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_out_rejects_redirect_origin() {
+        let options = OauthOptions::builder()
+            .redirect_origins([Url::parse("https://other.example.com").unwrap()])
+            .build();
+
+        assert!(
+            sign_out(options)
+                .await
+                .is_err_and(|err| err.to_string().contains("not allowed"))
+        );
+    }
+}
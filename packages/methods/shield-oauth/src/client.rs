@@ -7,3 +7,21 @@ pub fn async_http_client() -> Result<reqwest::Client, ConfigurationError> {
         .build()
         .map_err(|err| ConfigurationError::Invalid(err.to_string()))
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use axum::Router;
+    use tokio::net::TcpListener;
+
+    /// Serve the router on a local port, returning its base URL.
+    pub async fn serve(router: Router) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("listener should bind");
+        let address = listener.local_addr().expect("listener should have address");
+
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        format!("http://{address}")
+    }
+}
@@ -4,8 +4,10 @@ mod connection;
 mod method;
 mod options;
 mod provider;
+mod revocation;
 mod session;
 mod storage;
+mod token;
 
 pub use connection::*;
 pub use method::*;
@@ -1,25 +1,31 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use shield::{Method, MethodAction, ShieldError, User, erased_method};
+use secrecy::SecretString;
+use shield::{
+    Method, MethodAction, ProviderError, ShieldError, StorageError, TokenRefresher, User,
+    UserConnection, erased_method,
+};
+use tracing::warn;
 
 use crate::{
     OauthConnection,
-    actions::{OauthSignInAction, OauthSignInCallbackAction},
+    actions::{OauthSignInAction, OauthSignInCallbackAction, OauthSignOutAction},
     options::OauthOptions,
     provider::OauthProvider,
+    revocation::revoke_oauth_connection,
     session::OauthSession,
     storage::OauthStorage,
+    token::access_token,
 };
 
-// TODO: Add sign out hook.
-
 pub const OAUTH_METHOD_ID: &str = "oauth";
 
 pub struct OauthMethod<U: User> {
     options: OauthOptions,
     providers: Vec<OauthProvider>,
     storage: Arc<dyn OauthStorage<U>>,
+    token_refresher: TokenRefresher,
 }
 
 impl<U: User> OauthMethod<U> {
@@ -28,6 +34,7 @@ impl<U: User> OauthMethod<U> {
             options: OauthOptions::default(),
             providers: vec![],
             storage: Arc::new(storage),
+            token_refresher: TokenRefresher::default(),
         }
     }
 
@@ -41,6 +48,33 @@ impl<U: User> OauthMethod<U> {
         self
     }
 
+    /// Get a non-expired access token of the user's connection with the provider.
+    ///
+    /// An expired access token is refreshed with the refresh token grant. Concurrent refreshes of
+    /// the same connection are coalesced into a single request.
+    pub async fn access_token(
+        &self,
+        user: &U,
+        provider_id: &str,
+    ) -> Result<SecretString, ShieldError> {
+        let provider = self
+            .oauth_provider_by_id_or_slug(provider_id)
+            .await?
+            .ok_or_else(|| ProviderError::NotFound(Some(provider_id.to_owned())))?;
+
+        let connection = self
+            .storage
+            .user_oauth_connections(&user.id(), Some(&provider.id))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                StorageError::NotFound("OauthConnection".to_owned(), provider.id.clone())
+            })?;
+
+        access_token(&self.token_refresher, &provider, &*self.storage, connection).await
+    }
+
     async fn oauth_provider_by_id_or_slug(
         &self,
         provider_id: &str,
@@ -82,6 +116,10 @@ impl<U: User + 'static> Method for OauthMethod<U> {
                 self.options.clone(),
                 self.storage.clone(),
             )),
+            Box::new(OauthSignOutAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
         ]
     }
 
@@ -115,6 +153,112 @@ impl<U: User + 'static> Method for OauthMethod<U> {
             .user_oauth_connections(user_id, provider_id)
             .await?)
     }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        Ok(self
+            .storage
+            .user_oauth_connections(user_id, None)
+            .await?
+            .into_iter()
+            .map(|connection| UserConnection {
+                id: connection.id,
+                method_id: OAUTH_METHOD_ID.to_owned(),
+                provider_id: Some(connection.provider_id),
+                identifier: Some(connection.identifier),
+                sign_in: true,
+                unlinkable: true,
+            })
+            .collect())
+    }
+
+    async fn unlink_user_connection(
+        &self,
+        user_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        let connection = self
+            .storage
+            .oauth_connection_by_id(connection_id)
+            .await?
+            .filter(|connection| connection.user_id == user_id)
+            .ok_or_else(|| {
+                StorageError::NotFound("OauthConnection".to_owned(), connection_id.to_owned())
+            })?;
+
+        let revocation = async {
+            let provider = self
+                .oauth_provider_by_id_or_slug(&connection.provider_id)
+                .await?
+                .ok_or_else(|| ProviderError::NotFound(Some(connection.provider_id.clone())))?;
+
+            revoke_oauth_connection(&provider, &connection).await
+        };
+
+        if let Err(err) = revocation.await {
+            // Failing to revoke the tokens should not prevent the user from unlinking the connection.
+            warn!("failed to revoke OAuth tokens: {err}");
+        }
+
+        Ok(self.storage.delete_oauth_connection(&connection.id).await?)
+    }
 }
 
 erased_method!(OauthMethod, <U: User>);
+
+#[cfg(test)]
+mod tests {
+    use shield::{Method, ShieldError};
+
+    use crate::{
+        connection::CreateOauthConnection,
+        provider::OauthProvider,
+        storage::{
+            OauthStorage,
+            tests::{TestStorage, TestUser},
+        },
+    };
+
+    use super::OauthMethod;
+
+    #[tokio::test]
+    async fn unlink_user_connection_when_revocation_fails() -> Result<(), ShieldError> {
+        let storage = TestStorage::default();
+        let method =
+            OauthMethod::<TestUser>::new(storage.clone()).with_providers([OauthProvider::builder(
+            )
+            .id("test")
+            .name("Test")
+            .client_id("client")
+            .user_url("https://127.0.0.1:1/user")
+            // Nothing listens on this port, so the revocation request fails.
+            .revocation_url("https://127.0.0.1:1/revoke")
+            .build()]);
+
+        let connection = storage
+            .create_oauth_connection(CreateOauthConnection {
+                identifier: "identifier".to_owned(),
+                token_type: "bearer".to_owned(),
+                access_token: "access".into(),
+                refresh_token: Some("refresh".into()),
+                expired_at: None,
+                scopes: None,
+                provider_id: "test".to_owned(),
+                user_id: "1".to_owned(),
+            })
+            .await?;
+
+        assert!(matches!(
+            method.unlink_user_connection("2", &connection.id).await,
+            Err(ShieldError::Storage(_))
+        ));
+
+        method.unlink_user_connection("1", &connection.id).await?;
+
+        assert!(storage.connections().is_empty());
+
+        Ok(())
+    }
+}
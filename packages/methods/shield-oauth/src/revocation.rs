@@ -0,0 +1,49 @@
+use oauth2::{AccessToken, RefreshToken, StandardRevocableToken, url::form_urlencoded::parse};
+use secrecy::ExposeSecret;
+use shield::{ConfigurationError, ShieldError};
+
+use crate::{client::async_http_client, connection::OauthConnection, provider::OauthProvider};
+
+/// Revoke the tokens of the connection, if the provider has a revocation URL.
+pub(crate) async fn revoke_oauth_connection(
+    provider: &OauthProvider,
+    connection: &OauthConnection,
+) -> Result<(), ShieldError> {
+    if provider.revocation_url.is_none() {
+        return Ok(());
+    }
+
+    let client = provider.oauth_client().await?;
+    let async_http_client = async_http_client()?;
+
+    let mut tokens = vec![StandardRevocableToken::AccessToken(AccessToken::new(
+        connection.access_token.expose_secret().to_owned(),
+    ))];
+    if let Some(refresh_token) = &connection.refresh_token {
+        tokens.push(StandardRevocableToken::RefreshToken(RefreshToken::new(
+            refresh_token.expose_secret().to_owned(),
+        )));
+    }
+
+    for token in tokens {
+        let mut revocation_request = client
+            .revoke_token(token)
+            .map_err(|err| ConfigurationError::Invalid(err.to_string()))?;
+
+        if let Some(revocation_url_params) = &provider.revocation_url_params {
+            let params = parse(revocation_url_params.trim_start_matches('?').as_bytes());
+
+            for (name, value) in params {
+                revocation_request =
+                    revocation_request.add_extra_param(name.into_owned(), value.into_owned());
+            }
+        }
+
+        revocation_request
+            .request_async(&async_http_client)
+            .await
+            .map_err(|err| ShieldError::Request(err.to_string()))?;
+    }
+
+    Ok(())
+}
@@ -45,3 +45,242 @@ pub trait OauthStorage<U: User>: Storage<U> + Sync {
         provider_id: Option<&str>,
     ) -> Result<Vec<OauthConnection>, StorageError>;
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+    use shield::{
+        CreateEmailAddress, CreateUser, EmailAddress, Storage, StorageError, UpdateUser, User,
+    };
+
+    use crate::{
+        connection::{CreateOauthConnection, OauthConnection, UpdateOauthConnection},
+        provider::OauthProvider,
+    };
+
+    use super::OauthStorage;
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct TestUser {
+        id: String,
+        name: Option<String>,
+        email: String,
+        is_verified: bool,
+    }
+
+    #[async_trait]
+    impl User for TestUser {
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn name(&self) -> Option<String> {
+            self.name.clone()
+        }
+
+        async fn email_addresses(&self) -> Result<Vec<EmailAddress>, StorageError> {
+            Ok(vec![EmailAddress {
+                id: self.id.clone(),
+                email: self.email.clone(),
+                is_primary: true,
+                is_verified: self.is_verified,
+                verification_token: None,
+                verification_token_expired_at: None,
+                verified_at: None,
+                user_id: self.id.clone(),
+            }])
+        }
+
+        fn additional(&self) -> Option<impl Serialize> {
+            None::<()>
+        }
+    }
+
+    #[derive(Clone, Default)]
+    pub struct TestStorage {
+        users: Arc<Mutex<Vec<TestUser>>>,
+        connections: Arc<Mutex<Vec<OauthConnection>>>,
+    }
+
+    impl TestStorage {
+        pub fn users(&self) -> Vec<TestUser> {
+            self.users.lock().expect("lock").clone()
+        }
+
+        pub fn connections(&self) -> Vec<OauthConnection> {
+            self.connections.lock().expect("lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl Storage<TestUser> for TestStorage {
+        fn id(&self) -> String {
+            "test".to_owned()
+        }
+
+        async fn user_by_id(&self, user_id: &str) -> Result<Option<TestUser>, StorageError> {
+            Ok(self.users().into_iter().find(|user| user.id == user_id))
+        }
+
+        async fn user_by_email(&self, email: &str) -> Result<Option<TestUser>, StorageError> {
+            Ok(self
+                .users()
+                .into_iter()
+                .find(|user| user.email.eq_ignore_ascii_case(email)))
+        }
+
+        async fn create_user(
+            &self,
+            user: CreateUser,
+            email_address: CreateEmailAddress,
+        ) -> Result<TestUser, StorageError> {
+            let mut users = self.users.lock().expect("lock");
+            let user = TestUser {
+                id: (users.len() + 1).to_string(),
+                name: user.name,
+                email: email_address.email,
+                is_verified: email_address.is_verified,
+            };
+            users.push(user.clone());
+
+            Ok(user)
+        }
+
+        async fn update_user(&self, user: UpdateUser) -> Result<TestUser, StorageError> {
+            let mut users = self.users.lock().expect("lock");
+            let existing_user = users
+                .iter_mut()
+                .find(|existing_user| existing_user.id == user.id)
+                .ok_or_else(|| StorageError::NotFound("User".to_owned(), user.id.clone()))?;
+
+            if let Some(name) = user.name {
+                existing_user.name = name;
+            }
+
+            Ok(existing_user.clone())
+        }
+
+        async fn delete_user(&self, user_id: &str) -> Result<(), StorageError> {
+            self.users
+                .lock()
+                .expect("lock")
+                .retain(|user| user.id != user_id);
+
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl OauthStorage<TestUser> for TestStorage {
+        async fn oauth_providers(&self) -> Result<Vec<OauthProvider>, StorageError> {
+            Ok(vec![])
+        }
+
+        async fn oauth_provider_by_id_or_slug(
+            &self,
+            _provider_id: &str,
+        ) -> Result<Option<OauthProvider>, StorageError> {
+            Ok(None)
+        }
+
+        async fn oauth_connection_by_id(
+            &self,
+            connection_id: &str,
+        ) -> Result<Option<OauthConnection>, StorageError> {
+            Ok(self
+                .connections()
+                .into_iter()
+                .find(|connection| connection.id == connection_id))
+        }
+
+        async fn oauth_connection_by_identifier(
+            &self,
+            provider_id: &str,
+            identifier: &str,
+        ) -> Result<Option<OauthConnection>, StorageError> {
+            Ok(self.connections().into_iter().find(|connection| {
+                connection.provider_id == provider_id && connection.identifier == identifier
+            }))
+        }
+
+        async fn create_oauth_connection(
+            &self,
+            connection: CreateOauthConnection,
+        ) -> Result<OauthConnection, StorageError> {
+            let mut connections = self.connections.lock().expect("lock");
+            let connection = OauthConnection {
+                id: (connections.len() + 1).to_string(),
+                identifier: connection.identifier,
+                token_type: connection.token_type,
+                access_token: connection.access_token,
+                refresh_token: connection.refresh_token,
+                expired_at: connection.expired_at,
+                scopes: connection.scopes,
+                provider_id: connection.provider_id,
+                user_id: connection.user_id,
+            };
+            connections.push(connection.clone());
+
+            Ok(connection)
+        }
+
+        async fn update_oauth_connection(
+            &self,
+            connection: UpdateOauthConnection,
+        ) -> Result<OauthConnection, StorageError> {
+            let mut connections = self.connections.lock().expect("lock");
+            let existing_connection = connections
+                .iter_mut()
+                .find(|existing_connection| existing_connection.id == connection.id)
+                .ok_or_else(|| {
+                    StorageError::NotFound("OauthConnection".to_owned(), connection.id.clone())
+                })?;
+
+            if let Some(token_type) = connection.token_type {
+                existing_connection.token_type = token_type;
+            }
+            if let Some(access_token) = connection.access_token {
+                existing_connection.access_token = access_token;
+            }
+            if let Some(refresh_token) = connection.refresh_token {
+                existing_connection.refresh_token = refresh_token;
+            }
+            if let Some(expired_at) = connection.expired_at {
+                existing_connection.expired_at = expired_at;
+            }
+            if let Some(scopes) = connection.scopes {
+                existing_connection.scopes = scopes;
+            }
+
+            Ok(existing_connection.clone())
+        }
+
+        async fn delete_oauth_connection(&self, connection_id: &str) -> Result<(), StorageError> {
+            self.connections
+                .lock()
+                .expect("lock")
+                .retain(|connection| connection.id != connection_id);
+
+            Ok(())
+        }
+
+        async fn user_oauth_connections(
+            &self,
+            user_id: &str,
+            provider_id: Option<&str>,
+        ) -> Result<Vec<OauthConnection>, StorageError> {
+            Ok(self
+                .connections()
+                .into_iter()
+                .filter(|connection| {
+                    connection.user_id == user_id
+                        && provider_id
+                            .is_none_or(|provider_id| connection.provider_id == provider_id)
+                })
+                .collect())
+        }
+    }
+}
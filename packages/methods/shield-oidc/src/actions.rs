@@ -1,6 +1,11 @@
+mod backchannel_logout;
+mod frontchannel_logout;
 mod sign_in;
 mod sign_in_callback;
 mod sign_out;
 
+pub use backchannel_logout::*;
+pub use frontchannel_logout::*;
 pub use sign_in::*;
 pub use sign_in_callback::*;
+pub use sign_out::*;
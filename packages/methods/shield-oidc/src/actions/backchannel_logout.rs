@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use shield::{
+    Form, MethodAction, MethodSession, RegisteredSessionFilter, Request, RequestMethod, Response,
+    ResponseType, SessionAction, ShieldError, User, erased_method_action,
+};
+
+use crate::{
+    logout::{LogoutTokenReplayCache, verify_logout_token},
+    method::OIDC_METHOD_ID,
+    provider::OidcProvider,
+    session::OidcSession,
+    storage::OidcStorage,
+};
+
+pub const BACKCHANNEL_LOGOUT_ACTION_ID: &str = "backchannel-logout";
+const BACKCHANNEL_LOGOUT_ACTION_NAME: &str = "Back-channel logout";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BackchannelLogoutData {
+    pub logout_token: String,
+}
+
+pub struct OidcBackchannelLogoutAction<U: User> {
+    storage: Arc<dyn OidcStorage<U>>,
+    replay_cache: LogoutTokenReplayCache,
+}
+
+impl<U: User> OidcBackchannelLogoutAction<U> {
+    pub(crate) fn new(
+        storage: Arc<dyn OidcStorage<U>>,
+        replay_cache: LogoutTokenReplayCache,
+    ) -> Self {
+        Self {
+            storage,
+            replay_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<OidcProvider, OidcSession> for OidcBackchannelLogoutAction<U> {
+    fn id(&self) -> String {
+        BACKCHANNEL_LOGOUT_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        BACKCHANNEL_LOGOUT_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Back-channel logout with OpenID Connect"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Terminate the sessions identified by a logout token sent by the OpenID Connect provider."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    /// The logout token is posted by the OpenID provider.
+    fn csrf_protected(&self) -> bool {
+        false
+    }
+
+    fn condition(
+        &self,
+        _provider: &OidcProvider,
+        _session: &MethodSession<OidcSession>,
+    ) -> Result<bool, ShieldError> {
+        // Requested by the provider, so there is nothing to show to the user.
+        Ok(false)
+    }
+
+    async fn forms(&self, _provider: OidcProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        provider: OidcProvider,
+        _session: &MethodSession<OidcSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<BackchannelLogoutData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let provider_metadata = provider.oidc_provider_metadata().await?;
+        let claims = verify_logout_token(&provider, &provider_metadata, &data.logout_token)?;
+        self.replay_cache.insert(&claims)?;
+
+        let user_id = match &claims.sub {
+            Some(subject) => {
+                match self
+                    .storage
+                    .oidc_connection_by_identifier(&provider.id, subject)
+                    .await?
+                {
+                    Some(connection) => Some(connection.user_id),
+                    // The subject never signed in, so there are no sessions to terminate.
+                    None => return Ok(Response::new(ResponseType::Default)),
+                }
+            }
+            None => None,
+        };
+
+        Ok(
+            Response::new(ResponseType::Default).session_action(SessionAction::destroy_sessions(
+                RegisteredSessionFilter {
+                    user_id,
+                    method_id: Some(OIDC_METHOD_ID.to_owned()),
+                    provider_id: Some(provider.id),
+                    provider_session_id: claims.sid,
+                },
+            )),
+        )
+    }
+}
+
+erased_method_action!(OidcBackchannelLogoutAction, <U: User>);
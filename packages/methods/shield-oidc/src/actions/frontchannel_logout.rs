@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use shield::{
+    Form, MethodAction, MethodSession, RegisteredSessionFilter, Request, RequestMethod, Response,
+    ResponseType, SessionAction, ShieldError, erased_method_action,
+};
+
+use crate::{method::OIDC_METHOD_ID, provider::OidcProvider, session::OidcSession};
+
+pub const FRONTCHANNEL_LOGOUT_ACTION_ID: &str = "frontchannel-logout";
+const FRONTCHANNEL_LOGOUT_ACTION_NAME: &str = "Front-channel logout";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct FrontchannelLogoutData {
+    pub iss: Option<String>,
+    pub sid: Option<String>,
+}
+
+pub struct OidcFrontchannelLogoutAction;
+
+#[async_trait]
+impl MethodAction<OidcProvider, OidcSession> for OidcFrontchannelLogoutAction {
+    fn id(&self) -> String {
+        FRONTCHANNEL_LOGOUT_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        FRONTCHANNEL_LOGOUT_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Front-channel logout with OpenID Connect"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Terminate the sessions of a provider session, rendered by the OpenID Connect provider in a frame."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Get
+    }
+
+    fn condition(
+        &self,
+        _provider: &OidcProvider,
+        _session: &MethodSession<OidcSession>,
+    ) -> Result<bool, ShieldError> {
+        // Requested by the provider, so there is nothing to show to the user.
+        Ok(false)
+    }
+
+    async fn forms(&self, _provider: OidcProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        provider: OidcProvider,
+        session: &MethodSession<OidcSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<FrontchannelLogoutData>(request.query)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let sid = match (data.iss, data.sid) {
+            (Some(iss), Some(sid)) => {
+                let provider_metadata = provider.oidc_provider_metadata().await?;
+
+                if iss != provider_metadata.issuer().as_str() {
+                    return Err(ShieldError::Validation("Invalid issuer.".to_owned()));
+                }
+
+                Some(sid)
+            }
+            (None, None) => None,
+            _ => {
+                return Err(ShieldError::Validation(
+                    "The `iss` and `sid` parameters must be used together.".to_owned(),
+                ));
+            }
+        };
+
+        let mut response = Response::new(ResponseType::Default);
+
+        // The frame is requested with the cookies of the user, so the current session can be signed out directly.
+        if let Some(authentication) = &session.base.authentication
+            && authentication.method_id == OIDC_METHOD_ID
+            && authentication.provider_id.as_ref() == Some(&provider.id)
+            && (sid.is_none() || authentication.provider_session_id == sid)
+        {
+            response = response.session_action(SessionAction::unauthenticate());
+        }
+
+        if let Some(sid) = sid {
+            response =
+                response.session_action(SessionAction::destroy_sessions(RegisteredSessionFilter {
+                    method_id: Some(OIDC_METHOD_ID.to_owned()),
+                    provider_id: Some(provider.id),
+                    provider_session_id: Some(sid),
+                    ..Default::default()
+                }));
+        }
+
+        Ok(response)
+    }
+}
+
+erased_method_action!(OidcFrontchannelLogoutAction);
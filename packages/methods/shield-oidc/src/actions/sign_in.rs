@@ -100,42 +100,17 @@ impl MethodAction<OidcProvider, OidcSession> for OidcSignInAction {
     async fn call(
         &self,
         provider: OidcProvider,
-        _session: &MethodSession<OidcSession>,
+        session: &MethodSession<OidcSession>,
         request: Request,
     ) -> Result<Response, ShieldError> {
         let data = serde_json::from_value::<SignInData>(request.form_data)
             .map_err(|err| ShieldError::Validation(err.to_string()))?;
 
-        let redirect_url = data
-            .redirect_url
-            .map(|redirect_url| data.redirect_origin.join(&redirect_url))
-            .unwrap_or_else(|| data.redirect_origin.join(&self.options.sign_in_redirect))
-            .map_err(|err| ShieldError::Validation(format!("redirect URL parse error: {err}")))?;
-
-        if let Some(redirect_origins) = &self.options.redirect_origins {
-            let redirect_origin = Url::parse(&redirect_url.origin().ascii_serialization())
-                .map_err(|err| {
-                    ShieldError::Validation(format!("redirect origin parse error: {err}"))
-                })?;
-
-            if !redirect_origins.contains(&redirect_origin) {
-                return Err(ShieldError::Validation(format!(
-                    "redirect origin `{redirect_origin}` not allowed"
-                )));
-            }
-        }
-
-        if let Some(redirect_patterns) = &self.options.redirect_patterns {
-            let redirect_url_str = redirect_url.to_string();
-            if !redirect_patterns
-                .iter()
-                .any(|pattern| pattern.is_match(&redirect_url_str))
-            {
-                return Err(ShieldError::Validation(format!(
-                    "redirect URL `{redirect_url}` not allowed"
-                )));
-            }
-        }
+        let redirect_url = self.options.redirect_url(
+            &data.redirect_origin,
+            data.redirect_url.as_deref(),
+            &self.options.sign_in_redirect,
+        )?;
 
         let client = provider.oidc_client().await?;
 
@@ -172,19 +147,24 @@ impl MethodAction<OidcProvider, OidcSession> for OidcSignInAction {
 
         let (auth_url, csrf_token, nonce) = authorization_request.url();
 
-        Ok(Response::new(ResponseType::Redirect(auth_url.to_string()))
-            .session_action(SessionAction::unauthenticate())
-            .session_action(SessionAction::method_data(
-                &provider,
-                OidcSession {
-                    redirect_url: Some(redirect_url),
-                    csrf: Some(csrf_token.secret().clone()),
-                    nonce: Some(nonce.secret().clone()),
-                    pkce_verifier: pkce_code_challenge
-                        .map(|(_, pkce_code_verifier)| pkce_code_verifier.secret().clone()),
-                    oidc_connection_id: None,
-                },
-            )?))
+        let mut response = Response::new(ResponseType::Redirect(auth_url.to_string()));
+
+        // Keep an authenticated session, so the callback links the connection to the current user.
+        if session.base.authentication.is_none() {
+            response = response.session_action(SessionAction::unauthenticate());
+        }
+
+        Ok(response.session_action(SessionAction::method_data(
+            &provider,
+            OidcSession {
+                redirect_url: Some(redirect_url),
+                csrf: Some(csrf_token.secret().clone()),
+                nonce: Some(nonce.secret().clone()),
+                pkce_verifier: pkce_code_challenge
+                    .map(|(_, pkce_code_verifier)| pkce_code_verifier.secret().clone()),
+                oidc_connection_id: None,
+            },
+        )?))
     }
 }
 
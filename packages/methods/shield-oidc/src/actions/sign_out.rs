@@ -1,52 +1,322 @@
-// TODO: Add hooks to perform the logic below.
-
-// TODO: See [`OidcProvider::oidc_client`].
-
-// let provider = match request.provider_id {
-//     Some(provider_id) => self.oidc_provider_by_id_or_slug(&provider_id).await?,
-//     None => return Err(ProviderError::ProviderMissing.into()),
-// };
-
-// let connection_id = {
-//     let session_data = session.data();
-//     let session_data = session_data
-//         .lock()
-//         .map_err(|err| SessionError::Lock(err.to_string()))?;
-
-//     session_data.oidc_connection_id.clone()
-// };
-
-// if let Some(connection_id) = connection_id {
-//     if let Some(connection) = self.storage.oidc_connection_by_id(&connection_id).await? {
-//         debug!("revoking access token {:?}", connection.access_token);
-
-//         let token = AccessToken::new(connection.access_token);
-
-//         let client = subprovider.oidc_client().await?;
-
-//         let revocation_request = match client.revoke_token(token.into()) {
-//             Ok(revocation_request) => Some(revocation_request),
-//             Err(openidconnect::ConfigurationError::MissingUrl("revocation")) => None,
-//             Err(err) => return Err(ConfigurationError::Invalid(err.to_string()).into()),
-//         };
-
-//         if let Some(revocation_request) = revocation_request {
-//             let mut revocation_request = revocation_request;
-
-//             if let Some(revocation_url_params) = subprovider.revocation_url_params {
-//                 let params =
-//                     parse(revocation_url_params.trim_start_matches('?').as_bytes());
-
-//                 for (name, value) in params {
-//                     revocation_request = revocation_request
-//                         .add_extra_param(name.into_owned(), value.into_owned());
-//                 }
-//             }
-
-//             revocation_request
-//                 .request_async(async_http_client)
-//                 .await
-//                 .map_err(|err| ShieldError::Request(err.to_string()))?;
-//         }
-//     }
-// }
+use std::{str::FromStr, sync::Arc};
+
+use async_trait::async_trait;
+use openidconnect::{ClientId, LogoutRequest, PostLogoutRedirectUrl, core::CoreIdToken};
+use secrecy::ExposeSecret;
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue, MethodAction,
+    MethodSession, Provider, Request, RequestMethod, Response, ResponseType, SessionAction,
+    ShieldError, SignOutAction, User, erased_method_action,
+};
+use tracing::warn;
+use url::Url;
+
+use crate::{
+    method::OIDC_METHOD_ID, options::OidcOptions, provider::OidcProvider,
+    revocation::revoke_oidc_connection, session::OidcSession, storage::OidcStorage,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignOutData {
+    pub redirect_origin: Url,
+    pub redirect_url: Option<String>,
+}
+
+pub struct OidcSignOutAction<U: User> {
+    options: OidcOptions,
+    storage: Arc<dyn OidcStorage<U>>,
+}
+
+impl<U: User> OidcSignOutAction<U> {
+    pub fn new(options: OidcOptions, storage: Arc<dyn OidcStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<OidcProvider, OidcSession> for OidcSignOutAction<U> {
+    fn id(&self) -> String {
+        SignOutAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignOutAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign out with OpenID Connect"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign out with OpenID Connect, revoking the tokens and signing out at the provider."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        provider: &OidcProvider,
+        session: &MethodSession<OidcSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session
+            .base
+            .authentication
+            .as_ref()
+            .is_some_and(|authentication| {
+                authentication.method_id == OIDC_METHOD_ID
+                    && authentication.provider_id.as_ref() == Some(&provider.id)
+            }))
+    }
+
+    async fn forms(&self, provider: OidcProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "redirectOrigin".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Origin),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "redirectUrl".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Query {
+                        key: "redirectUrl".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: format!("Sign out of {}", provider.name()),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: OidcProvider,
+        session: &MethodSession<OidcSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignOutData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let redirect_url = self.options.redirect_url(
+            &data.redirect_origin,
+            data.redirect_url.as_deref(),
+            &self.options.sign_out_redirect,
+        )?;
+
+        let connection = match &session.method.oidc_connection_id {
+            Some(connection_id) => self.storage.oidc_connection_by_id(connection_id).await?,
+            None => None,
+        };
+
+        let provider_metadata = provider.oidc_provider_metadata().await?;
+        let end_session_url = provider_metadata
+            .additional_metadata()
+            .end_session_endpoint
+            .clone();
+
+        if let Some(connection) = &connection
+            && let Err(err) = revoke_oidc_connection(&provider, provider_metadata, connection).await
+        {
+            // Failing to revoke the tokens should not prevent the user from signing out.
+            warn!("failed to revoke OpenID Connect tokens: {err}");
+        }
+
+        let redirect_url = match end_session_url {
+            Some(end_session_url) => {
+                let mut logout_request = LogoutRequest::from(end_session_url)
+                    .set_client_id(ClientId::new(provider.client_id.clone()))
+                    .set_post_logout_redirect_uri(PostLogoutRedirectUrl::from_url(redirect_url));
+
+                if let Some(id_token) = connection
+                    .as_ref()
+                    .and_then(|connection| connection.id_token.as_ref())
+                {
+                    let id_token = CoreIdToken::from_str(id_token.expose_secret())
+                        .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+                    logout_request = logout_request.set_id_token_hint(&id_token);
+                }
+
+                logout_request.http_get_url()
+            }
+            None => redirect_url,
+        };
+
+        Ok(
+            Response::new(ResponseType::Redirect(redirect_url.to_string()))
+                .session_action(SessionAction::unauthenticate()),
+        )
+    }
+}
+
+erased_method_action!(OidcSignOutAction, <U: User>);
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use axum::Router;
+    use chrono::Utc;
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde_json::json;
+    use shield::{
+        BaseSession, MethodAction, MethodSession, Request, ResponseType, SessionAction, ShieldError,
+    };
+    use url::Url;
+
+    use crate::{
+        client::tests::serve,
+        connection::CreateOidcConnection,
+        options::OidcOptions,
+        provider::OidcProvider,
+        session::OidcSession,
+        storage::{
+            OidcStorage,
+            tests::{TestStorage, TestUser},
+        },
+    };
+
+    use super::OidcSignOutAction;
+
+    async fn sign_out(
+        url: &str,
+        id_token: Option<&str>,
+    ) -> Result<(Url, Vec<SessionAction>), ShieldError> {
+        let provider = OidcProvider::builder()
+            .id("test")
+            .name("Test")
+            .client_id("client")
+            .discovery_url(url)
+            // Nothing listens on this port, so the revocation request fails.
+            .revocation_url("https://127.0.0.1:1/revoke")
+            .build();
+
+        let storage = TestStorage::default();
+        let connection = storage
+            .create_oidc_connection(CreateOidcConnection {
+                identifier: "subject".to_owned(),
+                token_type: "bearer".to_owned(),
+                access_token: "access".into(),
+                refresh_token: Some("refresh".into()),
+                id_token: id_token.map(Into::into),
+                expired_at: None,
+                scopes: None,
+                provider_id: "test".to_owned(),
+                user_id: "1".to_owned(),
+            })
+            .await?;
+
+        let base = BaseSession::default();
+        let method = OidcSession {
+            oidc_connection_id: Some(connection.id),
+            ..Default::default()
+        };
+
+        let response =
+            OidcSignOutAction::<TestUser>::new(OidcOptions::default(), Arc::new(storage))
+                .call(
+                    provider,
+                    &MethodSession {
+                        base: &base,
+                        method: &method,
+                    },
+                    Request::new(
+                        json!({}),
+                        json!({
+                            "redirectOrigin": "https://app.example.com",
+                            "redirectUrl": "/signed-out",
+                        }),
+                    ),
+                )
+                .await?;
+
+        let ResponseType::Redirect(redirect_url) = response.r#type else {
+            panic!("sign out should redirect");
+        };
+
+        Ok((
+            Url::parse(&redirect_url).expect("redirect URL should be valid"),
+            response.session_actions,
+        ))
+    }
+
+    #[tokio::test]
+    async fn rp_initiated_logout() -> Result<(), ShieldError> {
+        let url = serve(|_| Router::new()).await;
+        let id_token = jsonwebtoken::encode(
+            &Header::default(),
+            &json!({
+                "iss": url,
+                "aud": "client",
+                "sub": "subject",
+                "iat": Utc::now().timestamp(),
+                "exp": Utc::now().timestamp() + 3600,
+            }),
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .expect("ID token should be encoded");
+
+        let (redirect_url, session_actions) = sign_out(&url, Some(&id_token)).await?;
+        let query = redirect_url
+            .query_pairs()
+            .into_owned()
+            .collect::<HashMap<_, _>>();
+
+        assert!(redirect_url.as_str().starts_with(&format!("{url}/logout?")));
+        assert_eq!(Some(&id_token), query.get("id_token_hint"));
+        assert_eq!(Some("client"), query.get("client_id").map(String::as_str));
+        assert_eq!(
+            Some("https://app.example.com/signed-out"),
+            query.get("post_logout_redirect_uri").map(String::as_str)
+        );
+        assert!(matches!(
+            session_actions.as_slice(),
+            [SessionAction::Unauthenticate]
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rp_initiated_logout_without_id_token() -> Result<(), ShieldError> {
+        let url = serve(|_| Router::new()).await;
+
+        let (redirect_url, session_actions) = sign_out(&url, None).await?;
+        let query = redirect_url
+            .query_pairs()
+            .into_owned()
+            .collect::<HashMap<_, _>>();
+
+        assert!(redirect_url.as_str().starts_with(&format!("{url}/logout?")));
+        assert!(!query.contains_key("id_token_hint"));
+        assert_eq!(
+            Some("https://app.example.com/signed-out"),
+            query.get("post_logout_redirect_uri").map(String::as_str)
+        );
+        assert!(matches!(
+            session_actions.as_slice(),
+            [SessionAction::Unauthenticate]
+        ));
+
+        Ok(())
+    }
+}
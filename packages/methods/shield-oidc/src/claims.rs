@@ -25,6 +25,13 @@ impl Claims {
         }
     }
 
+    pub fn email_verified(&self) -> Option<bool> {
+        match &self {
+            Claims::IdToken(id_token_claims) => id_token_claims.email_verified(),
+            Claims::UserInfo(user_info_claims) => user_info_claims.email_verified(),
+        }
+    }
+
     pub fn name(&self) -> Option<&LocalizedClaim<EndUserName>> {
         match &self {
             Claims::IdToken(id_token_claims) => id_token_claims.name(),
@@ -7,3 +7,48 @@ pub fn async_http_client() -> Result<reqwest::Client, ConfigurationError> {
         .build()
         .map_err(|err| ConfigurationError::Invalid(err.to_string()))
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use axum::{Json, Router, routing::get};
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    /// Serve an OpenID Connect provider with discovery on a local port, returning its issuer URL.
+    ///
+    /// The router is created with the issuer URL and should handle the `/token`, `/userinfo`,
+    /// `/revoke` and `/logout` endpoints it needs.
+    pub async fn serve(router: impl FnOnce(&str) -> Router) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("listener should bind");
+        let url = format!(
+            "http://{}",
+            listener.local_addr().expect("listener should have address")
+        );
+
+        let metadata = json!({
+            "issuer": url,
+            "authorization_endpoint": format!("{url}/authorize"),
+            "token_endpoint": format!("{url}/token"),
+            "userinfo_endpoint": format!("{url}/userinfo"),
+            "jwks_uri": format!("{url}/jwks"),
+            "revocation_endpoint": format!("{url}/revoke"),
+            "end_session_endpoint": format!("{url}/logout"),
+            "response_types_supported": ["code"],
+            "subject_types_supported": ["public"],
+            "id_token_signing_alg_values_supported": ["RS256"],
+        });
+
+        let router = router(&url)
+            .route(
+                "/.well-known/openid-configuration",
+                get(move || async move { Json(metadata) }),
+            )
+            .route("/jwks", get(|| async { Json(json!({ "keys": [] })) }));
+
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        url
+    }
+}
@@ -3,12 +3,15 @@ mod builders;
 mod claims;
 mod client;
 mod connection;
+mod logout;
 mod metadata;
 mod method;
 mod options;
 mod provider;
+mod revocation;
 mod session;
 mod storage;
+mod token;
 
 pub use builders::*;
 pub use connection::*;
@@ -0,0 +1,206 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{
+    DecodingKey, Validation, dangerous::insecure_decode, decode, decode_header, jwk::Jwk,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use shield::{ConfigurationError, ShieldError};
+
+use crate::{metadata::OidcProviderMetadata, provider::OidcProvider};
+
+const BACKCHANNEL_LOGOUT_EVENT: &str = "http://schemas.openid.net/event/backchannel-logout";
+
+/// Logout tokens issued longer ago are rejected, which bounds how long token IDs are remembered.
+const LOGOUT_TOKEN_MAX_AGE: Duration = Duration::minutes(5);
+
+/// Allowed clock skew for logout tokens issued in the future.
+const LOGOUT_TOKEN_LEEWAY: Duration = Duration::seconds(60);
+
+#[derive(Debug, Deserialize)]
+struct SessionIdClaims {
+    sid: Option<String>,
+}
+
+/// Session ID (`sid` claim) of an ID token which has already been verified.
+pub(crate) fn id_token_session_id(id_token: &str) -> Option<String> {
+    insecure_decode::<SessionIdClaims>(id_token)
+        .ok()
+        .and_then(|token_data| token_data.claims.sid)
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LogoutTokenClaims {
+    pub sub: Option<String>,
+    pub sid: Option<String>,
+    iss: String,
+    iat: i64,
+    jti: String,
+    #[serde(default)]
+    events: HashMap<String, Value>,
+    nonce: Option<Value>,
+}
+
+/// Verify a back-channel logout token against the JSON Web Key Set of the provider.
+pub(crate) fn verify_logout_token(
+    provider: &OidcProvider,
+    provider_metadata: &OidcProviderMetadata,
+    logout_token: &str,
+) -> Result<LogoutTokenClaims, ShieldError> {
+    let header =
+        decode_header(logout_token).map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_required_spec_claims(&["iss", "aud"]);
+    validation.set_issuer(&[provider_metadata.issuer().as_str()]);
+    validation.set_audience(&[&provider.client_id]);
+
+    let jwks = serde_json::to_value(provider_metadata.jwks())
+        .map_err(|err| ConfigurationError::Invalid(err.to_string()))?;
+
+    // Keys which are not supported for verification are skipped.
+    let keys = jwks
+        .get("keys")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|key| serde_json::from_value::<Jwk>(key.clone()).ok())
+        .filter(|key| header.kid.is_none() || key.common.key_id.as_ref() == header.kid.as_ref());
+
+    let mut claims = None;
+    for key in keys {
+        let Ok(decoding_key) = DecodingKey::from_jwk(&key) else {
+            continue;
+        };
+
+        if let Ok(token_data) =
+            decode::<LogoutTokenClaims>(logout_token, &decoding_key, &validation)
+        {
+            claims = Some(token_data.claims);
+            break;
+        }
+    }
+
+    let claims = claims
+        .ok_or_else(|| ShieldError::Validation("Logout token could not be verified.".to_owned()))?;
+
+    if !claims.events.contains_key(BACKCHANNEL_LOGOUT_EVENT) {
+        return Err(ShieldError::Validation(
+            "Logout token is missing the back-channel logout event.".to_owned(),
+        ));
+    }
+
+    if claims.nonce.is_some() {
+        return Err(ShieldError::Validation(
+            "Logout token must not contain a nonce.".to_owned(),
+        ));
+    }
+
+    if claims.sub.is_none() && claims.sid.is_none() {
+        return Err(ShieldError::Validation(
+            "Logout token is missing a subject or session ID.".to_owned(),
+        ));
+    }
+
+    if !is_fresh(claims.iat) {
+        return Err(ShieldError::Validation(
+            "Logout token is not fresh.".to_owned(),
+        ));
+    }
+
+    Ok(claims)
+}
+
+fn is_fresh(issued_at: i64) -> bool {
+    let now = Utc::now();
+
+    DateTime::from_timestamp(issued_at, 0).is_some_and(|issued_at| {
+        issued_at >= now - LOGOUT_TOKEN_MAX_AGE && issued_at <= now + LOGOUT_TOKEN_LEEWAY
+    })
+}
+
+/// Issuer (`iss` claim) and token ID (`jti` claim) of a logout token.
+type LogoutTokenId = (String, String);
+
+/// Token IDs (`jti` claim) of recently used logout tokens, so they can not be replayed.
+///
+/// Only logout tokens within [`LOGOUT_TOKEN_MAX_AGE`] are accepted, so older token IDs are forgotten.
+#[derive(Clone, Default)]
+pub(crate) struct LogoutTokenReplayCache {
+    token_ids: Arc<Mutex<HashMap<LogoutTokenId, DateTime<Utc>>>>,
+}
+
+impl LogoutTokenReplayCache {
+    /// Remember the token ID of the logout token, failing if it has been used before.
+    pub(crate) fn insert(&self, claims: &LogoutTokenClaims) -> Result<(), ShieldError> {
+        let now = Utc::now();
+
+        let mut token_ids = self
+            .token_ids
+            .lock()
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        token_ids.retain(|_, used_at| *used_at > now - LOGOUT_TOKEN_MAX_AGE - LOGOUT_TOKEN_LEEWAY);
+
+        if token_ids
+            .insert((claims.iss.clone(), claims.jti.clone()), now)
+            .is_some()
+        {
+            return Err(ShieldError::Validation(
+                "Logout token has already been used.".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::{Duration, Utc};
+
+    use super::{LogoutTokenClaims, LogoutTokenReplayCache, is_fresh};
+
+    fn claims(iss: &str, jti: &str) -> LogoutTokenClaims {
+        LogoutTokenClaims {
+            sub: Some("subject".to_owned()),
+            sid: None,
+            iss: iss.to_owned(),
+            iat: Utc::now().timestamp(),
+            jti: jti.to_owned(),
+            events: HashMap::new(),
+            nonce: None,
+        }
+    }
+
+    #[test]
+    fn logout_token_freshness() {
+        let now = Utc::now();
+
+        assert!(is_fresh(now.timestamp()));
+        assert!(is_fresh((now - Duration::minutes(4)).timestamp()));
+        assert!(!is_fresh((now - Duration::minutes(6)).timestamp()));
+        assert!(!is_fresh((now + Duration::minutes(2)).timestamp()));
+    }
+
+    #[test]
+    fn logout_token_replay() {
+        let replay_cache = LogoutTokenReplayCache::default();
+
+        assert!(replay_cache.insert(&claims("issuer", "first")).is_ok());
+        assert!(replay_cache.insert(&claims("issuer", "second")).is_ok());
+        assert!(replay_cache.insert(&claims("other", "first")).is_ok());
+
+        assert!(
+            replay_cache
+                .insert(&claims("issuer", "first"))
+                .is_err_and(|err| err.to_string().contains("already been used"))
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use openidconnect::{
-    AdditionalProviderMetadata, IntrospectionUrl, ProviderMetadata, RevocationUrl,
+    AdditionalProviderMetadata, EndSessionUrl, IntrospectionUrl, ProviderMetadata, RevocationUrl,
     core::{
         CoreAuthDisplay, CoreClaimName, CoreClaimType, CoreClientAuthMethod, CoreGrantType,
         CoreJsonWebKey, CoreJweContentEncryptionAlgorithm, CoreJweKeyManagementAlgorithm,
@@ -14,6 +14,8 @@ pub struct NonStandardProviderMetadata {
     pub introspection_endpoint: Option<IntrospectionUrl>,
     #[serde(default)]
     pub revocation_endpoint: Option<RevocationUrl>,
+    #[serde(default)]
+    pub end_session_endpoint: Option<EndSessionUrl>,
 }
 
 impl AdditionalProviderMetadata for NonStandardProviderMetadata {}
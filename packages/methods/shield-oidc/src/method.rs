@@ -1,15 +1,26 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use shield::{Method, MethodAction, ShieldError, User, erased_method};
+use secrecy::SecretString;
+use shield::{
+    Method, MethodAction, ProviderError, ShieldError, StorageError, TokenRefresher, User,
+    UserConnection, erased_method,
+};
+use tracing::warn;
 
 use crate::{
     OidcConnection,
-    actions::{OidcSignInAction, OidcSignInCallbackAction},
+    actions::{
+        OidcBackchannelLogoutAction, OidcFrontchannelLogoutAction, OidcSignInAction,
+        OidcSignInCallbackAction, OidcSignOutAction,
+    },
+    logout::LogoutTokenReplayCache,
     options::OidcOptions,
     provider::OidcProvider,
+    revocation::revoke_oidc_connection,
     session::OidcSession,
     storage::OidcStorage,
+    token::access_token,
 };
 
 pub const OIDC_METHOD_ID: &str = "oidc";
@@ -18,6 +29,8 @@ pub struct OidcMethod<U: User> {
     options: OidcOptions,
     providers: Vec<OidcProvider>,
     storage: Arc<dyn OidcStorage<U>>,
+    token_refresher: TokenRefresher,
+    logout_token_replay_cache: LogoutTokenReplayCache,
 }
 
 impl<U: User> OidcMethod<U> {
@@ -26,6 +39,8 @@ impl<U: User> OidcMethod<U> {
             options: OidcOptions::default(),
             providers: vec![],
             storage: Arc::new(storage),
+            token_refresher: TokenRefresher::default(),
+            logout_token_replay_cache: LogoutTokenReplayCache::default(),
         }
     }
 
@@ -39,6 +54,33 @@ impl<U: User> OidcMethod<U> {
         self
     }
 
+    /// Get a non-expired access token of the user's connection with the provider.
+    ///
+    /// An expired access token is refreshed with the refresh token grant. Concurrent refreshes of
+    /// the same connection are coalesced into a single request.
+    pub async fn access_token(
+        &self,
+        user: &U,
+        provider_id: &str,
+    ) -> Result<SecretString, ShieldError> {
+        let provider = self
+            .oidc_provider_by_id_or_slug(provider_id)
+            .await?
+            .ok_or_else(|| ProviderError::NotFound(Some(provider_id.to_owned())))?;
+
+        let connection = self
+            .storage
+            .user_oidc_connections(&user.id(), Some(&provider.id))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                StorageError::NotFound("OidcConnection".to_owned(), provider.id.clone())
+            })?;
+
+        access_token(&self.token_refresher, &provider, &*self.storage, connection).await
+    }
+
     async fn oidc_provider_by_id_or_slug(
         &self,
         provider_id: &str,
@@ -80,6 +122,15 @@ impl<U: User + 'static> Method for OidcMethod<U> {
                 self.options.clone(),
                 self.storage.clone(),
             )),
+            Box::new(OidcSignOutAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(OidcBackchannelLogoutAction::new(
+                self.storage.clone(),
+                self.logout_token_replay_cache.clone(),
+            )),
+            Box::new(OidcFrontchannelLogoutAction),
         ]
     }
 
@@ -113,6 +164,59 @@ impl<U: User + 'static> Method for OidcMethod<U> {
             .user_oidc_connections(user_id, provider_id)
             .await?)
     }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        Ok(self
+            .storage
+            .user_oidc_connections(user_id, None)
+            .await?
+            .into_iter()
+            .map(|connection| UserConnection {
+                id: connection.id,
+                method_id: OIDC_METHOD_ID.to_owned(),
+                provider_id: Some(connection.provider_id),
+                identifier: Some(connection.identifier),
+                sign_in: true,
+                unlinkable: true,
+            })
+            .collect())
+    }
+
+    async fn unlink_user_connection(
+        &self,
+        user_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        let connection = self
+            .storage
+            .oidc_connection_by_id(connection_id)
+            .await?
+            .filter(|connection| connection.user_id == user_id)
+            .ok_or_else(|| {
+                StorageError::NotFound("OidcConnection".to_owned(), connection_id.to_owned())
+            })?;
+
+        let revocation = async {
+            let provider = self
+                .oidc_provider_by_id_or_slug(&connection.provider_id)
+                .await?
+                .ok_or_else(|| ProviderError::NotFound(Some(connection.provider_id.clone())))?;
+
+            let provider_metadata = provider.oidc_provider_metadata().await?;
+
+            revoke_oidc_connection(&provider, provider_metadata, &connection).await
+        };
+
+        if let Err(err) = revocation.await {
+            // Failing to revoke the tokens should not prevent the user from unlinking the connection.
+            warn!("failed to revoke OpenID Connect tokens: {err}");
+        }
+
+        Ok(self.storage.delete_oidc_connection(&connection.id).await?)
+    }
 }
 
 erased_method!(OidcMethod, <U: User>);
@@ -88,9 +88,52 @@ pub struct OidcProvider {
 
 impl OidcProvider {
     pub async fn oidc_client(&self) -> Result<OidcClient, ConfigurationError> {
+        self.oidc_client_from_metadata(self.oidc_provider_metadata().await?)
+    }
+
+    pub(crate) fn oidc_client_from_metadata(
+        &self,
+        provider_metadata: OidcProviderMetadata,
+    ) -> Result<OidcClient, ConfigurationError> {
+        let mut client = CoreClient::from_provider_metadata(
+            provider_metadata,
+            ClientId::new(self.client_id.clone()),
+            self.client_secret
+                .clone()
+                .map(|client_secret| ClientSecret::new(client_secret.expose_secret().to_owned())),
+        );
+
+        // TODO: Upstream: _option version of these (and other) functions which set the type to EndpointMaybeSet.
+
+        // if let Some(introspection_endpoint) = provider_metadata
+        //     .additional_metadata()
+        //     .introspection_endpoint
+        // {
+        //     client = client.set_introspection_url(introspection_endpoint);
+        // }
+        // if let Some(revocation_url) = provider_metadata.additional_metadata().revocation_endpoint {
+        //     client = client.set_introspection_url(revocation_url);
+        // }
+
+        if let Some(redirect_url) = &self.redirect_url {
+            client = client.set_redirect_uri(
+                RedirectUrl::new(redirect_url.clone())
+                    .map_err(|err| ConfigurationError::Invalid(err.to_string()))?,
+            );
+        }
+
+        // TODO: Client options.
+
+        Ok(client)
+    }
+
+    /// Provider metadata, either discovered or from the provider configuration.
+    pub(crate) async fn oidc_provider_metadata(
+        &self,
+    ) -> Result<OidcProviderMetadata, ConfigurationError> {
         let async_http_client = async_http_client()?;
 
-        let provider_metadata = if let Some(discovery_url) = &self.discovery_url {
+        Ok(if let Some(discovery_url) = &self.discovery_url {
             OidcProviderMetadata::discover_async(
                 // TODO: Consider stripping `/.well-known/openid-configuration` so `openidconnect` doesn't error.
                 IssuerUrl::new(discovery_url.clone())
@@ -153,6 +196,7 @@ impl OidcProvider {
                                 .map_err(|err| ConfigurationError::Invalid(err.to_string()))
                         })
                         .transpose()?,
+                    end_session_endpoint: None,
                 },
             );
 
@@ -177,38 +221,7 @@ impl OidcProvider {
             }
 
             provider_metadata
-        };
-
-        let mut client = CoreClient::from_provider_metadata(
-            provider_metadata,
-            ClientId::new(self.client_id.clone()),
-            self.client_secret
-                .clone()
-                .map(|client_secret| ClientSecret::new(client_secret.expose_secret().to_owned())),
-        );
-
-        // TODO: Upstream: _option version of these (and other) functions which set the type to EndpointMaybeSet.
-
-        // if let Some(introspection_endpoint) = provider_metadata
-        //     .additional_metadata()
-        //     .introspection_endpoint
-        // {
-        //     client = client.set_introspection_url(introspection_endpoint);
-        // }
-        // if let Some(revocation_url) = provider_metadata.additional_metadata().revocation_endpoint {
-        //     client = client.set_introspection_url(revocation_url);
-        // }
-
-        if let Some(redirect_url) = &self.redirect_url {
-            client = client.set_redirect_uri(
-                RedirectUrl::new(redirect_url.clone())
-                    .map_err(|err| ConfigurationError::Invalid(err.to_string()))?,
-            );
-        }
-
-        // TODO: Client options.
-
-        Ok(client)
+        })
     }
 }
 
@@ -0,0 +1,67 @@
+use openidconnect::{
+    AccessToken, RefreshToken, RevocationUrl, core::CoreRevocableToken, url::form_urlencoded::parse,
+};
+use secrecy::ExposeSecret;
+use shield::{ConfigurationError, ShieldError};
+
+use crate::{
+    client::async_http_client, connection::OidcConnection, metadata::OidcProviderMetadata,
+    provider::OidcProvider,
+};
+
+/// Revoke the tokens of the connection, if the provider has a revocation endpoint.
+pub(crate) async fn revoke_oidc_connection(
+    provider: &OidcProvider,
+    provider_metadata: OidcProviderMetadata,
+    connection: &OidcConnection,
+) -> Result<(), ShieldError> {
+    let revocation_url = match &provider.revocation_url {
+        Some(revocation_url) => Some(
+            RevocationUrl::new(revocation_url.clone())
+                .map_err(|err| ConfigurationError::Invalid(err.to_string()))?,
+        ),
+        None => provider_metadata
+            .additional_metadata()
+            .revocation_endpoint
+            .clone(),
+    };
+    let Some(revocation_url) = revocation_url else {
+        return Ok(());
+    };
+
+    let client = provider
+        .oidc_client_from_metadata(provider_metadata)?
+        .set_revocation_url(revocation_url);
+    let async_http_client = async_http_client()?;
+
+    let mut tokens = vec![CoreRevocableToken::AccessToken(AccessToken::new(
+        connection.access_token.expose_secret().to_owned(),
+    ))];
+    if let Some(refresh_token) = &connection.refresh_token {
+        tokens.push(CoreRevocableToken::RefreshToken(RefreshToken::new(
+            refresh_token.expose_secret().to_owned(),
+        )));
+    }
+
+    for token in tokens {
+        let mut revocation_request = client
+            .revoke_token(token)
+            .map_err(|err| ConfigurationError::Invalid(err.to_string()))?;
+
+        if let Some(revocation_url_params) = &provider.revocation_url_params {
+            let params = parse(revocation_url_params.trim_start_matches('?').as_bytes());
+
+            for (name, value) in params {
+                revocation_request =
+                    revocation_request.add_extra_param(name.into_owned(), value.into_owned());
+            }
+        }
+
+        revocation_request
+            .request_async(&async_http_client)
+            .await
+            .map_err(|err| ShieldError::Request(err.to_string()))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use openidconnect::{
+    OAuth2TokenResponse, RefreshToken, TokenResponse, core::CoreTokenResponse,
+    url::form_urlencoded::parse,
+};
+use secrecy::{ExposeSecret, SecretString};
+use shield::{ConfigurationError, ShieldError, StorageError, TokenRefresher, User};
+
+use crate::{
+    client::async_http_client,
+    connection::{OidcConnection, UpdateOidcConnection},
+    provider::OidcProvider,
+    storage::OidcStorage,
+};
+
+type ParsedTokenResponse = (
+    String,
+    SecretString,
+    Option<SecretString>,
+    Option<SecretString>,
+    Option<DateTime<FixedOffset>>,
+    Option<Vec<String>>,
+);
+
+pub(crate) fn parse_token_response(
+    token_response: CoreTokenResponse,
+) -> Result<ParsedTokenResponse, ShieldError> {
+    Ok((
+        token_response.token_type().as_ref().to_string(),
+        token_response.access_token().secret().as_str().into(),
+        token_response
+            .refresh_token()
+            .map(|refresh_token| refresh_token.secret().as_str().into()),
+        token_response
+            .id_token()
+            .map(|id_token| id_token.to_string().into()),
+        match token_response.expires_in() {
+            Some(expires_in) => Some(
+                (Utc::now()
+                    + Duration::from_std(expires_in)
+                        .map_err(|err| ShieldError::Validation(err.to_string()))?)
+                .into(),
+            ),
+            None => None,
+        },
+        token_response
+            .scopes()
+            .map(|scopes| scopes.iter().map(|scope| scope.to_string()).collect()),
+    ))
+}
+
+/// Get a non-expired access token for the connection, refreshing it if necessary.
+pub(crate) async fn access_token<U: User>(
+    token_refresher: &TokenRefresher,
+    provider: &OidcProvider,
+    storage: &dyn OidcStorage<U>,
+    connection: OidcConnection,
+) -> Result<SecretString, ShieldError> {
+    if !TokenRefresher::is_expired(connection.expired_at) {
+        return Ok(connection.access_token);
+    }
+
+    token_refresher
+        .refresh(&connection.id, refresh(provider, storage, &connection.id))
+        .await
+}
+
+async fn refresh<U: User>(
+    provider: &OidcProvider,
+    storage: &dyn OidcStorage<U>,
+    connection_id: &str,
+) -> Result<SecretString, ShieldError> {
+    // Another request might have refreshed the connection while waiting for the lock.
+    let connection = storage
+        .oidc_connection_by_id(connection_id)
+        .await?
+        .ok_or_else(|| {
+            StorageError::NotFound("OidcConnection".to_owned(), connection_id.to_owned())
+        })?;
+
+    if !TokenRefresher::is_expired(connection.expired_at) {
+        return Ok(connection.access_token);
+    }
+
+    let refresh_token = connection.refresh_token.as_ref().ok_or_else(|| {
+        ShieldError::Validation(
+            "Access token expired and connection has no refresh token.".to_owned(),
+        )
+    })?;
+
+    let client = provider.oidc_client().await?;
+    let refresh_token = RefreshToken::new(refresh_token.expose_secret().to_owned());
+
+    let mut token_request = client
+        .exchange_refresh_token(&refresh_token)
+        .map_err(|err| ConfigurationError::Missing(err.to_string()))?;
+
+    if let Some(token_url_params) = &provider.token_url_params {
+        let params = parse(token_url_params.trim_start_matches('?').as_bytes());
+
+        for (name, value) in params {
+            token_request = token_request.add_extra_param(name.into_owned(), value.into_owned());
+        }
+    }
+
+    let token_response = token_request
+        .request_async(&async_http_client()?)
+        .await
+        .map_err(|err| ShieldError::Request(err.to_string()))?;
+
+    let (token_type, access_token, refresh_token, id_token, expired_at, scopes) =
+        parse_token_response(token_response)?;
+
+    let connection = storage
+        .update_oidc_connection(UpdateOidcConnection {
+            id: connection.id,
+            token_type: Some(token_type),
+            access_token: Some(access_token),
+            refresh_token: refresh_token.map(Some),
+            id_token: id_token.map(Some),
+            expired_at: Some(expired_at),
+            scopes: scopes.map(Some),
+        })
+        .await?;
+
+    Ok(connection.access_token)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use axum::{Json, Router, routing::post};
+    use chrono::{TimeDelta, Utc};
+    use secrecy::ExposeSecret;
+    use serde_json::json;
+    use shield::{ShieldError, TokenRefresher};
+
+    use crate::{
+        client::tests::serve,
+        connection::CreateOidcConnection,
+        provider::OidcProvider,
+        storage::{
+            OidcStorage,
+            tests::{TestStorage, TestUser},
+        },
+    };
+
+    use super::access_token;
+
+    #[tokio::test]
+    async fn concurrent_refresh() -> Result<(), ShieldError> {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let url = serve(|_| {
+            Router::new().route(
+                "/token",
+                post({
+                    let refreshes = refreshes.clone();
+
+                    move || async move {
+                        let refresh = refreshes.fetch_add(1, Ordering::SeqCst) + 1;
+
+                        Json(json!({
+                            "access_token": format!("access-{refresh}"),
+                            "token_type": "bearer",
+                            "expires_in": 3600,
+                            "refresh_token": format!("refresh-{refresh}"),
+                        }))
+                    }
+                }),
+            )
+        })
+        .await;
+
+        let provider = OidcProvider::builder()
+            .id("test")
+            .name("Test")
+            .client_id("client")
+            .discovery_url(url)
+            .build();
+        let storage = TestStorage::default();
+        let connection = storage
+            .create_oidc_connection(CreateOidcConnection {
+                identifier: "subject".to_owned(),
+                token_type: "bearer".to_owned(),
+                access_token: "access-0".into(),
+                refresh_token: Some("refresh-0".into()),
+                id_token: None,
+                expired_at: Some((Utc::now() - TimeDelta::minutes(1)).into()),
+                scopes: None,
+                provider_id: "test".to_owned(),
+                user_id: "1".to_owned(),
+            })
+            .await?;
+
+        let token_refresher = TokenRefresher::default();
+        let access_token = |connection| {
+            access_token::<TestUser>(&token_refresher, &provider, &storage, connection)
+        };
+
+        let (first, second, third) = tokio::join!(
+            access_token(connection.clone()),
+            access_token(connection.clone()),
+            access_token(connection.clone()),
+        );
+
+        for token in [first?, second?, third?] {
+            assert_eq!("access-1", token.expose_secret());
+        }
+        assert_eq!(1, refreshes.load(Ordering::SeqCst));
+
+        let connection = storage
+            .oidc_connection_by_id(&connection.id)
+            .await?
+            .expect("connection should exist");
+        assert_eq!(
+            Some("refresh-1"),
+            connection
+                .refresh_token
+                .as_ref()
+                .map(|refresh_token| refresh_token.expose_secret())
+        );
+
+        // The refreshed token is used until it expires.
+        assert_eq!("access-1", access_token(connection).await?.expose_secret());
+        assert_eq!(1, refreshes.load(Ordering::SeqCst));
+
+        Ok(())
+    }
+}
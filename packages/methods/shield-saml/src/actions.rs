@@ -0,0 +1,5 @@
+mod sign_in;
+mod sign_in_callback;
+
+pub use sign_in::*;
+pub use sign_in_callback::*;
@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use shield::{
+    Form, Input, InputAddon, InputType, InputTypeHidden, InputTypeSubmit, InputValue, MethodAction,
+    MethodSession, Provider, Request, RequestMethod, Response, ResponseType, SessionAction,
+    ShieldError, SignInAction, erased_method_action,
+};
+use url::Url;
+
+use crate::{
+    options::SamlOptions,
+    provider::{SamlBinding, SamlProvider},
+    request::AuthnRequest,
+    session::SamlSession,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInData {
+    pub redirect_origin: Url,
+    pub redirect_url: Option<String>,
+}
+
+pub struct SamlSignInAction {
+    options: SamlOptions,
+}
+
+impl SamlSignInAction {
+    pub fn new(options: SamlOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[async_trait]
+impl MethodAction<SamlProvider, SamlSession> for SamlSignInAction {
+    fn id(&self) -> String {
+        SignInAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignInAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign in with SAML"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign in with SAML. For the HTTP-POST binding, the response contains the URL and form data to post to the identity provider."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self, provider: SamlProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "redirectOrigin".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Origin),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "redirectUrl".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden::default()),
+                    value: Some(InputValue::Query {
+                        key: "redirectUrl".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: format!("Sign in with {}", provider.name()),
+                    }),
+                    addon_start: provider
+                        .icon_url
+                        .as_ref()
+                        .map(|icon_url| InputAddon::Image {
+                            alt: format!("{} logo", provider.name()),
+                            src: icon_url.clone(),
+                        }),
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: SamlProvider,
+        session: &MethodSession<SamlSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignInData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let redirect_url = self.options.redirect_url(
+            &data.redirect_origin,
+            data.redirect_url.as_deref(),
+            &self.options.sign_in_redirect,
+        )?;
+
+        let identity_provider = provider.identity_provider()?;
+        let authn_request = AuthnRequest::new(&provider, &identity_provider);
+
+        let mut response = Response::new(match identity_provider.sso_binding {
+            SamlBinding::Redirect => ResponseType::Redirect(
+                authn_request
+                    .redirect_url(&identity_provider.sso_url)?
+                    .to_string(),
+            ),
+            SamlBinding::Post => ResponseType::Data(json!({
+                "url": identity_provider.sso_url,
+                "formData": {
+                    "SAMLRequest": authn_request.post_form_data(),
+                },
+            })),
+        });
+
+        // Keep an authenticated session, so the callback links the connection to the current user.
+        if session.base.authentication.is_none() {
+            response = response.session_action(SessionAction::unauthenticate());
+        }
+
+        Ok(response.session_action(SessionAction::method_data(
+            &provider,
+            SamlSession {
+                redirect_url: Some(redirect_url),
+                request_id: Some(authn_request.id),
+                saml_connection_id: None,
+            },
+        )?))
+    }
+}
+
+erased_method_action!(SamlSignInAction);
@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use shield::{
+    ConnectionEvent, ConnectionLinkEvent, CreateEmailAddress, CreateUser, Form, Hook, Hooks,
+    MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, SessionAction,
+    ShieldError, SignInCallbackAction, SignUpEvent, UpdateUser, User, UserEvent,
+    erased_method_action,
+};
+
+use crate::{
+    connection::{CreateSamlConnection, SamlConnection},
+    options::SamlOptions,
+    provider::SamlProvider,
+    replay::AssertionReplayCache,
+    response::{ResponseValidator, SamlAssertion},
+    session::SamlSession,
+    storage::SamlStorage,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SignInCallbackData {
+    #[serde(rename = "SAMLResponse")]
+    pub saml_response: String,
+}
+
+pub struct SamlSignInCallbackAction<U: User> {
+    options: SamlOptions,
+    storage: Arc<dyn SamlStorage<U>>,
+    replay_cache: AssertionReplayCache,
+}
+
+impl<U: User> SamlSignInCallbackAction<U> {
+    pub(crate) fn new(
+        options: SamlOptions,
+        storage: Arc<dyn SamlStorage<U>>,
+        replay_cache: AssertionReplayCache,
+    ) -> Self {
+        Self {
+            options,
+            storage,
+            replay_cache,
+        }
+    }
+
+    async fn create_user(
+        &self,
+        hooks: &Hooks,
+        provider: &SamlProvider,
+        assertion: &SamlAssertion,
+    ) -> Result<U, ShieldError> {
+        let Some(email) = assertion.email(provider) else {
+            return Err(ShieldError::Validation(
+                "Missing email address in SAML assertion.".to_owned(),
+            ));
+        };
+
+        let email_verified = provider.trust_email;
+
+        if let Some(user) = self.storage.user_by_email(email).await? {
+            if self.options.link_verified_email
+                && email_verified
+                && user.email_addresses().await?.iter().any(|email_address| {
+                    email_address.is_verified && email_address.email.eq_ignore_ascii_case(email)
+                })
+            {
+                return Ok(user);
+            }
+
+            return Err(ShieldError::Validation(format!(
+                "\
+                Email address `{email}` is already used by another account. \
+                To link a new provider, sign in with your existing account first. \
+                If this is not your account, please contact support for assistance.\
+                "
+            )));
+        }
+
+        let name = assertion.name(provider).map(ToOwned::to_owned);
+
+        hooks
+            .sign_up(
+                SignUpEvent {
+                    name: name.clone(),
+                    email: Some(email.to_owned()),
+                    ..SignUpEvent::new(provider)
+                },
+                self.storage.create_user(
+                    CreateUser { name },
+                    CreateEmailAddress {
+                        email: email.to_owned(),
+                        is_primary: true,
+                        is_verified: email_verified,
+                        verification_token: None,
+                        verification_token_expired_at: None,
+                        verified_at: email_verified.then(|| Utc::now().into()),
+                    },
+                ),
+            )
+            .await
+    }
+
+    async fn update_user(
+        &self,
+        hooks: &Hooks,
+        provider: &SamlProvider,
+        user_id: &str,
+        assertion: &SamlAssertion,
+    ) -> Result<U, ShieldError> {
+        hooks
+            .before_user_update(&UserEvent {
+                user_id: user_id.to_owned(),
+            })
+            .await?;
+
+        let user = self
+            .storage
+            .update_user(UpdateUser {
+                id: user_id.to_owned(),
+                name: assertion.name(provider).map(|name| Some(name.to_owned())),
+            })
+            .await?;
+
+        hooks
+            .user_updated(&UserEvent { user_id: user.id() })
+            .await?;
+
+        Ok(user)
+    }
+
+    async fn create_saml_connection(
+        &self,
+        hooks: &Hooks,
+        provider: &SamlProvider,
+        user_id: String,
+        assertion: &SamlAssertion,
+    ) -> Result<SamlConnection, ShieldError> {
+        hooks
+            .before_connection_link(&ConnectionLinkEvent::new(
+                provider,
+                &user_id,
+                Some(&assertion.name_id),
+            ))
+            .await?;
+
+        let connection = self
+            .storage
+            .create_saml_connection(CreateSamlConnection {
+                identifier: assertion.name_id.clone(),
+                provider_id: provider.id.clone(),
+                user_id,
+            })
+            .await?;
+
+        hooks
+            .connection_linked(&ConnectionEvent::new(
+                provider,
+                &connection.user_id,
+                &connection.id,
+                Some(&connection.identifier),
+            ))
+            .await?;
+
+        Ok(connection)
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<SamlProvider, SamlSession> for SamlSignInCallbackAction<U> {
+    fn id(&self) -> String {
+        SignInCallbackAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignInCallbackAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign in callback for SAML"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Assertion consumer service for SAML, receiving the response of the identity provider with the HTTP-POST binding."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    /// The response is posted by the identity provider and verified with the request ID instead.
+    fn csrf_protected(&self) -> bool {
+        false
+    }
+
+    fn condition(
+        &self,
+        provider: &SamlProvider,
+        session: &MethodSession<SamlSession>,
+    ) -> Result<bool, ShieldError> {
+        SignInCallbackAction::condition(provider, session)
+    }
+
+    async fn forms(&self, _provider: SamlProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn call(
+        &self,
+        provider: SamlProvider,
+        session: &MethodSession<SamlSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignInCallbackData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let identity_provider = provider.identity_provider()?;
+
+        let assertion = ResponseValidator {
+            provider: &provider,
+            identity_provider: &identity_provider,
+            request_id: session.method.request_id.as_deref(),
+            clock_skew: self.options.clock_skew,
+            now: Utc::now(),
+        }
+        .validate(&data.saml_response)?;
+
+        self.replay_cache.insert(
+            &identity_provider.entity_id,
+            &assertion,
+            self.options.clock_skew,
+        )?;
+
+        let connection = self
+            .storage
+            .saml_connection_by_identifier(&provider.id, &assertion.name_id)
+            .await?;
+
+        // Identity provider initiated responses are not bound to this session, so they could have
+        // been sent by another site. They only sign in and never link to the current user.
+        let authentication = session
+            .method
+            .request_id
+            .as_ref()
+            .and(session.base.authentication.as_ref());
+
+        let (connection, user) = match (connection, authentication) {
+            (Some(connection), Some(authentication)) => {
+                if connection.user_id != authentication.user_id {
+                    return Err(ShieldError::Validation(format!(
+                        "This {} account is already linked to another account.",
+                        provider.name
+                    )));
+                }
+
+                (connection, None)
+            }
+            (Some(connection), None) => {
+                let user = self
+                    .update_user(&request.hooks, &provider, &connection.user_id, &assertion)
+                    .await?;
+
+                (connection, Some(user))
+            }
+            (None, Some(authentication)) => {
+                let connection = self
+                    .create_saml_connection(
+                        &request.hooks,
+                        &provider,
+                        authentication.user_id.clone(),
+                        &assertion,
+                    )
+                    .await?;
+
+                (connection, None)
+            }
+            (None, None) => {
+                let user = self
+                    .create_user(&request.hooks, &provider, &assertion)
+                    .await?;
+
+                let connection = self
+                    .create_saml_connection(&request.hooks, &provider, user.id(), &assertion)
+                    .await?;
+
+                (connection, Some(user))
+            }
+        };
+
+        let mut response = Response::new(ResponseType::Redirect(
+            session
+                .method
+                .redirect_url
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| self.options.sign_in_redirect.clone()),
+        ));
+
+        // Linking a connection to the current user keeps the existing authentication.
+        if let Some(user) = user {
+            response = response.session_action(SessionAction::authenticate_with_provider_session(
+                &provider,
+                user,
+                assertion.session_index,
+            ));
+        }
+
+        Ok(response.session_action(SessionAction::method_data(
+            &provider,
+            SamlSession {
+                redirect_url: None,
+                request_id: None,
+                saml_connection_id: Some(connection.id),
+            },
+        )?))
+    }
+}
+
+erased_method_action!(SamlSignInCallbackAction, <U: User>);
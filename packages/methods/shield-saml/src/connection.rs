@@ -0,0 +1,15 @@
+#[derive(Clone, Debug)]
+pub struct SamlConnection {
+    pub id: String,
+    /// Name ID of the subject at the identity provider.
+    pub identifier: String,
+    pub provider_id: String,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateSamlConnection {
+    pub identifier: String,
+    pub provider_id: String,
+    pub user_id: String,
+}
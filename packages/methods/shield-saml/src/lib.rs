@@ -0,0 +1,20 @@
+mod actions;
+mod connection;
+mod metadata;
+mod method;
+mod options;
+mod provider;
+mod replay;
+mod request;
+mod response;
+mod session;
+mod signature;
+mod storage;
+mod xml;
+
+pub use connection::*;
+pub use metadata::SamlIdentityProviderMetadata;
+pub use method::*;
+pub use options::*;
+pub use provider::*;
+pub use storage::*;
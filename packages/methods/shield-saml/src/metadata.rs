@@ -0,0 +1,115 @@
+use roxmltree::Document;
+use shield::ConfigurationError;
+
+use crate::{
+    provider::{SamlBinding, SamlProvider},
+    xml::{
+        HTTP_POST_BINDING, HTTP_REDIRECT_BINDING, METADATA_NAMESPACE, SIGNATURE_NAMESPACE, child,
+        children, escape, text,
+    },
+};
+
+/// Identity provider metadata, as imported from the `EntityDescriptor` published by the provider.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SamlIdentityProviderMetadata {
+    pub entity_id: String,
+    /// Single sign-on service URLs by binding.
+    pub single_sign_on_services: Vec<(SamlBinding, String)>,
+    /// Base64 encoded DER certificates used for signing.
+    pub signing_certificates: Vec<String>,
+}
+
+impl SamlIdentityProviderMetadata {
+    pub fn parse(metadata: &str) -> Result<Self, ConfigurationError> {
+        let document = Document::parse(metadata).map_err(|err| {
+            ConfigurationError::Invalid(format!("identity provider metadata: {err}"))
+        })?;
+
+        // Metadata is either a single entity or an aggregate of entities.
+        let (entity_descriptor, idp_sso_descriptor) = document
+            .descendants()
+            .filter(|node| node.has_tag_name((METADATA_NAMESPACE, "EntityDescriptor")))
+            .find_map(|node| {
+                child(node, METADATA_NAMESPACE, "IDPSSODescriptor")
+                    .map(|idp_sso_descriptor| (node, idp_sso_descriptor))
+            })
+            .ok_or_else(|| {
+                ConfigurationError::Missing("identity provider entity descriptor".to_owned())
+            })?;
+
+        let entity_id = entity_descriptor
+            .attribute("entityID")
+            .ok_or_else(|| ConfigurationError::Missing("identity provider entity ID".to_owned()))?
+            .to_owned();
+
+        let single_sign_on_services = children(
+            idp_sso_descriptor,
+            METADATA_NAMESPACE,
+            "SingleSignOnService",
+        )
+        .filter_map(|service| {
+            let binding = match service.attribute("Binding")? {
+                HTTP_REDIRECT_BINDING => SamlBinding::Redirect,
+                HTTP_POST_BINDING => SamlBinding::Post,
+                _ => return None,
+            };
+
+            Some((binding, service.attribute("Location")?.to_owned()))
+        })
+        .collect();
+
+        let signing_certificates =
+            children(idp_sso_descriptor, METADATA_NAMESPACE, "KeyDescriptor")
+                .filter(|key_descriptor| {
+                    key_descriptor
+                        .attribute("use")
+                        .is_none_or(|key_use| key_use == "signing")
+                })
+                .filter_map(|key_descriptor| {
+                    let key_info = child(key_descriptor, SIGNATURE_NAMESPACE, "KeyInfo")?;
+                    let x509_data = child(key_info, SIGNATURE_NAMESPACE, "X509Data")?;
+                    let certificate = child(x509_data, SIGNATURE_NAMESPACE, "X509Certificate")?;
+
+                    Some(
+                        text(certificate)?
+                            .chars()
+                            .filter(|c| !c.is_whitespace())
+                            .collect(),
+                    )
+                })
+                .collect();
+
+        Ok(Self {
+            entity_id,
+            single_sign_on_services,
+            signing_certificates,
+        })
+    }
+}
+
+/// Service provider metadata to register Shield at the identity provider.
+pub(crate) fn service_provider_metadata(provider: &SamlProvider) -> String {
+    let name_id_format = provider
+        .name_id_format
+        .as_ref()
+        .map(|name_id_format| {
+            format!(
+                "<md:NameIDFormat>{}</md:NameIDFormat>",
+                escape(name_id_format)
+            )
+        })
+        .unwrap_or_default();
+
+    format!(
+        "<md:EntityDescriptor xmlns:md=\"{METADATA_NAMESPACE}\" entityID=\"{}\">\
+        <md:SPSSODescriptor AuthnRequestsSigned=\"false\" WantAssertionsSigned=\"true\" \
+        protocolSupportEnumeration=\"urn:oasis:names:tc:SAML:2.0:protocol\">\
+        {name_id_format}\
+        <md:AssertionConsumerService Binding=\"{HTTP_POST_BINDING}\" Location=\"{}\" index=\"0\" \
+        isDefault=\"true\"/>\
+        </md:SPSSODescriptor>\
+        </md:EntityDescriptor>",
+        escape(&provider.entity_id),
+        escape(&provider.acs_url),
+    )
+}
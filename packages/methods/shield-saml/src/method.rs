@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Method, MethodAction, ProviderError, ShieldError, StorageError, User, UserConnection,
+    erased_method,
+};
+
+use crate::{
+    actions::{SamlSignInAction, SamlSignInCallbackAction},
+    connection::SamlConnection,
+    options::SamlOptions,
+    provider::SamlProvider,
+    replay::AssertionReplayCache,
+    session::SamlSession,
+    storage::SamlStorage,
+};
+
+pub const SAML_METHOD_ID: &str = "saml";
+
+pub struct SamlMethod<U: User> {
+    options: SamlOptions,
+    providers: Vec<SamlProvider>,
+    storage: Arc<dyn SamlStorage<U>>,
+    assertion_replay_cache: AssertionReplayCache,
+}
+
+impl<U: User> SamlMethod<U> {
+    pub fn new<S: SamlStorage<U> + 'static>(storage: S) -> Self {
+        Self {
+            options: SamlOptions::default(),
+            providers: vec![],
+            storage: Arc::new(storage),
+            assertion_replay_cache: AssertionReplayCache::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: SamlOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_providers<I: IntoIterator<Item = SamlProvider>>(mut self, providers: I) -> Self {
+        self.providers = providers.into_iter().collect();
+        self
+    }
+
+    /// Service provider metadata XML of a provider, to be served to the identity provider.
+    pub async fn service_provider_metadata(
+        &self,
+        provider_id: &str,
+    ) -> Result<String, ShieldError> {
+        let provider = self
+            .saml_provider_by_id_or_slug(provider_id)
+            .await?
+            .ok_or_else(|| ProviderError::NotFound(Some(provider_id.to_owned())))?;
+
+        Ok(provider.service_provider_metadata())
+    }
+
+    async fn saml_provider_by_id_or_slug(
+        &self,
+        provider_id: &str,
+    ) -> Result<Option<SamlProvider>, ShieldError> {
+        if let Some(provider) = self
+            .providers
+            .iter()
+            .find(|provider| provider.id == provider_id)
+        {
+            return Ok(Some(provider.clone()));
+        }
+
+        Ok(self
+            .storage
+            .saml_provider_by_id_or_slug(provider_id)
+            .await?)
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> Method for SamlMethod<U> {
+    type Provider = SamlProvider;
+    type Connection = SamlConnection;
+    type Session = SamlSession;
+
+    fn id(&self) -> String {
+        SAML_METHOD_ID.to_owned()
+    }
+
+    fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+        vec![
+            Box::new(SamlSignInAction::new(self.options.clone())),
+            Box::new(SamlSignInCallbackAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+                self.assertion_replay_cache.clone(),
+            )),
+        ]
+    }
+
+    async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+        Ok(self
+            .providers
+            .iter()
+            .cloned()
+            .chain(self.storage.saml_providers().await?)
+            .collect())
+    }
+
+    async fn provider_by_id(
+        &self,
+        provider_id: Option<&str>,
+    ) -> Result<Option<Self::Provider>, ShieldError> {
+        if let Some(provider_id) = provider_id {
+            self.saml_provider_by_id_or_slug(provider_id).await
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn user_connections(
+        &self,
+        user_id: &str,
+        provider_id: Option<&str>,
+    ) -> Result<Vec<Self::Connection>, ShieldError> {
+        Ok(self
+            .storage
+            .user_saml_connections(user_id, provider_id)
+            .await?)
+    }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        Ok(self
+            .storage
+            .user_saml_connections(user_id, None)
+            .await?
+            .into_iter()
+            .map(|connection| UserConnection {
+                id: connection.id,
+                method_id: SAML_METHOD_ID.to_owned(),
+                provider_id: Some(connection.provider_id),
+                identifier: Some(connection.identifier),
+                sign_in: true,
+                unlinkable: true,
+            })
+            .collect())
+    }
+
+    async fn unlink_user_connection(
+        &self,
+        user_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        let connection = self
+            .storage
+            .saml_connection_by_id(connection_id)
+            .await?
+            .filter(|connection| connection.user_id == user_id)
+            .ok_or_else(|| {
+                StorageError::NotFound("SamlConnection".to_owned(), connection_id.to_owned())
+            })?;
+
+        Ok(self.storage.delete_saml_connection(&connection.id).await?)
+    }
+}
+
+erased_method!(SamlMethod, <U: User>);
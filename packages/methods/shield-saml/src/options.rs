@@ -0,0 +1,74 @@
+use bon::Builder;
+use chrono::TimeDelta;
+use regex::Regex;
+use shield::ShieldError;
+use url::Url;
+
+#[derive(Builder, Clone, Debug)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct SamlOptions {
+    #[builder(default = "/")]
+    pub(crate) sign_in_redirect: String,
+
+    #[builder(with = FromIterator::from_iter)]
+    pub(crate) redirect_origins: Option<Vec<Url>>,
+
+    #[builder(with = FromIterator::from_iter)]
+    pub(crate) redirect_patterns: Option<Vec<Regex>>,
+
+    /// Link a new connection to the existing user with the same email address, if both the
+    /// provider and the existing user have verified the email address.
+    #[builder(default)]
+    pub(crate) link_verified_email: bool,
+
+    /// Allowed clock difference with the identity provider when validating assertions.
+    #[builder(default = TimeDelta::minutes(2))]
+    pub(crate) clock_skew: TimeDelta,
+}
+
+impl Default for SamlOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl SamlOptions {
+    /// Resolve the redirect URL against the origin and check it is allowed.
+    pub(crate) fn redirect_url(
+        &self,
+        redirect_origin: &Url,
+        redirect_url: Option<&str>,
+        default_redirect_url: &str,
+    ) -> Result<Url, ShieldError> {
+        let redirect_url = redirect_origin
+            .join(redirect_url.unwrap_or(default_redirect_url))
+            .map_err(|err| ShieldError::Validation(format!("redirect URL parse error: {err}")))?;
+
+        if let Some(redirect_origins) = &self.redirect_origins {
+            let redirect_origin = Url::parse(&redirect_url.origin().ascii_serialization())
+                .map_err(|err| {
+                    ShieldError::Validation(format!("redirect origin parse error: {err}"))
+                })?;
+
+            if !redirect_origins.contains(&redirect_origin) {
+                return Err(ShieldError::Validation(format!(
+                    "redirect origin `{redirect_origin}` not allowed"
+                )));
+            }
+        }
+
+        if let Some(redirect_patterns) = &self.redirect_patterns {
+            let redirect_url_str = redirect_url.to_string();
+            if !redirect_patterns
+                .iter()
+                .any(|pattern| pattern.is_match(&redirect_url_str))
+            {
+                return Err(ShieldError::Validation(format!(
+                    "redirect URL `{redirect_url}` not allowed"
+                )));
+            }
+        }
+
+        Ok(redirect_url)
+    }
+}
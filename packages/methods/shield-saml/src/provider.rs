@@ -0,0 +1,146 @@
+use base64::{Engine, prelude::BASE64_STANDARD};
+use bon::Builder;
+use shield::{ConfigurationError, Provider};
+
+use crate::{
+    metadata::{SamlIdentityProviderMetadata, service_provider_metadata},
+    method::SAML_METHOD_ID,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SamlProviderVisibility {
+    Public,
+    Unlisted,
+}
+
+/// SAML binding used to send authentication requests to the identity provider.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SamlBinding {
+    Redirect,
+    Post,
+}
+
+#[derive(Builder, Clone, Debug)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct SamlProvider {
+    pub id: String,
+    pub name: String,
+    pub slug: Option<String>,
+    pub icon_url: Option<String>,
+    #[builder(default = SamlProviderVisibility::Public)]
+    pub visibility: SamlProviderVisibility,
+    /// Entity ID of Shield as service provider.
+    pub entity_id: String,
+    /// Assertion consumer service URL, i.e. the URL of the sign in callback action.
+    pub acs_url: String,
+    /// Identity provider metadata XML. Explicitly configured identity provider fields take precedence.
+    pub idp_metadata: Option<String>,
+    pub idp_entity_id: Option<String>,
+    pub idp_sso_url: Option<String>,
+    #[builder(default = SamlBinding::Redirect)]
+    pub idp_sso_binding: SamlBinding,
+    /// Base64 encoded DER certificates of the identity provider used for signing.
+    pub idp_certificates: Option<Vec<String>>,
+    pub name_id_format: Option<String>,
+    /// Attribute containing the name of the user, common attribute names are tried if not set.
+    pub name_attribute: Option<String>,
+    /// Attribute containing the email address of the user, common attribute names are tried if not
+    /// set.
+    pub email_attribute: Option<String>,
+    /// Treat email addresses asserted by the identity provider as verified.
+    #[builder(default)]
+    pub trust_email: bool,
+    /// Accept responses which were not requested by Shield, i.e. identity provider initiated sign in.
+    #[builder(default)]
+    pub allow_idp_initiated: bool,
+}
+
+impl SamlProvider {
+    /// Service provider metadata XML to register this provider at the identity provider.
+    pub fn service_provider_metadata(&self) -> String {
+        service_provider_metadata(self)
+    }
+
+    /// Identity provider configuration, either from the metadata or the provider configuration.
+    pub(crate) fn identity_provider(&self) -> Result<IdentityProvider, ConfigurationError> {
+        let metadata = self
+            .idp_metadata
+            .as_deref()
+            .map(SamlIdentityProviderMetadata::parse)
+            .transpose()?
+            .unwrap_or_default();
+
+        let entity_id = self
+            .idp_entity_id
+            .clone()
+            .or((!metadata.entity_id.is_empty()).then_some(metadata.entity_id))
+            .ok_or(ConfigurationError::Missing(
+                "identity provider entity ID".to_owned(),
+            ))?;
+
+        let (sso_binding, sso_url) = match &self.idp_sso_url {
+            Some(idp_sso_url) => (self.idp_sso_binding, idp_sso_url.clone()),
+            None => metadata
+                .single_sign_on_services
+                .iter()
+                .find(|(binding, _)| *binding == self.idp_sso_binding)
+                .or(metadata.single_sign_on_services.first())
+                .cloned()
+                .ok_or(ConfigurationError::Missing(
+                    "identity provider single sign-on URL".to_owned(),
+                ))?,
+        };
+
+        let certificates = self
+            .idp_certificates
+            .clone()
+            .unwrap_or(metadata.signing_certificates)
+            .into_iter()
+            .map(|certificate| {
+                let certificate = certificate
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect::<String>();
+
+                BASE64_STANDARD.decode(certificate).map_err(|err| {
+                    ConfigurationError::Invalid(format!("identity provider certificate: {err}"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if certificates.is_empty() {
+            return Err(ConfigurationError::Missing(
+                "identity provider certificate".to_owned(),
+            ));
+        }
+
+        Ok(IdentityProvider {
+            entity_id,
+            sso_url,
+            sso_binding,
+            certificates,
+        })
+    }
+}
+
+impl Provider for SamlProvider {
+    fn method_id(&self) -> String {
+        SAML_METHOD_ID.to_owned()
+    }
+
+    fn id(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+pub(crate) struct IdentityProvider {
+    pub(crate) entity_id: String,
+    pub(crate) sso_url: String,
+    pub(crate) sso_binding: SamlBinding,
+    /// DER encoded signing certificates.
+    pub(crate) certificates: Vec<Vec<u8>>,
+}
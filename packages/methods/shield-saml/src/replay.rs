@@ -0,0 +1,108 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use shield::ShieldError;
+
+use crate::response::SamlAssertion;
+
+/// Entity ID of the identity provider and ID of an assertion.
+type AssertionId = (String, String);
+
+/// IDs of recently used assertions, so they can not be replayed.
+///
+/// Assertions are rejected after their subject confirmation expires, so their IDs are only
+/// remembered until then.
+#[derive(Clone, Default)]
+pub(crate) struct AssertionReplayCache {
+    assertion_ids: Arc<Mutex<HashMap<AssertionId, DateTime<Utc>>>>,
+}
+
+impl AssertionReplayCache {
+    /// Remember the ID of the assertion, failing if it has been used before.
+    pub(crate) fn insert(
+        &self,
+        idp_entity_id: &str,
+        assertion: &SamlAssertion,
+        clock_skew: TimeDelta,
+    ) -> Result<(), ShieldError> {
+        let now = Utc::now();
+
+        let mut assertion_ids = self
+            .assertion_ids
+            .lock()
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        assertion_ids.retain(|_, expired_at| *expired_at + clock_skew > now);
+
+        if assertion_ids
+            .insert(
+                (idp_entity_id.to_owned(), assertion.id.clone()),
+                assertion.expired_at,
+            )
+            .is_some()
+        {
+            return Err(ShieldError::Validation(
+                "SAML assertion has already been used.".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+
+    use super::AssertionReplayCache;
+    use crate::response::SamlAssertion;
+
+    fn assertion(id: &str, expires_in: TimeDelta) -> SamlAssertion {
+        SamlAssertion {
+            id: id.to_owned(),
+            expired_at: Utc::now() + expires_in,
+            name_id: "alice@example.com".to_owned(),
+            name_id_format: None,
+            session_index: None,
+            attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn assertion_replay() {
+        let replay_cache = AssertionReplayCache::default();
+        let clock_skew = TimeDelta::minutes(2);
+
+        let first = assertion("_first", TimeDelta::minutes(5));
+        assert!(replay_cache.insert("idp", &first, clock_skew).is_ok());
+        assert!(
+            replay_cache
+                .insert(
+                    "idp",
+                    &assertion("_second", TimeDelta::minutes(5)),
+                    clock_skew
+                )
+                .is_ok()
+        );
+        assert!(replay_cache.insert("other", &first, clock_skew).is_ok());
+
+        assert!(
+            replay_cache
+                .insert("idp", &first, clock_skew)
+                .is_err_and(|err| err.to_string().contains("already been used"))
+        );
+    }
+
+    #[test]
+    fn assertion_replay_expiry() {
+        let replay_cache = AssertionReplayCache::default();
+        let clock_skew = TimeDelta::minutes(2);
+
+        let expired = assertion("_expired", TimeDelta::minutes(-5));
+        assert!(replay_cache.insert("idp", &expired, clock_skew).is_ok());
+        assert!(replay_cache.insert("idp", &expired, clock_skew).is_ok());
+    }
+}
@@ -0,0 +1,75 @@
+use std::io::Write;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use chrono::{SecondsFormat, Utc};
+use flate2::{Compression, write::DeflateEncoder};
+use rand::distr::{Alphanumeric, SampleString};
+use shield::ShieldError;
+use url::Url;
+
+use crate::{
+    provider::{IdentityProvider, SamlProvider},
+    xml::{ASSERTION_NAMESPACE, HTTP_POST_BINDING, PROTOCOL_NAMESPACE, escape},
+};
+
+pub(crate) struct AuthnRequest {
+    pub(crate) id: String,
+    pub(crate) xml: String,
+}
+
+impl AuthnRequest {
+    pub(crate) fn new(provider: &SamlProvider, identity_provider: &IdentityProvider) -> Self {
+        // IDs must be valid XML names, which can not start with a digit.
+        let id = format!("_{}", Alphanumeric.sample_string(&mut rand::rng(), 32));
+
+        let name_id_policy = provider
+            .name_id_format
+            .as_ref()
+            .map(|name_id_format| {
+                format!(
+                    "<samlp:NameIDPolicy Format=\"{}\" AllowCreate=\"true\"/>",
+                    escape(name_id_format)
+                )
+            })
+            .unwrap_or_default();
+
+        let xml = format!(
+            "<samlp:AuthnRequest xmlns:samlp=\"{PROTOCOL_NAMESPACE}\" \
+            xmlns:saml=\"{ASSERTION_NAMESPACE}\" ID=\"{id}\" Version=\"2.0\" \
+            IssueInstant=\"{}\" Destination=\"{}\" AssertionConsumerServiceURL=\"{}\" \
+            ProtocolBinding=\"{HTTP_POST_BINDING}\">\
+            <saml:Issuer>{}</saml:Issuer>\
+            {name_id_policy}\
+            </samlp:AuthnRequest>",
+            Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            escape(&identity_provider.sso_url),
+            escape(&provider.acs_url),
+            escape(&provider.entity_id),
+        );
+
+        Self { id, xml }
+    }
+
+    /// URL of the identity provider with the deflated request, for the HTTP-Redirect binding.
+    pub(crate) fn redirect_url(&self, sso_url: &str) -> Result<Url, ShieldError> {
+        let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+        encoder
+            .write_all(self.xml.as_bytes())
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+        let deflated = encoder
+            .finish()
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let mut url = Url::parse(sso_url)
+            .map_err(|err| ShieldError::Validation(format!("Invalid single sign-on URL: {err}")))?;
+        url.query_pairs_mut()
+            .append_pair("SAMLRequest", &BASE64_STANDARD.encode(deflated));
+
+        Ok(url)
+    }
+
+    /// Encoded request for the `SAMLRequest` form field of the HTTP-POST binding.
+    pub(crate) fn post_form_data(&self) -> String {
+        BASE64_STANDARD.encode(&self.xml)
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SamlSession {
+    pub redirect_url: Option<Url>,
+    /// ID of the pending authentication request, to match the `InResponseTo` of the response.
+    pub request_id: Option<String>,
+    pub saml_connection_id: Option<String>,
+}
@@ -0,0 +1,359 @@
+use std::collections::BTreeMap;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use roxmltree::{Node, NodeId, NodeType};
+use rsa::{
+    RsaPublicKey,
+    pkcs1v15::{Signature, VerifyingKey},
+    pkcs8::DecodePublicKey,
+    sha2::{Digest, Sha256, Sha512},
+    signature::Verifier,
+};
+use shield::{ConfigurationError, ShieldError};
+use x509_parser::parse_x509_certificate;
+
+use crate::xml::{SIGNATURE_NAMESPACE, child, children, element_qname, prefix};
+
+const EXCLUSIVE_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+const ENVELOPED_SIGNATURE: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+const RSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+const RSA_SHA512: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512";
+const SHA256: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+const SHA512: &str = "http://www.w3.org/2001/04/xmlenc#sha512";
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Verify the enveloped signature of an element against the certificates of the identity provider.
+///
+/// Returns `false` if the element is not signed. Only the signed element itself is covered by the
+/// signature, so callers must read data from this element and not search the document for it.
+pub(crate) fn verify_enveloped_signature(
+    element: Node,
+    certificates: &[Vec<u8>],
+) -> Result<bool, ShieldError> {
+    let Some(signature) = child(element, SIGNATURE_NAMESPACE, "Signature") else {
+        return Ok(false);
+    };
+
+    let invalid = |message: &str| ShieldError::Validation(format!("Invalid signature: {message}."));
+
+    let signed_info = child(signature, SIGNATURE_NAMESPACE, "SignedInfo")
+        .ok_or_else(|| invalid("missing signed info"))?;
+
+    let canonicalization_method = child(signed_info, SIGNATURE_NAMESPACE, "CanonicalizationMethod")
+        .ok_or_else(|| invalid("missing canonicalization method"))?;
+    if canonicalization_method.attribute("Algorithm") != Some(EXCLUSIVE_C14N) {
+        return Err(invalid("unsupported canonicalization method"));
+    }
+
+    let signature_method = child(signed_info, SIGNATURE_NAMESPACE, "SignatureMethod")
+        .and_then(|signature_method| signature_method.attribute("Algorithm"))
+        .ok_or_else(|| invalid("missing signature method"))?;
+
+    let mut references = children(signed_info, SIGNATURE_NAMESPACE, "Reference");
+    let reference = references
+        .next()
+        .ok_or_else(|| invalid("missing reference"))?;
+    if references.next().is_some() {
+        return Err(invalid("multiple references"));
+    }
+
+    let id = element
+        .attribute("ID")
+        .ok_or_else(|| invalid("signed element has no ID"))?;
+    if reference.attribute("URI") != Some(&format!("#{id}")) {
+        return Err(invalid("reference does not match the signed element"));
+    }
+
+    let mut inclusive_prefixes = None;
+    let mut enveloped = false;
+    if let Some(transforms) = child(reference, SIGNATURE_NAMESPACE, "Transforms") {
+        for transform in children(transforms, SIGNATURE_NAMESPACE, "Transform") {
+            match transform.attribute("Algorithm") {
+                Some(ENVELOPED_SIGNATURE) => enveloped = true,
+                Some(EXCLUSIVE_C14N) => {
+                    inclusive_prefixes = Some(inclusive_namespace_prefixes(transform));
+                }
+                _ => return Err(invalid("unsupported transform")),
+            }
+        }
+    }
+    let (Some(inclusive_prefixes), true) = (inclusive_prefixes, enveloped) else {
+        return Err(invalid("missing transforms"));
+    };
+
+    let digest_method = child(reference, SIGNATURE_NAMESPACE, "DigestMethod")
+        .and_then(|digest_method| digest_method.attribute("Algorithm"))
+        .ok_or_else(|| invalid("missing digest method"))?;
+    let digest_value = child(reference, SIGNATURE_NAMESPACE, "DigestValue")
+        .and_then(|digest_value| decode_base64(digest_value))
+        .ok_or_else(|| invalid("missing digest value"))?;
+
+    let canonical_element = canonicalize(element, Some(signature.id()), &inclusive_prefixes);
+    let digest = match digest_method {
+        SHA256 => Sha256::digest(canonical_element).to_vec(),
+        SHA512 => Sha512::digest(canonical_element).to_vec(),
+        _ => return Err(invalid("unsupported digest method")),
+    };
+    if digest != digest_value {
+        return Err(invalid("digest mismatch"));
+    }
+
+    let signature_value = child(signature, SIGNATURE_NAMESPACE, "SignatureValue")
+        .and_then(|signature_value| decode_base64(signature_value))
+        .ok_or_else(|| invalid("missing signature value"))?;
+    let signature_value = Signature::try_from(signature_value.as_slice())
+        .map_err(|_| invalid("malformed signature value"))?;
+
+    let canonical_signed_info = canonicalize(
+        signed_info,
+        None,
+        &inclusive_namespace_prefixes(canonicalization_method),
+    );
+
+    for certificate in certificates {
+        let public_key = public_key(certificate)?;
+
+        let verified = match signature_method {
+            RSA_SHA256 => VerifyingKey::<Sha256>::new(public_key)
+                .verify(canonical_signed_info.as_bytes(), &signature_value)
+                .is_ok(),
+            RSA_SHA512 => VerifyingKey::<Sha512>::new(public_key)
+                .verify(canonical_signed_info.as_bytes(), &signature_value)
+                .is_ok(),
+            _ => return Err(invalid("unsupported signature method")),
+        };
+
+        if verified {
+            return Ok(true);
+        }
+    }
+
+    Err(invalid("not signed by the identity provider"))
+}
+
+fn public_key(certificate: &[u8]) -> Result<RsaPublicKey, ConfigurationError> {
+    let (_, certificate) = parse_x509_certificate(certificate).map_err(|err| {
+        ConfigurationError::Invalid(format!("identity provider certificate: {err}"))
+    })?;
+
+    RsaPublicKey::from_public_key_der(certificate.public_key().raw)
+        .map_err(|err| ConfigurationError::Invalid(format!("identity provider certificate: {err}")))
+}
+
+fn decode_base64(node: Node) -> Option<Vec<u8>> {
+    let value = node
+        .text()?
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+
+    BASE64_STANDARD.decode(value).ok()
+}
+
+/// Prefixes of the `InclusiveNamespaces` of an exclusive canonicalization method or transform.
+fn inclusive_namespace_prefixes(node: Node) -> Vec<String> {
+    child(node, EXCLUSIVE_C14N, "InclusiveNamespaces")
+        .and_then(|inclusive_namespaces| inclusive_namespaces.attribute("PrefixList"))
+        .map(|prefix_list| {
+            prefix_list
+                .split_whitespace()
+                .map(|prefix| match prefix {
+                    "#default" => String::new(),
+                    prefix => prefix.to_owned(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Exclusive XML canonicalization without comments of an element, see
+/// <https://www.w3.org/TR/xml-exc-c14n/>.
+pub(crate) fn canonicalize(
+    element: Node,
+    excluded: Option<NodeId>,
+    inclusive_prefixes: &[String],
+) -> String {
+    let mut output = String::new();
+    write_element(
+        &mut output,
+        element,
+        excluded,
+        inclusive_prefixes,
+        &BTreeMap::new(),
+    );
+
+    output
+}
+
+fn write_element(
+    output: &mut String,
+    element: Node,
+    excluded: Option<NodeId>,
+    inclusive_prefixes: &[String],
+    rendered: &BTreeMap<String, String>,
+) {
+    let qname = element_qname(element);
+
+    // Namespaces visibly utilized by the element and its attributes, and the inclusive namespaces.
+    let mut prefixes = vec![prefix(qname).to_owned()];
+    for attribute in element.attributes() {
+        if attribute
+            .namespace()
+            .is_some_and(|uri| uri != XML_NAMESPACE)
+        {
+            prefixes.push(prefix(attribute_qname(element, &attribute)).to_owned());
+        }
+    }
+    prefixes.extend(inclusive_prefixes.iter().cloned());
+    prefixes.sort();
+    prefixes.dedup();
+
+    let mut rendered = rendered.clone();
+    let mut namespaces = vec![];
+    for prefix in prefixes {
+        let uri = element
+            .lookup_namespace_uri((!prefix.is_empty()).then_some(prefix.as_str()))
+            .unwrap_or("");
+
+        // An empty default namespace is only rendered to undeclare a rendered default namespace.
+        let previous = rendered.get(&prefix).map(String::as_str);
+        if previous == Some(uri) || (uri.is_empty() && previous.is_none()) {
+            continue;
+        }
+        // Inclusive namespaces which are not in scope are not rendered.
+        if uri.is_empty() && !prefix.is_empty() {
+            continue;
+        }
+
+        rendered.insert(prefix.clone(), uri.to_owned());
+        namespaces.push((prefix, uri));
+    }
+
+    output.push('<');
+    output.push_str(qname);
+
+    for (prefix, uri) in namespaces {
+        if prefix.is_empty() {
+            output.push_str(" xmlns=\"");
+        } else {
+            output.push_str(" xmlns:");
+            output.push_str(&prefix);
+            output.push_str("=\"");
+        }
+        escape_attribute(output, uri);
+        output.push('"');
+    }
+
+    let mut attributes = element.attributes().collect::<Vec<_>>();
+    attributes.sort_by_key(|attribute| (attribute.namespace().unwrap_or(""), attribute.name()));
+    for attribute in attributes {
+        output.push(' ');
+        output.push_str(attribute_qname(element, &attribute));
+        output.push_str("=\"");
+        escape_attribute(output, attribute.value());
+        output.push('"');
+    }
+
+    output.push('>');
+
+    for child in element.children() {
+        match child.node_type() {
+            NodeType::Element => {
+                if Some(child.id()) != excluded {
+                    write_element(output, child, excluded, inclusive_prefixes, &rendered);
+                }
+            }
+            NodeType::Text => escape_text(output, child.text().unwrap_or_default()),
+            NodeType::PI => {
+                if let Some(pi) = child.pi() {
+                    output.push_str("<?");
+                    output.push_str(pi.target);
+                    if let Some(value) = pi.value {
+                        output.push(' ');
+                        output.push_str(value);
+                    }
+                    output.push_str("?>");
+                }
+            }
+            NodeType::Root | NodeType::Comment => {}
+        }
+    }
+
+    output.push_str("</");
+    output.push_str(qname);
+    output.push('>');
+}
+
+fn attribute_qname<'input>(
+    element: Node<'_, 'input>,
+    attribute: &roxmltree::Attribute,
+) -> &'input str {
+    &element.document().input_text()[attribute.range_qname()]
+}
+
+fn escape_attribute(output: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '"' => output.push_str("&quot;"),
+            '\t' => output.push_str("&#x9;"),
+            '\n' => output.push_str("&#xA;"),
+            '\r' => output.push_str("&#xD;"),
+            c => output.push(c),
+        }
+    }
+}
+
+fn escape_text(output: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '\r' => output.push_str("&#xD;"),
+            c => output.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roxmltree::Document;
+
+    use super::canonicalize;
+
+    #[test]
+    fn test_canonicalize() {
+        let xml = r#"<?xml version="1.0"?>
+<saml:Assertion xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:unused="urn:unused" b="2" a='&apos;1&apos;'>
+  <!-- comment -->
+  <Signature xmlns="http://www.w3.org/2000/09/xmldsig#"/>
+  <saml:AttributeValue xsi:type="xs:string">A &amp; B &gt; C</saml:AttributeValue>
+  <Empty xmlns="urn:default" b="2" a="1"/>
+</saml:Assertion>"#;
+        let document = Document::parse(xml).unwrap();
+        let assertion = document.root_element();
+        let signature = assertion
+            .children()
+            .find(|node| node.has_tag_name("Signature"))
+            .unwrap();
+
+        assert_eq!(
+            canonicalize(assertion, Some(signature.id()), &[]),
+            "<saml:Assertion xmlns:saml=\"urn:oasis:names:tc:SAML:2.0:assertion\" a=\"'1'\" b=\"2\">\n  \n  \n  \
+            <saml:AttributeValue xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:type=\"xs:string\">A &amp; B &gt; C</saml:AttributeValue>\n  \
+            <Empty xmlns=\"urn:default\" a=\"1\" b=\"2\"></Empty>\n\
+            </saml:Assertion>"
+        );
+
+        assert_eq!(
+            canonicalize(assertion, Some(signature.id()), &["xs".to_owned()]),
+            "<saml:Assertion xmlns:saml=\"urn:oasis:names:tc:SAML:2.0:assertion\" xmlns:xs=\"http://www.w3.org/2001/XMLSchema\" a=\"'1'\" b=\"2\">\n  \n  \n  \
+            <saml:AttributeValue xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:type=\"xs:string\">A &amp; B &gt; C</saml:AttributeValue>\n  \
+            <Empty xmlns=\"urn:default\" a=\"1\" b=\"2\"></Empty>\n\
+            </saml:Assertion>"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+
+use shield::{Storage, StorageError, User};
+
+use crate::{
+    connection::{CreateSamlConnection, SamlConnection},
+    provider::SamlProvider,
+};
+
+#[async_trait]
+pub trait SamlStorage<U: User>: Storage<U> + Sync {
+    async fn saml_providers(&self) -> Result<Vec<SamlProvider>, StorageError>;
+
+    async fn saml_provider_by_id_or_slug(
+        &self,
+        provider_id: &str,
+    ) -> Result<Option<SamlProvider>, StorageError>;
+
+    async fn saml_connection_by_id(
+        &self,
+        connection_id: &str,
+    ) -> Result<Option<SamlConnection>, StorageError>;
+
+    async fn saml_connection_by_identifier(
+        &self,
+        provider_id: &str,
+        identifier: &str,
+    ) -> Result<Option<SamlConnection>, StorageError>;
+
+    async fn create_saml_connection(
+        &self,
+        connection: CreateSamlConnection,
+    ) -> Result<SamlConnection, StorageError>;
+
+    async fn delete_saml_connection(&self, connection_id: &str) -> Result<(), StorageError>;
+
+    async fn user_saml_connections(
+        &self,
+        user_id: &str,
+        provider_id: Option<&str>,
+    ) -> Result<Vec<SamlConnection>, StorageError>;
+}
@@ -0,0 +1,80 @@
+use roxmltree::Node;
+
+pub(crate) const ASSERTION_NAMESPACE: &str = "urn:oasis:names:tc:SAML:2.0:assertion";
+pub(crate) const METADATA_NAMESPACE: &str = "urn:oasis:names:tc:SAML:2.0:metadata";
+pub(crate) const PROTOCOL_NAMESPACE: &str = "urn:oasis:names:tc:SAML:2.0:protocol";
+pub(crate) const SIGNATURE_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+pub(crate) const HTTP_POST_BINDING: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST";
+pub(crate) const HTTP_REDIRECT_BINDING: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect";
+
+/// Escape text or an attribute value for generated XML.
+pub(crate) fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Child elements with the given namespace and name.
+pub(crate) fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    namespace: &'a str,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.has_tag_name((namespace, name)))
+}
+
+/// First child element with the given namespace and name.
+pub(crate) fn child<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    namespace: &'a str,
+    name: &'a str,
+) -> Option<Node<'a, 'input>> {
+    children(node, namespace, name).next()
+}
+
+/// Text content of an element, with surrounding whitespace removed.
+///
+/// All text descendants are concatenated, because canonicalization drops comments. Reading only
+/// the first text node would turn a signed `admin@example.com<!---->.evil.com` into
+/// `admin@example.com`.
+pub(crate) fn text(node: Node) -> Option<String> {
+    let text = node
+        .descendants()
+        .filter(|descendant| descendant.is_text())
+        .filter_map(|descendant| descendant.text())
+        .collect::<String>();
+    let text = text.trim();
+
+    (!text.is_empty()).then(|| text.to_owned())
+}
+
+/// Qualified name of an element as written in the source, e.g. `saml:Assertion`.
+pub(crate) fn element_qname<'input>(node: Node<'_, 'input>) -> &'input str {
+    let source = &node.document().input_text()[node.range().start + 1..];
+    let end = source
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(source.len());
+
+    &source[..end]
+}
+
+/// Prefix of a qualified name, empty for the default namespace.
+pub(crate) fn prefix(qname: &str) -> &str {
+    qname
+        .split_once(':')
+        .map(|(prefix, _)| prefix)
+        .unwrap_or("")
+}
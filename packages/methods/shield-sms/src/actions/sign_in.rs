@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeSubmit, InputTypeTel, InputValue, MethodAction, MethodSession,
+    Request, RequestMethod, Response, ResponseType, SessionAction, ShieldError, SignInAction, User,
+    erased_method_action,
+};
+
+use crate::{
+    code::{CreateSmsAuthCode, generate_code, hash_code},
+    options::SmsOptions,
+    phone_number::normalize_phone_number,
+    provider::SmsProvider,
+    storage::SmsStorage,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInData {
+    pub phone_number: String,
+}
+
+pub struct SmsSignInAction<U: User> {
+    options: SmsOptions,
+    storage: Arc<dyn SmsStorage<U>>,
+}
+
+impl<U: User> SmsSignInAction<U> {
+    pub fn new(options: SmsOptions, storage: Arc<dyn SmsStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<SmsProvider, ()> for SmsSignInAction<U> {
+    fn id(&self) -> String {
+        SignInAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignInAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign in with SMS"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign in with a code sent by SMS to a phone number."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self, _provider: SmsProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "phoneNumber".to_owned(),
+                    label: Some("Phone number".to_owned()),
+                    r#type: InputType::Tel(InputTypeTel {
+                        autocomplete: Some("tel".to_owned()),
+                        placeholder: Some("Phone number".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: "Sign in with SMS".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: SmsProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignInData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let phone_number = normalize_phone_number(&data.phone_number)?;
+        let code = generate_code(self.options.code_length);
+
+        let sms_auth_code = self
+            .storage
+            .create_sms_auth_code(CreateSmsAuthCode {
+                code: hash_code(&phone_number, &code, &self.options.secret),
+                phone_number,
+                expired_at: (Utc::now() + self.options.expires_in).into(),
+            })
+            .await?;
+
+        self.options
+            .sender
+            .send(&sms_auth_code.phone_number, &code, sms_auth_code.expired_at)
+            .await?;
+
+        Ok(Response::new(ResponseType::Default).session_action(SessionAction::unauthenticate()))
+    }
+}
+
+erased_method_action!(SmsSignInAction, <U: User>);
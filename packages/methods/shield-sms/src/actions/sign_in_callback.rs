@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use shield::{
+    CreatePhoneNumber, CreateUser, Form, Input, InputType, InputTypeSubmit, InputTypeTel,
+    InputTypeText, InputValue, MethodAction, MethodSession, Request, RequestMethod, Response,
+    ResponseType, SessionAction, ShieldError, SignInCallbackAction, SignUpEvent, User,
+    erased_method_action,
+};
+
+use crate::{
+    code::hash_code, options::SmsOptions, phone_number::normalize_phone_number,
+    provider::SmsProvider, storage::SmsStorage,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInCallbackData {
+    pub phone_number: String,
+    pub code: String,
+}
+
+pub struct SmsSignInCallbackAction<U: User> {
+    options: SmsOptions,
+    storage: Arc<dyn SmsStorage<U>>,
+}
+
+impl<U: User> SmsSignInCallbackAction<U> {
+    pub fn new(options: SmsOptions, storage: Arc<dyn SmsStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<SmsProvider, ()> for SmsSignInCallbackAction<U> {
+    fn id(&self) -> String {
+        SignInCallbackAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignInCallbackAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign in callback for SMS"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign in callback for SMS, verifying the code sent to the phone number."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        provider: &SmsProvider,
+        session: &MethodSession<()>,
+    ) -> Result<bool, ShieldError> {
+        SignInCallbackAction::condition(provider, session)
+    }
+
+    async fn forms(&self, _provider: SmsProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "phoneNumber".to_owned(),
+                    label: Some("Phone number".to_owned()),
+                    r#type: InputType::Tel(InputTypeTel {
+                        autocomplete: Some("tel".to_owned()),
+                        placeholder: Some("Phone number".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: Some(InputValue::Query {
+                        key: "phoneNumber".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "code".to_owned(),
+                    label: Some("Code".to_owned()),
+                    r#type: InputType::Text(InputTypeText {
+                        autocomplete: Some("one-time-code".to_owned()),
+                        pattern: Some("[0-9]*".to_owned()),
+                        placeholder: Some("Code".to_owned()),
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: "Sign in with SMS".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    fn rate_limit_identifier(&self, request: &Request) -> Option<String> {
+        serde_json::from_value::<SignInCallbackData>(request.form_data.clone())
+            .ok()
+            .and_then(|data| normalize_phone_number(&data.phone_number).ok())
+    }
+
+    async fn call(
+        &self,
+        provider: SmsProvider,
+        _session: &MethodSession<()>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignInCallbackData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let phone_number = normalize_phone_number(&data.phone_number)?;
+
+        let sms_auth_code = self
+            .storage
+            .sms_auth_code(&phone_number)
+            .await?
+            .filter(|sms_auth_code| sms_auth_code.expired_at > Utc::now())
+            .ok_or_else(|| {
+                ShieldError::Validation("SMS authentication code not found.".to_owned())
+            })?;
+
+        if sms_auth_code.attempts >= self.options.max_attempts {
+            self.storage.delete_sms_auth_code(&sms_auth_code.id).await?;
+
+            return Err(ShieldError::Validation(
+                "SMS authentication code not found.".to_owned(),
+            ));
+        }
+
+        if sms_auth_code.code != hash_code(&phone_number, data.code.trim(), &self.options.secret) {
+            let sms_auth_code = self
+                .storage
+                .increment_sms_auth_code_attempts(&sms_auth_code.id)
+                .await?;
+
+            // The code is invalidated after too many attempts, as short codes are easy to guess.
+            if sms_auth_code.attempts >= self.options.max_attempts {
+                self.storage.delete_sms_auth_code(&sms_auth_code.id).await?;
+            }
+
+            return Err(ShieldError::Validation(
+                "Incorrect SMS authentication code.".to_owned(),
+            ));
+        }
+
+        self.storage.delete_sms_auth_code(&sms_auth_code.id).await?;
+
+        let user = match self.storage.user_by_phone_number(&phone_number).await? {
+            Some(user) => user,
+            None => {
+                request
+                    .hooks
+                    .sign_up(
+                        SignUpEvent {
+                            phone_number: Some(phone_number.clone()),
+                            ..SignUpEvent::new(&provider)
+                        },
+                        self.storage.create_user_with_phone_number(
+                            CreateUser { name: None },
+                            CreatePhoneNumber {
+                                phone_number,
+                                is_primary: true,
+                                is_verified: true,
+                                verified_at: Some(Utc::now().into()),
+                            },
+                        ),
+                    )
+                    .await?
+            }
+        };
+
+        Ok(Response::new(ResponseType::Redirect(
+            self.options.sign_in_redirect.clone(),
+        ))
+        .session_action(SessionAction::authenticate(&provider, user)))
+    }
+}
+
+erased_method_action!(SmsSignInCallbackAction, <U: User>);
@@ -0,0 +1,72 @@
+use chrono::{DateTime, FixedOffset};
+use secrecy::{ExposeSecret, SecretString};
+use sha3::{Digest, Sha3_256};
+
+#[derive(Clone, Debug)]
+pub struct SmsAuthCode {
+    pub id: String,
+    pub phone_number: String,
+    /// Hash of the code.
+    pub code: String,
+    /// Number of incorrect codes entered.
+    pub attempts: i32,
+    pub expired_at: DateTime<FixedOffset>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateSmsAuthCode {
+    pub phone_number: String,
+    pub code: String,
+    pub expired_at: DateTime<FixedOffset>,
+}
+
+pub(crate) fn generate_code(length: usize) -> String {
+    (0..length)
+        .map(|_| char::from(b'0' + rand::random_range(0..10u8)))
+        .collect()
+}
+
+/// Codes are short, so the phone number and secret are included to prevent lookups of all codes.
+pub(crate) fn hash_code(phone_number: &str, code: &str, secret: &SecretString) -> String {
+    hex::encode(
+        Sha3_256::new()
+            .chain_update(phone_number)
+            .chain_update(":")
+            .chain_update(code)
+            .chain_update(secret.expose_secret())
+            .finalize(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use secrecy::SecretString;
+
+    use super::{generate_code, hash_code};
+
+    #[test]
+    fn test_generate_code() {
+        let code = generate_code(6);
+
+        assert_eq!(6, code.len());
+        assert!(code.chars().all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_hash_code() {
+        let secret = SecretString::from("secret");
+
+        assert_eq!(
+            hash_code("+31612345678", "123456", &secret),
+            hash_code("+31612345678", "123456", &secret)
+        );
+        assert_ne!(
+            hash_code("+31612345678", "123456", &secret),
+            hash_code("+31612345679", "123456", &secret)
+        );
+        assert_ne!(
+            hash_code("+31612345678", "123456", &secret),
+            hash_code("+31612345678", "123456", &SecretString::from("other"))
+        );
+    }
+}
@@ -0,0 +1,16 @@
+mod actions;
+mod code;
+mod method;
+mod options;
+mod phone_number;
+mod provider;
+mod sender;
+mod storage;
+
+pub use code::*;
+pub use method::*;
+pub use options::*;
+pub use phone_number::*;
+pub use provider::*;
+pub use sender::*;
+pub use storage::*;
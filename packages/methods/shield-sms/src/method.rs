@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{Method, MethodAction, ShieldError, User, UserConnection, erased_method};
+
+use crate::{
+    actions::{SmsSignInAction, SmsSignInCallbackAction},
+    options::SmsOptions,
+    provider::SmsProvider,
+    storage::SmsStorage,
+};
+
+pub const SMS_METHOD_ID: &str = "sms";
+
+pub struct SmsMethod<U: User> {
+    options: SmsOptions,
+    storage: Arc<dyn SmsStorage<U>>,
+}
+
+impl<U: User> SmsMethod<U> {
+    pub fn new<S: SmsStorage<U> + 'static>(options: SmsOptions, storage: S) -> Self {
+        Self {
+            options,
+            storage: Arc::new(storage),
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> Method for SmsMethod<U> {
+    type Provider = SmsProvider;
+    type Connection = ();
+    type Session = ();
+
+    fn id(&self) -> String {
+        SMS_METHOD_ID.to_owned()
+    }
+
+    fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+        vec![
+            Box::new(SmsSignInAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(SmsSignInCallbackAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+        ]
+    }
+
+    async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+        Ok(vec![SmsProvider])
+    }
+
+    async fn user_connections(
+        &self,
+        _user_id: &str,
+        _provider_id: Option<&str>,
+    ) -> Result<Vec<Self::Connection>, ShieldError> {
+        Ok(vec![])
+    }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        let Some(user) = self.storage.user_by_id(user_id).await? else {
+            return Ok(vec![]);
+        };
+
+        // Phone numbers are managed on the user, so they are listed but can not be unlinked here.
+        Ok(user
+            .phone_numbers()
+            .await?
+            .into_iter()
+            .map(|phone_number| UserConnection {
+                id: phone_number.id,
+                method_id: SMS_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: Some(phone_number.phone_number),
+                sign_in: true,
+                unlinkable: false,
+            })
+            .collect())
+    }
+}
+
+erased_method!(SmsMethod, <U: User>);
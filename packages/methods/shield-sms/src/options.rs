@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use bon::Builder;
+use chrono::TimeDelta;
+use secrecy::SecretString;
+
+use crate::sender::SmsSender;
+
+#[derive(Builder, Clone)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct SmsOptions {
+    /// Secret used to hash codes before they are stored.
+    #[builder(into)]
+    pub(crate) secret: SecretString,
+
+    #[builder(with = |sender: impl SmsSender + 'static| Arc::new(sender))]
+    pub(crate) sender: Arc<dyn SmsSender>,
+
+    #[builder(default = TimeDelta::minutes(5))]
+    pub(crate) expires_in: TimeDelta,
+
+    /// Number of digits of a code.
+    #[builder(default = 6)]
+    pub(crate) code_length: usize,
+
+    /// Number of incorrect codes after which the code is invalidated.
+    #[builder(default = 5)]
+    pub(crate) max_attempts: i32,
+
+    #[builder(default = "/")]
+    pub(crate) sign_in_redirect: String,
+}
@@ -0,0 +1,69 @@
+use shield::ShieldError;
+
+/// Normalize a phone number to E.164 format, e.g. `+31 (0)6 1234-5678` becomes `+31612345678`.
+///
+/// Numbers must include the country code, as there is no default region to assume.
+pub fn normalize_phone_number(phone_number: &str) -> Result<String, ShieldError> {
+    let invalid = || {
+        ShieldError::Validation(
+            "Phone number must include the country code, e.g. `+31612345678`.".to_owned(),
+        )
+    };
+
+    // The trunk prefix is not dialed with the country code.
+    let phone_number = phone_number
+        .replace("(0)", "")
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect::<String>();
+
+    let digits = phone_number
+        .strip_prefix('+')
+        .or_else(|| phone_number.strip_prefix("00"))
+        .ok_or_else(invalid)?;
+
+    if !(8..=15).contains(&digits.len())
+        || digits.starts_with('0')
+        || !digits.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    Ok(format!("+{digits}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_phone_number;
+
+    #[test]
+    fn test_normalize_phone_number() {
+        for phone_number in [
+            "+31612345678",
+            "+31 6 1234-5678",
+            "+31 (0)6 1234 5678",
+            "0031 (6) 12.34.56.78",
+        ] {
+            assert_eq!(
+                "+31612345678",
+                normalize_phone_number(phone_number).unwrap(),
+                "`{phone_number}` should be normalized."
+            );
+        }
+
+        for phone_number in [
+            "",
+            "0612345678",
+            "+",
+            "+0612345678",
+            "+31 6 1234 567a",
+            "+1234567",
+            "+1234567890123456",
+        ] {
+            assert!(
+                normalize_phone_number(phone_number).is_err(),
+                "`{phone_number}` should be invalid."
+            );
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use shield::Provider;
+
+use crate::method::SMS_METHOD_ID;
+
+pub struct SmsProvider;
+
+impl Provider for SmsProvider {
+    fn method_id(&self) -> String {
+        SMS_METHOD_ID.to_owned()
+    }
+
+    fn id(&self) -> Option<String> {
+        None
+    }
+
+    fn name(&self) -> String {
+        "SMS".to_owned()
+    }
+}
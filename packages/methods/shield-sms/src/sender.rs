@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use shield::ShieldError;
+
+#[async_trait]
+pub trait SmsSender: Send + Sync {
+    async fn send(
+        &self,
+        phone_number: &str,
+        code: &str,
+        expires_at: DateTime<FixedOffset>,
+    ) -> Result<(), ShieldError>;
+}
+
+#[cfg(feature = "sender-tracing")]
+mod tracing {
+    use async_trait::async_trait;
+    use chrono::{DateTime, FixedOffset};
+    use shield::ShieldError;
+    use tracing::info;
+
+    use super::SmsSender;
+
+    pub struct TracingSender;
+
+    #[async_trait]
+    impl SmsSender for TracingSender {
+        async fn send(
+            &self,
+            phone_number: &str,
+            code: &str,
+            expires_at: DateTime<FixedOffset>,
+        ) -> Result<(), ShieldError> {
+            info!(
+                "SMS authentication code for `{phone_number}` expires at `{expires_at}`:\n`{code}`"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "sender-tracing")]
+pub use tracing::*;
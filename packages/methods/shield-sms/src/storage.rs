@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use shield::{CreatePhoneNumber, CreateUser, Storage, StorageError, User};
+
+use crate::code::{CreateSmsAuthCode, SmsAuthCode};
+
+#[async_trait]
+pub trait SmsStorage<U: User>: Storage<U> + Sync {
+    async fn user_by_phone_number(&self, phone_number: &str) -> Result<Option<U>, StorageError>;
+
+    async fn create_user_with_phone_number(
+        &self,
+        user: CreateUser,
+        phone_number: CreatePhoneNumber,
+    ) -> Result<U, StorageError>;
+
+    /// Most recent code sent to the phone number.
+    async fn sms_auth_code(&self, phone_number: &str) -> Result<Option<SmsAuthCode>, StorageError>;
+
+    /// Create a code, replacing the previous codes of the phone number.
+    async fn create_sms_auth_code(
+        &self,
+        sms_auth_code: CreateSmsAuthCode,
+    ) -> Result<SmsAuthCode, StorageError>;
+
+    async fn increment_sms_auth_code_attempts(
+        &self,
+        sms_auth_code_id: &str,
+    ) -> Result<SmsAuthCode, StorageError>;
+
+    async fn delete_sms_auth_code(&self, sms_auth_code_id: &str) -> Result<(), StorageError>;
+
+    async fn delete_expired_sms_auth_codes(&self) -> Result<(), StorageError>;
+}
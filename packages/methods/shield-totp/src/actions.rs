@@ -0,0 +1,11 @@
+mod disable;
+mod enroll;
+mod enroll_callback;
+mod recovery_codes;
+mod verify;
+
+pub use disable::*;
+pub use enroll::*;
+pub use enroll_callback::*;
+pub use recovery_codes::*;
+pub use verify::*;
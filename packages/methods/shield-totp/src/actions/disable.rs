@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError,
+    User, erased_method_action,
+};
+
+use crate::{
+    form::{CodeData, code_input, submit_input},
+    options::TotpOptions,
+    provider::TotpProvider,
+    recovery_code::verify_recovery_code,
+    session::TotpSession,
+    storage::TotpStorage,
+    totp::verify_user_totp,
+};
+
+pub const DISABLE_ACTION_ID: &str = "disable";
+const DISABLE_ACTION_NAME: &str = "Remove authenticator app";
+
+pub struct TotpDisableAction<U: User> {
+    options: TotpOptions,
+    storage: Arc<dyn TotpStorage<U>>,
+}
+
+impl<U: User> TotpDisableAction<U> {
+    pub fn new(options: TotpOptions, storage: Arc<dyn TotpStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<TotpProvider, TotpSession> for TotpDisableAction<U> {
+    fn id(&self) -> String {
+        DISABLE_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        DISABLE_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Remove authenticator app"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Remove the TOTP secret and recovery codes of the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &TotpProvider,
+        session: &MethodSession<TotpSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.authentication.is_some())
+    }
+
+    async fn forms(&self, _provider: TotpProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                code_input("Authentication code or recovery code"),
+                submit_input("Remove authenticator app"),
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: TotpProvider,
+        session: &MethodSession<TotpSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .base
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let data = serde_json::from_value::<CodeData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let user_totp = self
+            .storage
+            .user_totp(&authentication.user_id)
+            .await?
+            .ok_or_else(|| {
+                ShieldError::Validation("Authenticator app is not set up.".to_owned())
+            })?;
+
+        if !verify_user_totp(&self.options, &*self.storage, &user_totp, &data.code).await?
+            && !verify_recovery_code(&*self.storage, &authentication.user_id, &data.code).await?
+        {
+            return Err(ShieldError::Validation(
+                "Invalid authentication code.".to_owned(),
+            ));
+        }
+
+        self.storage
+            .delete_totp_recovery_codes(&authentication.user_id)
+            .await?;
+        self.storage.delete_user_totp(&user_totp.id).await?;
+
+        Ok(Response::new(ResponseType::Default))
+    }
+}
+
+erased_method_action!(TotpDisableAction, <U: User>);
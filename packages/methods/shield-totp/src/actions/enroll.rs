@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Serialize;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType,
+    SessionAction, ShieldError, User, erased_method_action,
+};
+use totp_rs::Secret;
+
+use crate::{
+    form::submit_input, options::TotpOptions, provider::TotpProvider, session::TotpSession,
+    storage::TotpStorage,
+};
+
+pub const ENROLL_ACTION_ID: &str = "enroll";
+const ENROLL_ACTION_NAME: &str = "Set up authenticator app";
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpEnrollment {
+    /// Base32 encoded secret for manual entry.
+    pub secret: String,
+    /// `otpauth://` URI for display as QR code.
+    pub url: String,
+}
+
+pub struct TotpEnrollAction<U: User> {
+    options: TotpOptions,
+    storage: Arc<dyn TotpStorage<U>>,
+}
+
+impl<U: User> TotpEnrollAction<U> {
+    pub fn new(options: TotpOptions, storage: Arc<dyn TotpStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<TotpProvider, TotpSession> for TotpEnrollAction<U> {
+    fn id(&self) -> String {
+        ENROLL_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        ENROLL_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Set up authenticator app"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Generate a TOTP secret for the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &TotpProvider,
+        session: &MethodSession<TotpSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.authentication.is_some())
+    }
+
+    async fn forms(&self, _provider: TotpProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![submit_input("Set up authenticator app")],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: TotpProvider,
+        session: &MethodSession<TotpSession>,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .base
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let user = self
+            .storage
+            .user_by_id(&authentication.user_id)
+            .await?
+            .ok_or(ShieldError::Unauthorized)?;
+
+        if self.storage.user_totp(&user.id()).await?.is_some() {
+            return Err(ShieldError::Validation(
+                "Authenticator app is already set up.".to_owned(),
+            ));
+        }
+
+        let account_name = user
+            .email_addresses()
+            .await?
+            .into_iter()
+            .find(|email_address| email_address.is_primary)
+            .map(|email_address| email_address.email)
+            .unwrap_or_else(|| user.id());
+
+        let secret = Secret::generate().to_base32();
+        let url = self
+            .options
+            .totp(&secret, &account_name)?
+            .to_url()
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let enrollment = serde_json::to_value(TotpEnrollment {
+            secret: secret.clone(),
+            url,
+        })
+        .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(
+            Response::new(ResponseType::Data(enrollment)).session_action(
+                SessionAction::method_data(
+                    &provider,
+                    TotpSession {
+                        secret: Some(secret),
+                    },
+                )?,
+            ),
+        )
+    }
+}
+
+erased_method_action!(TotpEnrollAction, <U: User>);
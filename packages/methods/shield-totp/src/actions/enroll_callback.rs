@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType,
+    SessionAction, ShieldError, User, erased_method_action,
+};
+
+use crate::{
+    form::{CodeData, code_input, submit_input},
+    options::TotpOptions,
+    provider::TotpProvider,
+    recovery_code::create_recovery_codes,
+    session::TotpSession,
+    storage::TotpStorage,
+    totp::CreateUserTotp,
+};
+
+pub const ENROLL_CALLBACK_ACTION_ID: &str = "enroll-callback";
+const ENROLL_CALLBACK_ACTION_NAME: &str = "Confirm authenticator app";
+
+pub struct TotpEnrollCallbackAction<U: User> {
+    options: TotpOptions,
+    storage: Arc<dyn TotpStorage<U>>,
+}
+
+impl<U: User> TotpEnrollCallbackAction<U> {
+    pub fn new(options: TotpOptions, storage: Arc<dyn TotpStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<TotpProvider, TotpSession> for TotpEnrollCallbackAction<U> {
+    fn id(&self) -> String {
+        ENROLL_CALLBACK_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        ENROLL_CALLBACK_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Confirm authenticator app"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Confirm the TOTP secret with a code from the authenticator app and generate recovery codes."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &TotpProvider,
+        session: &MethodSession<TotpSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.authentication.is_some() && session.method.secret.is_some())
+    }
+
+    async fn forms(&self, _provider: TotpProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![code_input("123456"), submit_input("Confirm")],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: TotpProvider,
+        session: &MethodSession<TotpSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .base
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let secret = session
+            .method
+            .secret
+            .as_ref()
+            .ok_or_else(|| ShieldError::Validation("Missing TOTP enrollment.".to_owned()))?;
+
+        let data = serde_json::from_value::<CodeData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let Some(step) = self
+            .options
+            .totp(secret, "")?
+            .check_current(data.code.trim())
+        else {
+            return Err(ShieldError::Validation(
+                "Invalid authentication code.".to_owned(),
+            ));
+        };
+
+        if self
+            .storage
+            .user_totp(&authentication.user_id)
+            .await?
+            .is_some()
+        {
+            return Err(ShieldError::Validation(
+                "Authenticator app is already set up.".to_owned(),
+            ));
+        }
+
+        self.storage
+            .create_user_totp(CreateUserTotp {
+                secret: secret.clone().into(),
+                last_used_step: Some(step as i64),
+                user_id: authentication.user_id.clone(),
+            })
+            .await?;
+
+        let recovery_codes =
+            create_recovery_codes(&self.options, &*self.storage, &authentication.user_id).await?;
+        let recovery_codes = serde_json::to_value(recovery_codes)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(
+            Response::new(ResponseType::Data(recovery_codes)).session_action(
+                SessionAction::method_data(&provider, TotpSession::default())?,
+            ),
+        )
+    }
+}
+
+erased_method_action!(TotpEnrollCallbackAction, <U: User>);
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType, ShieldError,
+    User, erased_method_action,
+};
+
+use crate::{
+    form::{CodeData, code_input, submit_input},
+    options::TotpOptions,
+    provider::TotpProvider,
+    recovery_code::create_recovery_codes,
+    session::TotpSession,
+    storage::TotpStorage,
+    totp::verify_user_totp,
+};
+
+pub const RECOVERY_CODES_ACTION_ID: &str = "recovery-codes";
+const RECOVERY_CODES_ACTION_NAME: &str = "Generate recovery codes";
+
+pub struct TotpRecoveryCodesAction<U: User> {
+    options: TotpOptions,
+    storage: Arc<dyn TotpStorage<U>>,
+}
+
+impl<U: User> TotpRecoveryCodesAction<U> {
+    pub fn new(options: TotpOptions, storage: Arc<dyn TotpStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<TotpProvider, TotpSession> for TotpRecoveryCodesAction<U> {
+    fn id(&self) -> String {
+        RECOVERY_CODES_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        RECOVERY_CODES_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Generate recovery codes"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Replace the recovery codes of the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &TotpProvider,
+        session: &MethodSession<TotpSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.authentication.is_some())
+    }
+
+    async fn forms(&self, _provider: TotpProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                code_input("Authentication code"),
+                submit_input("Generate recovery codes"),
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        _provider: TotpProvider,
+        session: &MethodSession<TotpSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .base
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let data = serde_json::from_value::<CodeData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let user_totp = self
+            .storage
+            .user_totp(&authentication.user_id)
+            .await?
+            .ok_or_else(|| {
+                ShieldError::Validation("Authenticator app is not set up.".to_owned())
+            })?;
+
+        if !verify_user_totp(&self.options, &*self.storage, &user_totp, &data.code).await? {
+            return Err(ShieldError::Validation(
+                "Invalid authentication code.".to_owned(),
+            ));
+        }
+
+        let recovery_codes =
+            create_recovery_codes(&self.options, &*self.storage, &authentication.user_id).await?;
+        let recovery_codes = serde_json::to_value(recovery_codes)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(Response::new(ResponseType::Data(recovery_codes)))
+    }
+}
+
+erased_method_action!(TotpRecoveryCodesAction, <U: User>);
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Form, MethodAction, MethodSession, Request, RequestMethod, Response, ResponseType,
+    SessionAction, ShieldError, User, erased_method_action,
+};
+
+use crate::{
+    form::{CodeData, code_input, submit_input},
+    method::TOTP_METHOD_ID,
+    options::TotpOptions,
+    provider::TotpProvider,
+    recovery_code::verify_recovery_code,
+    session::TotpSession,
+    storage::TotpStorage,
+    totp::verify_user_totp,
+};
+
+pub const VERIFY_ACTION_ID: &str = "verify";
+const VERIFY_ACTION_NAME: &str = "Two-factor authentication";
+
+pub struct TotpVerifyAction<U: User> {
+    options: TotpOptions,
+    storage: Arc<dyn TotpStorage<U>>,
+}
+
+impl<U: User> TotpVerifyAction<U> {
+    pub fn new(options: TotpOptions, storage: Arc<dyn TotpStorage<U>>) -> Self {
+        Self { options, storage }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<TotpProvider, TotpSession> for TotpVerifyAction<U> {
+    fn id(&self) -> String {
+        VERIFY_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        VERIFY_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Verify authentication code"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Verify a code from the authenticator app or a recovery code as second factor."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &TotpProvider,
+        session: &MethodSession<TotpSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.pending_authentication.is_some()
+            || session
+                .base
+                .authentication
+                .as_ref()
+                .is_some_and(|authentication| !authentication.has_factor(TOTP_METHOD_ID)))
+    }
+
+    async fn forms(&self, _provider: TotpProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                code_input("Authentication code or recovery code"),
+                submit_input("Verify"),
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: TotpProvider,
+        session: &MethodSession<TotpSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .base
+            .pending_authentication
+            .as_ref()
+            .or(session.base.authentication.as_ref())
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let data = serde_json::from_value::<CodeData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let user_totp = self
+            .storage
+            .user_totp(&authentication.user_id)
+            .await?
+            .ok_or(ShieldError::Unauthorized)?;
+
+        if !verify_user_totp(&self.options, &*self.storage, &user_totp, &data.code).await?
+            && !verify_recovery_code(&*self.storage, &authentication.user_id, &data.code).await?
+        {
+            return Err(ShieldError::Validation(
+                "Invalid authentication code.".to_owned(),
+            ));
+        }
+
+        Ok(
+            Response::new(ResponseType::Redirect(self.options.verify_redirect.clone()))
+                .session_action(SessionAction::add_factor(&provider)),
+        )
+    }
+}
+
+erased_method_action!(TotpVerifyAction, <U: User>);
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use shield::{Input, InputType, InputTypeSubmit, InputTypeText, InputValue};
+
+pub(crate) fn code_input(placeholder: &str) -> Input {
+    Input {
+        name: "code".to_owned(),
+        label: Some("Code".to_owned()),
+        r#type: InputType::Text(InputTypeText {
+            autocomplete: Some("one-time-code".to_owned()),
+            placeholder: Some(placeholder.to_owned()),
+            required: Some(true),
+            ..Default::default()
+        }),
+        value: None,
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+pub(crate) fn submit_input(value: &str) -> Input {
+    Input {
+        name: "submit".to_owned(),
+        label: None,
+        r#type: InputType::Submit(InputTypeSubmit::default()),
+        value: Some(InputValue::String {
+            value: value.to_owned(),
+        }),
+        addon_start: None,
+        addon_end: None,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CodeData {
+    pub(crate) code: String,
+}
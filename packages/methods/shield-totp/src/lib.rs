@@ -0,0 +1,16 @@
+mod actions;
+mod form;
+mod method;
+mod options;
+mod provider;
+mod recovery_code;
+mod session;
+mod storage;
+mod totp;
+
+pub use method::*;
+pub use options::*;
+pub use provider::*;
+pub use recovery_code::{CreateTotpRecoveryCode, TotpRecoveryCode, TotpRecoveryCodes};
+pub use storage::*;
+pub use totp::*;
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{Method, MethodAction, ShieldError, User, UserConnection, erased_method};
+
+use crate::{
+    actions::{
+        TotpDisableAction, TotpEnrollAction, TotpEnrollCallbackAction, TotpRecoveryCodesAction,
+        TotpVerifyAction, VERIFY_ACTION_ID,
+    },
+    options::TotpOptions,
+    provider::TotpProvider,
+    session::TotpSession,
+    storage::TotpStorage,
+    totp::UserTotp,
+};
+
+pub const TOTP_METHOD_ID: &str = "totp";
+
+pub struct TotpMethod<U: User> {
+    options: TotpOptions,
+    storage: Arc<dyn TotpStorage<U>>,
+}
+
+impl<U: User> TotpMethod<U> {
+    pub fn new<S: TotpStorage<U> + 'static>(options: TotpOptions, storage: S) -> Self {
+        Self {
+            options,
+            storage: Arc::new(storage),
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> Method for TotpMethod<U> {
+    type Provider = TotpProvider;
+    type Connection = UserTotp;
+    type Session = TotpSession;
+
+    fn id(&self) -> String {
+        TOTP_METHOD_ID.to_owned()
+    }
+
+    fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+        vec![
+            Box::new(TotpVerifyAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(TotpEnrollAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(TotpEnrollCallbackAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(TotpRecoveryCodesAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(TotpDisableAction::new(
+                self.options.clone(),
+                self.storage.clone(),
+            )),
+        ]
+    }
+
+    async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+        Ok(vec![TotpProvider])
+    }
+
+    async fn user_connections(
+        &self,
+        user_id: &str,
+        _provider_id: Option<&str>,
+    ) -> Result<Vec<Self::Connection>, ShieldError> {
+        Ok(self.storage.user_totp(user_id).await?.into_iter().collect())
+    }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        // Disabling the authenticator requires a code, see the disable action.
+        Ok(self
+            .storage
+            .user_totp(user_id)
+            .await?
+            .into_iter()
+            .map(|user_totp| UserConnection {
+                id: user_totp.id,
+                method_id: TOTP_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: None,
+                sign_in: false,
+                unlinkable: false,
+            })
+            .collect())
+    }
+
+    async fn second_factor_action_id(&self, user_id: &str) -> Result<Option<String>, ShieldError> {
+        Ok(self
+            .storage
+            .user_totp(user_id)
+            .await?
+            .map(|_| VERIFY_ACTION_ID.to_owned()))
+    }
+}
+
+erased_method!(TotpMethod, <U: User>);
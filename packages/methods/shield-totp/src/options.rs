@@ -0,0 +1,70 @@
+use bon::Builder;
+use shield::{ConfigurationError, ShieldError};
+use totp_rs::{Builder as TotpBuilder, Secret, Totp};
+
+#[derive(Builder, Clone)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct TotpOptions {
+    /// Name of the service shown in authenticator apps.
+    pub(crate) issuer: String,
+
+    #[builder(default = 6)]
+    pub(crate) digits: u8,
+
+    #[builder(default = 30)]
+    pub(crate) step: u64,
+
+    /// Number of steps before and after the current step that are accepted.
+    #[builder(default = 1)]
+    pub(crate) skew: u16,
+
+    #[builder(default = 10)]
+    pub(crate) recovery_codes: usize,
+
+    #[builder(default = "/")]
+    pub(crate) verify_redirect: String,
+}
+
+impl TotpOptions {
+    pub(crate) fn totp(&self, secret: &str, account_name: &str) -> Result<Totp, ShieldError> {
+        let secret = Secret::try_from_base32(secret)
+            .map_err(|err| ShieldError::Validation(format!("invalid TOTP secret: {err}")))?;
+
+        TotpBuilder::new()
+            .with_secret(secret)
+            .with_digits(self.digits)
+            .with_step_duration(self.step)
+            .with_skew(self.skew)
+            .with_issuer(Some(self.issuer.as_str()))
+            .with_account_name(account_name.replace(':', ""))
+            .build()
+            .map_err(|err| {
+                ConfigurationError::Invalid(format!("invalid TOTP configuration: {err}")).into()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use totp_rs::Secret;
+
+    use super::TotpOptions;
+
+    #[test]
+    fn test_totp() {
+        let options = TotpOptions::builder().issuer("Shield").build();
+        let secret = Secret::generate().to_base32();
+
+        let totp = options
+            .totp(&secret, "alice@example.com")
+            .expect("TOTP should build.");
+
+        let url = totp.to_url().expect("URL should be generated.");
+        assert!(url.starts_with("otpauth://totp/Shield:alice%40example.com?"));
+        assert!(url.contains(&format!("secret={secret}")));
+
+        let code = totp.generate(1_000_000).to_string();
+        assert_eq!(totp.check(&code, 1_000_000), Some(1_000_000 / 30));
+        assert_eq!(totp.check(&code, 1_000_000 + 90), None);
+    }
+}
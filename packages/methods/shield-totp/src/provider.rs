@@ -0,0 +1,19 @@
+use shield::Provider;
+
+use crate::method::TOTP_METHOD_ID;
+
+pub struct TotpProvider;
+
+impl Provider for TotpProvider {
+    fn method_id(&self) -> String {
+        TOTP_METHOD_ID.to_owned()
+    }
+
+    fn id(&self) -> Option<String> {
+        None
+    }
+
+    fn name(&self) -> String {
+        "Authenticator app".to_owned()
+    }
+}
@@ -0,0 +1,147 @@
+use rand::distr::{Alphanumeric, SampleString};
+use serde::Serialize;
+use sha3::{Digest, Sha3_256};
+use shield::{ShieldError, User};
+
+use crate::{options::TotpOptions, storage::TotpStorage};
+
+#[derive(Clone, Debug)]
+pub struct TotpRecoveryCode {
+    pub id: String,
+    pub code_hash: String,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateTotpRecoveryCode {
+    pub code_hash: String,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpRecoveryCodes {
+    pub recovery_codes: Vec<String>,
+}
+
+const RECOVERY_CODE_LENGTH: usize = 10;
+
+/// Replace the user's recovery codes, returning the new codes in plain text.
+pub(crate) async fn create_recovery_codes<U: User>(
+    options: &TotpOptions,
+    storage: &dyn TotpStorage<U>,
+    user_id: &str,
+) -> Result<TotpRecoveryCodes, ShieldError> {
+    storage.delete_totp_recovery_codes(user_id).await?;
+
+    let mut recovery_codes = Vec::with_capacity(options.recovery_codes);
+    for _ in 0..options.recovery_codes {
+        let recovery_code = generate_recovery_code();
+
+        storage
+            .create_totp_recovery_code(CreateTotpRecoveryCode {
+                code_hash: hash_recovery_code(&recovery_code),
+                user_id: user_id.to_owned(),
+            })
+            .await?;
+
+        recovery_codes.push(recovery_code);
+    }
+
+    Ok(TotpRecoveryCodes { recovery_codes })
+}
+
+/// Check a recovery code, consuming it when it matches.
+pub(crate) async fn verify_recovery_code<U: User>(
+    storage: &dyn TotpStorage<U>,
+    user_id: &str,
+    code: &str,
+) -> Result<bool, ShieldError> {
+    let code_hash = hash_recovery_code(code);
+
+    let Some(recovery_code) = storage
+        .totp_recovery_codes(user_id)
+        .await?
+        .into_iter()
+        .find(|recovery_code| recovery_code.code_hash == code_hash)
+    else {
+        return Ok(false);
+    };
+
+    Ok(storage.delete_totp_recovery_code(&recovery_code.id).await?)
+}
+
+/// Generate a recovery code formatted as two groups of five characters.
+pub(crate) fn generate_recovery_code() -> String {
+    let code = Alphanumeric
+        .sample_string(&mut rand::rng(), RECOVERY_CODE_LENGTH)
+        .to_lowercase();
+
+    format!(
+        "{}-{}",
+        &code[..RECOVERY_CODE_LENGTH / 2],
+        &code[RECOVERY_CODE_LENGTH / 2..]
+    )
+}
+
+/// Recovery codes are random, so a fast hash is sufficient.
+pub(crate) fn hash_recovery_code(code: &str) -> String {
+    hex::encode(Sha3_256::digest(normalize_recovery_code(code)))
+}
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use shield::ShieldError;
+
+    use crate::{
+        options::TotpOptions,
+        storage::tests::{TestStorage, TestUser},
+    };
+
+    use super::{
+        create_recovery_codes, generate_recovery_code, hash_recovery_code, verify_recovery_code,
+    };
+
+    #[test]
+    fn test_recovery_code() {
+        let code = generate_recovery_code();
+
+        assert_eq!(code.len(), 11);
+        assert_eq!(
+            hash_recovery_code(&code),
+            hash_recovery_code(&format!(" {} ", code.to_uppercase().replace('-', "")))
+        );
+        assert_ne!(
+            hash_recovery_code(&code),
+            hash_recovery_code(&generate_recovery_code())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_recovery_code() -> Result<(), ShieldError> {
+        let options = TotpOptions::builder().issuer("Shield").build();
+        let storage = TestStorage::default();
+
+        let recovery_codes = create_recovery_codes::<TestUser>(&options, &storage, "1").await?;
+        let code = &recovery_codes.recovery_codes[0];
+        let verify = |code| verify_recovery_code::<TestUser>(&storage, "1", code);
+
+        assert!(!verify("invalid").await?);
+
+        // Both verifications find the code before either deleted it, only one of them succeeds.
+        let (first, second) = tokio::join!(verify(code), verify(code));
+        assert!(first? != second?);
+
+        assert!(!verify(code).await?);
+        assert!(verify(&recovery_codes.recovery_codes[1]).await?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TotpSession {
+    /// Base32 encoded secret of an enrollment that has not been confirmed yet.
+    pub secret: Option<String>,
+}
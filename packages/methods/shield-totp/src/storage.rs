@@ -0,0 +1,244 @@
+use async_trait::async_trait;
+
+use shield::{Storage, StorageError, User};
+
+use crate::{
+    recovery_code::{CreateTotpRecoveryCode, TotpRecoveryCode},
+    totp::{CreateUserTotp, UpdateUserTotp, UserTotp},
+};
+
+#[async_trait]
+pub trait TotpStorage<U: User>: Storage<U> + Sync {
+    async fn user_totp(&self, user_id: &str) -> Result<Option<UserTotp>, StorageError>;
+
+    async fn create_user_totp(&self, user_totp: CreateUserTotp) -> Result<UserTotp, StorageError>;
+
+    async fn update_user_totp(&self, user_totp: UpdateUserTotp) -> Result<UserTotp, StorageError>;
+
+    /// Record the step of a verified code as used, unless the same or a later step has been used already.
+    ///
+    /// Returns whether the step was recorded. This must be a single conditional update, so concurrent
+    /// verifications with the same code can not both succeed.
+    async fn use_user_totp_step(&self, user_totp_id: &str, step: i64)
+    -> Result<bool, StorageError>;
+
+    async fn delete_user_totp(&self, user_totp_id: &str) -> Result<(), StorageError>;
+
+    async fn totp_recovery_codes(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<TotpRecoveryCode>, StorageError>;
+
+    async fn create_totp_recovery_code(
+        &self,
+        recovery_code: CreateTotpRecoveryCode,
+    ) -> Result<TotpRecoveryCode, StorageError>;
+
+    /// Delete a recovery code, consuming it.
+    ///
+    /// Returns whether the recovery code was deleted. This must be a single conditional delete, so concurrent
+    /// verifications with the same code can not both succeed.
+    async fn delete_totp_recovery_code(&self, recovery_code_id: &str)
+    -> Result<bool, StorageError>;
+
+    async fn delete_totp_recovery_codes(&self, user_id: &str) -> Result<(), StorageError>;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+    use shield::{
+        CreateEmailAddress, CreateUser, EmailAddress, Storage, StorageError, UpdateUser, User,
+    };
+
+    use crate::{
+        recovery_code::{CreateTotpRecoveryCode, TotpRecoveryCode},
+        totp::{CreateUserTotp, UpdateUserTotp, UserTotp},
+    };
+
+    use super::TotpStorage;
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct TestUser {
+        id: String,
+    }
+
+    #[async_trait]
+    impl User for TestUser {
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn name(&self) -> Option<String> {
+            None
+        }
+
+        async fn email_addresses(&self) -> Result<Vec<EmailAddress>, StorageError> {
+            Ok(vec![])
+        }
+
+        fn additional(&self) -> Option<impl Serialize> {
+            None::<()>
+        }
+    }
+
+    #[derive(Default)]
+    pub struct TestStorage {
+        user_totps: Mutex<Vec<UserTotp>>,
+        recovery_codes: Mutex<Vec<TotpRecoveryCode>>,
+    }
+
+    #[async_trait]
+    impl Storage<TestUser> for TestStorage {
+        fn id(&self) -> String {
+            "test".to_owned()
+        }
+
+        async fn user_by_id(&self, _user_id: &str) -> Result<Option<TestUser>, StorageError> {
+            todo!("user_by_id")
+        }
+
+        async fn user_by_email(&self, _email: &str) -> Result<Option<TestUser>, StorageError> {
+            todo!("user_by_email")
+        }
+
+        async fn create_user(
+            &self,
+            _user: CreateUser,
+            _email_address: CreateEmailAddress,
+        ) -> Result<TestUser, StorageError> {
+            todo!("create_user")
+        }
+
+        async fn update_user(&self, _user: UpdateUser) -> Result<TestUser, StorageError> {
+            todo!("update_user")
+        }
+
+        async fn delete_user(&self, _user_id: &str) -> Result<(), StorageError> {
+            todo!("delete_user")
+        }
+    }
+
+    #[async_trait]
+    impl TotpStorage<TestUser> for TestStorage {
+        async fn user_totp(&self, user_id: &str) -> Result<Option<UserTotp>, StorageError> {
+            Ok(self
+                .user_totps
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|user_totp| user_totp.user_id == user_id)
+                .cloned())
+        }
+
+        async fn create_user_totp(
+            &self,
+            user_totp: CreateUserTotp,
+        ) -> Result<UserTotp, StorageError> {
+            let mut user_totps = self.user_totps.lock().expect("lock");
+            let user_totp = UserTotp {
+                id: (user_totps.len() + 1).to_string(),
+                secret: user_totp.secret,
+                last_used_step: user_totp.last_used_step,
+                user_id: user_totp.user_id,
+            };
+            user_totps.push(user_totp.clone());
+
+            Ok(user_totp)
+        }
+
+        async fn update_user_totp(
+            &self,
+            _user_totp: UpdateUserTotp,
+        ) -> Result<UserTotp, StorageError> {
+            todo!("update_user_totp")
+        }
+
+        async fn use_user_totp_step(
+            &self,
+            user_totp_id: &str,
+            step: i64,
+        ) -> Result<bool, StorageError> {
+            // Let concurrent verifications interleave, like a round trip to a database.
+            tokio::task::yield_now().await;
+
+            let mut user_totps = self.user_totps.lock().expect("lock");
+            let user_totp = user_totps
+                .iter_mut()
+                .find(|user_totp| user_totp.id == user_totp_id)
+                .ok_or_else(|| {
+                    StorageError::NotFound("UserTotp".to_owned(), user_totp_id.to_owned())
+                })?;
+
+            if user_totp
+                .last_used_step
+                .is_some_and(|last_used_step| last_used_step >= step)
+            {
+                return Ok(false);
+            }
+
+            user_totp.last_used_step = Some(step);
+
+            Ok(true)
+        }
+
+        async fn delete_user_totp(&self, _user_totp_id: &str) -> Result<(), StorageError> {
+            todo!("delete_user_totp")
+        }
+
+        async fn totp_recovery_codes(
+            &self,
+            user_id: &str,
+        ) -> Result<Vec<TotpRecoveryCode>, StorageError> {
+            Ok(self
+                .recovery_codes
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|recovery_code| recovery_code.user_id == user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn create_totp_recovery_code(
+            &self,
+            recovery_code: CreateTotpRecoveryCode,
+        ) -> Result<TotpRecoveryCode, StorageError> {
+            let mut recovery_codes = self.recovery_codes.lock().expect("lock");
+            let recovery_code = TotpRecoveryCode {
+                id: (recovery_codes.len() + 1).to_string(),
+                code_hash: recovery_code.code_hash,
+                user_id: recovery_code.user_id,
+            };
+            recovery_codes.push(recovery_code.clone());
+
+            Ok(recovery_code)
+        }
+
+        async fn delete_totp_recovery_code(
+            &self,
+            recovery_code_id: &str,
+        ) -> Result<bool, StorageError> {
+            // Let concurrent verifications interleave, like a round trip to a database.
+            tokio::task::yield_now().await;
+
+            let mut recovery_codes = self.recovery_codes.lock().expect("lock");
+            let length = recovery_codes.len();
+            recovery_codes.retain(|recovery_code| recovery_code.id != recovery_code_id);
+
+            Ok(recovery_codes.len() < length)
+        }
+
+        async fn delete_totp_recovery_codes(&self, user_id: &str) -> Result<(), StorageError> {
+            self.recovery_codes
+                .lock()
+                .expect("lock")
+                .retain(|recovery_code| recovery_code.user_id != user_id);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::Utc;
+use secrecy::{ExposeSecret, SecretString};
+use shield::{ShieldError, User};
+
+use crate::{options::TotpOptions, storage::TotpStorage};
+
+#[derive(Clone, Debug)]
+pub struct UserTotp {
+    pub id: String,
+    pub secret: SecretString,
+    pub last_used_step: Option<i64>,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateUserTotp {
+    pub secret: SecretString,
+    pub last_used_step: Option<i64>,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateUserTotp {
+    pub id: String,
+    pub last_used_step: Option<Option<i64>>,
+}
+
+/// Check a code against the user's secret, rejecting codes of a step that was already used.
+pub(crate) async fn verify_user_totp<U: User>(
+    options: &TotpOptions,
+    storage: &dyn TotpStorage<U>,
+    user_totp: &UserTotp,
+    code: &str,
+) -> Result<bool, ShieldError> {
+    let totp = options.totp(user_totp.secret.expose_secret(), "")?;
+
+    let now = Utc::now().timestamp().max(0) as u64;
+    let Some(step) = totp.check(code.trim(), now).map(|step| step as i64) else {
+        return Ok(false);
+    };
+
+    if user_totp
+        .last_used_step
+        .is_some_and(|last_used_step| last_used_step >= step)
+    {
+        return Ok(false);
+    }
+
+    // Losing a race with a concurrent verification of the same code is treated the same as reuse.
+    Ok(storage.use_user_totp_step(&user_totp.id, step).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use shield::ShieldError;
+    use totp_rs::Secret;
+
+    use crate::{
+        options::TotpOptions,
+        storage::{
+            TotpStorage,
+            tests::{TestStorage, TestUser},
+        },
+    };
+
+    use super::{CreateUserTotp, verify_user_totp};
+
+    #[tokio::test]
+    async fn test_verify_user_totp() -> Result<(), ShieldError> {
+        let options = TotpOptions::builder().issuer("Shield").build();
+        let storage = TestStorage::default();
+        let secret = Secret::generate().to_base32();
+        let user_totp = storage
+            .create_user_totp(CreateUserTotp {
+                secret: secret.clone().into(),
+                last_used_step: None,
+                user_id: "1".to_owned(),
+            })
+            .await?;
+
+        let code = options
+            .totp(&secret, "")?
+            .generate(Utc::now().timestamp() as u64)
+            .to_string();
+        let verify = |code| verify_user_totp::<TestUser>(&options, &storage, &user_totp, code);
+
+        assert!(!verify("invalid").await?);
+
+        // Both verifications read the TOTP before either recorded the step, only one of them succeeds.
+        let (first, second) = tokio::join!(verify(&code), verify(&code));
+        assert!(first? != second?);
+
+        assert!(!verify(&code).await?);
+
+        Ok(())
+    }
+}
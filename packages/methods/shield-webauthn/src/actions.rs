@@ -0,0 +1,9 @@
+mod register;
+mod register_callback;
+mod sign_in;
+mod sign_in_callback;
+
+pub use register::*;
+pub use register_callback::*;
+pub use sign_in::*;
+pub use sign_in_callback::*;
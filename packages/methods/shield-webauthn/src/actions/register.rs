@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shield::{
+    Form, Input, InputType, InputTypeSubmit, InputValue, MethodAction, MethodSession, Request,
+    RequestMethod, Response, ResponseType, SessionAction, ShieldError, User, erased_method_action,
+};
+use webauthn_rs::Webauthn;
+use webauthn_rs_proto::ResidentKeyRequirement;
+
+use crate::{
+    credential::user_handle, options::WebauthnOptions, provider::WebauthnProvider,
+    session::WebauthnSession, storage::WebauthnStorage,
+};
+
+pub const REGISTER_ACTION_ID: &str = "register";
+const REGISTER_ACTION_NAME: &str = "Register passkey";
+
+pub struct WebauthnRegisterAction<U: User> {
+    options: WebauthnOptions,
+    webauthn: Arc<Webauthn>,
+    storage: Arc<dyn WebauthnStorage<U>>,
+}
+
+impl<U: User> WebauthnRegisterAction<U> {
+    pub fn new(
+        options: WebauthnOptions,
+        webauthn: Arc<Webauthn>,
+        storage: Arc<dyn WebauthnStorage<U>>,
+    ) -> Self {
+        Self {
+            options,
+            webauthn,
+            storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<WebauthnProvider, WebauthnSession>
+    for WebauthnRegisterAction<U>
+{
+    fn id(&self) -> String {
+        REGISTER_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        REGISTER_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Register passkey"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Register a passkey for the current user."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &WebauthnProvider,
+        session: &MethodSession<WebauthnSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.authentication.is_some())
+    }
+
+    async fn forms(&self, _provider: WebauthnProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![Input {
+                name: "submit".to_owned(),
+                label: None,
+                r#type: InputType::Submit(InputTypeSubmit::default()),
+                value: Some(InputValue::String {
+                    value: "Register passkey".to_owned(),
+                }),
+                addon_start: None,
+                addon_end: None,
+            }],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: WebauthnProvider,
+        session: &MethodSession<WebauthnSession>,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .base
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let user = self
+            .storage
+            .user_by_id(&authentication.user_id)
+            .await?
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let user_name = user
+            .email_addresses()
+            .await?
+            .into_iter()
+            .find(|email_address| email_address.is_primary)
+            .map(|email_address| email_address.email)
+            .unwrap_or_else(|| user.id());
+        let user_display_name = user.name().unwrap_or_else(|| user_name.clone());
+
+        let exclude_credentials = self
+            .storage
+            .user_webauthn_credentials(&user.id())
+            .await?
+            .into_iter()
+            .map(|credential| credential.passkey.cred_id().clone())
+            .collect::<Vec<_>>();
+
+        let (mut challenge, registration) = self
+            .webauthn
+            .start_passkey_registration(
+                user_handle(&user.id()),
+                &user_name,
+                &user_display_name,
+                Some(exclude_credentials),
+            )
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        // Sign in uses discoverable credentials, so the passkey has to be stored on the authenticator.
+        if let Some(authenticator_selection) = &mut challenge.public_key.authenticator_selection {
+            authenticator_selection.resident_key = Some(ResidentKeyRequirement::Required);
+            authenticator_selection.require_resident_key = true;
+        }
+
+        let challenge = serde_json::to_value(challenge)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(
+            Response::new(ResponseType::Data(challenge)).session_action(
+                SessionAction::method_data(
+                    &provider,
+                    WebauthnSession {
+                        registration: Some(registration),
+                        authentication: None,
+                        user_id: Some(user.id()),
+                        expired_at: Some(Utc::now() + self.options.challenge_expires_in),
+                    },
+                )?,
+            ),
+        )
+    }
+}
+
+erased_method_action!(WebauthnRegisterAction, <U: User>);
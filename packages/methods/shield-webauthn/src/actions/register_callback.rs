@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue, MethodAction,
+    MethodSession, Request, RequestMethod, Response, ResponseType, SessionAction, ShieldError,
+    User, erased_method_action,
+};
+use webauthn_rs::{Webauthn, prelude::RegisterPublicKeyCredential};
+
+use crate::{
+    credential::{CreateWebauthnCredential, encode_credential_id},
+    options::WebauthnOptions,
+    provider::WebauthnProvider,
+    session::WebauthnSession,
+    storage::WebauthnStorage,
+};
+
+pub const REGISTER_CALLBACK_ACTION_ID: &str = "register-callback";
+const REGISTER_CALLBACK_ACTION_NAME: &str = "Register passkey callback";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterCallbackData {
+    pub credential: String,
+}
+
+pub struct WebauthnRegisterCallbackAction<U: User> {
+    options: WebauthnOptions,
+    webauthn: Arc<Webauthn>,
+    storage: Arc<dyn WebauthnStorage<U>>,
+}
+
+impl<U: User> WebauthnRegisterCallbackAction<U> {
+    pub fn new(
+        options: WebauthnOptions,
+        webauthn: Arc<Webauthn>,
+        storage: Arc<dyn WebauthnStorage<U>>,
+    ) -> Self {
+        Self {
+            options,
+            webauthn,
+            storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<WebauthnProvider, WebauthnSession>
+    for WebauthnRegisterCallbackAction<U>
+{
+    fn id(&self) -> String {
+        REGISTER_CALLBACK_ACTION_ID.to_owned()
+    }
+
+    fn name(&self) -> String {
+        REGISTER_CALLBACK_ACTION_NAME.to_owned()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Register passkey callback"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Register passkey callback."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        _provider: &WebauthnProvider,
+        session: &MethodSession<WebauthnSession>,
+    ) -> Result<bool, ShieldError> {
+        Ok(session.base.authentication.is_some())
+    }
+
+    async fn forms(&self, _provider: WebauthnProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "credential".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden {
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: "Register passkey".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: WebauthnProvider,
+        session: &MethodSession<WebauthnSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let authentication = session
+            .base
+            .authentication
+            .as_ref()
+            .ok_or(ShieldError::Unauthorized)?;
+
+        let data = serde_json::from_value::<RegisterCallbackData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let credential = serde_json::from_str::<RegisterPublicKeyCredential>(&data.credential)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let (Some(registration), Some(user_id)) =
+            (&session.method.registration, &session.method.user_id)
+        else {
+            return Err(ShieldError::Validation(
+                "Missing passkey registration state.".to_owned(),
+            ));
+        };
+
+        if authentication.user_id != *user_id {
+            return Err(ShieldError::Unauthorized);
+        }
+
+        if session.method.is_expired() {
+            return Err(ShieldError::Validation(
+                "Passkey registration has expired.".to_owned(),
+            ));
+        }
+
+        // Verifies the challenge, origin and attestation.
+        let passkey = self
+            .webauthn
+            .finish_passkey_registration(&credential, registration)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let credential_id = encode_credential_id(passkey.cred_id());
+
+        if self
+            .storage
+            .webauthn_credential_by_credential_id(&credential_id)
+            .await?
+            .is_some()
+        {
+            return Err(ShieldError::Validation(
+                "Passkey is already registered.".to_owned(),
+            ));
+        }
+
+        self.storage
+            .create_webauthn_credential(CreateWebauthnCredential {
+                credential_id,
+                passkey,
+                user_id: user_id.clone(),
+            })
+            .await?;
+
+        Ok(Response::new(ResponseType::Redirect(
+            self.options.register_redirect.clone(),
+        ))
+        .session_action(SessionAction::method_data(
+            &provider,
+            WebauthnSession::default(),
+        )?))
+    }
+}
+
+erased_method_action!(WebauthnRegisterCallbackAction, <U: User>);
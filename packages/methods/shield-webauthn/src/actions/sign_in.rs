@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shield::{
+    Form, Input, InputType, InputTypeSubmit, InputValue, MethodAction, MethodSession, Request,
+    RequestMethod, Response, ResponseType, SessionAction, ShieldError, SignInAction,
+    erased_method_action,
+};
+use webauthn_rs::Webauthn;
+
+use crate::{options::WebauthnOptions, provider::WebauthnProvider, session::WebauthnSession};
+
+pub struct WebauthnSignInAction {
+    options: WebauthnOptions,
+    webauthn: Arc<Webauthn>,
+}
+
+impl WebauthnSignInAction {
+    pub fn new(options: WebauthnOptions, webauthn: Arc<Webauthn>) -> Self {
+        Self { options, webauthn }
+    }
+}
+
+#[async_trait]
+impl MethodAction<WebauthnProvider, WebauthnSession> for WebauthnSignInAction {
+    fn id(&self) -> String {
+        SignInAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignInAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign in with passkey"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign in with passkey."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    async fn forms(&self, _provider: WebauthnProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![Input {
+                name: "submit".to_owned(),
+                label: None,
+                r#type: InputType::Submit(InputTypeSubmit::default()),
+                value: Some(InputValue::String {
+                    value: "Sign in with passkey".to_owned(),
+                }),
+                addon_start: None,
+                addon_end: None,
+            }],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: WebauthnProvider,
+        _session: &MethodSession<WebauthnSession>,
+        _request: Request,
+    ) -> Result<Response, ShieldError> {
+        // Passkeys are discoverable, so the authenticator picks the user and no identifier is needed.
+        let (mut challenge, authentication) = self
+            .webauthn
+            .start_discoverable_authentication()
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        // Sign in is started explicitly by the user, so show the regular passkey prompt.
+        challenge.mediation = None;
+
+        let challenge = serde_json::to_value(challenge)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        Ok(
+            Response::new(ResponseType::Data(challenge)).session_action(
+                SessionAction::method_data(
+                    &provider,
+                    WebauthnSession {
+                        registration: None,
+                        authentication: Some(authentication),
+                        user_id: None,
+                        expired_at: Some(Utc::now() + self.options.challenge_expires_in),
+                    },
+                )?,
+            ),
+        )
+    }
+}
+
+erased_method_action!(WebauthnSignInAction);
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use shield::{
+    Form, Input, InputType, InputTypeHidden, InputTypeSubmit, InputValue, MethodAction,
+    MethodSession, Request, RequestMethod, Response, ResponseType, SessionAction, ShieldError,
+    SignInCallbackAction, User, erased_method_action,
+};
+use webauthn_rs::{
+    Webauthn,
+    prelude::{DiscoverableKey, PublicKeyCredential},
+};
+
+use crate::{
+    credential::{UpdateWebauthnCredential, encode_credential_id, user_handle},
+    options::WebauthnOptions,
+    provider::WebauthnProvider,
+    session::WebauthnSession,
+    storage::WebauthnStorage,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInCallbackData {
+    pub credential: String,
+}
+
+pub struct WebauthnSignInCallbackAction<U: User> {
+    options: WebauthnOptions,
+    webauthn: Arc<Webauthn>,
+    storage: Arc<dyn WebauthnStorage<U>>,
+}
+
+impl<U: User> WebauthnSignInCallbackAction<U> {
+    pub fn new(
+        options: WebauthnOptions,
+        webauthn: Arc<Webauthn>,
+        storage: Arc<dyn WebauthnStorage<U>>,
+    ) -> Self {
+        Self {
+            options,
+            webauthn,
+            storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> MethodAction<WebauthnProvider, WebauthnSession>
+    for WebauthnSignInCallbackAction<U>
+{
+    fn id(&self) -> String {
+        SignInCallbackAction::id()
+    }
+
+    fn name(&self) -> String {
+        SignInCallbackAction::name()
+    }
+
+    fn openapi_summary(&self) -> &'static str {
+        "Sign in callback for passkey"
+    }
+
+    fn openapi_description(&self) -> &'static str {
+        "Sign in callback for passkey."
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn condition(
+        &self,
+        provider: &WebauthnProvider,
+        session: &MethodSession<WebauthnSession>,
+    ) -> Result<bool, ShieldError> {
+        SignInCallbackAction::condition(provider, session)
+    }
+
+    async fn forms(&self, _provider: WebauthnProvider) -> Result<Vec<Form>, ShieldError> {
+        Ok(vec![Form {
+            inputs: vec![
+                Input {
+                    name: "credential".to_owned(),
+                    label: None,
+                    r#type: InputType::Hidden(InputTypeHidden {
+                        required: Some(true),
+                        ..Default::default()
+                    }),
+                    value: None,
+                    addon_start: None,
+                    addon_end: None,
+                },
+                Input {
+                    name: "submit".to_owned(),
+                    label: None,
+                    r#type: InputType::Submit(InputTypeSubmit::default()),
+                    value: Some(InputValue::String {
+                        value: "Sign in with passkey".to_owned(),
+                    }),
+                    addon_start: None,
+                    addon_end: None,
+                },
+            ],
+        }])
+    }
+
+    async fn call(
+        &self,
+        provider: WebauthnProvider,
+        session: &MethodSession<WebauthnSession>,
+        request: Request,
+    ) -> Result<Response, ShieldError> {
+        let data = serde_json::from_value::<SignInCallbackData>(request.form_data)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let credential = serde_json::from_str::<PublicKeyCredential>(&data.credential)
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let Some(authentication) = &session.method.authentication else {
+            return Err(ShieldError::Validation(
+                "Missing passkey authentication state.".to_owned(),
+            ));
+        };
+
+        if session.method.is_expired() {
+            return Err(ShieldError::Validation(
+                "Passkey sign in has expired.".to_owned(),
+            ));
+        }
+
+        let (handle, credential_id) = self
+            .webauthn
+            .identify_discoverable_authentication(&credential)
+            .map_err(|_| sign_in_failed())?;
+
+        // The user handle is not covered by the assertion signature, so only trust it once it matches the stored credential.
+        let mut webauthn_credential = self
+            .storage
+            .webauthn_credential_by_credential_id(&encode_credential_id(credential_id))
+            .await?
+            .filter(|webauthn_credential| user_handle(&webauthn_credential.user_id) == handle)
+            .ok_or_else(sign_in_failed)?;
+
+        // Verifies the challenge, origin, assertion signature and signature counter.
+        let result = self
+            .webauthn
+            .finish_discoverable_authentication(
+                &credential,
+                authentication.clone(),
+                &[DiscoverableKey::from(&webauthn_credential.passkey)],
+            )
+            .map_err(|err| ShieldError::Validation(err.to_string()))?;
+
+        let passkey = webauthn_credential
+            .passkey
+            .update_credential(&result)
+            .unwrap_or_default()
+            .then_some(webauthn_credential.passkey);
+
+        let user_id = webauthn_credential.user_id;
+
+        self.storage
+            .update_webauthn_credential(UpdateWebauthnCredential {
+                id: webauthn_credential.id,
+                passkey,
+                last_used_at: Some(Some(Utc::now().into())),
+            })
+            .await?;
+
+        let user = self
+            .storage
+            .user_by_id(&user_id)
+            .await?
+            .ok_or_else(sign_in_failed)?;
+
+        Ok(Response::new(ResponseType::Redirect(
+            self.options.sign_in_redirect.clone(),
+        ))
+        .session_action(SessionAction::method_data(
+            &provider,
+            WebauthnSession::default(),
+        )?)
+        .session_action(SessionAction::authenticate(&provider, user)))
+    }
+}
+
+erased_method_action!(WebauthnSignInCallbackAction, <U: User>);
+
+fn sign_in_failed() -> ShieldError {
+    ShieldError::Validation("Passkey sign in failed.".to_owned())
+}
@@ -0,0 +1,36 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, FixedOffset};
+use uuid::Uuid;
+use webauthn_rs::prelude::Passkey;
+
+#[derive(Clone, Debug)]
+pub struct WebauthnCredential {
+    pub id: String,
+    pub credential_id: String,
+    pub passkey: Passkey,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateWebauthnCredential {
+    pub credential_id: String,
+    pub passkey: Passkey,
+    pub user_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateWebauthnCredential {
+    pub id: String,
+    pub passkey: Option<Passkey>,
+    pub last_used_at: Option<Option<DateTime<FixedOffset>>>,
+}
+
+pub(crate) fn encode_credential_id(credential_id: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(credential_id)
+}
+
+/// WebAuthn requires an opaque user handle, so derive a stable one from the user ID.
+pub(crate) fn user_handle(user_id: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_OID, user_id.as_bytes())
+}
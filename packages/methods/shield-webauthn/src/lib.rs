@@ -1 +1,13 @@
+mod actions;
+mod credential;
+mod method;
+mod options;
+mod provider;
+mod session;
+mod storage;
 
+pub use credential::*;
+pub use method::*;
+pub use options::*;
+pub use provider::*;
+pub use storage::*;
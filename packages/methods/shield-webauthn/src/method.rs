@@ -0,0 +1,649 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shield::{
+    Method, MethodAction, ShieldError, StorageError, User, UserConnection, erased_method,
+};
+use webauthn_rs::Webauthn;
+
+use crate::{
+    actions::{
+        WebauthnRegisterAction, WebauthnRegisterCallbackAction, WebauthnSignInAction,
+        WebauthnSignInCallbackAction,
+    },
+    credential::WebauthnCredential,
+    options::WebauthnOptions,
+    provider::WebauthnProvider,
+    session::WebauthnSession,
+    storage::WebauthnStorage,
+};
+
+pub const WEBAUTHN_METHOD_ID: &str = "webauthn";
+
+/// Browser script that runs the passkey ceremonies against the Shield routes. Serve it to pages with passkey forms.
+pub const WEBAUTHN_SCRIPT: &str = include_str!("webauthn.js");
+
+pub struct WebauthnMethod<U: User> {
+    options: WebauthnOptions,
+    webauthn: Arc<Webauthn>,
+    storage: Arc<dyn WebauthnStorage<U>>,
+}
+
+impl<U: User> WebauthnMethod<U> {
+    pub fn new<S: WebauthnStorage<U> + 'static>(
+        options: WebauthnOptions,
+        storage: S,
+    ) -> Result<Self, ShieldError> {
+        Ok(Self {
+            webauthn: Arc::new(options.webauthn()?),
+            options,
+            storage: Arc::new(storage),
+        })
+    }
+}
+
+#[async_trait]
+impl<U: User + 'static> Method for WebauthnMethod<U> {
+    type Provider = WebauthnProvider;
+    type Connection = WebauthnCredential;
+    type Session = WebauthnSession;
+
+    fn id(&self) -> String {
+        WEBAUTHN_METHOD_ID.to_owned()
+    }
+
+    fn actions(&self) -> Vec<Box<dyn MethodAction<Self::Provider, Self::Session>>> {
+        vec![
+            Box::new(WebauthnSignInAction::new(
+                self.options.clone(),
+                self.webauthn.clone(),
+            )),
+            Box::new(WebauthnSignInCallbackAction::new(
+                self.options.clone(),
+                self.webauthn.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(WebauthnRegisterAction::new(
+                self.options.clone(),
+                self.webauthn.clone(),
+                self.storage.clone(),
+            )),
+            Box::new(WebauthnRegisterCallbackAction::new(
+                self.options.clone(),
+                self.webauthn.clone(),
+                self.storage.clone(),
+            )),
+        ]
+    }
+
+    async fn providers(&self) -> Result<Vec<Self::Provider>, ShieldError> {
+        Ok(vec![WebauthnProvider])
+    }
+
+    async fn user_connections(
+        &self,
+        user_id: &str,
+        _provider_id: Option<&str>,
+    ) -> Result<Vec<Self::Connection>, ShieldError> {
+        Ok(self.storage.user_webauthn_credentials(user_id).await?)
+    }
+
+    async fn list_user_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserConnection>, ShieldError> {
+        Ok(self
+            .storage
+            .user_webauthn_credentials(user_id)
+            .await?
+            .into_iter()
+            .map(|credential| UserConnection {
+                id: credential.id,
+                method_id: WEBAUTHN_METHOD_ID.to_owned(),
+                provider_id: None,
+                identifier: Some(credential.credential_id),
+                sign_in: true,
+                unlinkable: true,
+            })
+            .collect())
+    }
+
+    async fn unlink_user_connection(
+        &self,
+        user_id: &str,
+        connection_id: &str,
+    ) -> Result<(), ShieldError> {
+        match self
+            .storage
+            .webauthn_credential_by_id(connection_id)
+            .await?
+        {
+            Some(credential) if credential.user_id == user_id => Ok(self
+                .storage
+                .delete_webauthn_credential(&credential.id)
+                .await?),
+            _ => Err(StorageError::NotFound(
+                "WebauthnCredential".to_owned(),
+                connection_id.to_owned(),
+            )
+            .into()),
+        }
+    }
+}
+
+erased_method!(WebauthnMethod, <U: User>);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{TimeDelta, Utc};
+    use serde::{Deserialize, Serialize};
+    use serde_json::{Value, json};
+    use shield::{
+        Authentication, BaseSession, CreateEmailAddress, CreateUser, EmailAddress, MethodAction,
+        MethodSession, Request, Response, ResponseType, SessionAction, ShieldError, Storage,
+        StorageError, UpdateUser, User,
+    };
+    use webauthn_authenticator_rs::{WebauthnAuthenticator, softpasskey::SoftPasskey};
+    use webauthn_rs::{
+        Webauthn,
+        prelude::{
+            CreationChallengeResponse, PublicKeyCredential, RegisterPublicKeyCredential,
+            RequestChallengeResponse, Url,
+        },
+    };
+    use webauthn_rs_proto::{AllowCredentials, ResidentKeyRequirement};
+
+    use crate::{
+        actions::{
+            WebauthnRegisterAction, WebauthnRegisterCallbackAction, WebauthnSignInAction,
+            WebauthnSignInCallbackAction,
+        },
+        credential::{
+            CreateWebauthnCredential, UpdateWebauthnCredential, WebauthnCredential, user_handle,
+        },
+        options::WebauthnOptions,
+        provider::WebauthnProvider,
+        session::WebauthnSession,
+        storage::WebauthnStorage,
+    };
+
+    const ORIGIN: &str = "https://example.com";
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    struct TestUser {
+        id: String,
+    }
+
+    #[async_trait]
+    impl User for TestUser {
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn name(&self) -> Option<String> {
+            None
+        }
+
+        async fn email_addresses(&self) -> Result<Vec<EmailAddress>, StorageError> {
+            Ok(vec![])
+        }
+
+        fn additional(&self) -> Option<impl Serialize> {
+            None::<()>
+        }
+    }
+
+    #[derive(Default)]
+    struct TestStorage {
+        credentials: Mutex<Vec<WebauthnCredential>>,
+    }
+
+    impl TestStorage {
+        fn credentials(&self) -> Vec<WebauthnCredential> {
+            self.credentials.lock().expect("lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl Storage<TestUser> for TestStorage {
+        fn id(&self) -> String {
+            "test".to_owned()
+        }
+
+        async fn user_by_id(&self, user_id: &str) -> Result<Option<TestUser>, StorageError> {
+            Ok(Some(TestUser {
+                id: user_id.to_owned(),
+            }))
+        }
+
+        async fn user_by_email(&self, _email: &str) -> Result<Option<TestUser>, StorageError> {
+            todo!("user_by_email")
+        }
+
+        async fn create_user(
+            &self,
+            _user: CreateUser,
+            _email_address: CreateEmailAddress,
+        ) -> Result<TestUser, StorageError> {
+            todo!("create_user")
+        }
+
+        async fn update_user(&self, _user: UpdateUser) -> Result<TestUser, StorageError> {
+            todo!("update_user")
+        }
+
+        async fn delete_user(&self, _user_id: &str) -> Result<(), StorageError> {
+            todo!("delete_user")
+        }
+    }
+
+    #[async_trait]
+    impl WebauthnStorage<TestUser> for TestStorage {
+        async fn webauthn_credential_by_id(
+            &self,
+            id: &str,
+        ) -> Result<Option<WebauthnCredential>, StorageError> {
+            Ok(self
+                .credentials()
+                .into_iter()
+                .find(|credential| credential.id == id))
+        }
+
+        async fn webauthn_credential_by_credential_id(
+            &self,
+            credential_id: &str,
+        ) -> Result<Option<WebauthnCredential>, StorageError> {
+            Ok(self
+                .credentials()
+                .into_iter()
+                .find(|credential| credential.credential_id == credential_id))
+        }
+
+        async fn create_webauthn_credential(
+            &self,
+            credential: CreateWebauthnCredential,
+        ) -> Result<WebauthnCredential, StorageError> {
+            let mut credentials = self.credentials.lock().expect("lock");
+            let credential = WebauthnCredential {
+                id: credentials.len().to_string(),
+                credential_id: credential.credential_id,
+                passkey: credential.passkey,
+                last_used_at: None,
+                user_id: credential.user_id,
+            };
+            credentials.push(credential.clone());
+
+            Ok(credential)
+        }
+
+        async fn update_webauthn_credential(
+            &self,
+            update: UpdateWebauthnCredential,
+        ) -> Result<WebauthnCredential, StorageError> {
+            let mut credentials = self.credentials.lock().expect("lock");
+            let credential = credentials
+                .iter_mut()
+                .find(|credential| credential.id == update.id)
+                .ok_or_else(|| {
+                    StorageError::NotFound("WebauthnCredential".to_owned(), update.id)
+                })?;
+
+            if let Some(passkey) = update.passkey {
+                credential.passkey = passkey;
+            }
+            if let Some(last_used_at) = update.last_used_at {
+                credential.last_used_at = last_used_at;
+            }
+
+            Ok(credential.clone())
+        }
+
+        async fn delete_webauthn_credential(&self, id: &str) -> Result<(), StorageError> {
+            self.credentials
+                .lock()
+                .expect("lock")
+                .retain(|credential| credential.id != id);
+
+            Ok(())
+        }
+
+        async fn user_webauthn_credentials(
+            &self,
+            user_id: &str,
+        ) -> Result<Vec<WebauthnCredential>, StorageError> {
+            Ok(self
+                .credentials()
+                .into_iter()
+                .filter(|credential| credential.user_id == user_id)
+                .collect())
+        }
+    }
+
+    struct Test {
+        options: WebauthnOptions,
+        webauthn: Arc<Webauthn>,
+        storage: Arc<TestStorage>,
+        authenticator: WebauthnAuthenticator<SoftPasskey>,
+    }
+
+    impl Test {
+        fn new() -> Result<Self, ShieldError> {
+            let options = WebauthnOptions::builder()
+                .rp_id("example.com")
+                .rp_origin(ORIGIN)
+                .build();
+
+            Ok(Self {
+                webauthn: Arc::new(options.webauthn()?),
+                options,
+                storage: Arc::new(TestStorage::default()),
+                authenticator: WebauthnAuthenticator::new(SoftPasskey::new(true)),
+            })
+        }
+
+        fn signed_in(user_id: &str) -> BaseSession {
+            BaseSession {
+                authentication: Some(Authentication {
+                    user_id: user_id.to_owned(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        }
+
+        async fn start_register(
+            &mut self,
+            user_id: &str,
+        ) -> Result<(WebauthnSession, RegisterPublicKeyCredential), ShieldError> {
+            let response = WebauthnRegisterAction::new(
+                self.options.clone(),
+                self.webauthn.clone(),
+                self.storage.clone(),
+            )
+            .call(
+                WebauthnProvider,
+                &MethodSession {
+                    base: &Self::signed_in(user_id),
+                    method: &WebauthnSession::default(),
+                },
+                Request::new(json!({}), json!({})),
+            )
+            .await?;
+            let session = method_session(&response);
+
+            let mut challenge = serde_json::from_value::<CreationChallengeResponse>(data(response))
+                .expect("creation challenge");
+            let authenticator_selection = challenge
+                .public_key
+                .authenticator_selection
+                .as_mut()
+                .expect("authenticator selection");
+            assert_eq!(
+                authenticator_selection.resident_key,
+                Some(ResidentKeyRequirement::Required)
+            );
+            // The soft passkey can't store resident keys, the user handle is added to its assertions instead.
+            authenticator_selection.require_resident_key = false;
+
+            let credential = self
+                .authenticator
+                .do_registration(Url::parse(ORIGIN).expect("origin"), challenge)
+                .expect("registration");
+
+            Ok((session, credential))
+        }
+
+        async fn finish_register(
+            &self,
+            user_id: &str,
+            session: &WebauthnSession,
+            credential: &RegisterPublicKeyCredential,
+        ) -> Result<Response, ShieldError> {
+            WebauthnRegisterCallbackAction::new(
+                self.options.clone(),
+                self.webauthn.clone(),
+                self.storage.clone(),
+            )
+            .call(
+                WebauthnProvider,
+                &MethodSession {
+                    base: &Self::signed_in(user_id),
+                    method: session,
+                },
+                Request::new(
+                    json!({}),
+                    json!({ "credential": serde_json::to_string(credential).expect("credential") }),
+                ),
+            )
+            .await
+        }
+
+        async fn register(&mut self, user_id: &str) -> Result<Response, ShieldError> {
+            let (session, credential) = self.start_register(user_id).await?;
+
+            self.finish_register(user_id, &session, &credential).await
+        }
+
+        async fn start_sign_in(
+            &self,
+        ) -> Result<(WebauthnSession, RequestChallengeResponse), ShieldError> {
+            let response = WebauthnSignInAction::new(self.options.clone(), self.webauthn.clone())
+                .call(
+                    WebauthnProvider,
+                    &MethodSession {
+                        base: &BaseSession::default(),
+                        method: &WebauthnSession::default(),
+                    },
+                    Request::new(json!({}), json!({})),
+                )
+                .await?;
+            let session = method_session(&response);
+
+            let challenge = serde_json::from_value::<RequestChallengeResponse>(data(response))
+                .expect("request challenge");
+            assert!(challenge.public_key.allow_credentials.is_empty());
+            assert!(challenge.mediation.is_none());
+
+            Ok((session, challenge))
+        }
+
+        /// Sign the challenge like a browser would with a discoverable credential.
+        fn assert(
+            &mut self,
+            credential: &WebauthnCredential,
+            mut challenge: RequestChallengeResponse,
+        ) -> PublicKeyCredential {
+            // The soft passkey can't discover credentials, so point it to the registered one.
+            challenge.public_key.allow_credentials = vec![AllowCredentials {
+                type_: "public-key".to_owned(),
+                id: credential.passkey.cred_id().clone().into(),
+                transports: None,
+            }];
+
+            let mut assertion = self
+                .authenticator
+                .do_authentication(Url::parse(ORIGIN).expect("origin"), challenge)
+                .expect("authentication");
+            assertion.response.user_handle =
+                Some(user_handle(&credential.user_id).as_bytes().to_vec().into());
+
+            assertion
+        }
+
+        async fn finish_sign_in(
+            &self,
+            session: &WebauthnSession,
+            assertion: &PublicKeyCredential,
+        ) -> Result<Response, ShieldError> {
+            WebauthnSignInCallbackAction::new(
+                self.options.clone(),
+                self.webauthn.clone(),
+                self.storage.clone(),
+            )
+            .call(
+                WebauthnProvider,
+                &MethodSession {
+                    base: &BaseSession::default(),
+                    method: session,
+                },
+                Request::new(
+                    json!({}),
+                    json!({ "credential": serde_json::to_string(assertion).expect("assertion") }),
+                ),
+            )
+            .await
+        }
+    }
+
+    fn data(response: Response) -> Value {
+        match response.r#type {
+            ResponseType::Data(data) => data,
+            _ => panic!("expected data response"),
+        }
+    }
+
+    fn method_session(response: &Response) -> WebauthnSession {
+        response
+            .session_actions
+            .iter()
+            .find_map(|action| match action {
+                SessionAction::MethodData { value, .. } => {
+                    Some(serde_json::from_str(value).expect("method session"))
+                }
+                _ => None,
+            })
+            .expect("method data")
+    }
+
+    fn authenticated_user_id(response: &Response) -> Option<String> {
+        response
+            .session_actions
+            .iter()
+            .find_map(|action| match action {
+                SessionAction::Authenticate { user_id, .. } => Some(user_id.clone()),
+                _ => None,
+            })
+    }
+
+    #[tokio::test]
+    async fn register_and_sign_in() -> Result<(), ShieldError> {
+        let mut test = Test::new()?;
+
+        let response = test.register("1").await?;
+        assert!(matches!(response.r#type, ResponseType::Redirect(_)));
+
+        let credentials = test.storage.credentials();
+        assert_eq!(credentials.len(), 1);
+        assert_eq!(credentials[0].user_id, "1");
+
+        let (session, challenge) = test.start_sign_in().await?;
+        let assertion = test.assert(&credentials[0], challenge);
+        let response = test.finish_sign_in(&session, &assertion).await?;
+
+        assert_eq!(authenticated_user_id(&response), Some("1".to_owned()));
+
+        let credential = &test.storage.credentials()[0];
+        assert!(credential.last_used_at.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn register_rejects_expired_challenge() -> Result<(), ShieldError> {
+        let mut test = Test::new()?;
+
+        let (mut session, credential) = test.start_register("1").await?;
+        session.expired_at = Some(Utc::now() - TimeDelta::seconds(1));
+
+        let result = test.finish_register("1", &session, &credential).await;
+        assert!(result.is_err_and(|err| err.to_string().contains("expired")));
+        assert!(test.storage.credentials().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn register_rejects_other_user() -> Result<(), ShieldError> {
+        let mut test = Test::new()?;
+
+        let (session, credential) = test.start_register("1").await?;
+
+        let result = test.finish_register("2", &session, &credential).await;
+        assert!(matches!(result, Err(ShieldError::Unauthorized)));
+        assert!(test.storage.credentials().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_in_rejects_expired_challenge() -> Result<(), ShieldError> {
+        let mut test = Test::new()?;
+        test.register("1").await?;
+        let credential = test.storage.credentials()[0].clone();
+
+        let (mut session, challenge) = test.start_sign_in().await?;
+        let assertion = test.assert(&credential, challenge);
+
+        session.expired_at = Some(Utc::now() - TimeDelta::seconds(1));
+        let result = test.finish_sign_in(&session, &assertion).await;
+        assert!(result.is_err_and(|err| err.to_string().contains("expired")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_in_rejects_other_challenge() -> Result<(), ShieldError> {
+        let mut test = Test::new()?;
+        test.register("1").await?;
+        let credential = test.storage.credentials()[0].clone();
+
+        let (_, challenge) = test.start_sign_in().await?;
+        let assertion = test.assert(&credential, challenge);
+
+        let (other_session, _) = test.start_sign_in().await?;
+        let result = test.finish_sign_in(&other_session, &assertion).await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_in_rejects_unknown_user_handle() -> Result<(), ShieldError> {
+        let mut test = Test::new()?;
+        test.register("1").await?;
+        let credential = test.storage.credentials()[0].clone();
+
+        let (session, challenge) = test.start_sign_in().await?;
+        let mut assertion = test.assert(&credential, challenge);
+        assertion.response.user_handle = Some(user_handle("2").as_bytes().to_vec().into());
+
+        let result = test.finish_sign_in(&session, &assertion).await;
+        assert!(result.is_err_and(|err| err.to_string().contains("Passkey sign in failed.")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_in_detects_cloned_authenticator() -> Result<(), ShieldError> {
+        let mut test = Test::new()?;
+        test.register("1").await?;
+        let credential = test.storage.credentials()[0].clone();
+
+        // Two assertions from copies of the same key, the first one with a lower signature counter.
+        let (first_session, first_challenge) = test.start_sign_in().await?;
+        let first_assertion = test.assert(&credential, first_challenge);
+        let (second_session, second_challenge) = test.start_sign_in().await?;
+        let second_assertion = test.assert(&credential, second_challenge);
+
+        test.finish_sign_in(&second_session, &second_assertion)
+            .await?;
+
+        let result = test.finish_sign_in(&first_session, &first_assertion).await;
+        assert!(result.is_err_and(|err| err.to_string().contains("compromised")));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use bon::Builder;
+use chrono::TimeDelta;
+use shield::{ConfigurationError, ShieldError};
+use webauthn_rs::{
+    Webauthn, WebauthnBuilder,
+    prelude::{Url, WebauthnError},
+};
+
+#[derive(Builder, Clone)]
+#[builder(on(String, into), state_mod(vis = "pub(crate)"))]
+pub struct WebauthnOptions {
+    pub(crate) rp_id: String,
+
+    pub(crate) rp_origin: String,
+
+    #[builder(default = "Shield")]
+    pub(crate) rp_name: String,
+
+    /// How long a registration or sign in challenge remains valid.
+    #[builder(default = TimeDelta::minutes(5))]
+    pub(crate) challenge_expires_in: TimeDelta,
+
+    #[builder(default = "/")]
+    pub(crate) sign_in_redirect: String,
+
+    #[builder(default = "/")]
+    pub(crate) register_redirect: String,
+}
+
+impl WebauthnOptions {
+    pub(crate) fn webauthn(&self) -> Result<Webauthn, ShieldError> {
+        let rp_origin = Url::parse(&self.rp_origin).map_err(|err| {
+            ConfigurationError::Invalid(format!("invalid WebAuthn relying party origin: {err}"))
+        })?;
+
+        let timeout = self.challenge_expires_in.to_std().map_err(|err| {
+            ConfigurationError::Invalid(format!("invalid WebAuthn challenge expiry: {err}"))
+        })?;
+
+        WebauthnBuilder::new(&self.rp_id, &rp_origin)
+            .and_then(|builder| builder.rp_name(&self.rp_name).timeout(timeout).build())
+            .map_err(|err: WebauthnError| {
+                ConfigurationError::Invalid(format!("invalid WebAuthn configuration: {err}")).into()
+            })
+    }
+}
@@ -0,0 +1,19 @@
+use shield::Provider;
+
+use crate::method::WEBAUTHN_METHOD_ID;
+
+pub struct WebauthnProvider;
+
+impl Provider for WebauthnProvider {
+    fn method_id(&self) -> String {
+        WEBAUTHN_METHOD_ID.to_owned()
+    }
+
+    fn id(&self) -> Option<String> {
+        None
+    }
+
+    fn name(&self) -> String {
+        "Passkey".to_owned()
+    }
+}
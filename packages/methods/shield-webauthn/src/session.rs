@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use webauthn_rs::prelude::{DiscoverableAuthentication, PasskeyRegistration};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct WebauthnSession {
+    pub registration: Option<PasskeyRegistration>,
+    pub authentication: Option<DiscoverableAuthentication>,
+    pub user_id: Option<String>,
+    pub expired_at: Option<DateTime<Utc>>,
+}
+
+impl WebauthnSession {
+    pub(crate) fn is_expired(&self) -> bool {
+        self.expired_at
+            .is_none_or(|expired_at| expired_at <= Utc::now())
+    }
+}
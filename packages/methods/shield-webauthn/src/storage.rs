@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+use shield::{Storage, StorageError, User};
+
+use crate::credential::{CreateWebauthnCredential, UpdateWebauthnCredential, WebauthnCredential};
+
+#[async_trait]
+pub trait WebauthnStorage<U: User>: Storage<U> + Sync {
+    async fn webauthn_credential_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<WebauthnCredential>, StorageError>;
+
+    async fn webauthn_credential_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> Result<Option<WebauthnCredential>, StorageError>;
+
+    async fn create_webauthn_credential(
+        &self,
+        credential: CreateWebauthnCredential,
+    ) -> Result<WebauthnCredential, StorageError>;
+
+    async fn update_webauthn_credential(
+        &self,
+        credential: UpdateWebauthnCredential,
+    ) -> Result<WebauthnCredential, StorageError>;
+
+    async fn delete_webauthn_credential(&self, id: &str) -> Result<(), StorageError>;
+
+    async fn user_webauthn_credentials(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<WebauthnCredential>, StorageError>;
+}
@@ -0,0 +1,125 @@
+// Client-side WebAuthn ceremonies for Shield.
+//
+// Usage:
+//
+//     <script src="/shield-webauthn.js"></script>
+//     <script>
+//         document.querySelector('#passkey').addEventListener('click', () => shieldWebauthn.signIn());
+//     </script>
+//
+// Both functions accept `{ baseUrl, csrfToken }`. `baseUrl` defaults to `/api/auth` and `csrfToken` defaults to the
+// value of the first `csrf_token` input on the page.
+(() => {
+    const METHOD_ID = 'webauthn';
+
+    const decode = (value) => {
+        const base64 = value.replace(/-/g, '+').replace(/_/g, '/');
+        const padded = base64.padEnd(base64.length + ((4 - (base64.length % 4)) % 4), '=');
+        return Uint8Array.from(atob(padded), (c) => c.charCodeAt(0));
+    };
+
+    const encode = (buffer) =>
+        btoa(String.fromCharCode(...new Uint8Array(buffer)))
+            .replace(/\+/g, '-')
+            .replace(/\//g, '_')
+            .replace(/=+$/, '');
+
+    const decodeCredentials = (credentials) =>
+        credentials?.map((credential) => ({ ...credential, id: decode(credential.id) }));
+
+    const csrfTokenFromPage = () => document.querySelector('input[name="csrf_token"]')?.value;
+
+    const post = async (url, data, csrfToken) => {
+        const body = new URLSearchParams(data);
+        if (csrfToken) {
+            body.set('csrf_token', csrfToken);
+        }
+
+        const response = await fetch(url, {
+            method: 'POST',
+            credentials: 'same-origin',
+            headers: { 'Content-Type': 'application/x-www-form-urlencoded' },
+            body,
+        });
+        if (!response.ok) {
+            throw new Error((await response.text()) || `Request failed with status ${response.status}.`);
+        }
+        return response;
+    };
+
+    const follow = (response) => {
+        if (response.redirected) {
+            window.location.assign(response.url);
+        }
+    };
+
+    const signIn = async ({ baseUrl = '/api/auth', csrfToken = csrfTokenFromPage() } = {}) => {
+        const options = await (await post(`${baseUrl}/sign-in/${METHOD_ID}`, {}, csrfToken)).json();
+
+        const credential = await navigator.credentials.get({
+            publicKey: {
+                ...options.publicKey,
+                challenge: decode(options.publicKey.challenge),
+                allowCredentials: decodeCredentials(options.publicKey.allowCredentials),
+            },
+        });
+
+        follow(
+            await post(
+                `${baseUrl}/sign-in-callback/${METHOD_ID}`,
+                {
+                    credential: JSON.stringify({
+                        id: credential.id,
+                        rawId: encode(credential.rawId),
+                        type: credential.type,
+                        response: {
+                            authenticatorData: encode(credential.response.authenticatorData),
+                            clientDataJSON: encode(credential.response.clientDataJSON),
+                            signature: encode(credential.response.signature),
+                            userHandle: credential.response.userHandle
+                                ? encode(credential.response.userHandle)
+                                : null,
+                        },
+                        extensions: credential.getClientExtensionResults(),
+                    }),
+                },
+                csrfToken,
+            ),
+        );
+    };
+
+    const register = async ({ baseUrl = '/api/auth', csrfToken = csrfTokenFromPage() } = {}) => {
+        const options = await (await post(`${baseUrl}/register/${METHOD_ID}`, {}, csrfToken)).json();
+
+        const credential = await navigator.credentials.create({
+            publicKey: {
+                ...options.publicKey,
+                challenge: decode(options.publicKey.challenge),
+                user: { ...options.publicKey.user, id: decode(options.publicKey.user.id) },
+                excludeCredentials: decodeCredentials(options.publicKey.excludeCredentials),
+            },
+        });
+
+        follow(
+            await post(
+                `${baseUrl}/register-callback/${METHOD_ID}`,
+                {
+                    credential: JSON.stringify({
+                        id: credential.id,
+                        rawId: encode(credential.rawId),
+                        type: credential.type,
+                        response: {
+                            attestationObject: encode(credential.response.attestationObject),
+                            clientDataJSON: encode(credential.response.clientDataJSON),
+                            transports: credential.response.getTransports?.() ?? null,
+                        },
+                        extensions: credential.getClientExtensionResults(),
+                    }),
+                },
+                csrfToken,
+            ),
+        );
+    };
+
+    window.shieldWebauthn = { signIn, register };
+})();
@@ -0,0 +1,20 @@
+CREATE TABLE `user` (
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    created_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    name TEXT NOT NULL
+);
+
+CREATE TABLE email_address (
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    created_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    email VARCHAR(254) NOT NULL UNIQUE,
+    is_primary BOOLEAN NOT NULL DEFAULT FALSE,
+    is_verified BOOLEAN NOT NULL DEFAULT FALSE,
+    verification_token VARCHAR(64),
+    verification_token_expired_at DATETIME(6),
+    verified_at DATETIME(6),
+    user_id VARCHAR(36) NOT NULL,
+    CONSTRAINT fk_email_address_user FOREIGN KEY (user_id) REFERENCES `user` (id) ON UPDATE CASCADE ON DELETE CASCADE
+);
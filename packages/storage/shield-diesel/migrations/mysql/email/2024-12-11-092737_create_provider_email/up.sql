@@ -0,0 +1,9 @@
+CREATE TABLE email_auth_token (
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    created_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    updated_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    email VARCHAR(254) NOT NULL,
+    token VARCHAR(32) NOT NULL,
+    expired_at DATETIME(6) NOT NULL,
+    CONSTRAINT unique_email_token UNIQUE (email, token)
+);
@@ -0,0 +1,3 @@
+DROP TABLE oidc_provider_connection;
+
+DROP TABLE oidc_provider;
@@ -0,0 +1,3 @@
+DROP TABLE email_address;
+
+DROP TABLE "user";
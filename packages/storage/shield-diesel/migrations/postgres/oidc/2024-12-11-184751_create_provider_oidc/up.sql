@@ -0,0 +1,46 @@
+CREATE TABLE oidc_provider (
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    name VARCHAR(256) NOT NULL,
+    slug VARCHAR(256),
+    type VARCHAR(32) NOT NULL CHECK (type IN ('custom')),
+    visibility VARCHAR(32) NOT NULL CHECK (visibility IN ('public', 'unlisted')),
+    client_id TEXT NOT NULL,
+    client_secret TEXT,
+    scopes TEXT,
+    redirect_url TEXT,
+    discovery_url TEXT,
+    issuer_url TEXT,
+    authorization_url TEXT,
+    authorization_url_params TEXT,
+    token_url TEXT,
+    token_url_params TEXT,
+    introspection_url TEXT,
+    introspection_url_params TEXT,
+    revocation_url TEXT,
+    revocation_url_params TEXT,
+    user_info_url TEXT,
+    json_web_key_set_url TEXT,
+    json_web_key_set TEXT,
+    pkce_code_challenge VARCHAR(32) NOT NULL CHECK (pkce_code_challenge IN ('none', 'plain', 's256')),
+    icon_url TEXT
+);
+
+CREATE TABLE oidc_provider_connection (
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    identifier VARCHAR(256) NOT NULL,
+    token_type TEXT NOT NULL,
+    access_token TEXT NOT NULL,
+    refresh_token TEXT,
+    id_token TEXT,
+    expired_at TIMESTAMP,
+    scopes TEXT,
+    provider_id VARCHAR(36) NOT NULL,
+    user_id VARCHAR(36) NOT NULL,
+    CONSTRAINT fk_oidc_provider_connection_provider FOREIGN KEY (provider_id) REFERENCES oidc_provider (id) ON UPDATE CASCADE ON DELETE CASCADE,
+    CONSTRAINT fk_oidc_provider_connection_user FOREIGN KEY (user_id) REFERENCES "user" (id) ON UPDATE CASCADE ON DELETE CASCADE,
+    CONSTRAINT unique_oidc_provider_identifier UNIQUE (provider_id, identifier)
+);
@@ -0,0 +1,9 @@
+CREATE TABLE email_auth_token (
+    id TEXT NOT NULL PRIMARY KEY,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    email TEXT NOT NULL,
+    token TEXT NOT NULL,
+    expired_at TIMESTAMP NOT NULL,
+    CONSTRAINT unique_email_token UNIQUE (email, token)
+);
@@ -0,0 +1,3 @@
+DROP TABLE oauth_provider_connection;
+
+DROP TABLE oauth_provider;
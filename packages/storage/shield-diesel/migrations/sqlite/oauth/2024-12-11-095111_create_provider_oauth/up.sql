@@ -0,0 +1,45 @@
+CREATE TABLE oauth_provider (
+    id TEXT NOT NULL PRIMARY KEY,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    name TEXT NOT NULL,
+    slug TEXT,
+    type TEXT NOT NULL CHECK (type IN ('custom')),
+    visibility TEXT NOT NULL CHECK (visibility IN ('public', 'unlisted')),
+    client_id TEXT NOT NULL,
+    client_secret TEXT,
+    scopes TEXT,
+    redirect_url TEXT,
+    authorization_url TEXT,
+    authorization_url_params TEXT,
+    token_url TEXT,
+    token_url_params TEXT,
+    introspection_url TEXT,
+    introspection_url_params TEXT,
+    revocation_url TEXT,
+    revocation_url_params TEXT,
+    user_url TEXT NOT NULL,
+    user_path TEXT,
+    user_id_path TEXT,
+    user_email_path TEXT,
+    user_name_path TEXT,
+    pkce_code_challenge TEXT NOT NULL CHECK (pkce_code_challenge IN ('none', 'plain', 's256')),
+    icon_url TEXT
+);
+
+CREATE TABLE oauth_provider_connection (
+    id TEXT NOT NULL PRIMARY KEY,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    identifier TEXT NOT NULL,
+    token_type TEXT NOT NULL,
+    access_token TEXT NOT NULL,
+    refresh_token TEXT,
+    expired_at TIMESTAMP,
+    scopes TEXT,
+    provider_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    CONSTRAINT fk_oauth_provider_connection_provider FOREIGN KEY (provider_id) REFERENCES oauth_provider (id) ON UPDATE CASCADE ON DELETE CASCADE,
+    CONSTRAINT fk_oauth_provider_connection_user FOREIGN KEY (user_id) REFERENCES "user" (id) ON UPDATE CASCADE ON DELETE CASCADE,
+    CONSTRAINT unique_oauth_provider_identifier UNIQUE (provider_id, identifier)
+);
@@ -1 +1,14 @@
+#![cfg_attr(
+    not(any(feature = "mysql", feature = "postgres", feature = "sqlite")),
+    allow(dead_code, unused_macros)
+)]
 
+mod methods;
+pub mod migrations;
+pub mod models;
+pub mod schema;
+mod storage;
+mod user;
+
+pub use storage::*;
+pub use user::*;
@@ -0,0 +1,6 @@
+#[cfg(feature = "method-email")]
+mod email;
+#[cfg(feature = "method-oauth")]
+mod oauth;
+#[cfg(feature = "method-oidc")]
+mod oidc;
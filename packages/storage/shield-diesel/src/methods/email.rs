@@ -0,0 +1,105 @@
+use shield_email::EmailAuthToken;
+
+use crate::{models::email_auth_token, storage::for_each_connection};
+
+macro_rules! impl_email_storage {
+    ($connection:ty) => {
+        const _: () =
+            {
+                use async_trait::async_trait;
+                use chrono::Utc;
+                use diesel::prelude::*;
+                use diesel_async::RunQueryDsl;
+                use shield::StorageError;
+                use shield_email::{CreateEmailAuthToken, EmailStorage};
+                use uuid::Uuid;
+
+                use crate::{schema, storage::DieselStorage, user::User};
+
+                #[async_trait]
+                impl EmailStorage<User> for DieselStorage<$connection> {
+                    async fn email_auth_token(
+                        &self,
+                        email: &str,
+                        token: &str,
+                    ) -> Result<Option<EmailAuthToken>, StorageError> {
+                        let mut connection = self.connection().await?;
+
+                        schema::email_auth_token::table
+                            .filter(schema::email_auth_token::email.eq(email))
+                            .filter(schema::email_auth_token::token.eq(token))
+                            .filter(schema::email_auth_token::expired_at.gt(Utc::now().naive_utc()))
+                            .select(email_auth_token::Model::as_select())
+                            .first(&mut connection)
+                            .await
+                            .optional()
+                            .map_err(|err| StorageError::Engine(err.to_string()))
+                            .map(|email_auth_token| email_auth_token.map(EmailAuthToken::from))
+                    }
+
+                    async fn create_email_auth_token(
+                        &self,
+                        email_auth_token: CreateEmailAuthToken,
+                    ) -> Result<EmailAuthToken, StorageError> {
+                        let mut connection = self.connection().await?;
+
+                        let now = Utc::now().naive_utc();
+
+                        let model = email_auth_token::Model {
+                            id: Uuid::new_v4().to_string(),
+                            created_at: now,
+                            updated_at: now,
+                            email: email_auth_token.email,
+                            token: email_auth_token.token,
+                            expired_at: email_auth_token.expired_at.naive_utc(),
+                        };
+
+                        diesel::insert_into(schema::email_auth_token::table)
+                            .values(&model)
+                            .execute(&mut connection)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))
+                            .map(|_| EmailAuthToken::from(model))
+                    }
+
+                    async fn delete_email_auth_token(
+                        &self,
+                        email_auth_token_id: &str,
+                    ) -> Result<(), StorageError> {
+                        let mut connection = self.connection().await?;
+
+                        diesel::delete(schema::email_auth_token::table.find(email_auth_token_id))
+                            .execute(&mut connection)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))
+                            .map(|_| ())
+                    }
+
+                    async fn delete_expired_email_auth_tokens(&self) -> Result<(), StorageError> {
+                        let mut connection = self.connection().await?;
+
+                        diesel::delete(schema::email_auth_token::table.filter(
+                            schema::email_auth_token::expired_at.le(Utc::now().naive_utc()),
+                        ))
+                        .execute(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                    }
+                }
+            };
+    };
+}
+
+for_each_connection!(impl_email_storage);
+
+impl From<email_auth_token::Model> for EmailAuthToken {
+    fn from(value: email_auth_token::Model) -> Self {
+        EmailAuthToken {
+            id: value.id,
+            email: value.email,
+            token: value.token,
+            expired_at: value.expired_at.and_utc().fixed_offset(),
+        }
+    }
+}
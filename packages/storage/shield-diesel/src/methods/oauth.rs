@@ -0,0 +1,301 @@
+use shield::StorageError;
+use shield_oauth::{
+    OauthConnection, OauthProvider, OauthProviderPkceCodeChallenge, OauthProviderVisibility,
+};
+
+use crate::{
+    models::{oauth_provider, oauth_provider_connection},
+    storage::for_each_connection,
+};
+
+macro_rules! impl_oauth_storage {
+    ($connection:ty) => {
+        const _: () = {
+            use async_trait::async_trait;
+            use chrono::Utc;
+            use diesel::prelude::*;
+            use diesel_async::RunQueryDsl;
+            use secrecy::ExposeSecret;
+            use shield_oauth::{CreateOauthConnection, OauthStorage, UpdateOauthConnection};
+            use uuid::Uuid;
+
+            use crate::{schema, storage::DieselStorage, user::User};
+
+            #[async_trait]
+            impl OauthStorage<User> for DieselStorage<$connection> {
+                async fn oauth_providers(&self) -> Result<Vec<OauthProvider>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    schema::oauth_provider::table
+                        .select(oauth_provider::Model::as_select())
+                        .load(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .and_then(|providers| {
+                            providers.into_iter().map(OauthProvider::try_from).collect()
+                        })
+                }
+
+                async fn oauth_provider_by_id_or_slug(
+                    &self,
+                    provider_id: &str,
+                ) -> Result<Option<OauthProvider>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let query = schema::oauth_provider::table
+                        .select(oauth_provider::Model::as_select())
+                        .into_boxed();
+
+                    let query = match Uuid::parse_str(provider_id) {
+                        Ok(_) => query.filter(schema::oauth_provider::id.eq(provider_id)),
+                        Err(_) => query
+                            .filter(schema::oauth_provider::slug.eq(provider_id.to_lowercase())),
+                    };
+
+                    query
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .and_then(|provider| match provider {
+                            Some(provider) => OauthProvider::try_from(provider).map(Option::Some),
+                            None => Ok(None),
+                        })
+                }
+
+                async fn oauth_connection_by_id(
+                    &self,
+                    connection_id: &str,
+                ) -> Result<Option<OauthConnection>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    schema::oauth_provider_connection::table
+                        .find(connection_id)
+                        .select(oauth_provider_connection::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|connection| connection.map(OauthConnection::from))
+                }
+
+                async fn oauth_connection_by_identifier(
+                    &self,
+                    provider_id: &str,
+                    identifier: &str,
+                ) -> Result<Option<OauthConnection>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    schema::oauth_provider_connection::table
+                        .filter(schema::oauth_provider_connection::provider_id.eq(provider_id))
+                        .filter(schema::oauth_provider_connection::identifier.eq(identifier))
+                        .select(oauth_provider_connection::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|connection| connection.map(OauthConnection::from))
+                }
+
+                async fn create_oauth_connection(
+                    &self,
+                    connection: CreateOauthConnection,
+                ) -> Result<OauthConnection, StorageError> {
+                    let now = Utc::now().naive_utc();
+
+                    let model = oauth_provider_connection::Model {
+                        id: Uuid::new_v4().to_string(),
+                        created_at: now,
+                        updated_at: now,
+                        identifier: connection.identifier,
+                        token_type: connection.token_type,
+                        access_token: connection.access_token.expose_secret().to_owned(),
+                        refresh_token: connection
+                            .refresh_token
+                            .map(|refresh_token| refresh_token.expose_secret().to_owned()),
+                        expired_at: connection
+                            .expired_at
+                            .map(|expired_at| expired_at.naive_utc()),
+                        scopes: connection.scopes.map(|scopes| scopes.join(",")),
+                        provider_id: connection.provider_id,
+                        user_id: connection.user_id,
+                    };
+
+                    let mut connection = self.connection().await?;
+
+                    diesel::insert_into(schema::oauth_provider_connection::table)
+                        .values(&model)
+                        .execute(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| OauthConnection::from(model))
+                }
+
+                async fn update_oauth_connection(
+                    &self,
+                    connection: UpdateOauthConnection,
+                ) -> Result<OauthConnection, StorageError> {
+                    let changeset = oauth_provider_connection::Changeset {
+                        updated_at: Utc::now().naive_utc(),
+                        token_type: connection.token_type,
+                        access_token: connection
+                            .access_token
+                            .map(|access_token| access_token.expose_secret().to_owned()),
+                        refresh_token: connection.refresh_token.map(|refresh_token| {
+                            refresh_token
+                                .map(|refresh_token| refresh_token.expose_secret().to_owned())
+                        }),
+                        expired_at: connection
+                            .expired_at
+                            .map(|expired_at| expired_at.map(|expired_at| expired_at.naive_utc())),
+                        scopes: connection
+                            .scopes
+                            .map(|scopes| scopes.map(|scopes| scopes.join(","))),
+                    };
+
+                    let connection_id = connection.id;
+                    let mut connection = self.connection().await?;
+
+                    let updated = diesel::update(
+                        schema::oauth_provider_connection::table.find(&connection_id),
+                    )
+                    .set(&changeset)
+                    .execute(&mut connection)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    if updated == 0 {
+                        return Err(StorageError::NotFound(
+                            "OauthConnection".to_owned(),
+                            connection_id,
+                        ));
+                    }
+
+                    schema::oauth_provider_connection::table
+                        .find(&connection_id)
+                        .select(oauth_provider_connection::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(OauthConnection::from)
+                }
+
+                async fn delete_oauth_connection(
+                    &self,
+                    connection_id: &str,
+                ) -> Result<(), StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    diesel::delete(schema::oauth_provider_connection::table.find(connection_id))
+                        .execute(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                }
+
+                async fn user_oauth_connections(
+                    &self,
+                    user_id: &str,
+                    provider_id: Option<&str>,
+                ) -> Result<Vec<OauthConnection>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let mut query = schema::oauth_provider_connection::table
+                        .filter(schema::oauth_provider_connection::user_id.eq(user_id))
+                        .select(oauth_provider_connection::Model::as_select())
+                        .into_boxed();
+
+                    if let Some(provider_id) = provider_id {
+                        query = query
+                            .filter(schema::oauth_provider_connection::provider_id.eq(provider_id));
+                    }
+
+                    query
+                        .load(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|connections| {
+                            connections.into_iter().map(OauthConnection::from).collect()
+                        })
+                }
+            }
+        };
+    };
+}
+
+for_each_connection!(impl_oauth_storage);
+
+fn parse_visibility(value: &str) -> Result<OauthProviderVisibility, StorageError> {
+    match value {
+        "public" => Ok(OauthProviderVisibility::Public),
+        "unlisted" => Ok(OauthProviderVisibility::Unlisted),
+        value => Err(StorageError::Validation(format!(
+            "Invalid OAuth provider visibility `{value}`."
+        ))),
+    }
+}
+
+fn parse_pkce_code_challenge(value: &str) -> Result<OauthProviderPkceCodeChallenge, StorageError> {
+    match value {
+        "none" => Ok(OauthProviderPkceCodeChallenge::None),
+        "plain" => Ok(OauthProviderPkceCodeChallenge::Plain),
+        "s256" => Ok(OauthProviderPkceCodeChallenge::S256),
+        value => Err(StorageError::Validation(format!(
+            "Invalid OAuth provider PKCE code challenge `{value}`."
+        ))),
+    }
+}
+
+impl TryFrom<oauth_provider::Model> for OauthProvider {
+    type Error = StorageError;
+
+    fn try_from(value: oauth_provider::Model) -> Result<Self, Self::Error> {
+        Ok(OauthProvider {
+            id: value.id,
+            name: value.name,
+            slug: value.slug,
+            icon_url: value.icon_url,
+            visibility: parse_visibility(&value.visibility)?,
+            client_id: value.client_id,
+            client_secret: value.client_secret.map(Into::into),
+            scopes: value
+                .scopes
+                .map(|scopes| scopes.split(',').map(|s| s.to_string()).collect()),
+            redirect_url: value.redirect_url,
+            authorization_url: value.authorization_url,
+            authorization_url_params: value.authorization_url_params,
+            token_url: value.token_url,
+            token_url_params: value.token_url_params,
+            introspection_url: value.introspection_url,
+            introspection_url_params: value.introspection_url_params,
+            revocation_url: value.revocation_url,
+            revocation_url_params: value.revocation_url_params,
+            pkce_code_challenge: parse_pkce_code_challenge(&value.pkce_code_challenge)?,
+            user_url: value.user_url,
+            user_path: value.user_path,
+            user_id_path: value.user_id_path.unwrap_or("id".to_owned()),
+            user_email_path: value.user_email_path.unwrap_or("email".to_owned()),
+            user_name_path: value.user_name_path.unwrap_or("name".to_owned()),
+        })
+    }
+}
+
+impl From<oauth_provider_connection::Model> for OauthConnection {
+    fn from(value: oauth_provider_connection::Model) -> Self {
+        OauthConnection {
+            id: value.id,
+            identifier: value.identifier,
+            token_type: value.token_type,
+            access_token: value.access_token.into(),
+            refresh_token: value.refresh_token.map(Into::into),
+            expired_at: value
+                .expired_at
+                .map(|expired_at| expired_at.and_utc().fixed_offset()),
+            scopes: value
+                .scopes
+                .map(|scopes| scopes.split(',').map(|s| s.to_string()).collect()),
+            provider_id: value.provider_id,
+            user_id: value.user_id,
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use shield::StorageError;
+use shield_oidc::{
+    OidcConnection, OidcProvider, OidcProviderPkceCodeChallenge, OidcProviderVisibility,
+};
+
+use crate::{
+    models::{oidc_provider, oidc_provider_connection},
+    storage::for_each_connection,
+};
+
+macro_rules! impl_oidc_storage {
+    ($connection:ty) => {
+        const _: () = {
+            use async_trait::async_trait;
+            use chrono::Utc;
+            use diesel::prelude::*;
+            use diesel_async::RunQueryDsl;
+            use secrecy::ExposeSecret;
+            use shield_oidc::{CreateOidcConnection, OidcStorage, UpdateOidcConnection};
+            use uuid::Uuid;
+
+            use crate::{schema, storage::DieselStorage, user::User};
+
+            #[async_trait]
+            impl OidcStorage<User> for DieselStorage<$connection> {
+                async fn oidc_providers(&self) -> Result<Vec<OidcProvider>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    schema::oidc_provider::table
+                        .select(oidc_provider::Model::as_select())
+                        .load(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .and_then(|providers| {
+                            providers.into_iter().map(OidcProvider::try_from).collect()
+                        })
+                }
+
+                async fn oidc_provider_by_id_or_slug(
+                    &self,
+                    provider_id: &str,
+                ) -> Result<Option<OidcProvider>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let query = schema::oidc_provider::table
+                        .select(oidc_provider::Model::as_select())
+                        .into_boxed();
+
+                    let query = match Uuid::parse_str(provider_id) {
+                        Ok(_) => query.filter(schema::oidc_provider::id.eq(provider_id)),
+                        Err(_) => {
+                            query.filter(schema::oidc_provider::slug.eq(provider_id.to_lowercase()))
+                        }
+                    };
+
+                    query
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .and_then(|provider| match provider {
+                            Some(provider) => OidcProvider::try_from(provider).map(Option::Some),
+                            None => Ok(None),
+                        })
+                }
+
+                async fn oidc_connection_by_id(
+                    &self,
+                    connection_id: &str,
+                ) -> Result<Option<OidcConnection>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    schema::oidc_provider_connection::table
+                        .find(connection_id)
+                        .select(oidc_provider_connection::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|connection| connection.map(OidcConnection::from))
+                }
+
+                async fn oidc_connection_by_identifier(
+                    &self,
+                    provider_id: &str,
+                    identifier: &str,
+                ) -> Result<Option<OidcConnection>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    schema::oidc_provider_connection::table
+                        .filter(schema::oidc_provider_connection::provider_id.eq(provider_id))
+                        .filter(schema::oidc_provider_connection::identifier.eq(identifier))
+                        .select(oidc_provider_connection::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|connection| connection.map(OidcConnection::from))
+                }
+
+                async fn create_oidc_connection(
+                    &self,
+                    connection: CreateOidcConnection,
+                ) -> Result<OidcConnection, StorageError> {
+                    let now = Utc::now().naive_utc();
+
+                    let model = oidc_provider_connection::Model {
+                        id: Uuid::new_v4().to_string(),
+                        created_at: now,
+                        updated_at: now,
+                        identifier: connection.identifier,
+                        token_type: connection.token_type,
+                        access_token: connection.access_token.expose_secret().to_owned(),
+                        refresh_token: connection
+                            .refresh_token
+                            .map(|refresh_token| refresh_token.expose_secret().to_owned()),
+                        id_token: connection
+                            .id_token
+                            .map(|id_token| id_token.expose_secret().to_owned()),
+                        expired_at: connection
+                            .expired_at
+                            .map(|expired_at| expired_at.naive_utc()),
+                        scopes: connection.scopes.map(|scopes| scopes.join(",")),
+                        provider_id: connection.provider_id,
+                        user_id: connection.user_id,
+                    };
+
+                    let mut connection = self.connection().await?;
+
+                    diesel::insert_into(schema::oidc_provider_connection::table)
+                        .values(&model)
+                        .execute(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| OidcConnection::from(model))
+                }
+
+                async fn update_oidc_connection(
+                    &self,
+                    connection: UpdateOidcConnection,
+                ) -> Result<OidcConnection, StorageError> {
+                    let changeset = oidc_provider_connection::Changeset {
+                        updated_at: Utc::now().naive_utc(),
+                        token_type: connection.token_type,
+                        access_token: connection
+                            .access_token
+                            .map(|access_token| access_token.expose_secret().to_owned()),
+                        refresh_token: connection.refresh_token.map(|refresh_token| {
+                            refresh_token
+                                .map(|refresh_token| refresh_token.expose_secret().to_owned())
+                        }),
+                        id_token: connection.id_token.map(|id_token| {
+                            id_token.map(|id_token| id_token.expose_secret().to_owned())
+                        }),
+                        expired_at: connection
+                            .expired_at
+                            .map(|expired_at| expired_at.map(|expired_at| expired_at.naive_utc())),
+                        scopes: connection
+                            .scopes
+                            .map(|scopes| scopes.map(|scopes| scopes.join(","))),
+                    };
+
+                    let connection_id = connection.id;
+                    let mut connection = self.connection().await?;
+
+                    let updated = diesel::update(
+                        schema::oidc_provider_connection::table.find(&connection_id),
+                    )
+                    .set(&changeset)
+                    .execute(&mut connection)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    if updated == 0 {
+                        return Err(StorageError::NotFound(
+                            "OidcConnection".to_owned(),
+                            connection_id,
+                        ));
+                    }
+
+                    schema::oidc_provider_connection::table
+                        .find(&connection_id)
+                        .select(oidc_provider_connection::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(OidcConnection::from)
+                }
+
+                async fn delete_oidc_connection(
+                    &self,
+                    connection_id: &str,
+                ) -> Result<(), StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    diesel::delete(schema::oidc_provider_connection::table.find(connection_id))
+                        .execute(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                }
+
+                async fn user_oidc_connections(
+                    &self,
+                    user_id: &str,
+                    provider_id: Option<&str>,
+                ) -> Result<Vec<OidcConnection>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let mut query = schema::oidc_provider_connection::table
+                        .filter(schema::oidc_provider_connection::user_id.eq(user_id))
+                        .select(oidc_provider_connection::Model::as_select())
+                        .into_boxed();
+
+                    if let Some(provider_id) = provider_id {
+                        query = query
+                            .filter(schema::oidc_provider_connection::provider_id.eq(provider_id));
+                    }
+
+                    query
+                        .load(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|connections| {
+                            connections.into_iter().map(OidcConnection::from).collect()
+                        })
+                }
+            }
+        };
+    };
+}
+
+for_each_connection!(impl_oidc_storage);
+
+fn parse_visibility(value: &str) -> Result<OidcProviderVisibility, StorageError> {
+    match value {
+        "public" => Ok(OidcProviderVisibility::Public),
+        "unlisted" => Ok(OidcProviderVisibility::Unlisted),
+        value => Err(StorageError::Validation(format!(
+            "Invalid OIDC provider visibility `{value}`."
+        ))),
+    }
+}
+
+fn parse_pkce_code_challenge(value: &str) -> Result<OidcProviderPkceCodeChallenge, StorageError> {
+    match value {
+        "none" => Ok(OidcProviderPkceCodeChallenge::None),
+        "plain" => Ok(OidcProviderPkceCodeChallenge::Plain),
+        "s256" => Ok(OidcProviderPkceCodeChallenge::S256),
+        value => Err(StorageError::Validation(format!(
+            "Invalid OIDC provider PKCE code challenge `{value}`."
+        ))),
+    }
+}
+
+impl TryFrom<oidc_provider::Model> for OidcProvider {
+    type Error = StorageError;
+
+    fn try_from(value: oidc_provider::Model) -> Result<Self, Self::Error> {
+        Ok(OidcProvider {
+            id: value.id,
+            name: value.name,
+            slug: value.slug,
+            icon_url: value.icon_url,
+            visibility: parse_visibility(&value.visibility)?,
+            client_id: value.client_id,
+            client_secret: value.client_secret.map(Into::into),
+            scopes: value
+                .scopes
+                .map(|scopes| scopes.split(',').map(|s| s.to_string()).collect()),
+            redirect_url: value.redirect_url,
+            discovery_url: value.discovery_url,
+            issuer_url: value.issuer_url,
+            authorization_url: value.authorization_url,
+            authorization_url_params: value.authorization_url_params,
+            token_url: value.token_url,
+            token_url_params: value.token_url_params,
+            introspection_url: value.introspection_url,
+            introspection_url_params: value.introspection_url_params,
+            revocation_url: value.revocation_url,
+            revocation_url_params: value.revocation_url_params,
+            user_info_url: value.user_info_url,
+            json_web_key_set_url: value.json_web_key_set_url,
+            json_web_key_set: match value.json_web_key_set {
+                Some(json_web_key_set) => serde_json::from_str(&json_web_key_set)
+                    .map_err(|err| StorageError::Validation(err.to_string()))?,
+                None => None,
+            },
+            pkce_code_challenge: parse_pkce_code_challenge(&value.pkce_code_challenge)?,
+        })
+    }
+}
+
+impl From<oidc_provider_connection::Model> for OidcConnection {
+    fn from(value: oidc_provider_connection::Model) -> Self {
+        OidcConnection {
+            id: value.id,
+            identifier: value.identifier,
+            token_type: value.token_type,
+            access_token: value.access_token.into(),
+            refresh_token: value.refresh_token.map(Into::into),
+            id_token: value.id_token.map(Into::into),
+            expired_at: value
+                .expired_at
+                .map(|expired_at| expired_at.and_utc().fixed_offset()),
+            scopes: value
+                .scopes
+                .map(|scopes| scopes.split(',').map(|s| s.to_string()).collect()),
+            provider_id: value.provider_id,
+            user_id: value.user_id,
+        }
+    }
+}
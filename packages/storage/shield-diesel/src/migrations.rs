@@ -0,0 +1,12 @@
+#[cfg(feature = "mysql")]
+pub mod mysql;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+use diesel_migrations::EmbeddedMigrations;
+
+pub trait Migrations {
+    fn migrations() -> Vec<EmbeddedMigrations>;
+}
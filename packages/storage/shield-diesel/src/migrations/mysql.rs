@@ -0,0 +1,26 @@
+use diesel_async::AsyncMysqlConnection;
+use diesel_migrations::{EmbeddedMigrations, embed_migrations};
+
+use crate::migrations::Migrations;
+
+pub const CORE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/mysql/core");
+#[cfg(feature = "method-email")]
+pub const EMAIL_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/mysql/email");
+#[cfg(feature = "method-oauth")]
+pub const OAUTH_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/mysql/oauth");
+#[cfg(feature = "method-oidc")]
+pub const OIDC_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/mysql/oidc");
+
+impl Migrations for AsyncMysqlConnection {
+    fn migrations() -> Vec<EmbeddedMigrations> {
+        Vec::from([
+            CORE_MIGRATIONS,
+            #[cfg(feature = "method-email")]
+            EMAIL_MIGRATIONS,
+            #[cfg(feature = "method-oauth")]
+            OAUTH_MIGRATIONS,
+            #[cfg(feature = "method-oidc")]
+            OIDC_MIGRATIONS,
+        ])
+    }
+}
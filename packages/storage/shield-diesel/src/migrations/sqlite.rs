@@ -0,0 +1,27 @@
+use diesel::sqlite::SqliteConnection;
+use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+use diesel_migrations::{EmbeddedMigrations, embed_migrations};
+
+use crate::migrations::Migrations;
+
+pub const CORE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite/core");
+#[cfg(feature = "method-email")]
+pub const EMAIL_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite/email");
+#[cfg(feature = "method-oauth")]
+pub const OAUTH_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite/oauth");
+#[cfg(feature = "method-oidc")]
+pub const OIDC_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite/oidc");
+
+impl Migrations for SyncConnectionWrapper<SqliteConnection> {
+    fn migrations() -> Vec<EmbeddedMigrations> {
+        Vec::from([
+            CORE_MIGRATIONS,
+            #[cfg(feature = "method-email")]
+            EMAIL_MIGRATIONS,
+            #[cfg(feature = "method-oauth")]
+            OAUTH_MIGRATIONS,
+            #[cfg(feature = "method-oidc")]
+            OIDC_MIGRATIONS,
+        ])
+    }
+}
@@ -0,0 +1,12 @@
+pub mod email_address;
+#[cfg(feature = "method-email")]
+pub mod email_auth_token;
+#[cfg(feature = "method-oauth")]
+pub mod oauth_provider;
+#[cfg(feature = "method-oauth")]
+pub mod oauth_provider_connection;
+#[cfg(feature = "method-oidc")]
+pub mod oidc_provider;
+#[cfg(feature = "method-oidc")]
+pub mod oidc_provider_connection;
+pub mod user;
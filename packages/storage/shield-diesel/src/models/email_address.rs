@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::schema::email_address;
+
+#[derive(
+    AsChangeset, Associations, Clone, Debug, Identifiable, Insertable, Queryable, Selectable,
+)]
+#[diesel(table_name = email_address, belongs_to(super::user::Model, foreign_key = user_id))]
+pub struct Model {
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub email: String,
+    pub is_primary: bool,
+    pub is_verified: bool,
+    pub verification_token: Option<String>,
+    pub verification_token_expired_at: Option<NaiveDateTime>,
+    pub verified_at: Option<NaiveDateTime>,
+    pub user_id: String,
+}
@@ -0,0 +1,15 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::schema::email_auth_token;
+
+#[derive(Clone, Debug, Identifiable, Insertable, Queryable, Selectable)]
+#[diesel(table_name = email_auth_token)]
+pub struct Model {
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub email: String,
+    pub token: String,
+    pub expired_at: NaiveDateTime,
+}
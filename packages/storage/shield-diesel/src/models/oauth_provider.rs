@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::schema::oauth_provider;
+
+#[derive(AsChangeset, Clone, Debug, Identifiable, Insertable, Queryable, Selectable)]
+#[diesel(table_name = oauth_provider)]
+pub struct Model {
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub name: String,
+    pub slug: Option<String>,
+    pub type_: String,
+    pub visibility: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub scopes: Option<String>,
+    pub redirect_url: Option<String>,
+    pub authorization_url: Option<String>,
+    pub authorization_url_params: Option<String>,
+    pub token_url: Option<String>,
+    pub token_url_params: Option<String>,
+    pub introspection_url: Option<String>,
+    pub introspection_url_params: Option<String>,
+    pub revocation_url: Option<String>,
+    pub revocation_url_params: Option<String>,
+    pub user_url: String,
+    pub user_path: Option<String>,
+    pub user_id_path: Option<String>,
+    pub user_email_path: Option<String>,
+    pub user_name_path: Option<String>,
+    pub pkce_code_challenge: String,
+    pub icon_url: Option<String>,
+}
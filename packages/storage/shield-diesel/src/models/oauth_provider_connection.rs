@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::schema::oauth_provider_connection;
+
+#[derive(Associations, Clone, Debug, Identifiable, Insertable, Queryable, Selectable)]
+#[diesel(
+    table_name = oauth_provider_connection,
+    belongs_to(super::oauth_provider::Model, foreign_key = provider_id),
+    belongs_to(super::user::Model, foreign_key = user_id)
+)]
+pub struct Model {
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub identifier: String,
+    pub token_type: String,
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expired_at: Option<NaiveDateTime>,
+    pub scopes: Option<String>,
+    pub provider_id: String,
+    pub user_id: String,
+}
+
+#[derive(AsChangeset, Clone, Debug)]
+#[diesel(table_name = oauth_provider_connection)]
+pub struct Changeset {
+    pub updated_at: NaiveDateTime,
+    pub token_type: Option<String>,
+    pub access_token: Option<String>,
+    pub refresh_token: Option<Option<String>>,
+    pub expired_at: Option<Option<NaiveDateTime>>,
+    pub scopes: Option<Option<String>>,
+}
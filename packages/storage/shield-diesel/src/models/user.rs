@@ -0,0 +1,13 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::schema::user;
+
+#[derive(AsChangeset, Clone, Debug, Identifiable, Insertable, Queryable, Selectable)]
+#[diesel(table_name = user)]
+pub struct Model {
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub name: String,
+}
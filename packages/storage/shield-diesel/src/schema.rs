@@ -0,0 +1,152 @@
+diesel::table! {
+    user (id) {
+        id -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    email_address (id) {
+        id -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        email -> Text,
+        is_primary -> Bool,
+        is_verified -> Bool,
+        verification_token -> Nullable<Text>,
+        verification_token_expired_at -> Nullable<Timestamp>,
+        verified_at -> Nullable<Timestamp>,
+        user_id -> Text,
+    }
+}
+
+diesel::joinable!(email_address -> user (user_id));
+
+diesel::allow_tables_to_appear_in_same_query!(email_address, user);
+
+#[cfg(feature = "method-email")]
+diesel::table! {
+    email_auth_token (id) {
+        id -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        email -> Text,
+        token -> Text,
+        expired_at -> Timestamp,
+    }
+}
+
+#[cfg(feature = "method-oauth")]
+diesel::table! {
+    oauth_provider (id) {
+        id -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        name -> Text,
+        slug -> Nullable<Text>,
+        #[sql_name = "type"]
+        type_ -> Text,
+        visibility -> Text,
+        client_id -> Text,
+        client_secret -> Nullable<Text>,
+        scopes -> Nullable<Text>,
+        redirect_url -> Nullable<Text>,
+        authorization_url -> Nullable<Text>,
+        authorization_url_params -> Nullable<Text>,
+        token_url -> Nullable<Text>,
+        token_url_params -> Nullable<Text>,
+        introspection_url -> Nullable<Text>,
+        introspection_url_params -> Nullable<Text>,
+        revocation_url -> Nullable<Text>,
+        revocation_url_params -> Nullable<Text>,
+        user_url -> Text,
+        user_path -> Nullable<Text>,
+        user_id_path -> Nullable<Text>,
+        user_email_path -> Nullable<Text>,
+        user_name_path -> Nullable<Text>,
+        pkce_code_challenge -> Text,
+        icon_url -> Nullable<Text>,
+    }
+}
+
+#[cfg(feature = "method-oauth")]
+diesel::table! {
+    oauth_provider_connection (id) {
+        id -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        identifier -> Text,
+        token_type -> Text,
+        access_token -> Text,
+        refresh_token -> Nullable<Text>,
+        expired_at -> Nullable<Timestamp>,
+        scopes -> Nullable<Text>,
+        provider_id -> Text,
+        user_id -> Text,
+    }
+}
+
+#[cfg(feature = "method-oauth")]
+diesel::joinable!(oauth_provider_connection -> oauth_provider (provider_id));
+
+#[cfg(feature = "method-oauth")]
+diesel::joinable!(oauth_provider_connection -> user (user_id));
+
+#[cfg(feature = "method-oidc")]
+diesel::table! {
+    oidc_provider (id) {
+        id -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        name -> Text,
+        slug -> Nullable<Text>,
+        #[sql_name = "type"]
+        type_ -> Text,
+        visibility -> Text,
+        client_id -> Text,
+        client_secret -> Nullable<Text>,
+        scopes -> Nullable<Text>,
+        redirect_url -> Nullable<Text>,
+        discovery_url -> Nullable<Text>,
+        issuer_url -> Nullable<Text>,
+        authorization_url -> Nullable<Text>,
+        authorization_url_params -> Nullable<Text>,
+        token_url -> Nullable<Text>,
+        token_url_params -> Nullable<Text>,
+        introspection_url -> Nullable<Text>,
+        introspection_url_params -> Nullable<Text>,
+        revocation_url -> Nullable<Text>,
+        revocation_url_params -> Nullable<Text>,
+        user_info_url -> Nullable<Text>,
+        json_web_key_set_url -> Nullable<Text>,
+        json_web_key_set -> Nullable<Text>,
+        pkce_code_challenge -> Text,
+        icon_url -> Nullable<Text>,
+    }
+}
+
+#[cfg(feature = "method-oidc")]
+diesel::table! {
+    oidc_provider_connection (id) {
+        id -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        identifier -> Text,
+        token_type -> Text,
+        access_token -> Text,
+        refresh_token -> Nullable<Text>,
+        id_token -> Nullable<Text>,
+        expired_at -> Nullable<Timestamp>,
+        scopes -> Nullable<Text>,
+        provider_id -> Text,
+        user_id -> Text,
+    }
+}
+
+#[cfg(feature = "method-oidc")]
+diesel::joinable!(oidc_provider_connection -> oidc_provider (provider_id));
+
+#[cfg(feature = "method-oidc")]
+diesel::joinable!(oidc_provider_connection -> user (user_id));
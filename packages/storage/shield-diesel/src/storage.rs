@@ -0,0 +1,306 @@
+use deadpool::managed::Manager;
+use diesel_async::{
+    AsyncConnection,
+    async_connection_wrapper::AsyncConnectionWrapper,
+    pooled_connection::{
+        AsyncDieselConnectionManager, PoolError,
+        deadpool::{Object, Pool},
+    },
+};
+use diesel_migrations::MigrationHarness;
+use shield::StorageError;
+
+use crate::migrations::Migrations;
+
+pub const DIESEL_STORAGE_ID: &str = "diesel";
+
+pub struct DieselStorage<C>
+where
+    AsyncDieselConnectionManager<C>: Manager<Type = C, Error = PoolError>,
+{
+    pub(crate) pool: Pool<C>,
+}
+
+impl<C> DieselStorage<C>
+where
+    AsyncDieselConnectionManager<C>: Manager<Type = C, Error = PoolError>,
+{
+    /// Create storage from a connection pool.
+    ///
+    /// For SQLite, build the pool with [`DieselStorage::sqlite_manager`] so foreign keys are enforced.
+    pub fn new(pool: Pool<C>) -> Self {
+        Self { pool }
+    }
+
+    pub(crate) async fn connection(&self) -> Result<Object<C>, StorageError> {
+        self.pool
+            .get()
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+    }
+}
+
+impl<C> DieselStorage<C>
+where
+    C: AsyncConnection + Migrations + 'static,
+    AsyncDieselConnectionManager<C>: Manager<Type = C, Error = PoolError>,
+    AsyncConnectionWrapper<C>: MigrationHarness<C::Backend>,
+{
+    pub async fn run_pending_migrations(&self) -> Result<(), StorageError> {
+        let connection = Object::take(self.connection().await?);
+
+        tokio::task::spawn_blocking(move || {
+            let mut connection = AsyncConnectionWrapper::<C>::from(connection);
+
+            for migrations in C::migrations() {
+                connection
+                    .run_pending_migrations(migrations)
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+            }
+
+            Ok(())
+        })
+        .await
+        .map_err(|err| StorageError::Engine(err.to_string()))?
+    }
+}
+
+/// Asynchronous SQLite connection, like [`diesel_async::AsyncPgConnection`] and [`diesel_async::AsyncMysqlConnection`].
+#[cfg(feature = "sqlite")]
+pub type AsyncSqliteConnection =
+    diesel_async::sync_connection_wrapper::SyncConnectionWrapper<diesel::sqlite::SqliteConnection>;
+
+#[cfg(feature = "sqlite")]
+impl DieselStorage<AsyncSqliteConnection> {
+    /// Create an SQLite connection manager which enables foreign keys on every connection.
+    ///
+    /// SQLite doesn't enforce foreign keys by default, so deleting a user would leave its email addresses and
+    /// connections behind.
+    pub fn sqlite_manager(
+        database_url: impl Into<String>,
+    ) -> AsyncDieselConnectionManager<AsyncSqliteConnection> {
+        use diesel_async::{SimpleAsyncConnection, pooled_connection::ManagerConfig};
+
+        let mut config = ManagerConfig::default();
+        config.custom_setup = Box::new(|url| {
+            Box::pin(async move {
+                let mut connection = AsyncSqliteConnection::establish(url).await?;
+                connection
+                    .batch_execute("PRAGMA foreign_keys = ON")
+                    .await
+                    .map_err(diesel::ConnectionError::CouldntSetupConfiguration)?;
+
+                Ok(connection)
+            })
+        });
+
+        AsyncDieselConnectionManager::new_with_config(database_url, config)
+    }
+}
+
+impl<C> Clone for DieselStorage<C>
+where
+    AsyncDieselConnectionManager<C>: Manager<Type = C, Error = PoolError>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+macro_rules! for_each_connection {
+    ($macro:ident) => {
+        #[cfg(feature = "mysql")]
+        $macro!(diesel_async::AsyncMysqlConnection);
+
+        #[cfg(feature = "postgres")]
+        $macro!(diesel_async::AsyncPgConnection);
+
+        #[cfg(feature = "sqlite")]
+        $macro!(
+            diesel_async::sync_connection_wrapper::SyncConnectionWrapper<
+                diesel::sqlite::SqliteConnection,
+            >
+        );
+    };
+}
+
+pub(crate) use for_each_connection;
+
+macro_rules! impl_storage {
+    ($connection:ty) => {
+        const _: () = {
+            use async_trait::async_trait;
+            use chrono::Utc;
+            use diesel::prelude::*;
+            use diesel_async::{AsyncConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+            use shield::{CreateEmailAddress, CreateUser, Storage, StorageError, UpdateUser};
+            use uuid::Uuid;
+
+            use crate::{
+                models::{email_address, user},
+                schema,
+                storage::{DIESEL_STORAGE_ID, DieselStorage},
+                user::User,
+            };
+
+            impl DieselStorage<$connection> {
+                async fn user_with_email_addresses(
+                    connection: &mut $connection,
+                    user: user::Model,
+                ) -> Result<User, StorageError> {
+                    let email_addresses = email_address::Model::belonging_to(&user)
+                        .select(email_address::Model::as_select())
+                        .load(connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    Ok(User::new(user, email_addresses))
+                }
+            }
+
+            #[async_trait]
+            impl Storage<User> for DieselStorage<$connection> {
+                fn id(&self) -> String {
+                    DIESEL_STORAGE_ID.to_owned()
+                }
+
+                async fn user_by_id(&self, user_id: &str) -> Result<Option<User>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let user = schema::user::table
+                        .find(user_id)
+                        .select(user::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    match user {
+                        Some(user) => Self::user_with_email_addresses(&mut connection, user)
+                            .await
+                            .map(Some),
+                        None => Ok(None),
+                    }
+                }
+
+                async fn user_by_email(&self, email: &str) -> Result<Option<User>, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let user = schema::user::table
+                        .inner_join(schema::email_address::table)
+                        .filter(schema::email_address::email.eq(email))
+                        .select(user::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    match user {
+                        Some(user) => Self::user_with_email_addresses(&mut connection, user)
+                            .await
+                            .map(Some),
+                        None => Ok(None),
+                    }
+                }
+
+                async fn create_user(
+                    &self,
+                    user: CreateUser,
+                    email_address: CreateEmailAddress,
+                ) -> Result<User, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let now = Utc::now().naive_utc();
+
+                    let user = user::Model {
+                        id: Uuid::new_v4().to_string(),
+                        created_at: now,
+                        updated_at: now,
+                        name: user.name.unwrap_or_default(),
+                    };
+
+                    let email_address = email_address::Model {
+                        id: Uuid::new_v4().to_string(),
+                        created_at: now,
+                        updated_at: now,
+                        email: email_address.email,
+                        is_primary: email_address.is_primary,
+                        is_verified: email_address.is_verified,
+                        verification_token: email_address.verification_token,
+                        verification_token_expired_at: email_address
+                            .verification_token_expired_at
+                            .map(|date_time| date_time.naive_utc()),
+                        verified_at: email_address
+                            .verified_at
+                            .map(|date_time| date_time.naive_utc()),
+                        user_id: user.id.clone(),
+                    };
+
+                    connection
+                        .transaction::<_, diesel::result::Error, _>(|connection| {
+                            async {
+                                diesel::insert_into(schema::user::table)
+                                    .values(&user)
+                                    .execute(connection)
+                                    .await?;
+
+                                diesel::insert_into(schema::email_address::table)
+                                    .values(&email_address)
+                                    .execute(connection)
+                                    .await?;
+
+                                Ok(())
+                            }
+                            .scope_boxed()
+                        })
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    Ok(User::new(user, vec![email_address]))
+                }
+
+                async fn update_user(&self, user: UpdateUser) -> Result<User, StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    let mut user_model = schema::user::table
+                        .find(&user.id)
+                        .select(user::Model::as_select())
+                        .first(&mut connection)
+                        .await
+                        .optional()
+                        .map_err(|err| StorageError::Engine(err.to_string()))?
+                        .ok_or_else(|| {
+                            StorageError::NotFound("User".to_owned(), user.id.clone())
+                        })?;
+
+                    if let Some(Some(name)) = user.name {
+                        user_model.name = name;
+                        user_model.updated_at = Utc::now().naive_utc();
+
+                        diesel::update(&user_model)
+                            .set(&user_model)
+                            .execute(&mut connection)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))?;
+                    }
+
+                    Self::user_with_email_addresses(&mut connection, user_model).await
+                }
+
+                async fn delete_user(&self, user_id: &str) -> Result<(), StorageError> {
+                    let mut connection = self.connection().await?;
+
+                    diesel::delete(schema::user::table.find(user_id))
+                        .execute(&mut connection)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                }
+            }
+        };
+    };
+}
+
+self::for_each_connection!(impl_storage);
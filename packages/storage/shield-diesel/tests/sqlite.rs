@@ -0,0 +1,303 @@
+use std::path::PathBuf;
+
+use chrono::{Duration, Utc};
+use diesel::QueryDsl;
+use diesel_async::{AsyncConnection, RunQueryDsl, pooled_connection::deadpool::Pool};
+use secrecy::ExposeSecret;
+use shield::{CreateEmailAddress, CreateUser, Storage, UpdateUser, User as _};
+use shield_diesel::{
+    AsyncSqliteConnection as Connection, DieselStorage, User, models::oauth_provider, schema,
+};
+use shield_email::{CreateEmailAuthToken, EmailStorage};
+use shield_oauth::{
+    CreateOauthConnection, OauthProviderVisibility, OauthStorage, UpdateOauthConnection,
+};
+use uuid::Uuid;
+
+struct TestDatabase {
+    path: PathBuf,
+    storage: DieselStorage<Connection>,
+}
+
+impl TestDatabase {
+    async fn new() -> Self {
+        let path = std::env::temp_dir().join(format!("shield-diesel-{}.sqlite", Uuid::new_v4()));
+
+        let manager = DieselStorage::sqlite_manager(path.to_string_lossy());
+        let pool = Pool::builder(manager).build().expect("Pool should build.");
+
+        let storage = DieselStorage::new(pool);
+        storage
+            .run_pending_migrations()
+            .await
+            .expect("Migrations should run.");
+
+        Self { path, storage }
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+async fn create_user(storage: &DieselStorage<Connection>, email: &str) -> User {
+    storage
+        .create_user(
+            CreateUser {
+                name: Some("Alice".to_owned()),
+            },
+            CreateEmailAddress {
+                email: email.to_owned(),
+                is_primary: true,
+                is_verified: false,
+                verification_token: None,
+                verification_token_expired_at: None,
+                verified_at: None,
+            },
+        )
+        .await
+        .expect("User should be created.")
+}
+
+#[tokio::test]
+async fn migrations() {
+    let database = TestDatabase::new().await;
+
+    database
+        .storage
+        .run_pending_migrations()
+        .await
+        .expect("Migrations should be idempotent.");
+}
+
+#[tokio::test]
+async fn users() {
+    let database = TestDatabase::new().await;
+    let storage = &database.storage;
+
+    let user = create_user(storage, "alice@example.com").await;
+    assert_eq!(user.name(), Some("Alice".to_owned()));
+
+    let email_addresses = user.email_addresses().await.unwrap();
+    assert_eq!(email_addresses.len(), 1);
+    assert_eq!(email_addresses[0].email, "alice@example.com");
+    assert_eq!(email_addresses[0].user_id, user.id());
+
+    let found = storage.user_by_id(&user.id()).await.unwrap().unwrap();
+    assert_eq!(found.id(), user.id());
+
+    let found = storage
+        .user_by_email("alice@example.com")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), user.id());
+
+    assert!(
+        storage
+            .user_by_email("bob@example.com")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let updated = storage
+        .update_user(UpdateUser {
+            id: user.id(),
+            name: Some(Some("Alice Liddell".to_owned())),
+        })
+        .await
+        .unwrap();
+    assert_eq!(updated.name(), Some("Alice Liddell".to_owned()));
+    assert_eq!(updated.email_addresses().await.unwrap().len(), 1);
+
+    storage.delete_user(&user.id()).await.unwrap();
+    assert!(storage.user_by_id(&user.id()).await.unwrap().is_none());
+    assert_eq!(
+        schema::email_address::table
+            .count()
+            .get_result::<i64>(&mut storage_connection(&database).await)
+            .await
+            .unwrap(),
+        0
+    );
+    assert!(
+        storage
+            .user_by_email("alice@example.com")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn email_auth_tokens() {
+    let database = TestDatabase::new().await;
+    let storage = &database.storage;
+
+    let token = storage
+        .create_email_auth_token(CreateEmailAuthToken {
+            email: "alice@example.com".to_owned(),
+            token: "token".to_owned(),
+            expired_at: (Utc::now() + Duration::minutes(5)).fixed_offset(),
+        })
+        .await
+        .unwrap();
+
+    storage
+        .create_email_auth_token(CreateEmailAuthToken {
+            email: "alice@example.com".to_owned(),
+            token: "expired".to_owned(),
+            expired_at: (Utc::now() - Duration::minutes(5)).fixed_offset(),
+        })
+        .await
+        .unwrap();
+
+    let found = storage
+        .email_auth_token("alice@example.com", "token")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id, token.id);
+
+    assert!(
+        storage
+            .email_auth_token("alice@example.com", "expired")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    storage.delete_expired_email_auth_tokens().await.unwrap();
+    storage.delete_email_auth_token(&token.id).await.unwrap();
+    assert!(
+        storage
+            .email_auth_token("alice@example.com", "token")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn oauth_connections() {
+    let database = TestDatabase::new().await;
+    let storage = &database.storage;
+
+    let user = create_user(storage, "alice@example.com").await;
+
+    let now = Utc::now().naive_utc();
+    let provider_id = Uuid::new_v4().to_string();
+    let mut connection = storage_connection(&database).await;
+    diesel::insert_into(schema::oauth_provider::table)
+        .values(&oauth_provider::Model {
+            id: provider_id.clone(),
+            created_at: now,
+            updated_at: now,
+            name: "Example".to_owned(),
+            slug: Some("example".to_owned()),
+            type_: "custom".to_owned(),
+            visibility: "public".to_owned(),
+            client_id: "client".to_owned(),
+            client_secret: None,
+            scopes: Some("openid,email".to_owned()),
+            redirect_url: None,
+            authorization_url: None,
+            authorization_url_params: None,
+            token_url: None,
+            token_url_params: None,
+            introspection_url: None,
+            introspection_url_params: None,
+            revocation_url: None,
+            revocation_url_params: None,
+            user_url: "https://example.com/user".to_owned(),
+            user_path: None,
+            user_id_path: None,
+            user_email_path: None,
+            user_name_path: None,
+            pkce_code_challenge: "s256".to_owned(),
+            icon_url: None,
+        })
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    let provider = storage
+        .oauth_provider_by_id_or_slug("Example")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(provider.id, provider_id);
+    assert_eq!(provider.visibility, OauthProviderVisibility::Public);
+    assert_eq!(
+        provider.scopes,
+        Some(vec!["openid".to_owned(), "email".to_owned()])
+    );
+    assert_eq!(storage.oauth_providers().await.unwrap().len(), 1);
+
+    let connection = storage
+        .create_oauth_connection(CreateOauthConnection {
+            identifier: "alice".to_owned(),
+            token_type: "Bearer".to_owned(),
+            access_token: "access".into(),
+            refresh_token: None,
+            expired_at: None,
+            scopes: None,
+            provider_id: provider.id.clone(),
+            user_id: user.id(),
+        })
+        .await
+        .unwrap();
+
+    let updated = storage
+        .update_oauth_connection(UpdateOauthConnection {
+            id: connection.id.clone(),
+            token_type: None,
+            access_token: Some("refreshed".into()),
+            refresh_token: Some(Some("refresh".into())),
+            expired_at: None,
+            scopes: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(updated.access_token.expose_secret(), "refreshed");
+    assert_eq!(
+        updated
+            .refresh_token
+            .as_ref()
+            .map(|refresh_token| refresh_token.expose_secret()),
+        Some("refresh")
+    );
+
+    let found = storage
+        .oauth_connection_by_identifier(&provider.id, "alice")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id, connection.id);
+    assert_eq!(
+        storage
+            .user_oauth_connections(&user.id(), Some(&provider.id))
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    storage.delete_user(&user.id()).await.unwrap();
+    assert!(
+        storage
+            .oauth_connection_by_id(&connection.id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+async fn storage_connection(database: &TestDatabase) -> Connection {
+    Connection::establish(&database.path.to_string_lossy())
+        .await
+        .unwrap()
+}
@@ -0,0 +1,50 @@
+use std::cmp::Reverse;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shield::{AuditEntry, AuditFilter, AuditStorage, CreateAuditEntry, StorageError};
+use uuid::Uuid;
+
+use crate::storage::MemoryStorage;
+
+#[async_trait]
+impl AuditStorage for MemoryStorage {
+    async fn create_audit_entry(
+        &self,
+        entry: CreateAuditEntry,
+    ) -> Result<AuditEntry, StorageError> {
+        let entry = AuditEntry {
+            id: Uuid::new_v4().to_string(),
+            event_type: entry.event_type,
+            user_id: entry.user_id,
+            method_id: entry.method_id,
+            provider_id: entry.provider_id,
+            reason: entry.reason,
+            ip_address: entry.ip_address,
+            user_agent: entry.user_agent,
+            created_at: Utc::now(),
+        };
+
+        self.audit_entries
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(entry.clone());
+
+        Ok(entry)
+    }
+
+    async fn audit_entries(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>, StorageError> {
+        let mut entries = self
+            .audit_entries
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .filter(|entry| filter.matches(entry))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        entries.sort_by_key(|entry| Reverse(entry.created_at));
+
+        Ok(entries)
+    }
+}
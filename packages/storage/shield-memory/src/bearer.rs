@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shield::{CreateRefreshToken, RefreshToken, RefreshTokenStorage, StorageError};
+use uuid::Uuid;
+
+use crate::storage::MemoryStorage;
+
+#[async_trait]
+impl RefreshTokenStorage for MemoryStorage {
+    async fn refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<RefreshToken>, StorageError> {
+        Ok(self
+            .refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|refresh_token| refresh_token.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn create_refresh_token(
+        &self,
+        token: CreateRefreshToken,
+    ) -> Result<RefreshToken, StorageError> {
+        let refresh_token = RefreshToken {
+            id: Uuid::new_v4().to_string(),
+            token_hash: token.token_hash,
+            family_id: token.family_id,
+            user_id: token.user_id,
+            method_id: token.method_id,
+            provider_id: token.provider_id,
+            factors: token.factors,
+            expired_at: token.expired_at,
+            rotated_at: None,
+        };
+
+        self.refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(refresh_token.clone());
+
+        Ok(refresh_token)
+    }
+
+    async fn rotate_refresh_token(
+        &self,
+        refresh_token_id: &str,
+        rotated_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError> {
+        let mut refresh_tokens = self
+            .refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        match refresh_tokens.iter_mut().find(|refresh_token| {
+            refresh_token.id == refresh_token_id && refresh_token.rotated_at.is_none()
+        }) {
+            Some(refresh_token) => {
+                refresh_token.rotated_at = Some(rotated_at);
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete_refresh_token_family(&self, family_id: &str) -> Result<(), StorageError> {
+        self.refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|refresh_token| refresh_token.family_id != family_id);
+
+        Ok(())
+    }
+
+    async fn delete_user_refresh_tokens(&self, user_id: &str) -> Result<(), StorageError> {
+        self.refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|refresh_token| refresh_token.user_id != user_id);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,8 @@
+mod audit;
+mod bearer;
 mod methods;
+mod rate_limit;
+mod session_registry;
 mod storage;
 mod user;
 
@@ -1,6 +1,22 @@
+#[cfg(feature = "method-api-key")]
+pub mod api_key;
+#[cfg(feature = "method-credentials")]
+pub mod credentials;
 #[cfg(feature = "method-email")]
 pub mod email;
+#[cfg(feature = "method-ldap")]
+pub mod ldap;
 #[cfg(feature = "method-oauth")]
 pub mod oauth;
+#[cfg(feature = "method-oauth-server")]
+pub mod oauth_server;
 #[cfg(feature = "method-oidc")]
 pub mod oidc;
+#[cfg(feature = "method-saml")]
+pub mod saml;
+#[cfg(feature = "method-sms")]
+pub mod sms;
+#[cfg(feature = "method-totp")]
+pub mod totp;
+#[cfg(feature = "method-webauthn")]
+pub mod webauthn;
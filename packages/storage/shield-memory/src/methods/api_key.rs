@@ -0,0 +1,91 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shield::StorageError;
+use shield_api_key::{ApiKey, ApiKeyStorage, CreateApiKey, UpdateApiKey};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct ApiKeyMemoryStorage {
+    api_keys: Arc<Mutex<Vec<ApiKey>>>,
+}
+
+#[async_trait]
+impl ApiKeyStorage<User> for MemoryStorage {
+    async fn api_key_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, StorageError> {
+        Ok(self
+            .api_key
+            .api_keys
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|api_key| api_key.key_hash == key_hash)
+            .cloned())
+    }
+
+    async fn user_api_keys(&self, user_id: &str) -> Result<Vec<ApiKey>, StorageError> {
+        Ok(self
+            .api_key
+            .api_keys
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .filter(|api_key| api_key.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_api_key(&self, api_key: CreateApiKey) -> Result<ApiKey, StorageError> {
+        let api_key = ApiKey {
+            id: Uuid::new_v4().to_string(),
+            name: api_key.name,
+            key_prefix: api_key.key_prefix,
+            key_hash: api_key.key_hash,
+            scopes: api_key.scopes,
+            expired_at: api_key.expired_at,
+            last_used_at: None,
+            created_at: Utc::now().into(),
+            user_id: api_key.user_id,
+        };
+
+        self.api_key
+            .api_keys
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(api_key.clone());
+
+        Ok(api_key)
+    }
+
+    async fn update_api_key(&self, api_key: UpdateApiKey) -> Result<ApiKey, StorageError> {
+        let mut api_keys = self
+            .api_key
+            .api_keys
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let api_key_mut = api_keys
+            .iter_mut()
+            .find(|a| a.id == api_key.id)
+            .ok_or_else(|| StorageError::NotFound("ApiKey".to_owned(), api_key.id.clone()))?;
+
+        if let Some(last_used_at) = api_key.last_used_at {
+            api_key_mut.last_used_at = last_used_at;
+        }
+
+        Ok(api_key_mut.clone())
+    }
+
+    async fn delete_api_key(&self, api_key_id: &str) -> Result<(), StorageError> {
+        self.api_key
+            .api_keys
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|api_key| api_key.id != api_key_id);
+
+        Ok(())
+    }
+}
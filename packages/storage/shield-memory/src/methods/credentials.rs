@@ -0,0 +1,227 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shield::StorageError;
+use shield_credentials::{
+    CreatePasswordResetToken, CreateUserPassword, PasswordResetToken, PasswordStorage,
+    UpdateUserPassword, UserPassword,
+};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct CredentialsMemoryStorage {
+    user_passwords: Arc<Mutex<Vec<UserPassword>>>,
+    password_reset_tokens: Arc<Mutex<Vec<PasswordResetToken>>>,
+}
+
+#[async_trait]
+impl PasswordStorage<User> for MemoryStorage {
+    async fn user_password(&self, user_id: &str) -> Result<Option<UserPassword>, StorageError> {
+        Ok(self
+            .credentials
+            .user_passwords
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|user_password| user_password.user_id == user_id)
+            .cloned())
+    }
+
+    async fn user_password_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<UserPassword>, StorageError> {
+        Ok(self
+            .credentials
+            .user_passwords
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|user_password| user_password.username.as_deref() == Some(username))
+            .cloned())
+    }
+
+    async fn create_user_password(
+        &self,
+        user_password: CreateUserPassword,
+    ) -> Result<UserPassword, StorageError> {
+        let mut user_passwords = self
+            .credentials
+            .user_passwords
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        if user_passwords
+            .iter()
+            .any(|u| u.user_id == user_password.user_id)
+        {
+            return Err(StorageError::Validation(
+                "User already has a password.".to_owned(),
+            ));
+        }
+        if user_password.username.is_some()
+            && user_passwords
+                .iter()
+                .any(|u| u.username == user_password.username)
+        {
+            return Err(StorageError::Validation(
+                "Username is already taken.".to_owned(),
+            ));
+        }
+
+        let user_password = UserPassword {
+            id: Uuid::new_v4().to_string(),
+            username: user_password.username,
+            password_hash: user_password.password_hash,
+            user_id: user_password.user_id,
+        };
+
+        user_passwords.push(user_password.clone());
+
+        Ok(user_password)
+    }
+
+    async fn update_user_password(
+        &self,
+        user_password: UpdateUserPassword,
+    ) -> Result<UserPassword, StorageError> {
+        let mut user_passwords = self
+            .credentials
+            .user_passwords
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        if let Some(Some(username)) = &user_password.username
+            && user_passwords.iter().any(|u| {
+                u.id != user_password.id && u.username.as_deref() == Some(username.as_str())
+            })
+        {
+            return Err(StorageError::Validation(
+                "Username is already taken.".to_owned(),
+            ));
+        }
+
+        let user_password_mut = user_passwords
+            .iter_mut()
+            .find(|u| u.id == user_password.id)
+            .ok_or_else(|| {
+                StorageError::NotFound("UserPassword".to_owned(), user_password.id.clone())
+            })?;
+
+        if let Some(username) = user_password.username {
+            user_password_mut.username = username;
+        }
+        if let Some(password_hash) = user_password.password_hash {
+            user_password_mut.password_hash = password_hash;
+        }
+
+        Ok(user_password_mut.clone())
+    }
+
+    async fn delete_user_password(&self, user_password_id: &str) -> Result<(), StorageError> {
+        self.credentials
+            .user_passwords
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|user_password| user_password.id != user_password_id);
+
+        Ok(())
+    }
+
+    async fn password_reset_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<PasswordResetToken>, StorageError> {
+        Ok(self
+            .credentials
+            .password_reset_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|password_reset_token| {
+                password_reset_token.token == token && password_reset_token.expired_at > Utc::now()
+            })
+            .cloned())
+    }
+
+    async fn create_password_reset_token(
+        &self,
+        password_reset_token: CreatePasswordResetToken,
+    ) -> Result<PasswordResetToken, StorageError> {
+        let password_reset_token = PasswordResetToken {
+            id: Uuid::new_v4().to_string(),
+            token: password_reset_token.token,
+            expired_at: password_reset_token.expired_at,
+            user_id: password_reset_token.user_id,
+        };
+
+        self.credentials
+            .password_reset_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(password_reset_token.clone());
+
+        Ok(password_reset_token)
+    }
+
+    async fn delete_password_reset_token(
+        &self,
+        password_reset_token_id: &str,
+    ) -> Result<bool, StorageError> {
+        let mut password_reset_tokens = self
+            .credentials
+            .password_reset_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let length = password_reset_tokens.len();
+        password_reset_tokens
+            .retain(|password_reset_token| password_reset_token.id != password_reset_token_id);
+
+        Ok(password_reset_tokens.len() < length)
+    }
+
+    async fn delete_expired_password_reset_tokens(&self) -> Result<(), StorageError> {
+        let now = Utc::now();
+
+        self.credentials
+            .password_reset_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|password_reset_token| password_reset_token.expired_at > now);
+
+        Ok(())
+    }
+
+    async fn verify_email_address(&self, verification_token: &str) -> Result<bool, StorageError> {
+        let now = Utc::now();
+
+        let mut users = self
+            .users
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let Some(email_address) = users
+            .iter_mut()
+            .flat_map(|user| user.email_addresses.iter_mut())
+            .find(|email_address| {
+                email_address.verification_token.as_deref() == Some(verification_token)
+                    && email_address
+                        .verification_token_expired_at
+                        .is_some_and(|expired_at| expired_at > now)
+            })
+        else {
+            return Ok(false);
+        };
+
+        email_address.is_verified = true;
+        email_address.verification_token = None;
+        email_address.verification_token_expired_at = None;
+        email_address.verified_at = Some(now.into());
+
+        Ok(true)
+    }
+}
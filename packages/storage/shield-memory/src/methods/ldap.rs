@@ -0,0 +1,88 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use shield::StorageError;
+use shield_ldap::{CreateLdapConnection, LdapConnection, LdapStorage};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct LdapMemoryStorage {
+    connections: Arc<Mutex<Vec<LdapConnection>>>,
+}
+
+#[async_trait]
+impl LdapStorage<User> for MemoryStorage {
+    async fn ldap_connection_by_id(
+        &self,
+        connection_id: &str,
+    ) -> Result<Option<LdapConnection>, StorageError> {
+        Ok(self
+            .ldap
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|connection| connection.id == connection_id)
+            .cloned())
+    }
+
+    async fn ldap_connection_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<Option<LdapConnection>, StorageError> {
+        Ok(self
+            .ldap
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|connection| connection.identifier == identifier)
+            .cloned())
+    }
+
+    async fn create_ldap_connection(
+        &self,
+        connection: CreateLdapConnection,
+    ) -> Result<LdapConnection, StorageError> {
+        let connection = LdapConnection {
+            id: Uuid::new_v4().to_string(),
+            identifier: connection.identifier,
+            user_id: connection.user_id,
+        };
+
+        self.ldap
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(connection.clone());
+
+        Ok(connection)
+    }
+
+    async fn delete_ldap_connection(&self, connection_id: &str) -> Result<(), StorageError> {
+        self.ldap
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|connection| connection.id != connection_id);
+
+        Ok(())
+    }
+
+    async fn user_ldap_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<LdapConnection>, StorageError> {
+        Ok(self
+            .ldap
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .filter(|connection| connection.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,220 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shield::StorageError;
+use shield_oauth_server::{
+    CreateOauthServerAuthorizationCode, CreateOauthServerClient, CreateOauthServerRefreshToken,
+    OauthServerAuthorizationCode, OauthServerClient, OauthServerRefreshToken, OauthServerStorage,
+};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct OauthServerMemoryStorage {
+    clients: Arc<Mutex<Vec<OauthServerClient>>>,
+    authorization_codes: Arc<Mutex<Vec<OauthServerAuthorizationCode>>>,
+    refresh_tokens: Arc<Mutex<Vec<OauthServerRefreshToken>>>,
+}
+
+#[async_trait]
+impl OauthServerStorage<User> for MemoryStorage {
+    async fn oauth_server_client_by_client_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<OauthServerClient>, StorageError> {
+        Ok(self
+            .oauth_server
+            .clients
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|client| client.client_id == client_id)
+            .cloned())
+    }
+
+    async fn create_oauth_server_client(
+        &self,
+        client: CreateOauthServerClient,
+    ) -> Result<OauthServerClient, StorageError> {
+        let client = OauthServerClient {
+            id: Uuid::new_v4().to_string(),
+            client_id: client.client_id,
+            client_secret_hash: client.client_secret_hash,
+            name: client.name,
+            redirect_uris: client.redirect_uris,
+            scopes: client.scopes,
+            created_at: Utc::now().into(),
+        };
+
+        self.oauth_server
+            .clients
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(client.clone());
+
+        Ok(client)
+    }
+
+    async fn delete_oauth_server_client(&self, client_id: &str) -> Result<(), StorageError> {
+        self.oauth_server
+            .clients
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|client| client.client_id != client_id);
+
+        self.oauth_server
+            .authorization_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|authorization_code| authorization_code.client_id != client_id);
+
+        self.oauth_server
+            .refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|refresh_token| refresh_token.client_id != client_id);
+
+        Ok(())
+    }
+
+    async fn create_oauth_server_authorization_code(
+        &self,
+        authorization_code: CreateOauthServerAuthorizationCode,
+    ) -> Result<OauthServerAuthorizationCode, StorageError> {
+        let authorization_code = OauthServerAuthorizationCode {
+            id: Uuid::new_v4().to_string(),
+            code_hash: authorization_code.code_hash,
+            client_id: authorization_code.client_id,
+            user_id: authorization_code.user_id,
+            redirect_uri: authorization_code.redirect_uri,
+            scopes: authorization_code.scopes,
+            code_challenge: authorization_code.code_challenge,
+            nonce: authorization_code.nonce,
+            authenticated_at: authorization_code.authenticated_at,
+            expired_at: authorization_code.expired_at,
+            used_at: None,
+        };
+
+        self.oauth_server
+            .authorization_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(authorization_code.clone());
+
+        Ok(authorization_code)
+    }
+
+    async fn oauth_server_authorization_code_by_hash(
+        &self,
+        code_hash: &str,
+    ) -> Result<Option<OauthServerAuthorizationCode>, StorageError> {
+        Ok(self
+            .oauth_server
+            .authorization_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|authorization_code| authorization_code.code_hash == code_hash)
+            .cloned())
+    }
+
+    async fn use_oauth_server_authorization_code(
+        &self,
+        authorization_code_id: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError> {
+        let mut authorization_codes = self
+            .oauth_server
+            .authorization_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        match authorization_codes.iter_mut().find(|authorization_code| {
+            authorization_code.id == authorization_code_id && authorization_code.used_at.is_none()
+        }) {
+            Some(authorization_code) => {
+                authorization_code.used_at = Some(used_at.into());
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn oauth_server_refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<OauthServerRefreshToken>, StorageError> {
+        Ok(self
+            .oauth_server
+            .refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|refresh_token| refresh_token.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn create_oauth_server_refresh_token(
+        &self,
+        refresh_token: CreateOauthServerRefreshToken,
+    ) -> Result<OauthServerRefreshToken, StorageError> {
+        let refresh_token = OauthServerRefreshToken {
+            id: Uuid::new_v4().to_string(),
+            token_hash: refresh_token.token_hash,
+            family_id: refresh_token.family_id,
+            client_id: refresh_token.client_id,
+            user_id: refresh_token.user_id,
+            scopes: refresh_token.scopes,
+            authenticated_at: refresh_token.authenticated_at,
+            expired_at: refresh_token.expired_at,
+            rotated_at: None,
+        };
+
+        self.oauth_server
+            .refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(refresh_token.clone());
+
+        Ok(refresh_token)
+    }
+
+    async fn rotate_oauth_server_refresh_token(
+        &self,
+        refresh_token_id: &str,
+        rotated_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError> {
+        let mut refresh_tokens = self
+            .oauth_server
+            .refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        match refresh_tokens.iter_mut().find(|refresh_token| {
+            refresh_token.id == refresh_token_id && refresh_token.rotated_at.is_none()
+        }) {
+            Some(refresh_token) => {
+                refresh_token.rotated_at = Some(rotated_at.into());
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete_oauth_server_refresh_token_family(
+        &self,
+        family_id: &str,
+    ) -> Result<(), StorageError> {
+        self.oauth_server
+            .refresh_tokens
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|refresh_token| refresh_token.family_id != family_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use shield::StorageError;
+use shield_saml::{CreateSamlConnection, SamlConnection, SamlProvider, SamlStorage};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct SamlMemoryStorage {
+    connections: Arc<Mutex<Vec<SamlConnection>>>,
+}
+
+#[async_trait]
+impl SamlStorage<User> for MemoryStorage {
+    async fn saml_providers(&self) -> Result<Vec<SamlProvider>, StorageError> {
+        Ok(vec![])
+    }
+
+    async fn saml_provider_by_id_or_slug(
+        &self,
+        _provider_id: &str,
+    ) -> Result<Option<SamlProvider>, StorageError> {
+        Ok(None)
+    }
+
+    async fn saml_connection_by_id(
+        &self,
+        connection_id: &str,
+    ) -> Result<Option<SamlConnection>, StorageError> {
+        Ok(self
+            .saml
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|connection| connection.id == connection_id)
+            .cloned())
+    }
+
+    async fn saml_connection_by_identifier(
+        &self,
+        provider_id: &str,
+        identifier: &str,
+    ) -> Result<Option<SamlConnection>, StorageError> {
+        Ok(self
+            .saml
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|connection| {
+                connection.provider_id == provider_id && connection.identifier == identifier
+            })
+            .cloned())
+    }
+
+    async fn create_saml_connection(
+        &self,
+        connection: CreateSamlConnection,
+    ) -> Result<SamlConnection, StorageError> {
+        let connection = SamlConnection {
+            id: Uuid::new_v4().to_string(),
+            identifier: connection.identifier,
+            provider_id: connection.provider_id,
+            user_id: connection.user_id,
+        };
+
+        self.saml
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(connection.clone());
+
+        Ok(connection)
+    }
+
+    async fn delete_saml_connection(&self, connection_id: &str) -> Result<(), StorageError> {
+        self.saml
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|connection| connection.id != connection_id);
+
+        Ok(())
+    }
+
+    async fn user_saml_connections(
+        &self,
+        user_id: &str,
+        provider_id: Option<&str>,
+    ) -> Result<Vec<SamlConnection>, StorageError> {
+        Ok(self
+            .saml
+            .connections
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .filter(|connection| {
+                connection.user_id == user_id
+                    && provider_id.is_none_or(|provider_id| connection.provider_id == provider_id)
+            })
+            .cloned()
+            .collect())
+    }
+}
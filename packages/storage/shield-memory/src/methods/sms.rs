@@ -0,0 +1,139 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shield::{CreatePhoneNumber, CreateUser, PhoneNumber, StorageError};
+use shield_sms::{CreateSmsAuthCode, SmsAuthCode, SmsStorage};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct SmsMemoryStorage {
+    sms_auth_codes: Arc<Mutex<Vec<SmsAuthCode>>>,
+}
+
+#[async_trait]
+impl SmsStorage<User> for MemoryStorage {
+    async fn user_by_phone_number(&self, phone_number: &str) -> Result<Option<User>, StorageError> {
+        Ok(self
+            .users
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|user| {
+                user.phone_numbers
+                    .iter()
+                    .any(|user_phone_number| user_phone_number.phone_number == phone_number)
+            })
+            .cloned())
+    }
+
+    async fn create_user_with_phone_number(
+        &self,
+        user: CreateUser,
+        phone_number: CreatePhoneNumber,
+    ) -> Result<User, StorageError> {
+        let user_id = Uuid::new_v4().to_string();
+
+        let user = User {
+            id: user_id.clone(),
+            name: user.name,
+            email_addresses: vec![],
+            phone_numbers: vec![PhoneNumber {
+                id: Uuid::new_v4().to_string(),
+                phone_number: phone_number.phone_number,
+                is_primary: phone_number.is_primary,
+                is_verified: phone_number.is_verified,
+                verified_at: phone_number.verified_at,
+                user_id,
+            }],
+        };
+
+        self.users
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(user.clone());
+
+        Ok(user)
+    }
+
+    async fn sms_auth_code(&self, phone_number: &str) -> Result<Option<SmsAuthCode>, StorageError> {
+        Ok(self
+            .sms
+            .sms_auth_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .rfind(|sms_auth_code| sms_auth_code.phone_number == phone_number)
+            .cloned())
+    }
+
+    async fn create_sms_auth_code(
+        &self,
+        sms_auth_code: CreateSmsAuthCode,
+    ) -> Result<SmsAuthCode, StorageError> {
+        let sms_auth_code = SmsAuthCode {
+            id: Uuid::new_v4().to_string(),
+            phone_number: sms_auth_code.phone_number,
+            code: sms_auth_code.code,
+            attempts: 0,
+            expired_at: sms_auth_code.expired_at,
+        };
+
+        let mut sms_auth_codes = self
+            .sms
+            .sms_auth_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        sms_auth_codes.retain(|existing| existing.phone_number != sms_auth_code.phone_number);
+        sms_auth_codes.push(sms_auth_code.clone());
+
+        Ok(sms_auth_code)
+    }
+
+    async fn increment_sms_auth_code_attempts(
+        &self,
+        sms_auth_code_id: &str,
+    ) -> Result<SmsAuthCode, StorageError> {
+        let mut sms_auth_codes = self
+            .sms
+            .sms_auth_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let sms_auth_code = sms_auth_codes
+            .iter_mut()
+            .find(|sms_auth_code| sms_auth_code.id == sms_auth_code_id)
+            .ok_or_else(|| {
+                StorageError::NotFound("SmsAuthCode".to_owned(), sms_auth_code_id.to_owned())
+            })?;
+
+        sms_auth_code.attempts += 1;
+
+        Ok(sms_auth_code.clone())
+    }
+
+    async fn delete_sms_auth_code(&self, sms_auth_code_id: &str) -> Result<(), StorageError> {
+        self.sms
+            .sms_auth_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|sms_auth_code| sms_auth_code.id != sms_auth_code_id);
+
+        Ok(())
+    }
+
+    async fn delete_expired_sms_auth_codes(&self) -> Result<(), StorageError> {
+        let now = Utc::now();
+
+        self.sms
+            .sms_auth_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|sms_auth_code| sms_auth_code.expired_at > now);
+
+        Ok(())
+    }
+}
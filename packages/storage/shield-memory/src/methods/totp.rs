@@ -0,0 +1,174 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use shield::StorageError;
+use shield_totp::{
+    CreateTotpRecoveryCode, CreateUserTotp, TotpRecoveryCode, TotpStorage, UpdateUserTotp, UserTotp,
+};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct TotpMemoryStorage {
+    user_totps: Arc<Mutex<Vec<UserTotp>>>,
+    recovery_codes: Arc<Mutex<Vec<TotpRecoveryCode>>>,
+}
+
+#[async_trait]
+impl TotpStorage<User> for MemoryStorage {
+    async fn user_totp(&self, user_id: &str) -> Result<Option<UserTotp>, StorageError> {
+        Ok(self
+            .totp
+            .user_totps
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|user_totp| user_totp.user_id == user_id)
+            .cloned())
+    }
+
+    async fn create_user_totp(&self, user_totp: CreateUserTotp) -> Result<UserTotp, StorageError> {
+        let mut user_totps = self
+            .totp
+            .user_totps
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        if user_totps.iter().any(|u| u.user_id == user_totp.user_id) {
+            return Err(StorageError::Validation(
+                "User already has a TOTP secret.".to_owned(),
+            ));
+        }
+
+        let user_totp = UserTotp {
+            id: Uuid::new_v4().to_string(),
+            secret: user_totp.secret,
+            last_used_step: user_totp.last_used_step,
+            user_id: user_totp.user_id,
+        };
+
+        user_totps.push(user_totp.clone());
+
+        Ok(user_totp)
+    }
+
+    async fn update_user_totp(&self, user_totp: UpdateUserTotp) -> Result<UserTotp, StorageError> {
+        let mut user_totps = self
+            .totp
+            .user_totps
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let user_totp_mut = user_totps
+            .iter_mut()
+            .find(|u| u.id == user_totp.id)
+            .ok_or_else(|| StorageError::NotFound("UserTotp".to_owned(), user_totp.id.clone()))?;
+
+        if let Some(last_used_step) = user_totp.last_used_step {
+            user_totp_mut.last_used_step = last_used_step;
+        }
+
+        Ok(user_totp_mut.clone())
+    }
+
+    async fn use_user_totp_step(
+        &self,
+        user_totp_id: &str,
+        step: i64,
+    ) -> Result<bool, StorageError> {
+        let mut user_totps = self
+            .totp
+            .user_totps
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let user_totp_mut = user_totps
+            .iter_mut()
+            .find(|u| u.id == user_totp_id)
+            .ok_or_else(|| {
+                StorageError::NotFound("UserTotp".to_owned(), user_totp_id.to_owned())
+            })?;
+
+        if user_totp_mut
+            .last_used_step
+            .is_some_and(|last_used_step| last_used_step >= step)
+        {
+            return Ok(false);
+        }
+
+        user_totp_mut.last_used_step = Some(step);
+
+        Ok(true)
+    }
+
+    async fn delete_user_totp(&self, user_totp_id: &str) -> Result<(), StorageError> {
+        self.totp
+            .user_totps
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|user_totp| user_totp.id != user_totp_id);
+
+        Ok(())
+    }
+
+    async fn totp_recovery_codes(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<TotpRecoveryCode>, StorageError> {
+        Ok(self
+            .totp
+            .recovery_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .filter(|recovery_code| recovery_code.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_totp_recovery_code(
+        &self,
+        recovery_code: CreateTotpRecoveryCode,
+    ) -> Result<TotpRecoveryCode, StorageError> {
+        let recovery_code = TotpRecoveryCode {
+            id: Uuid::new_v4().to_string(),
+            code_hash: recovery_code.code_hash,
+            user_id: recovery_code.user_id,
+        };
+
+        self.totp
+            .recovery_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(recovery_code.clone());
+
+        Ok(recovery_code)
+    }
+
+    async fn delete_totp_recovery_code(
+        &self,
+        recovery_code_id: &str,
+    ) -> Result<bool, StorageError> {
+        let mut recovery_codes = self
+            .totp
+            .recovery_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let length = recovery_codes.len();
+        recovery_codes.retain(|recovery_code| recovery_code.id != recovery_code_id);
+
+        Ok(recovery_codes.len() < length)
+    }
+
+    async fn delete_totp_recovery_codes(&self, user_id: &str) -> Result<(), StorageError> {
+        self.totp
+            .recovery_codes
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|recovery_code| recovery_code.user_id != user_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use shield::StorageError;
+use shield_webauthn::{
+    CreateWebauthnCredential, UpdateWebauthnCredential, WebauthnCredential, WebauthnStorage,
+};
+use uuid::Uuid;
+
+use crate::{storage::MemoryStorage, user::User};
+
+#[derive(Clone, Debug, Default)]
+pub struct WebauthnMemoryStorage {
+    credentials: Arc<Mutex<Vec<WebauthnCredential>>>,
+}
+
+#[async_trait]
+impl WebauthnStorage<User> for MemoryStorage {
+    async fn webauthn_credential_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<WebauthnCredential>, StorageError> {
+        Ok(self
+            .webauthn
+            .credentials
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|credential| credential.id == id)
+            .cloned())
+    }
+
+    async fn webauthn_credential_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> Result<Option<WebauthnCredential>, StorageError> {
+        Ok(self
+            .webauthn
+            .credentials
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|credential| credential.credential_id == credential_id)
+            .cloned())
+    }
+
+    async fn create_webauthn_credential(
+        &self,
+        credential: CreateWebauthnCredential,
+    ) -> Result<WebauthnCredential, StorageError> {
+        let credential = WebauthnCredential {
+            id: Uuid::new_v4().to_string(),
+            credential_id: credential.credential_id,
+            passkey: credential.passkey,
+            last_used_at: None,
+            user_id: credential.user_id,
+        };
+
+        self.webauthn
+            .credentials
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .push(credential.clone());
+
+        Ok(credential)
+    }
+
+    async fn update_webauthn_credential(
+        &self,
+        credential: UpdateWebauthnCredential,
+    ) -> Result<WebauthnCredential, StorageError> {
+        let mut credentials = self
+            .webauthn
+            .credentials
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let credential_mut = credentials
+            .iter_mut()
+            .find(|c| c.id == credential.id)
+            .ok_or_else(|| {
+                StorageError::NotFound("WebauthnCredential".to_owned(), credential.id.clone())
+            })?;
+
+        if let Some(passkey) = credential.passkey {
+            credential_mut.passkey = passkey;
+        }
+        if let Some(last_used_at) = credential.last_used_at {
+            credential_mut.last_used_at = last_used_at;
+        }
+
+        Ok(credential_mut.clone())
+    }
+
+    async fn delete_webauthn_credential(&self, id: &str) -> Result<(), StorageError> {
+        self.webauthn
+            .credentials
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|credential| credential.id != id);
+
+        Ok(())
+    }
+
+    async fn user_webauthn_credentials(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<WebauthnCredential>, StorageError> {
+        Ok(self
+            .webauthn
+            .credentials
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .filter(|credential| credential.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+}
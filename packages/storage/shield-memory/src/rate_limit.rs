@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use shield::{RateLimitEntry, RateLimitStorage, StorageError};
+
+use crate::storage::MemoryStorage;
+
+#[async_trait]
+impl RateLimitStorage for MemoryStorage {
+    async fn rate_limit_entry(&self, key: &str) -> Result<Option<RateLimitEntry>, StorageError> {
+        Ok(self
+            .rate_limit_entries
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .iter()
+            .find(|entry| entry.key == key)
+            .cloned())
+    }
+
+    async fn record_failed_attempt(
+        &self,
+        key: &str,
+        now: DateTime<Utc>,
+        window: TimeDelta,
+    ) -> Result<RateLimitEntry, StorageError> {
+        let mut entries = self
+            .rate_limit_entries
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        match entries.iter_mut().find(|entry| entry.key == key) {
+            Some(entry_mut) => {
+                if now < entry_mut.window_started_at + window {
+                    entry_mut.attempts += 1;
+                } else {
+                    entry_mut.attempts = 1;
+                    entry_mut.window_started_at = now;
+                    entry_mut.locked_until = None;
+                }
+                entry_mut.last_attempted_at = now;
+
+                Ok(entry_mut.clone())
+            }
+            None => {
+                let entry = RateLimitEntry {
+                    key: key.to_owned(),
+                    attempts: 1,
+                    window_started_at: now,
+                    last_attempted_at: now,
+                    locked_until: None,
+                };
+                entries.push(entry.clone());
+
+                Ok(entry)
+            }
+        }
+    }
+
+    async fn save_rate_limit_entry(&self, entry: RateLimitEntry) -> Result<(), StorageError> {
+        let mut entries = self
+            .rate_limit_entries
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        match entries.iter_mut().find(|e| e.key == entry.key) {
+            Some(entry_mut) => *entry_mut = entry,
+            None => entries.push(entry),
+        }
+
+        Ok(())
+    }
+
+    async fn delete_rate_limit_entry(&self, key: &str) -> Result<(), StorageError> {
+        self.rate_limit_entries
+            .lock()
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .retain(|entry| entry.key != key);
+
+        Ok(())
+    }
+}
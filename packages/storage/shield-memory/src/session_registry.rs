@@ -0,0 +1,128 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shield::{
+    CreateRegisteredSession, RegisteredSession, RegisteredSessionFilter, SessionError,
+    SessionRegistry, UpdateRegisteredSession,
+};
+use uuid::Uuid;
+
+use crate::storage::MemoryStorage;
+
+#[derive(Clone, Debug)]
+pub struct SessionMemoryStorage {
+    sessions: Arc<Mutex<Vec<RegisteredSession>>>,
+    created_at: DateTime<Utc>,
+}
+
+impl Default for SessionMemoryStorage {
+    fn default() -> Self {
+        Self {
+            sessions: Arc::default(),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionRegistry for MemoryStorage {
+    fn registered_since(&self) -> Option<DateTime<Utc>> {
+        // Sessions are lost on restart.
+        Some(self.sessions.created_at)
+    }
+
+    async fn registered_session_by_id(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<RegisteredSession>, SessionError> {
+        Ok(self
+            .sessions
+            .sessions
+            .lock()
+            .map_err(|err| SessionError::Engine(err.to_string()))?
+            .iter()
+            .find(|session| session.id == session_id)
+            .cloned())
+    }
+
+    async fn registered_sessions(
+        &self,
+        filter: &RegisteredSessionFilter,
+    ) -> Result<Vec<RegisteredSession>, SessionError> {
+        Ok(self
+            .sessions
+            .sessions
+            .lock()
+            .map_err(|err| SessionError::Engine(err.to_string()))?
+            .iter()
+            .filter(|session| filter.matches(session))
+            .cloned()
+            .collect())
+    }
+
+    async fn create_registered_session(
+        &self,
+        session: CreateRegisteredSession,
+    ) -> Result<RegisteredSession, SessionError> {
+        let now = Utc::now();
+
+        let session = RegisteredSession {
+            id: Uuid::new_v4().to_string(),
+            user_id: session.user_id,
+            method_id: session.method_id,
+            provider_id: session.provider_id,
+            provider_session_id: session.provider_session_id,
+            ip_address: session.ip_address,
+            user_agent: session.user_agent,
+            created_at: now,
+            last_seen_at: now,
+        };
+
+        self.sessions
+            .sessions
+            .lock()
+            .map_err(|err| SessionError::Engine(err.to_string()))?
+            .push(session.clone());
+
+        Ok(session)
+    }
+
+    async fn update_registered_session(
+        &self,
+        session: UpdateRegisteredSession,
+    ) -> Result<RegisteredSession, SessionError> {
+        let mut sessions = self
+            .sessions
+            .sessions
+            .lock()
+            .map_err(|err| SessionError::Engine(err.to_string()))?;
+
+        let registered_session = sessions
+            .iter_mut()
+            .find(|registered_session| registered_session.id == session.id)
+            .ok_or_else(|| SessionError::Engine(format!("Session `{}` not found.", session.id)))?;
+
+        if let Some(ip_address) = session.ip_address {
+            registered_session.ip_address = ip_address;
+        }
+        if let Some(user_agent) = session.user_agent {
+            registered_session.user_agent = user_agent;
+        }
+        if let Some(last_seen_at) = session.last_seen_at {
+            registered_session.last_seen_at = last_seen_at;
+        }
+
+        Ok(registered_session.clone())
+    }
+
+    async fn delete_registered_session(&self, session_id: &str) -> Result<(), SessionError> {
+        self.sessions
+            .sessions
+            .lock()
+            .map_err(|err| SessionError::Engine(err.to_string()))?
+            .retain(|session| session.id != session_id);
+
+        Ok(())
+    }
+}
@@ -2,7 +2,8 @@ use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use shield::{
-    CreateEmailAddress, CreateUser, EmailAddress, Storage, StorageError, UpdateUser, User as _,
+    AuditEntry, CreateEmailAddress, CreateUser, EmailAddress, RateLimitEntry, RefreshToken,
+    Storage, StorageError, UpdateUser, User as _,
 };
 use uuid::Uuid;
 
@@ -13,12 +14,32 @@ pub const MEMORY_STORAGE_ID: &str = "memory";
 #[derive(Clone, Debug, Default)]
 pub struct MemoryStorage {
     pub(crate) users: Arc<Mutex<Vec<User>>>,
+    pub(crate) sessions: crate::session_registry::SessionMemoryStorage,
+    pub(crate) refresh_tokens: Arc<Mutex<Vec<RefreshToken>>>,
+    pub(crate) audit_entries: Arc<Mutex<Vec<AuditEntry>>>,
+    pub(crate) rate_limit_entries: Arc<Mutex<Vec<RateLimitEntry>>>,
+    #[cfg(feature = "method-api-key")]
+    pub(crate) api_key: crate::methods::api_key::ApiKeyMemoryStorage,
+    #[cfg(feature = "method-credentials")]
+    pub(crate) credentials: crate::methods::credentials::CredentialsMemoryStorage,
     #[cfg(feature = "method-email")]
     pub(crate) email: crate::methods::email::EmailMemoryStorage,
+    #[cfg(feature = "method-ldap")]
+    pub(crate) ldap: crate::methods::ldap::LdapMemoryStorage,
     #[cfg(feature = "method-oauth")]
     pub(crate) oauth: crate::methods::oauth::OauthMemoryStorage,
+    #[cfg(feature = "method-oauth-server")]
+    pub(crate) oauth_server: crate::methods::oauth_server::OauthServerMemoryStorage,
     #[cfg(feature = "method-oidc")]
     pub(crate) oidc: crate::methods::oidc::OidcMemoryStorage,
+    #[cfg(feature = "method-saml")]
+    pub(crate) saml: crate::methods::saml::SamlMemoryStorage,
+    #[cfg(feature = "method-sms")]
+    pub(crate) sms: crate::methods::sms::SmsMemoryStorage,
+    #[cfg(feature = "method-totp")]
+    pub(crate) totp: crate::methods::totp::TotpMemoryStorage,
+    #[cfg(feature = "method-webauthn")]
+    pub(crate) webauthn: crate::methods::webauthn::WebauthnMemoryStorage,
 }
 
 impl MemoryStorage {
@@ -77,6 +98,7 @@ impl Storage<User> for MemoryStorage {
                 verified_at: email_address.verified_at,
                 user_id,
             }],
+            phone_numbers: vec![],
         };
 
         self.users
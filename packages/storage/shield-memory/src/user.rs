@@ -1,12 +1,14 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use shield::{EmailAddress, StorageError};
+use shield::{EmailAddress, PhoneNumber, StorageError};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct User {
     pub(crate) id: String,
     pub(crate) name: Option<String>,
     pub(crate) email_addresses: Vec<EmailAddress>,
+    #[serde(default)]
+    pub(crate) phone_numbers: Vec<PhoneNumber>,
 }
 
 #[async_trait]
@@ -23,6 +25,10 @@ impl shield::User for User {
         Ok(self.email_addresses.clone())
     }
 
+    async fn phone_numbers(&self) -> Result<Vec<PhoneNumber>, StorageError> {
+        Ok(self.phone_numbers.clone())
+    }
+
     fn additional(&self) -> Option<impl Serialize> {
         None::<()>
     }
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use shield::{
+    AuditEntry, AuditEventType, AuditFilter, AuditStorage, CreateAuditEntry, StorageError,
+};
+
+use crate::{
+    entities::audit_entry::{self, AuditEntryEventType},
+    storage::SeaOrmStorage,
+};
+
+#[async_trait]
+impl AuditStorage for SeaOrmStorage {
+    async fn create_audit_entry(
+        &self,
+        entry: CreateAuditEntry,
+    ) -> Result<AuditEntry, StorageError> {
+        let active_model = audit_entry::ActiveModel {
+            event_type: ActiveValue::Set(entry.event_type.into()),
+            user_id: ActiveValue::Set(entry.user_id.as_deref().map(Self::parse_uuid).transpose()?),
+            method_id: ActiveValue::Set(entry.method_id),
+            provider_id: ActiveValue::Set(entry.provider_id),
+            reason: ActiveValue::Set(entry.reason),
+            ip_address: ActiveValue::Set(entry.ip_address),
+            user_agent: ActiveValue::Set(entry.user_agent),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(AuditEntry::from)
+    }
+
+    async fn audit_entries(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>, StorageError> {
+        let mut query = audit_entry::Entity::find();
+
+        if let Some(user_id) = &filter.user_id {
+            query = query.filter(audit_entry::Column::UserId.eq(Self::parse_uuid(user_id)?));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(audit_entry::Column::CreatedAt.gte(from.fixed_offset()));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(audit_entry::Column::CreatedAt.lt(to.fixed_offset()));
+        }
+
+        query
+            .order_by_desc(audit_entry::Column::CreatedAt)
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|entries| entries.into_iter().map(AuditEntry::from).collect())
+    }
+}
+
+impl From<AuditEventType> for AuditEntryEventType {
+    fn from(value: AuditEventType) -> Self {
+        match value {
+            AuditEventType::SignIn => AuditEntryEventType::SignIn,
+            AuditEventType::SignInFailed => AuditEntryEventType::SignInFailed,
+            AuditEventType::SignOut => AuditEntryEventType::SignOut,
+            AuditEventType::ConnectionLinked => AuditEntryEventType::ConnectionLinked,
+            AuditEventType::ConnectionUnlinked => AuditEntryEventType::ConnectionUnlinked,
+            AuditEventType::SessionRevoked => AuditEntryEventType::SessionRevoked,
+        }
+    }
+}
+
+impl From<AuditEntryEventType> for AuditEventType {
+    fn from(value: AuditEntryEventType) -> Self {
+        match value {
+            AuditEntryEventType::SignIn => AuditEventType::SignIn,
+            AuditEntryEventType::SignInFailed => AuditEventType::SignInFailed,
+            AuditEntryEventType::SignOut => AuditEventType::SignOut,
+            AuditEntryEventType::ConnectionLinked => AuditEventType::ConnectionLinked,
+            AuditEntryEventType::ConnectionUnlinked => AuditEventType::ConnectionUnlinked,
+            AuditEntryEventType::SessionRevoked => AuditEventType::SessionRevoked,
+        }
+    }
+}
+
+impl From<audit_entry::Model> for AuditEntry {
+    fn from(value: audit_entry::Model) -> Self {
+        AuditEntry {
+            id: value.id.to_string(),
+            event_type: value.event_type.into(),
+            user_id: value.user_id.map(|user_id| user_id.to_string()),
+            method_id: value.method_id,
+            provider_id: value.provider_id,
+            reason: value.reason,
+            ip_address: value.ip_address,
+            user_agent: value.user_agent,
+            created_at: value.created_at.to_utc(),
+        }
+    }
+}
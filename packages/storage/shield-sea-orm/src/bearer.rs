@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, prelude::Expr,
+};
+use shield::{CreateRefreshToken, RefreshToken, RefreshTokenStorage, StorageError};
+
+use crate::{entities::refresh_token, storage::SeaOrmStorage};
+
+#[async_trait]
+impl RefreshTokenStorage for SeaOrmStorage {
+    async fn refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<RefreshToken>, StorageError> {
+        refresh_token::Entity::find()
+            .filter(refresh_token::Column::TokenHash.eq(token_hash))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|refresh_token| refresh_token.map(RefreshToken::from))
+    }
+
+    async fn create_refresh_token(
+        &self,
+        token: CreateRefreshToken,
+    ) -> Result<RefreshToken, StorageError> {
+        let active_model = refresh_token::ActiveModel {
+            token_hash: ActiveValue::Set(token.token_hash),
+            family_id: ActiveValue::Set(token.family_id),
+            method_id: ActiveValue::Set(token.method_id),
+            provider_id: ActiveValue::Set(token.provider_id),
+            factors: ActiveValue::Set(token.factors.join(" ")),
+            expired_at: ActiveValue::Set(token.expired_at.fixed_offset()),
+            user_id: ActiveValue::Set(Self::parse_uuid(&token.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(RefreshToken::from)
+    }
+
+    async fn rotate_refresh_token(
+        &self,
+        refresh_token_id: &str,
+        rotated_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError> {
+        let result = refresh_token::Entity::update_many()
+            .col_expr(
+                refresh_token::Column::RotatedAt,
+                Expr::value(rotated_at.fixed_offset()),
+            )
+            .filter(refresh_token::Column::Id.eq(Self::parse_uuid(refresh_token_id)?))
+            .filter(refresh_token::Column::RotatedAt.is_null())
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete_refresh_token_family(&self, family_id: &str) -> Result<(), StorageError> {
+        refresh_token::Entity::delete_many()
+            .filter(refresh_token::Column::FamilyId.eq(family_id))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn delete_user_refresh_tokens(&self, user_id: &str) -> Result<(), StorageError> {
+        refresh_token::Entity::delete_many()
+            .filter(refresh_token::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+}
+
+impl From<refresh_token::Model> for RefreshToken {
+    fn from(value: refresh_token::Model) -> Self {
+        RefreshToken {
+            id: value.id.to_string(),
+            token_hash: value.token_hash,
+            family_id: value.family_id,
+            user_id: value.user_id.to_string(),
+            method_id: value.method_id,
+            provider_id: value.provider_id,
+            factors: value
+                .factors
+                .split_whitespace()
+                .map(ToOwned::to_owned)
+                .collect(),
+            expired_at: value.expired_at.to_utc(),
+            rotated_at: value.rotated_at.map(|rotated_at| rotated_at.to_utc()),
+        }
+    }
+}
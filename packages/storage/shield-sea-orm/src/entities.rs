@@ -1,20 +1,60 @@
 pub mod prelude;
 
+pub mod audit_entry;
 pub mod email_address;
+pub mod rate_limit_entry;
+pub mod refresh_token;
+pub mod registered_session;
 pub mod user;
 
 #[cfg(feature = "entity")]
 pub mod entity;
 
+#[cfg(feature = "method-api-key")]
+pub mod api_key;
+
+#[cfg(feature = "method-credentials")]
+pub mod password_reset_token;
+#[cfg(feature = "method-credentials")]
+pub mod user_password;
+
 #[cfg(feature = "method-email")]
 pub mod email_auth_token;
 
+#[cfg(feature = "method-ldap")]
+pub mod ldap_connection;
+
 #[cfg(feature = "method-oauth")]
 pub mod oauth_provider;
 #[cfg(feature = "method-oauth")]
 pub mod oauth_provider_connection;
 
+#[cfg(feature = "method-oauth-server")]
+pub mod oauth_server_authorization_code;
+#[cfg(feature = "method-oauth-server")]
+pub mod oauth_server_client;
+#[cfg(feature = "method-oauth-server")]
+pub mod oauth_server_refresh_token;
+
 #[cfg(feature = "method-oidc")]
 pub mod oidc_provider;
 #[cfg(feature = "method-oidc")]
 pub mod oidc_provider_connection;
+
+#[cfg(feature = "method-saml")]
+pub mod saml_provider;
+#[cfg(feature = "method-saml")]
+pub mod saml_provider_connection;
+
+#[cfg(feature = "method-sms")]
+pub mod phone_number;
+#[cfg(feature = "method-sms")]
+pub mod sms_auth_code;
+
+#[cfg(feature = "method-totp")]
+pub mod totp_recovery_code;
+#[cfg(feature = "method-totp")]
+pub mod user_totp;
+
+#[cfg(feature = "method-webauthn")]
+pub mod webauthn_credential;
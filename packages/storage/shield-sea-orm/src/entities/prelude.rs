@@ -1,12 +1,27 @@
+pub use super::audit_entry::{AuditEntryEventType, Entity as AuditEntry};
 pub use super::email_address::Entity as EmailAddress;
+pub use super::rate_limit_entry::Entity as RateLimitEntry;
+pub use super::refresh_token::Entity as RefreshToken;
+pub use super::registered_session::Entity as RegisteredSession;
 pub use super::user::Entity as User;
 
 #[cfg(feature = "entity")]
 pub use super::entity::Entity;
 
+#[cfg(feature = "method-api-key")]
+pub use super::api_key::Entity as ApiKey;
+
+#[cfg(feature = "method-credentials")]
+pub use super::password_reset_token::Entity as PasswordResetToken;
+#[cfg(feature = "method-credentials")]
+pub use super::user_password::Entity as UserPassword;
+
 #[cfg(feature = "method-email")]
 pub use super::email_auth_token::Entity as EmailAuthToken;
 
+#[cfg(feature = "method-ldap")]
+pub use super::ldap_connection::Entity as LdapConnection;
+
 #[cfg(feature = "method-oauth")]
 pub use super::oauth_provider::{
     Entity as OauthProvider, OauthProviderPkceCodeChallenge, OauthProviderType,
@@ -15,9 +30,36 @@ pub use super::oauth_provider::{
 #[cfg(feature = "method-oauth")]
 pub use super::oauth_provider_connection::Entity as OauthProviderConnection;
 
+#[cfg(feature = "method-oauth-server")]
+pub use super::oauth_server_authorization_code::Entity as OauthServerAuthorizationCode;
+#[cfg(feature = "method-oauth-server")]
+pub use super::oauth_server_client::Entity as OauthServerClient;
+#[cfg(feature = "method-oauth-server")]
+pub use super::oauth_server_refresh_token::Entity as OauthServerRefreshToken;
+
 #[cfg(feature = "method-oidc")]
 pub use super::oidc_provider::{
     Entity as OidcProvider, OidcProviderPkceCodeChallenge, OidcProviderType, OidcProviderVisibility,
 };
 #[cfg(feature = "method-oidc")]
 pub use super::oidc_provider_connection::Entity as OidcProviderConnection;
+
+#[cfg(feature = "method-saml")]
+pub use super::saml_provider::{
+    Entity as SamlProvider, SamlProviderBinding, SamlProviderVisibility,
+};
+#[cfg(feature = "method-saml")]
+pub use super::saml_provider_connection::Entity as SamlProviderConnection;
+
+#[cfg(feature = "method-sms")]
+pub use super::phone_number::Entity as PhoneNumber;
+#[cfg(feature = "method-sms")]
+pub use super::sms_auth_code::Entity as SmsAuthCode;
+
+#[cfg(feature = "method-totp")]
+pub use super::totp_recovery_code::Entity as TotpRecoveryCode;
+#[cfg(feature = "method-totp")]
+pub use super::user_totp::Entity as UserTotp;
+
+#[cfg(feature = "method-webauthn")]
+pub use super::webauthn_credential::Entity as WebauthnCredential;
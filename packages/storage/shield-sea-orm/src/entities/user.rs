@@ -32,12 +32,49 @@ pub enum Relation {
     #[cfg(not(feature = "entity"))]
     #[sea_orm(has_many = "super::email_address::Entity")]
     EmailAddress,
+    #[cfg(feature = "method-api-key")]
+    #[sea_orm(has_many = "super::api_key::Entity")]
+    ApiKey,
+    #[cfg(feature = "method-credentials")]
+    #[sea_orm(has_many = "super::password_reset_token::Entity")]
+    PasswordResetToken,
+    #[cfg(feature = "method-credentials")]
+    #[sea_orm(has_one = "super::user_password::Entity")]
+    UserPassword,
+    #[sea_orm(has_many = "super::refresh_token::Entity")]
+    RefreshToken,
+    #[sea_orm(has_many = "super::registered_session::Entity")]
+    RegisteredSession,
+    #[cfg(feature = "method-ldap")]
+    #[sea_orm(has_many = "super::ldap_connection::Entity")]
+    LdapConnection,
     #[cfg(feature = "method-oauth")]
     #[sea_orm(has_many = "super::oauth_provider_connection::Entity")]
     OauthProviderConnection,
+    #[cfg(feature = "method-oauth-server")]
+    #[sea_orm(has_many = "super::oauth_server_authorization_code::Entity")]
+    OauthServerAuthorizationCode,
+    #[cfg(feature = "method-oauth-server")]
+    #[sea_orm(has_many = "super::oauth_server_refresh_token::Entity")]
+    OauthServerRefreshToken,
     #[cfg(feature = "method-oidc")]
     #[sea_orm(has_many = "super::oidc_provider_connection::Entity")]
     OidcProviderConnection,
+    #[cfg(feature = "method-saml")]
+    #[sea_orm(has_many = "super::saml_provider_connection::Entity")]
+    SamlProviderConnection,
+    #[cfg(feature = "method-sms")]
+    #[sea_orm(has_many = "super::phone_number::Entity")]
+    PhoneNumber,
+    #[cfg(feature = "method-totp")]
+    #[sea_orm(has_many = "super::totp_recovery_code::Entity")]
+    TotpRecoveryCode,
+    #[cfg(feature = "method-totp")]
+    #[sea_orm(has_one = "super::user_totp::Entity")]
+    UserTotp,
+    #[cfg(feature = "method-webauthn")]
+    #[sea_orm(has_many = "super::webauthn_credential::Entity")]
+    WebauthnCredential,
 }
 
 #[cfg(feature = "entity")]
@@ -54,6 +91,46 @@ impl Related<super::email_address::Entity> for Entity {
     }
 }
 
+impl Related<super::refresh_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RefreshToken.def()
+    }
+}
+
+impl Related<super::registered_session::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RegisteredSession.def()
+    }
+}
+
+#[cfg(feature = "method-api-key")]
+impl Related<super::api_key::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ApiKey.def()
+    }
+}
+
+#[cfg(feature = "method-credentials")]
+impl Related<super::password_reset_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PasswordResetToken.def()
+    }
+}
+
+#[cfg(feature = "method-credentials")]
+impl Related<super::user_password::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserPassword.def()
+    }
+}
+
+#[cfg(feature = "method-ldap")]
+impl Related<super::ldap_connection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LdapConnection.def()
+    }
+}
+
 #[cfg(feature = "method-oauth")]
 impl Related<super::oauth_provider_connection::Entity> for Entity {
     fn to() -> RelationDef {
@@ -61,6 +138,20 @@ impl Related<super::oauth_provider_connection::Entity> for Entity {
     }
 }
 
+#[cfg(feature = "method-oauth-server")]
+impl Related<super::oauth_server_authorization_code::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OauthServerAuthorizationCode.def()
+    }
+}
+
+#[cfg(feature = "method-oauth-server")]
+impl Related<super::oauth_server_refresh_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OauthServerRefreshToken.def()
+    }
+}
+
 #[cfg(feature = "method-oidc")]
 impl Related<super::oidc_provider_connection::Entity> for Entity {
     fn to() -> RelationDef {
@@ -68,4 +159,39 @@ impl Related<super::oidc_provider_connection::Entity> for Entity {
     }
 }
 
+#[cfg(feature = "method-saml")]
+impl Related<super::saml_provider_connection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SamlProviderConnection.def()
+    }
+}
+
+#[cfg(feature = "method-sms")]
+impl Related<super::phone_number::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PhoneNumber.def()
+    }
+}
+
+#[cfg(feature = "method-totp")]
+impl Related<super::totp_recovery_code::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TotpRecoveryCode.def()
+    }
+}
+
+#[cfg(feature = "method-totp")]
+impl Related<super::user_totp::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserTotp.def()
+    }
+}
+
+#[cfg(feature = "method-webauthn")]
+impl Related<super::webauthn_credential::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebauthnCredential.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
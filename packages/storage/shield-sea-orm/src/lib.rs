@@ -1,7 +1,11 @@
+mod audit;
 pub mod base;
+mod bearer;
 pub mod entities;
 mod methods;
 pub mod migrations;
+mod rate_limit;
+mod session_registry;
 mod storage;
 mod user;
 
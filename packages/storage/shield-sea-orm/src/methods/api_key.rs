@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use shield::StorageError;
+use shield_api_key::{ApiKey, ApiKeyStorage, CreateApiKey, UpdateApiKey};
+
+use crate::{entities::api_key, storage::SeaOrmStorage, user::User};
+
+#[async_trait]
+impl ApiKeyStorage<User> for SeaOrmStorage {
+    async fn api_key_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, StorageError> {
+        api_key::Entity::find()
+            .filter(api_key::Column::KeyHash.eq(key_hash))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|api_key| api_key.map(ApiKey::from))
+    }
+
+    async fn user_api_keys(&self, user_id: &str) -> Result<Vec<ApiKey>, StorageError> {
+        api_key::Entity::find()
+            .filter(api_key::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .order_by_asc(api_key::Column::CreatedAt)
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|api_keys| api_keys.into_iter().map(ApiKey::from).collect())
+    }
+
+    async fn create_api_key(&self, api_key: CreateApiKey) -> Result<ApiKey, StorageError> {
+        let active_model = api_key::ActiveModel {
+            name: ActiveValue::Set(api_key.name),
+            key_prefix: ActiveValue::Set(api_key.key_prefix),
+            key_hash: ActiveValue::Set(api_key.key_hash),
+            scopes: ActiveValue::Set(api_key.scopes.join(",")),
+            expired_at: ActiveValue::Set(api_key.expired_at),
+            last_used_at: ActiveValue::Set(None),
+            user_id: ActiveValue::Set(Self::parse_uuid(&api_key.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(ApiKey::from)
+    }
+
+    async fn update_api_key(&self, api_key: UpdateApiKey) -> Result<ApiKey, StorageError> {
+        let mut active_model: api_key::ActiveModel =
+            api_key::Entity::find_by_id(Self::parse_uuid(&api_key.id)?)
+                .one(&self.database)
+                .await
+                .map_err(|err| StorageError::Engine(err.to_string()))?
+                .ok_or_else(|| StorageError::NotFound("ApiKey".to_owned(), api_key.id))?
+                .into();
+
+        if let Some(last_used_at) = api_key.last_used_at {
+            active_model.last_used_at = ActiveValue::Set(last_used_at);
+        }
+
+        active_model
+            .update(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(ApiKey::from)
+    }
+
+    async fn delete_api_key(&self, api_key_id: &str) -> Result<(), StorageError> {
+        api_key::Entity::delete_by_id(Self::parse_uuid(api_key_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+}
+
+impl From<api_key::Model> for ApiKey {
+    fn from(value: api_key::Model) -> Self {
+        ApiKey {
+            id: value.id.to_string(),
+            name: value.name,
+            key_prefix: value.key_prefix,
+            key_hash: value.key_hash,
+            scopes: value
+                .scopes
+                .split(',')
+                .filter(|scope| !scope.is_empty())
+                .map(ToOwned::to_owned)
+                .collect(),
+            expired_at: value.expired_at,
+            last_used_at: value.last_used_at,
+            created_at: value.created_at,
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, prelude::Expr,
+};
+use secrecy::ExposeSecret;
+use shield::StorageError;
+use shield_credentials::{
+    CreatePasswordResetToken, CreateUserPassword, PasswordResetToken, PasswordStorage,
+    UpdateUserPassword, UserPassword,
+};
+
+use crate::{
+    entities::{email_address, password_reset_token, user_password},
+    storage::SeaOrmStorage,
+    user::User,
+};
+
+#[async_trait]
+impl PasswordStorage<User> for SeaOrmStorage {
+    async fn user_password(&self, user_id: &str) -> Result<Option<UserPassword>, StorageError> {
+        user_password::Entity::find()
+            .filter(user_password::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|user_password| user_password.map(UserPassword::from))
+    }
+
+    async fn user_password_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<UserPassword>, StorageError> {
+        user_password::Entity::find()
+            .filter(user_password::Column::Username.eq(username))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|user_password| user_password.map(UserPassword::from))
+    }
+
+    async fn create_user_password(
+        &self,
+        user_password: CreateUserPassword,
+    ) -> Result<UserPassword, StorageError> {
+        let active_model = user_password::ActiveModel {
+            username: ActiveValue::Set(user_password.username),
+            password_hash: ActiveValue::Set(user_password.password_hash.expose_secret().to_owned()),
+            user_id: ActiveValue::Set(Self::parse_uuid(&user_password.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(UserPassword::from)
+    }
+
+    async fn update_user_password(
+        &self,
+        user_password: UpdateUserPassword,
+    ) -> Result<UserPassword, StorageError> {
+        let mut active_model: user_password::ActiveModel =
+            user_password::Entity::find_by_id(Self::parse_uuid(&user_password.id)?)
+                .one(&self.database)
+                .await
+                .map_err(|err| StorageError::Engine(err.to_string()))?
+                .ok_or_else(|| StorageError::NotFound("UserPassword".to_owned(), user_password.id))?
+                .into();
+
+        if let Some(username) = user_password.username {
+            active_model.username = ActiveValue::Set(username);
+        }
+        if let Some(password_hash) = user_password.password_hash {
+            active_model.password_hash = ActiveValue::Set(password_hash.expose_secret().to_owned());
+        }
+
+        active_model
+            .update(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(UserPassword::from)
+    }
+
+    async fn delete_user_password(&self, user_password_id: &str) -> Result<(), StorageError> {
+        user_password::Entity::delete_by_id(Self::parse_uuid(user_password_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn password_reset_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<PasswordResetToken>, StorageError> {
+        password_reset_token::Entity::find()
+            .filter(password_reset_token::Column::Token.eq(token))
+            .filter(password_reset_token::Column::ExpiredAt.gt(Utc::now()))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|password_reset_token| password_reset_token.map(PasswordResetToken::from))
+    }
+
+    async fn create_password_reset_token(
+        &self,
+        password_reset_token: CreatePasswordResetToken,
+    ) -> Result<PasswordResetToken, StorageError> {
+        let active_model = password_reset_token::ActiveModel {
+            token: ActiveValue::Set(password_reset_token.token),
+            expired_at: ActiveValue::Set(password_reset_token.expired_at),
+            user_id: ActiveValue::Set(Self::parse_uuid(&password_reset_token.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(PasswordResetToken::from)
+    }
+
+    async fn delete_password_reset_token(
+        &self,
+        password_reset_token_id: &str,
+    ) -> Result<bool, StorageError> {
+        password_reset_token::Entity::delete_by_id(Self::parse_uuid(password_reset_token_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|result| result.rows_affected > 0)
+    }
+
+    async fn delete_expired_password_reset_tokens(&self) -> Result<(), StorageError> {
+        password_reset_token::Entity::delete_many()
+            .filter(password_reset_token::Column::ExpiredAt.lte(Utc::now()))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn verify_email_address(&self, verification_token: &str) -> Result<bool, StorageError> {
+        let now = Utc::now();
+
+        // Update in the database, so the token can only be used once.
+        email_address::Entity::update_many()
+            .col_expr(email_address::Column::IsVerified, Expr::value(true))
+            .col_expr(
+                email_address::Column::VerificationToken,
+                Expr::value(None::<String>),
+            )
+            .col_expr(
+                email_address::Column::VerificationTokenExpiredAt,
+                Expr::value(None::<DateTime<FixedOffset>>),
+            )
+            .col_expr(
+                email_address::Column::VerifiedAt,
+                Expr::value(DateTime::<FixedOffset>::from(now)),
+            )
+            .filter(email_address::Column::VerificationToken.eq(verification_token))
+            .filter(email_address::Column::VerificationTokenExpiredAt.gt(now))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|result| result.rows_affected > 0)
+    }
+}
+
+impl From<password_reset_token::Model> for PasswordResetToken {
+    fn from(value: password_reset_token::Model) -> Self {
+        PasswordResetToken {
+            id: value.id.to_string(),
+            token: value.token,
+            expired_at: value.expired_at,
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
+
+impl From<user_password::Model> for UserPassword {
+    fn from(value: user_password::Model) -> Self {
+        UserPassword {
+            id: value.id.to_string(),
+            username: value.username,
+            password_hash: value.password_hash.into(),
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter};
+use shield::StorageError;
+use shield_ldap::{CreateLdapConnection, LdapConnection, LdapStorage};
+
+use crate::{entities::ldap_connection, storage::SeaOrmStorage, user::User};
+
+#[async_trait]
+impl LdapStorage<User> for SeaOrmStorage {
+    async fn ldap_connection_by_id(
+        &self,
+        connection_id: &str,
+    ) -> Result<Option<LdapConnection>, StorageError> {
+        ldap_connection::Entity::find_by_id(Self::parse_uuid(connection_id)?)
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|connection| connection.map(LdapConnection::from))
+    }
+
+    async fn ldap_connection_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<Option<LdapConnection>, StorageError> {
+        ldap_connection::Entity::find()
+            .filter(ldap_connection::Column::Identifier.eq(identifier))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|connection| connection.map(LdapConnection::from))
+    }
+
+    async fn create_ldap_connection(
+        &self,
+        connection: CreateLdapConnection,
+    ) -> Result<LdapConnection, StorageError> {
+        let active_model = ldap_connection::ActiveModel {
+            identifier: ActiveValue::Set(connection.identifier),
+            user_id: ActiveValue::Set(Self::parse_uuid(&connection.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(LdapConnection::from)
+    }
+
+    async fn delete_ldap_connection(&self, connection_id: &str) -> Result<(), StorageError> {
+        ldap_connection::Entity::delete_by_id(Self::parse_uuid(connection_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn user_ldap_connections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<LdapConnection>, StorageError> {
+        ldap_connection::Entity::find()
+            .filter(ldap_connection::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|connections| connections.into_iter().map(LdapConnection::from).collect())
+    }
+}
+
+impl From<ldap_connection::Model> for LdapConnection {
+    fn from(value: ldap_connection::Model) -> Self {
+        LdapConnection {
+            id: value.id.to_string(),
+            identifier: value.identifier,
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
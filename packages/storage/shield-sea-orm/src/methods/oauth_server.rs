@@ -0,0 +1,238 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, prelude::Expr,
+};
+use shield::StorageError;
+use shield_oauth_server::{
+    CreateOauthServerAuthorizationCode, CreateOauthServerClient, CreateOauthServerRefreshToken,
+    OauthServerAuthorizationCode, OauthServerClient, OauthServerRefreshToken, OauthServerStorage,
+};
+
+use crate::{
+    entities::{oauth_server_authorization_code, oauth_server_client, oauth_server_refresh_token},
+    storage::SeaOrmStorage,
+    user::User,
+};
+
+#[async_trait]
+impl OauthServerStorage<User> for SeaOrmStorage {
+    async fn oauth_server_client_by_client_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<OauthServerClient>, StorageError> {
+        oauth_server_client::Entity::find()
+            .filter(oauth_server_client::Column::ClientId.eq(client_id))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|client| client.map(OauthServerClient::from))
+    }
+
+    async fn create_oauth_server_client(
+        &self,
+        client: CreateOauthServerClient,
+    ) -> Result<OauthServerClient, StorageError> {
+        let active_model = oauth_server_client::ActiveModel {
+            client_id: ActiveValue::Set(client.client_id),
+            client_secret_hash: ActiveValue::Set(client.client_secret_hash),
+            name: ActiveValue::Set(client.name),
+            redirect_uris: ActiveValue::Set(client.redirect_uris.join(" ")),
+            scopes: ActiveValue::Set(client.scopes.join(" ")),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(OauthServerClient::from)
+    }
+
+    async fn delete_oauth_server_client(&self, client_id: &str) -> Result<(), StorageError> {
+        // Authorization codes and refresh tokens are deleted by the foreign key cascade.
+        oauth_server_client::Entity::delete_many()
+            .filter(oauth_server_client::Column::ClientId.eq(client_id))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn create_oauth_server_authorization_code(
+        &self,
+        authorization_code: CreateOauthServerAuthorizationCode,
+    ) -> Result<OauthServerAuthorizationCode, StorageError> {
+        let active_model = oauth_server_authorization_code::ActiveModel {
+            code_hash: ActiveValue::Set(authorization_code.code_hash),
+            redirect_uri: ActiveValue::Set(authorization_code.redirect_uri),
+            scopes: ActiveValue::Set(authorization_code.scopes.join(" ")),
+            code_challenge: ActiveValue::Set(authorization_code.code_challenge),
+            nonce: ActiveValue::Set(authorization_code.nonce),
+            authenticated_at: ActiveValue::Set(authorization_code.authenticated_at),
+            expired_at: ActiveValue::Set(authorization_code.expired_at),
+            used_at: ActiveValue::Set(None),
+            client_id: ActiveValue::Set(authorization_code.client_id),
+            user_id: ActiveValue::Set(Self::parse_uuid(&authorization_code.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(OauthServerAuthorizationCode::from)
+    }
+
+    async fn oauth_server_authorization_code_by_hash(
+        &self,
+        code_hash: &str,
+    ) -> Result<Option<OauthServerAuthorizationCode>, StorageError> {
+        oauth_server_authorization_code::Entity::find()
+            .filter(oauth_server_authorization_code::Column::CodeHash.eq(code_hash))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|authorization_code| authorization_code.map(OauthServerAuthorizationCode::from))
+    }
+
+    async fn use_oauth_server_authorization_code(
+        &self,
+        authorization_code_id: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError> {
+        let result = oauth_server_authorization_code::Entity::update_many()
+            .col_expr(
+                oauth_server_authorization_code::Column::UsedAt,
+                Expr::value(used_at.fixed_offset()),
+            )
+            .filter(
+                oauth_server_authorization_code::Column::Id
+                    .eq(Self::parse_uuid(authorization_code_id)?),
+            )
+            .filter(oauth_server_authorization_code::Column::UsedAt.is_null())
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn oauth_server_refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<OauthServerRefreshToken>, StorageError> {
+        oauth_server_refresh_token::Entity::find()
+            .filter(oauth_server_refresh_token::Column::TokenHash.eq(token_hash))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|refresh_token| refresh_token.map(OauthServerRefreshToken::from))
+    }
+
+    async fn create_oauth_server_refresh_token(
+        &self,
+        refresh_token: CreateOauthServerRefreshToken,
+    ) -> Result<OauthServerRefreshToken, StorageError> {
+        let active_model = oauth_server_refresh_token::ActiveModel {
+            token_hash: ActiveValue::Set(refresh_token.token_hash),
+            family_id: ActiveValue::Set(refresh_token.family_id),
+            scopes: ActiveValue::Set(refresh_token.scopes.join(" ")),
+            authenticated_at: ActiveValue::Set(refresh_token.authenticated_at),
+            expired_at: ActiveValue::Set(refresh_token.expired_at),
+            rotated_at: ActiveValue::Set(None),
+            client_id: ActiveValue::Set(refresh_token.client_id),
+            user_id: ActiveValue::Set(Self::parse_uuid(&refresh_token.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(OauthServerRefreshToken::from)
+    }
+
+    async fn rotate_oauth_server_refresh_token(
+        &self,
+        refresh_token_id: &str,
+        rotated_at: DateTime<Utc>,
+    ) -> Result<bool, StorageError> {
+        let result = oauth_server_refresh_token::Entity::update_many()
+            .col_expr(
+                oauth_server_refresh_token::Column::RotatedAt,
+                Expr::value(rotated_at.fixed_offset()),
+            )
+            .filter(oauth_server_refresh_token::Column::Id.eq(Self::parse_uuid(refresh_token_id)?))
+            .filter(oauth_server_refresh_token::Column::RotatedAt.is_null())
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete_oauth_server_refresh_token_family(
+        &self,
+        family_id: &str,
+    ) -> Result<(), StorageError> {
+        oauth_server_refresh_token::Entity::delete_many()
+            .filter(oauth_server_refresh_token::Column::FamilyId.eq(family_id))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value.split_whitespace().map(ToOwned::to_owned).collect()
+}
+
+impl From<oauth_server_client::Model> for OauthServerClient {
+    fn from(value: oauth_server_client::Model) -> Self {
+        OauthServerClient {
+            id: value.id.to_string(),
+            client_id: value.client_id,
+            client_secret_hash: value.client_secret_hash,
+            name: value.name,
+            redirect_uris: split_list(&value.redirect_uris),
+            scopes: split_list(&value.scopes),
+            created_at: value.created_at,
+        }
+    }
+}
+
+impl From<oauth_server_authorization_code::Model> for OauthServerAuthorizationCode {
+    fn from(value: oauth_server_authorization_code::Model) -> Self {
+        OauthServerAuthorizationCode {
+            id: value.id.to_string(),
+            code_hash: value.code_hash,
+            client_id: value.client_id,
+            user_id: value.user_id.to_string(),
+            redirect_uri: value.redirect_uri,
+            scopes: split_list(&value.scopes),
+            code_challenge: value.code_challenge,
+            nonce: value.nonce,
+            authenticated_at: value.authenticated_at,
+            expired_at: value.expired_at,
+            used_at: value.used_at,
+        }
+    }
+}
+
+impl From<oauth_server_refresh_token::Model> for OauthServerRefreshToken {
+    fn from(value: oauth_server_refresh_token::Model) -> Self {
+        OauthServerRefreshToken {
+            id: value.id.to_string(),
+            token_hash: value.token_hash,
+            family_id: value.family_id,
+            client_id: value.client_id,
+            user_id: value.user_id.to_string(),
+            scopes: split_list(&value.scopes),
+            authenticated_at: value.authenticated_at,
+            expired_at: value.expired_at,
+            rotated_at: value.rotated_at,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter};
+use shield::StorageError;
+use shield_saml::{
+    CreateSamlConnection, SamlBinding, SamlConnection, SamlProvider, SamlProviderVisibility,
+    SamlStorage,
+};
+
+use crate::{
+    entities::{saml_provider, saml_provider_connection},
+    storage::SeaOrmStorage,
+    user::User,
+};
+
+#[async_trait]
+impl SamlStorage<User> for SeaOrmStorage {
+    async fn saml_providers(&self) -> Result<Vec<SamlProvider>, StorageError> {
+        saml_provider::Entity::find()
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|providers| providers.into_iter().map(SamlProvider::from).collect())
+    }
+
+    async fn saml_provider_by_id_or_slug(
+        &self,
+        provider_id: &str,
+    ) -> Result<Option<SamlProvider>, StorageError> {
+        let condition = match Self::parse_uuid(provider_id) {
+            Ok(provider_id) => saml_provider::Column::Id.eq(provider_id),
+            Err(_) => saml_provider::Column::Slug.eq(provider_id.to_lowercase()),
+        };
+
+        saml_provider::Entity::find()
+            .filter(condition)
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|provider| provider.map(SamlProvider::from))
+    }
+
+    async fn saml_connection_by_id(
+        &self,
+        connection_id: &str,
+    ) -> Result<Option<SamlConnection>, StorageError> {
+        saml_provider_connection::Entity::find_by_id(Self::parse_uuid(connection_id)?)
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|connection| connection.map(SamlConnection::from))
+    }
+
+    async fn saml_connection_by_identifier(
+        &self,
+        provider_id: &str,
+        identifier: &str,
+    ) -> Result<Option<SamlConnection>, StorageError> {
+        saml_provider_connection::Entity::find()
+            .filter(saml_provider_connection::Column::ProviderId.eq(Self::parse_uuid(provider_id)?))
+            .filter(saml_provider_connection::Column::Identifier.eq(identifier))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|connection| connection.map(SamlConnection::from))
+    }
+
+    async fn create_saml_connection(
+        &self,
+        connection: CreateSamlConnection,
+    ) -> Result<SamlConnection, StorageError> {
+        let active_model = saml_provider_connection::ActiveModel {
+            identifier: ActiveValue::Set(connection.identifier),
+            provider_id: ActiveValue::Set(Self::parse_uuid(&connection.provider_id)?),
+            user_id: ActiveValue::Set(Self::parse_uuid(&connection.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(SamlConnection::from)
+    }
+
+    async fn delete_saml_connection(&self, connection_id: &str) -> Result<(), StorageError> {
+        saml_provider_connection::Entity::delete_by_id(Self::parse_uuid(connection_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn user_saml_connections(
+        &self,
+        user_id: &str,
+        provider_id: Option<&str>,
+    ) -> Result<Vec<SamlConnection>, StorageError> {
+        let mut query = saml_provider_connection::Entity::find()
+            .filter(saml_provider_connection::Column::UserId.eq(Self::parse_uuid(user_id)?));
+
+        if let Some(provider_id) = provider_id {
+            query = query.filter(
+                saml_provider_connection::Column::ProviderId.eq(Self::parse_uuid(provider_id)?),
+            );
+        }
+
+        query
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|connections| connections.into_iter().map(SamlConnection::from).collect())
+    }
+}
+
+impl From<saml_provider::SamlProviderVisibility> for SamlProviderVisibility {
+    fn from(value: saml_provider::SamlProviderVisibility) -> Self {
+        match value {
+            saml_provider::SamlProviderVisibility::Public => SamlProviderVisibility::Public,
+            saml_provider::SamlProviderVisibility::Unlisted => SamlProviderVisibility::Unlisted,
+        }
+    }
+}
+
+impl From<saml_provider::SamlProviderBinding> for SamlBinding {
+    fn from(value: saml_provider::SamlProviderBinding) -> Self {
+        match value {
+            saml_provider::SamlProviderBinding::Redirect => SamlBinding::Redirect,
+            saml_provider::SamlProviderBinding::Post => SamlBinding::Post,
+        }
+    }
+}
+
+impl From<saml_provider::Model> for SamlProvider {
+    fn from(value: saml_provider::Model) -> Self {
+        SamlProvider {
+            id: value.id.to_string(),
+            name: value.name,
+            slug: value.slug,
+            icon_url: value.icon_url,
+            visibility: value.visibility.into(),
+            entity_id: value.entity_id,
+            acs_url: value.acs_url,
+            idp_metadata: value.idp_metadata,
+            idp_entity_id: value.idp_entity_id,
+            idp_sso_url: value.idp_sso_url,
+            idp_sso_binding: value.idp_sso_binding.into(),
+            idp_certificates: value.idp_certificates.map(|certificates| {
+                certificates
+                    .split(',')
+                    .map(|certificate| certificate.to_string())
+                    .collect()
+            }),
+            name_id_format: value.name_id_format,
+            name_attribute: value.name_attribute,
+            email_attribute: value.email_attribute,
+            trust_email: value.trust_email,
+            allow_idp_initiated: value.allow_idp_initiated,
+        }
+    }
+}
+
+impl From<saml_provider_connection::Model> for SamlConnection {
+    fn from(value: saml_provider_connection::Model) -> Self {
+        SamlConnection {
+            id: value.id.to_string(),
+            identifier: value.identifier,
+            provider_id: value.provider_id.to_string(),
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder,
+    TransactionError, TransactionTrait, prelude::Expr, sea_query::ExprTrait,
+};
+use shield::{CreatePhoneNumber, CreateUser, PhoneNumber, Storage, StorageError};
+use shield_sms::{CreateSmsAuthCode, SmsAuthCode, SmsStorage};
+
+#[cfg(feature = "entity")]
+use crate::entities::entity;
+use crate::{
+    entities::{phone_number, sms_auth_code, user},
+    storage::SeaOrmStorage,
+    user::User,
+};
+
+#[async_trait]
+impl SmsStorage<User> for SeaOrmStorage {
+    async fn user_by_phone_number(&self, phone_number: &str) -> Result<Option<User>, StorageError> {
+        let phone_number = phone_number::Entity::find()
+            .filter(phone_number::Column::PhoneNumber.eq(phone_number))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        match phone_number {
+            Some(phone_number) => self.user_by_id(&phone_number.user_id.to_string()).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn create_user_with_phone_number(
+        &self,
+        user: CreateUser,
+        phone_number: CreatePhoneNumber,
+    ) -> Result<User, StorageError> {
+        #[cfg(feature = "entity")]
+        type UserAndEntity = (user::Model, entity::Model);
+
+        #[cfg(not(feature = "entity"))]
+        type UserAndEntity = user::Model;
+
+        let user_and_entity = self
+            .database
+            .transaction::<_, UserAndEntity, StorageError>(|database_transaction| {
+                Box::pin(async move {
+                    #[cfg(feature = "entity")]
+                    let (user, entity) = {
+                        let active_model = entity::ActiveModel {
+                            name: ActiveValue::Set(user.name.unwrap_or_default()),
+                            ..Default::default()
+                        };
+
+                        let entity = active_model
+                            .insert(database_transaction)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                        let active_model = user::ActiveModel {
+                            entity_id: ActiveValue::Set(entity.id),
+                            ..Default::default()
+                        };
+
+                        let user = active_model
+                            .insert(database_transaction)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                        (user, entity)
+                    };
+
+                    #[cfg(not(feature = "entity"))]
+                    let user = {
+                        let active_model = user::ActiveModel {
+                            name: ActiveValue::Set(user.name.unwrap_or_default()),
+                            ..Default::default()
+                        };
+
+                        active_model
+                            .insert(database_transaction)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))?
+                    };
+
+                    let active_model = phone_number::ActiveModel {
+                        phone_number: ActiveValue::Set(phone_number.phone_number),
+                        is_primary: ActiveValue::Set(phone_number.is_primary),
+                        is_verified: ActiveValue::Set(phone_number.is_verified),
+                        verified_at: ActiveValue::Set(phone_number.verified_at),
+                        user_id: ActiveValue::Set(user.id),
+                        ..Default::default()
+                    };
+
+                    active_model
+                        .insert(database_transaction)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    #[cfg(feature = "entity")]
+                    {
+                        Ok((user, entity))
+                    }
+
+                    #[cfg(not(feature = "entity"))]
+                    {
+                        Ok(user)
+                    }
+                })
+            })
+            .await
+            .map_err(|err| match err {
+                TransactionError::Connection(err) => StorageError::Engine(err.to_string()),
+                TransactionError::Transaction(err) => err,
+            })?;
+
+        #[cfg(feature = "entity")]
+        {
+            let (user, entity) = user_and_entity;
+            Ok(User::new(self.database.clone(), user, entity))
+        }
+
+        #[cfg(not(feature = "entity"))]
+        {
+            let user = user_and_entity;
+            Ok(User::new(self.database.clone(), user))
+        }
+    }
+
+    async fn sms_auth_code(&self, phone_number: &str) -> Result<Option<SmsAuthCode>, StorageError> {
+        sms_auth_code::Entity::find()
+            .filter(sms_auth_code::Column::PhoneNumber.eq(phone_number))
+            .order_by_desc(sms_auth_code::Column::CreatedAt)
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|sms_auth_code| sms_auth_code.map(SmsAuthCode::from))
+    }
+
+    async fn create_sms_auth_code(
+        &self,
+        sms_auth_code: CreateSmsAuthCode,
+    ) -> Result<SmsAuthCode, StorageError> {
+        self.database
+            .transaction::<_, sms_auth_code::Model, StorageError>(|database_transaction| {
+                Box::pin(async move {
+                    sms_auth_code::Entity::delete_many()
+                        .filter(sms_auth_code::Column::PhoneNumber.eq(&sms_auth_code.phone_number))
+                        .exec(database_transaction)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    let active_model = sms_auth_code::ActiveModel {
+                        phone_number: ActiveValue::Set(sms_auth_code.phone_number),
+                        code: ActiveValue::Set(sms_auth_code.code),
+                        attempts: ActiveValue::Set(0),
+                        expired_at: ActiveValue::Set(sms_auth_code.expired_at),
+                        ..Default::default()
+                    };
+
+                    active_model
+                        .insert(database_transaction)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                })
+            })
+            .await
+            .map_err(|err| match err {
+                TransactionError::Connection(err) => StorageError::Engine(err.to_string()),
+                TransactionError::Transaction(err) => err,
+            })
+            .map(SmsAuthCode::from)
+    }
+
+    async fn increment_sms_auth_code_attempts(
+        &self,
+        sms_auth_code_id: &str,
+    ) -> Result<SmsAuthCode, StorageError> {
+        let id = Self::parse_uuid(sms_auth_code_id)?;
+
+        // Increment in the database, so concurrent attempts are all counted.
+        sms_auth_code::Entity::update_many()
+            .col_expr(
+                sms_auth_code::Column::Attempts,
+                Expr::col(sms_auth_code::Column::Attempts).add(1),
+            )
+            .filter(sms_auth_code::Column::Id.eq(id))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        sms_auth_code::Entity::find_by_id(id)
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .map(SmsAuthCode::from)
+            .ok_or_else(|| {
+                StorageError::NotFound("SmsAuthCode".to_owned(), sms_auth_code_id.to_owned())
+            })
+    }
+
+    async fn delete_sms_auth_code(&self, sms_auth_code_id: &str) -> Result<(), StorageError> {
+        sms_auth_code::Entity::delete_by_id(Self::parse_uuid(sms_auth_code_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn delete_expired_sms_auth_codes(&self) -> Result<(), StorageError> {
+        sms_auth_code::Entity::delete_many()
+            .filter(sms_auth_code::Column::ExpiredAt.lte(Utc::now()))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+}
+
+impl From<phone_number::Model> for PhoneNumber {
+    fn from(value: phone_number::Model) -> Self {
+        PhoneNumber {
+            id: value.id.to_string(),
+            phone_number: value.phone_number,
+            is_primary: value.is_primary,
+            is_verified: value.is_verified,
+            verified_at: value.verified_at,
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
+
+impl From<sms_auth_code::Model> for SmsAuthCode {
+    fn from(value: sms_auth_code::Model) -> Self {
+        SmsAuthCode {
+            id: value.id.to_string(),
+            phone_number: value.phone_number,
+            code: value.code,
+            attempts: value.attempts,
+            expired_at: value.expired_at,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, QueryFilter, prelude::Expr,
+};
+use secrecy::ExposeSecret;
+use shield::StorageError;
+use shield_totp::{
+    CreateTotpRecoveryCode, CreateUserTotp, TotpRecoveryCode, TotpStorage, UpdateUserTotp, UserTotp,
+};
+
+use crate::{
+    entities::{totp_recovery_code, user_totp},
+    storage::SeaOrmStorage,
+    user::User,
+};
+
+#[async_trait]
+impl TotpStorage<User> for SeaOrmStorage {
+    async fn user_totp(&self, user_id: &str) -> Result<Option<UserTotp>, StorageError> {
+        user_totp::Entity::find()
+            .filter(user_totp::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|user_totp| user_totp.map(UserTotp::from))
+    }
+
+    async fn create_user_totp(&self, user_totp: CreateUserTotp) -> Result<UserTotp, StorageError> {
+        let active_model = user_totp::ActiveModel {
+            secret: ActiveValue::Set(user_totp.secret.expose_secret().to_owned()),
+            last_used_step: ActiveValue::Set(user_totp.last_used_step),
+            user_id: ActiveValue::Set(Self::parse_uuid(&user_totp.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(UserTotp::from)
+    }
+
+    async fn update_user_totp(&self, user_totp: UpdateUserTotp) -> Result<UserTotp, StorageError> {
+        let mut active_model: user_totp::ActiveModel =
+            user_totp::Entity::find_by_id(Self::parse_uuid(&user_totp.id)?)
+                .one(&self.database)
+                .await
+                .map_err(|err| StorageError::Engine(err.to_string()))?
+                .ok_or_else(|| StorageError::NotFound("UserTotp".to_owned(), user_totp.id))?
+                .into();
+
+        if let Some(last_used_step) = user_totp.last_used_step {
+            active_model.last_used_step = ActiveValue::Set(last_used_step);
+        }
+
+        active_model
+            .update(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(UserTotp::from)
+    }
+
+    async fn use_user_totp_step(
+        &self,
+        user_totp_id: &str,
+        step: i64,
+    ) -> Result<bool, StorageError> {
+        let result = user_totp::Entity::update_many()
+            .col_expr(user_totp::Column::LastUsedStep, Expr::value(step))
+            .filter(user_totp::Column::Id.eq(Self::parse_uuid(user_totp_id)?))
+            .filter(
+                Condition::any()
+                    .add(user_totp::Column::LastUsedStep.is_null())
+                    .add(user_totp::Column::LastUsedStep.lt(step)),
+            )
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete_user_totp(&self, user_totp_id: &str) -> Result<(), StorageError> {
+        user_totp::Entity::delete_by_id(Self::parse_uuid(user_totp_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn totp_recovery_codes(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<TotpRecoveryCode>, StorageError> {
+        totp_recovery_code::Entity::find()
+            .filter(totp_recovery_code::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|recovery_codes| {
+                recovery_codes
+                    .into_iter()
+                    .map(TotpRecoveryCode::from)
+                    .collect()
+            })
+    }
+
+    async fn create_totp_recovery_code(
+        &self,
+        recovery_code: CreateTotpRecoveryCode,
+    ) -> Result<TotpRecoveryCode, StorageError> {
+        let active_model = totp_recovery_code::ActiveModel {
+            code_hash: ActiveValue::Set(recovery_code.code_hash),
+            user_id: ActiveValue::Set(Self::parse_uuid(&recovery_code.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(TotpRecoveryCode::from)
+    }
+
+    async fn delete_totp_recovery_code(
+        &self,
+        recovery_code_id: &str,
+    ) -> Result<bool, StorageError> {
+        totp_recovery_code::Entity::delete_by_id(Self::parse_uuid(recovery_code_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|result| result.rows_affected > 0)
+    }
+
+    async fn delete_totp_recovery_codes(&self, user_id: &str) -> Result<(), StorageError> {
+        totp_recovery_code::Entity::delete_many()
+            .filter(totp_recovery_code::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+}
+
+impl From<user_totp::Model> for UserTotp {
+    fn from(value: user_totp::Model) -> Self {
+        UserTotp {
+            id: value.id.to_string(),
+            secret: value.secret.into(),
+            last_used_step: value.last_used_step,
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
+
+impl From<totp_recovery_code::Model> for TotpRecoveryCode {
+    fn from(value: totp_recovery_code::Model) -> Self {
+        TotpRecoveryCode {
+            id: value.id.to_string(),
+            code_hash: value.code_hash,
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
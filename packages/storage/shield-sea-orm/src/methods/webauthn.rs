@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter};
+use shield::StorageError;
+use shield_webauthn::{
+    CreateWebauthnCredential, UpdateWebauthnCredential, WebauthnCredential, WebauthnStorage,
+};
+
+use crate::{entities::webauthn_credential, storage::SeaOrmStorage, user::User};
+
+#[async_trait]
+impl WebauthnStorage<User> for SeaOrmStorage {
+    async fn webauthn_credential_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<WebauthnCredential>, StorageError> {
+        webauthn_credential::Entity::find_by_id(Self::parse_uuid(id)?)
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .map(WebauthnCredential::try_from)
+            .transpose()
+    }
+
+    async fn webauthn_credential_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> Result<Option<WebauthnCredential>, StorageError> {
+        webauthn_credential::Entity::find()
+            .filter(webauthn_credential::Column::CredentialId.eq(credential_id))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .map(WebauthnCredential::try_from)
+            .transpose()
+    }
+
+    async fn create_webauthn_credential(
+        &self,
+        credential: CreateWebauthnCredential,
+    ) -> Result<WebauthnCredential, StorageError> {
+        let active_model = webauthn_credential::ActiveModel {
+            credential_id: ActiveValue::Set(credential.credential_id),
+            passkey: ActiveValue::Set(
+                serde_json::to_string(&credential.passkey)
+                    .map_err(|err| StorageError::Engine(err.to_string()))?,
+            ),
+            user_id: ActiveValue::Set(Self::parse_uuid(&credential.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .and_then(WebauthnCredential::try_from)
+    }
+
+    async fn update_webauthn_credential(
+        &self,
+        credential: UpdateWebauthnCredential,
+    ) -> Result<WebauthnCredential, StorageError> {
+        let mut active_model: webauthn_credential::ActiveModel =
+            webauthn_credential::Entity::find_by_id(Self::parse_uuid(&credential.id)?)
+                .one(&self.database)
+                .await
+                .map_err(|err| StorageError::Engine(err.to_string()))?
+                .ok_or_else(|| {
+                    StorageError::NotFound("WebauthnCredential".to_owned(), credential.id)
+                })?
+                .into();
+
+        if let Some(passkey) = credential.passkey {
+            active_model.passkey = ActiveValue::Set(
+                serde_json::to_string(&passkey)
+                    .map_err(|err| StorageError::Engine(err.to_string()))?,
+            );
+        }
+        if let Some(last_used_at) = credential.last_used_at {
+            active_model.last_used_at = ActiveValue::Set(last_used_at);
+        }
+
+        active_model
+            .update(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .and_then(WebauthnCredential::try_from)
+    }
+
+    async fn delete_webauthn_credential(&self, id: &str) -> Result<(), StorageError> {
+        webauthn_credential::Entity::delete_by_id(Self::parse_uuid(id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+
+    async fn user_webauthn_credentials(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<WebauthnCredential>, StorageError> {
+        webauthn_credential::Entity::find()
+            .filter(webauthn_credential::Column::UserId.eq(Self::parse_uuid(user_id)?))
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?
+            .into_iter()
+            .map(WebauthnCredential::try_from)
+            .collect()
+    }
+}
+
+impl TryFrom<webauthn_credential::Model> for WebauthnCredential {
+    type Error = StorageError;
+
+    fn try_from(value: webauthn_credential::Model) -> Result<Self, Self::Error> {
+        Ok(WebauthnCredential {
+            id: value.id.to_string(),
+            credential_id: value.credential_id,
+            passkey: serde_json::from_str(&value.passkey)
+                .map_err(|err| StorageError::Engine(err.to_string()))?,
+            last_used_at: value.last_used_at,
+            user_id: value.user_id.to_string(),
+        })
+    }
+}
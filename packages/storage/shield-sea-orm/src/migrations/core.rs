@@ -1,4 +1,8 @@
 mod m20241210_203135_create_user;
+mod m20261018_142417_create_registered_session;
+mod m20261018_143622_create_refresh_token;
+mod m20261018_172806_create_audit_entry;
+mod m20261018_174315_create_rate_limit_entry;
 
 use async_trait::async_trait;
 use sea_orm_migration::{MigrationTrait, MigratorTrait};
@@ -8,6 +12,12 @@ pub struct CoreMigrator;
 #[async_trait]
 impl MigratorTrait for CoreMigrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(self::m20241210_203135_create_user::Migration)]
+        vec![
+            Box::new(self::m20241210_203135_create_user::Migration),
+            Box::new(self::m20261018_142417_create_registered_session::Migration),
+            Box::new(self::m20261018_143622_create_refresh_token::Migration),
+            Box::new(self::m20261018_172806_create_audit_entry::Migration),
+            Box::new(self::m20261018_174315_create_rate_limit_entry::Migration),
+        ]
     }
 }
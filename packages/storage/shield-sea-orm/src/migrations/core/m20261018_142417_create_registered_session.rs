@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(RegisteredSession::Table, manager)
+                    .col(
+                        ColumnDef::new(RegisteredSession::MethodId)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RegisteredSession::ProviderId).string_len(256))
+                    .col(ColumnDef::new(RegisteredSession::ProviderSessionId).string_len(256))
+                    .col(ColumnDef::new(RegisteredSession::IpAddress).string_len(45))
+                    .col(ColumnDef::new(RegisteredSession::UserAgent).text())
+                    .col(
+                        ColumnDef::new(RegisteredSession::LastSeenAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RegisteredSession::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(RegisteredSession::FkRegisteredSessionUser.to_string())
+                            .from(RegisteredSession::Table, RegisteredSession::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(RegisteredSession::IndexRegisteredSessionProviderSessionId.to_string())
+                    .table(RegisteredSession::Table)
+                    .col(RegisteredSession::ProviderSessionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(RegisteredSession::IndexRegisteredSessionProviderSessionId.to_string())
+                    .table(RegisteredSession::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(RegisteredSession::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum RegisteredSession {
+    Table,
+
+    MethodId,
+    ProviderId,
+    ProviderSessionId,
+    IpAddress,
+    UserAgent,
+    LastSeenAt,
+
+    UserId,
+
+    FkRegisteredSessionUser,
+
+    IndexRegisteredSessionProviderSessionId,
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(RefreshToken::Table, manager)
+                    .col(
+                        ColumnDef::new(RefreshToken::TokenHash)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshToken::FamilyId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshToken::MethodId)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::ProviderId).string_len(256))
+                    .col(ColumnDef::new(RefreshToken::Factors).text().not_null())
+                    .col(
+                        ColumnDef::new(RefreshToken::ExpiredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::RotatedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(RefreshToken::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(RefreshToken::FkRefreshTokenUser.to_string())
+                            .from(RefreshToken::Table, RefreshToken::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(RefreshToken::UniqueRefreshTokenTokenHash.to_string())
+                            .col(RefreshToken::TokenHash)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(RefreshToken::IndexRefreshTokenFamilyId.to_string())
+                    .table(RefreshToken::Table)
+                    .col(RefreshToken::FamilyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(RefreshToken::IndexRefreshTokenFamilyId.to_string())
+                    .table(RefreshToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(RefreshToken::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum RefreshToken {
+    Table,
+
+    TokenHash,
+    FamilyId,
+    MethodId,
+    ProviderId,
+    Factors,
+    ExpiredAt,
+    RotatedAt,
+
+    UserId,
+
+    FkRefreshTokenUser,
+
+    UniqueRefreshTokenTokenHash,
+    IndexRefreshTokenFamilyId,
+}
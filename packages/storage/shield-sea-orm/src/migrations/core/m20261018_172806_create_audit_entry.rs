@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseBackend;
+use sea_orm_migration::prelude::{extension::postgres::Type, *};
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        match manager.get_database_backend() {
+            DatabaseBackend::MySql | DatabaseBackend::Sqlite => {}
+            DatabaseBackend::Postgres => {
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(AuditEntryEventType::Table)
+                            .values(AuditEntryEventType::variants())
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+            backend => unimplemented!("unsupported database backend `{backend:?}`"),
+        }
+
+        // The user ID is not a foreign key, so entries are kept when the user is deleted.
+        manager
+            .create_table(
+                BaseTable::create(AuditEntry::Table, manager)
+                    .col({
+                        let mut column = ColumnDef::new(AuditEntry::EventType)
+                            .not_null()
+                            .into_column_def();
+
+                        match manager.get_database_backend() {
+                            DatabaseBackend::MySql | DatabaseBackend::Sqlite => column
+                                .enumeration(
+                                    AuditEntryEventType::Table,
+                                    AuditEntryEventType::variants(),
+                                )
+                                .into_column_def(),
+                            DatabaseBackend::Postgres => {
+                                column.custom(AuditEntryEventType::Table).into_column_def()
+                            }
+                            backend => unimplemented!("unsupported database backend `{backend:?}`"),
+                        }
+                    })
+                    .col(ColumnDef::new(AuditEntry::UserId).uuid())
+                    .col(ColumnDef::new(AuditEntry::MethodId).string_len(256))
+                    .col(ColumnDef::new(AuditEntry::ProviderId).string_len(256))
+                    .col(ColumnDef::new(AuditEntry::Reason).text())
+                    .col(ColumnDef::new(AuditEntry::IpAddress).string_len(45))
+                    .col(ColumnDef::new(AuditEntry::UserAgent).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(AuditEntry::IndexAuditEntryUserIdCreatedAt.to_string())
+                    .table(AuditEntry::Table)
+                    .col(AuditEntry::UserId)
+                    .col(Base::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(AuditEntry::IndexAuditEntryUserIdCreatedAt.to_string())
+                    .table(AuditEntry::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AuditEntry::Table).to_owned())
+            .await?;
+
+        match manager.get_database_backend() {
+            DatabaseBackend::MySql | DatabaseBackend::Sqlite => {}
+            DatabaseBackend::Postgres => {
+                manager
+                    .drop_type(Type::drop().name(AuditEntryEventType::Table).to_owned())
+                    .await?;
+            }
+            backend => unimplemented!("unsupported database backend `{backend:?}`"),
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditEntryEventType {
+    Table,
+
+    SignIn,
+    SignInFailed,
+    SignOut,
+    ConnectionLinked,
+    ConnectionUnlinked,
+    SessionRevoked,
+}
+
+impl AuditEntryEventType {
+    fn variants() -> Vec<Self> {
+        vec![
+            Self::SignIn,
+            Self::SignInFailed,
+            Self::SignOut,
+            Self::ConnectionLinked,
+            Self::ConnectionUnlinked,
+            Self::SessionRevoked,
+        ]
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditEntry {
+    Table,
+
+    EventType,
+    UserId,
+    MethodId,
+    ProviderId,
+    Reason,
+    IpAddress,
+    UserAgent,
+
+    IndexAuditEntryUserIdCreatedAt,
+}
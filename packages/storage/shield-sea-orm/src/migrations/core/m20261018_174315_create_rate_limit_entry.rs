@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::BaseTable;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(RateLimitEntry::Table, manager)
+                    .col(
+                        ColumnDef::new(RateLimitEntry::Key)
+                            .string_len(512)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RateLimitEntry::Attempts)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RateLimitEntry::WindowStartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RateLimitEntry::LastAttemptedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RateLimitEntry::LockedUntil).timestamp_with_time_zone())
+                    .index(
+                        Index::create()
+                            .name(RateLimitEntry::UniqueRateLimitEntryKey.to_string())
+                            .col(RateLimitEntry::Key)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RateLimitEntry::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RateLimitEntry {
+    Table,
+
+    Key,
+    Attempts,
+    WindowStartedAt,
+    LastAttemptedAt,
+    LockedUntil,
+
+    UniqueRateLimitEntryKey,
+}
@@ -1,9 +1,25 @@
+#[cfg(feature = "method-api-key")]
+pub mod api_key;
+#[cfg(feature = "method-credentials")]
+pub mod credentials;
 #[cfg(feature = "method-email")]
 pub mod email;
+#[cfg(feature = "method-ldap")]
+pub mod ldap;
 #[cfg(feature = "method-oauth")]
 pub mod oauth;
+#[cfg(feature = "method-oauth-server")]
+pub mod oauth_server;
 #[cfg(feature = "method-oidc")]
 pub mod oidc;
+#[cfg(feature = "method-saml")]
+pub mod saml;
+#[cfg(feature = "method-sms")]
+pub mod sms;
+#[cfg(feature = "method-totp")]
+pub mod totp;
+#[cfg(feature = "method-webauthn")]
+pub mod webauthn;
 
 use async_trait::async_trait;
 use sea_orm_migration::{MigrationTrait, MigratorTrait};
@@ -16,21 +32,61 @@ impl MigratorTrait for ProvidersMigrator {
         #[allow(unused_mut)]
         let mut migrations = vec![];
 
+        #[cfg(feature = "method-api-key")]
+        {
+            use self::api_key::ProviderApiKeyMigrator;
+            migrations.extend(ProviderApiKeyMigrator::migrations());
+        }
+        #[cfg(feature = "method-credentials")]
+        {
+            use self::credentials::ProviderCredentialsMigrator;
+            migrations.extend(ProviderCredentialsMigrator::migrations());
+        }
         #[cfg(feature = "method-email")]
         {
             use self::email::ProviderEmailMigrator;
             migrations.extend(ProviderEmailMigrator::migrations());
         }
+        #[cfg(feature = "method-ldap")]
+        {
+            use self::ldap::ProviderLdapMigrator;
+            migrations.extend(ProviderLdapMigrator::migrations());
+        }
         #[cfg(feature = "method-oauth")]
         {
             use self::oauth::ProviderOauthMigrator;
             migrations.extend(ProviderOauthMigrator::migrations());
         }
+        #[cfg(feature = "method-oauth-server")]
+        {
+            use self::oauth_server::ProviderOauthServerMigrator;
+            migrations.extend(ProviderOauthServerMigrator::migrations());
+        }
         #[cfg(feature = "method-oidc")]
         {
             use self::oidc::ProviderOidcMigrator;
             migrations.extend(ProviderOidcMigrator::migrations());
         }
+        #[cfg(feature = "method-saml")]
+        {
+            use self::saml::ProviderSamlMigrator;
+            migrations.extend(ProviderSamlMigrator::migrations());
+        }
+        #[cfg(feature = "method-sms")]
+        {
+            use self::sms::ProviderSmsMigrator;
+            migrations.extend(ProviderSmsMigrator::migrations());
+        }
+        #[cfg(feature = "method-totp")]
+        {
+            use self::totp::ProviderTotpMigrator;
+            migrations.extend(ProviderTotpMigrator::migrations());
+        }
+        #[cfg(feature = "method-webauthn")]
+        {
+            use self::webauthn::ProviderWebauthnMigrator;
+            migrations.extend(ProviderWebauthnMigrator::migrations());
+        }
 
         migrations
     }
@@ -0,0 +1,15 @@
+mod m20261018_193412_create_provider_api_key;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderApiKeyMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderApiKeyMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(
+            self::m20261018_193412_create_provider_api_key::Migration,
+        )]
+    }
+}
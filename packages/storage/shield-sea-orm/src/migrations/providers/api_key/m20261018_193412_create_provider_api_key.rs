@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(ApiKey::Table, manager)
+                    .col(ColumnDef::new(ApiKey::Name).text().not_null())
+                    .col(ColumnDef::new(ApiKey::KeyPrefix).string_len(255).not_null())
+                    .col(ColumnDef::new(ApiKey::KeyHash).string_len(255).not_null())
+                    .col(ColumnDef::new(ApiKey::Scopes).text().not_null())
+                    .col(ColumnDef::new(ApiKey::ExpiredAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(ApiKey::LastUsedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(ApiKey::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ApiKey::FkApiKeyUser.to_string())
+                            .from(ApiKey::Table, ApiKey::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(ApiKey::UniqueApiKeyKeyHash.to_string())
+                            .col(ApiKey::KeyHash)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApiKey::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum ApiKey {
+    Table,
+
+    Name,
+    KeyPrefix,
+    KeyHash,
+    Scopes,
+    ExpiredAt,
+    LastUsedAt,
+
+    UserId,
+
+    FkApiKeyUser,
+
+    UniqueApiKeyKeyHash,
+}
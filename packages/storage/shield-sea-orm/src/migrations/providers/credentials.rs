@@ -0,0 +1,17 @@
+mod m20261018_142907_create_provider_credentials;
+mod m20261018_160412_create_password_reset_token;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderCredentialsMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderCredentialsMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(self::m20261018_142907_create_provider_credentials::Migration),
+            Box::new(self::m20261018_160412_create_password_reset_token::Migration),
+        ]
+    }
+}
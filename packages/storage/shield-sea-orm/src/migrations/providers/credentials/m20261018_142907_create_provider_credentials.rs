@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(UserPassword::Table, manager)
+                    .col(ColumnDef::new(UserPassword::Username).string_len(255))
+                    .col(ColumnDef::new(UserPassword::PasswordHash).text().not_null())
+                    .col(ColumnDef::new(UserPassword::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(UserPassword::FkUserPasswordUser.to_string())
+                            .from(UserPassword::Table, UserPassword::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(UserPassword::UniqueUserPasswordUsername.to_string())
+                            .col(UserPassword::Username)
+                            .unique(),
+                    )
+                    .index(
+                        Index::create()
+                            .name(UserPassword::UniqueUserPasswordUser.to_string())
+                            .col(UserPassword::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserPassword::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum UserPassword {
+    Table,
+
+    Username,
+    PasswordHash,
+
+    UserId,
+
+    FkUserPasswordUser,
+
+    UniqueUserPasswordUsername,
+    UniqueUserPasswordUser,
+}
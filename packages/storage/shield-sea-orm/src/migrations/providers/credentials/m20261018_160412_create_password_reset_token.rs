@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(PasswordResetToken::Table, manager)
+                    .col(
+                        ColumnDef::new(PasswordResetToken::Token)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PasswordResetToken::ExpiredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PasswordResetToken::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(PasswordResetToken::FkPasswordResetTokenUser.to_string())
+                            .from(PasswordResetToken::Table, PasswordResetToken::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(PasswordResetToken::UniquePasswordResetTokenToken.to_string())
+                            .col(PasswordResetToken::Token)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PasswordResetToken::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum PasswordResetToken {
+    Table,
+
+    Token,
+    ExpiredAt,
+
+    UserId,
+
+    FkPasswordResetTokenUser,
+
+    UniquePasswordResetTokenToken,
+}
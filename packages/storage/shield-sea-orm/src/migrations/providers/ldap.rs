@@ -0,0 +1,15 @@
+mod m20261018_223105_create_provider_ldap;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderLdapMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderLdapMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(
+            self::m20261018_223105_create_provider_ldap::Migration,
+        )]
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(LdapConnection::Table, manager)
+                    .col(
+                        ColumnDef::new(LdapConnection::Identifier)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(LdapConnection::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(LdapConnection::FkLdapConnectionUser.to_string())
+                            .from(LdapConnection::Table, LdapConnection::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(LdapConnection::UniqueLdapConnectionIdentifier.to_string())
+                            .col(LdapConnection::Identifier)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LdapConnection::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum LdapConnection {
+    Table,
+
+    Identifier,
+
+    UserId,
+
+    FkLdapConnectionUser,
+
+    UniqueLdapConnectionIdentifier,
+}
@@ -0,0 +1,15 @@
+mod m20261018_235140_create_provider_oauth_server;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderOauthServerMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderOauthServerMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(
+            self::m20261018_235140_create_provider_oauth_server::Migration,
+        )]
+    }
+}
@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(OauthServerClient::Table, manager)
+                    .col(
+                        ColumnDef::new(OauthServerClient::ClientId)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OauthServerClient::ClientSecretHash).string_len(64))
+                    .col(
+                        ColumnDef::new(OauthServerClient::Name)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerClient::RedirectUris)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OauthServerClient::Scopes).text().not_null())
+                    .index(
+                        Index::create()
+                            .name(OauthServerClient::UniqueOauthServerClientClientId.to_string())
+                            .col(OauthServerClient::ClientId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                BaseTable::create(OauthServerAuthorizationCode::Table, manager)
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::CodeHash)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::RedirectUri)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::Scopes)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::CodeChallenge)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OauthServerAuthorizationCode::Nonce).text())
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::AuthenticatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::ExpiredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::UsedAt)
+                            .timestamp_with_time_zone(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::ClientId)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerAuthorizationCode::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(
+                                OauthServerAuthorizationCode::FkOauthServerAuthorizationCodeClient
+                                    .to_string(),
+                            )
+                            .from(
+                                OauthServerAuthorizationCode::Table,
+                                OauthServerAuthorizationCode::ClientId,
+                            )
+                            .to(OauthServerClient::Table, OauthServerClient::ClientId)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(
+                                OauthServerAuthorizationCode::FkOauthServerAuthorizationCodeUser
+                                    .to_string(),
+                            )
+                            .from(
+                                OauthServerAuthorizationCode::Table,
+                                OauthServerAuthorizationCode::UserId,
+                            )
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(
+                                OauthServerAuthorizationCode::UniqueOauthServerAuthorizationCodeCodeHash
+                                    .to_string(),
+                            )
+                            .col(OauthServerAuthorizationCode::CodeHash)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                BaseTable::create(OauthServerRefreshToken::Table, manager)
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::TokenHash)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::FamilyId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::Scopes)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::AuthenticatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::ExpiredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::RotatedAt)
+                            .timestamp_with_time_zone(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::ClientId)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthServerRefreshToken::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(
+                                OauthServerRefreshToken::FkOauthServerRefreshTokenClient
+                                    .to_string(),
+                            )
+                            .from(
+                                OauthServerRefreshToken::Table,
+                                OauthServerRefreshToken::ClientId,
+                            )
+                            .to(OauthServerClient::Table, OauthServerClient::ClientId)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(
+                                OauthServerRefreshToken::FkOauthServerRefreshTokenUser.to_string(),
+                            )
+                            .from(
+                                OauthServerRefreshToken::Table,
+                                OauthServerRefreshToken::UserId,
+                            )
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(
+                                OauthServerRefreshToken::UniqueOauthServerRefreshTokenTokenHash
+                                    .to_string(),
+                            )
+                            .col(OauthServerRefreshToken::TokenHash)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(OauthServerRefreshToken::IndexOauthServerRefreshTokenFamilyId.to_string())
+                    .table(OauthServerRefreshToken::Table)
+                    .col(OauthServerRefreshToken::FamilyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(OauthServerRefreshToken::IndexOauthServerRefreshTokenFamilyId.to_string())
+                    .table(OauthServerRefreshToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(OauthServerRefreshToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(OauthServerAuthorizationCode::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(OauthServerClient::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum OauthServerClient {
+    Table,
+
+    ClientId,
+    ClientSecretHash,
+    Name,
+    RedirectUris,
+    Scopes,
+
+    UniqueOauthServerClientClientId,
+}
+
+#[derive(DeriveIden)]
+enum OauthServerAuthorizationCode {
+    Table,
+
+    CodeHash,
+    RedirectUri,
+    Scopes,
+    CodeChallenge,
+    Nonce,
+    AuthenticatedAt,
+    ExpiredAt,
+    UsedAt,
+
+    ClientId,
+    UserId,
+
+    FkOauthServerAuthorizationCodeClient,
+    FkOauthServerAuthorizationCodeUser,
+
+    UniqueOauthServerAuthorizationCodeCodeHash,
+}
+
+#[derive(DeriveIden)]
+enum OauthServerRefreshToken {
+    Table,
+
+    TokenHash,
+    FamilyId,
+    Scopes,
+    AuthenticatedAt,
+    ExpiredAt,
+    RotatedAt,
+
+    ClientId,
+    UserId,
+
+    FkOauthServerRefreshTokenClient,
+    FkOauthServerRefreshTokenUser,
+
+    UniqueOauthServerRefreshTokenTokenHash,
+    IndexOauthServerRefreshTokenFamilyId,
+}
@@ -0,0 +1,15 @@
+mod m20261018_211540_create_provider_saml;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderSamlMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderSamlMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(
+            self::m20261018_211540_create_provider_saml::Migration,
+        )]
+    }
+}
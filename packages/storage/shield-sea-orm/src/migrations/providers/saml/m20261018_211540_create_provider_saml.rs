@@ -0,0 +1,264 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseBackend;
+use sea_orm_migration::prelude::{extension::postgres::Type, *};
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        match manager.get_database_backend() {
+            DatabaseBackend::MySql | DatabaseBackend::Sqlite => {}
+            DatabaseBackend::Postgres => {
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(SamlProviderVisibility::Table)
+                            .values(SamlProviderVisibility::variants())
+                            .to_owned(),
+                    )
+                    .await?;
+
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(SamlProviderBinding::Table)
+                            .values(SamlProviderBinding::variants())
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+            backend => unimplemented!("unsupported database backend `{backend:?}`"),
+        }
+
+        manager
+            .create_table(
+                BaseTable::create(SamlProvider::Table, manager)
+                    .col(
+                        ColumnDef::new(SamlProvider::Name)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SamlProvider::Slug).string_len(256))
+                    .col(ColumnDef::new(SamlProvider::IconUrl).text())
+                    .col({
+                        let mut column = ColumnDef::new(SamlProvider::Visibility)
+                            .not_null()
+                            .into_column_def();
+
+                        match manager.get_database_backend() {
+                            DatabaseBackend::MySql | DatabaseBackend::Sqlite => column
+                                .enumeration(
+                                    SamlProviderVisibility::Table,
+                                    SamlProviderVisibility::variants(),
+                                )
+                                .into_column_def(),
+                            DatabaseBackend::Postgres => column
+                                .custom(SamlProviderVisibility::Table)
+                                .into_column_def(),
+                            backend => unimplemented!("unsupported database backend `{backend:?}`"),
+                        }
+                    })
+                    .col(ColumnDef::new(SamlProvider::EntityId).text().not_null())
+                    .col(ColumnDef::new(SamlProvider::AcsUrl).text().not_null())
+                    .col(ColumnDef::new(SamlProvider::IdpMetadata).text())
+                    .col(ColumnDef::new(SamlProvider::IdpEntityId).text())
+                    .col(ColumnDef::new(SamlProvider::IdpSsoUrl).text())
+                    .col({
+                        let mut column = ColumnDef::new(SamlProvider::IdpSsoBinding)
+                            .not_null()
+                            .into_column_def();
+
+                        match manager.get_database_backend() {
+                            DatabaseBackend::MySql | DatabaseBackend::Sqlite => column
+                                .enumeration(
+                                    SamlProviderBinding::Table,
+                                    SamlProviderBinding::variants(),
+                                )
+                                .into_column_def(),
+                            DatabaseBackend::Postgres => {
+                                column.custom(SamlProviderBinding::Table).into_column_def()
+                            }
+                            backend => unimplemented!("unsupported database backend `{backend:?}`"),
+                        }
+                    })
+                    .col(ColumnDef::new(SamlProvider::IdpCertificates).text())
+                    .col(ColumnDef::new(SamlProvider::NameIdFormat).text())
+                    .col(ColumnDef::new(SamlProvider::NameAttribute).text())
+                    .col(ColumnDef::new(SamlProvider::EmailAttribute).text())
+                    .col(
+                        ColumnDef::new(SamlProvider::TrustEmail)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(SamlProvider::AllowIdpInitiated)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                BaseTable::create(SamlProviderConnection::Table, manager)
+                    .col(
+                        ColumnDef::new(SamlProviderConnection::Identifier)
+                            .string_len(256)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SamlProviderConnection::ProviderId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SamlProviderConnection::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(
+                                SamlProviderConnection::FkSamlProviderConnectionProvider
+                                    .to_string(),
+                            )
+                            .from(
+                                SamlProviderConnection::Table,
+                                SamlProviderConnection::ProviderId,
+                            )
+                            .to(SamlProvider::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(SamlProviderConnection::FkSamlProviderConnectionUser.to_string())
+                            .from(
+                                SamlProviderConnection::Table,
+                                SamlProviderConnection::UserId,
+                            )
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(SamlProviderConnection::UniqueSamlProviderIdentifier.to_string())
+                            .col(SamlProviderConnection::ProviderId)
+                            .col(SamlProviderConnection::Identifier)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(SamlProviderConnection::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SamlProvider::Table).to_owned())
+            .await?;
+
+        match manager.get_database_backend() {
+            DatabaseBackend::MySql | DatabaseBackend::Sqlite => {}
+            DatabaseBackend::Postgres => {
+                manager
+                    .drop_type(Type::drop().name(SamlProviderBinding::Table).to_owned())
+                    .await?;
+
+                manager
+                    .drop_type(Type::drop().name(SamlProviderVisibility::Table).to_owned())
+                    .await?;
+            }
+            backend => unimplemented!("unsupported database backend `{backend:?}`"),
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum SamlProviderVisibility {
+    Table,
+
+    Public,
+    Unlisted,
+}
+
+impl SamlProviderVisibility {
+    fn variants() -> Vec<Self> {
+        vec![Self::Public, Self::Unlisted]
+    }
+}
+
+#[derive(DeriveIden)]
+enum SamlProviderBinding {
+    Table,
+
+    Redirect,
+    Post,
+}
+
+impl SamlProviderBinding {
+    fn variants() -> Vec<Self> {
+        vec![Self::Redirect, Self::Post]
+    }
+}
+
+#[derive(DeriveIden)]
+enum SamlProvider {
+    Table,
+
+    Name,
+    Slug,
+    IconUrl,
+    Visibility,
+    EntityId,
+    AcsUrl,
+    IdpMetadata,
+    IdpEntityId,
+    IdpSsoUrl,
+    IdpSsoBinding,
+    IdpCertificates,
+    NameIdFormat,
+    NameAttribute,
+    EmailAttribute,
+    TrustEmail,
+    AllowIdpInitiated,
+}
+
+#[derive(DeriveIden)]
+enum SamlProviderConnection {
+    Table,
+
+    Identifier,
+
+    ProviderId,
+    UserId,
+
+    FkSamlProviderConnectionProvider,
+    FkSamlProviderConnectionUser,
+
+    UniqueSamlProviderIdentifier,
+}
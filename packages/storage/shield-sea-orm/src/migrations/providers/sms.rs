@@ -0,0 +1,15 @@
+mod m20261018_231204_create_provider_sms;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderSmsMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderSmsMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(
+            self::m20261018_231204_create_provider_sms::Migration,
+        )]
+    }
+}
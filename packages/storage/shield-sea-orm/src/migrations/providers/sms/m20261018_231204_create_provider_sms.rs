@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(PhoneNumber::Table, manager)
+                    .col(
+                        ColumnDef::new(PhoneNumber::Number)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PhoneNumber::IsPrimary).boolean().not_null())
+                    .col(ColumnDef::new(PhoneNumber::IsVerified).boolean().not_null())
+                    .col(ColumnDef::new(PhoneNumber::VerifiedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(PhoneNumber::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(PhoneNumber::FkPhoneNumberUser.to_string())
+                            .from(PhoneNumber::Table, PhoneNumber::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(PhoneNumber::UniqueNumber.to_string())
+                            .col(PhoneNumber::Number)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                BaseTable::create(SmsAuthCode::Table, manager)
+                    .col(
+                        ColumnDef::new(SmsAuthCode::PhoneNumber)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SmsAuthCode::Code).string_len(64).not_null())
+                    .col(
+                        ColumnDef::new(SmsAuthCode::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SmsAuthCode::ExpiredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(SmsAuthCode::IndexSmsAuthCodePhoneNumber.to_string())
+                    .table(SmsAuthCode::Table)
+                    .col(SmsAuthCode::PhoneNumber)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(SmsAuthCode::IndexSmsAuthCodePhoneNumber.to_string())
+                    .table(SmsAuthCode::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SmsAuthCode::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PhoneNumber::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum PhoneNumber {
+    Table,
+
+    #[sea_orm(iden = "phone_number")]
+    Number,
+    IsPrimary,
+    IsVerified,
+    VerifiedAt,
+
+    UserId,
+
+    FkPhoneNumberUser,
+
+    #[sea_orm(iden = "unique_phone_number")]
+    UniqueNumber,
+}
+
+#[derive(DeriveIden)]
+enum SmsAuthCode {
+    Table,
+
+    PhoneNumber,
+    Code,
+    Attempts,
+    ExpiredAt,
+
+    IndexSmsAuthCodePhoneNumber,
+}
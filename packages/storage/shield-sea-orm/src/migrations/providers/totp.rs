@@ -0,0 +1,15 @@
+mod m20261018_171530_create_provider_totp;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderTotpMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderTotpMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(
+            self::m20261018_171530_create_provider_totp::Migration,
+        )]
+    }
+}
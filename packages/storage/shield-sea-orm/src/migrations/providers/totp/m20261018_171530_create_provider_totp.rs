@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(UserTotp::Table, manager)
+                    .col(ColumnDef::new(UserTotp::Secret).text().not_null())
+                    .col(ColumnDef::new(UserTotp::LastUsedStep).big_integer())
+                    .col(ColumnDef::new(UserTotp::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(UserTotp::FkUserTotpUser.to_string())
+                            .from(UserTotp::Table, UserTotp::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(UserTotp::UniqueUserTotpUser.to_string())
+                            .col(UserTotp::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                BaseTable::create(TotpRecoveryCode::Table, manager)
+                    .col(
+                        ColumnDef::new(TotpRecoveryCode::CodeHash)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TotpRecoveryCode::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(TotpRecoveryCode::FkTotpRecoveryCodeUser.to_string())
+                            .from(TotpRecoveryCode::Table, TotpRecoveryCode::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TotpRecoveryCode::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserTotp::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum UserTotp {
+    Table,
+
+    Secret,
+    LastUsedStep,
+
+    UserId,
+
+    FkUserTotpUser,
+
+    UniqueUserTotpUser,
+}
+
+#[derive(DeriveIden)]
+enum TotpRecoveryCode {
+    Table,
+
+    CodeHash,
+
+    UserId,
+
+    FkTotpRecoveryCodeUser,
+}
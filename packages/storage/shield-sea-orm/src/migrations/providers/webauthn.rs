@@ -0,0 +1,15 @@
+mod m20261018_102314_create_provider_webauthn;
+
+use async_trait::async_trait;
+use sea_orm_migration::{MigrationTrait, MigratorTrait};
+
+pub struct ProviderWebauthnMigrator;
+
+#[async_trait]
+impl MigratorTrait for ProviderWebauthnMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(
+            self::m20261018_102314_create_provider_webauthn::Migration,
+        )]
+    }
+}
@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+use crate::base::{Base, BaseTable};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                BaseTable::create(WebauthnCredential::Table, manager)
+                    .col(
+                        ColumnDef::new(WebauthnCredential::CredentialId)
+                            .string_len(512)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredential::Passkey)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(WebauthnCredential::LastUsedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(WebauthnCredential::UserId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(WebauthnCredential::FkWebauthnCredentialUser.to_string())
+                            .from(WebauthnCredential::Table, WebauthnCredential::UserId)
+                            .to(User::Table, Base::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name(WebauthnCredential::UniqueWebauthnCredentialId.to_string())
+                            .col(WebauthnCredential::CredentialId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebauthnCredential::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum WebauthnCredential {
+    Table,
+
+    CredentialId,
+    Passkey,
+    LastUsedAt,
+
+    UserId,
+
+    FkWebauthnCredentialUser,
+
+    UniqueWebauthnCredentialId,
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, TryInsertResult,
+    prelude::Expr, sea_query::ExprTrait,
+};
+use shield::{RateLimitEntry, RateLimitStorage, StorageError};
+
+use crate::{entities::rate_limit_entry, storage::SeaOrmStorage};
+
+#[async_trait]
+impl RateLimitStorage for SeaOrmStorage {
+    async fn rate_limit_entry(&self, key: &str) -> Result<Option<RateLimitEntry>, StorageError> {
+        rate_limit_entry::Entity::find()
+            .filter(rate_limit_entry::Column::Key.eq(key))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|entry| entry.map(RateLimitEntry::from))
+    }
+
+    async fn record_failed_attempt(
+        &self,
+        key: &str,
+        now: DateTime<Utc>,
+        window: TimeDelta,
+    ) -> Result<RateLimitEntry, StorageError> {
+        let now = now.fixed_offset();
+        let window_started_after = now - window;
+
+        loop {
+            // Increment in the database, so concurrent attempts are all counted.
+            let result = rate_limit_entry::Entity::update_many()
+                .col_expr(
+                    rate_limit_entry::Column::Attempts,
+                    Expr::col(rate_limit_entry::Column::Attempts).add(1),
+                )
+                .col_expr(rate_limit_entry::Column::LastAttemptedAt, Expr::value(now))
+                .filter(rate_limit_entry::Column::Key.eq(key))
+                .filter(rate_limit_entry::Column::WindowStartedAt.gt(window_started_after))
+                .exec(&self.database)
+                .await
+                .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+            if result.rows_affected == 0 {
+                // Start a new window if the previous one has ended.
+                let result = rate_limit_entry::Entity::update_many()
+                    .col_expr(rate_limit_entry::Column::Attempts, Expr::value(1))
+                    .col_expr(rate_limit_entry::Column::WindowStartedAt, Expr::value(now))
+                    .col_expr(rate_limit_entry::Column::LastAttemptedAt, Expr::value(now))
+                    .col_expr(
+                        rate_limit_entry::Column::LockedUntil,
+                        Expr::value(None::<DateTime<FixedOffset>>),
+                    )
+                    .filter(rate_limit_entry::Column::Key.eq(key))
+                    .filter(rate_limit_entry::Column::WindowStartedAt.lte(window_started_after))
+                    .exec(&self.database)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                if result.rows_affected == 0 {
+                    let active_model = rate_limit_entry::ActiveModel {
+                        key: ActiveValue::Set(key.to_owned()),
+                        attempts: ActiveValue::Set(1),
+                        window_started_at: ActiveValue::Set(now),
+                        last_attempted_at: ActiveValue::Set(now),
+                        locked_until: ActiveValue::Set(None),
+                        ..Default::default()
+                    };
+
+                    let result = rate_limit_entry::Entity::insert(active_model)
+                        .on_conflict_do_nothing_on([rate_limit_entry::Column::Key])
+                        .exec_without_returning(&self.database)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    // A concurrent attempt created the entry, so count this attempt in its window.
+                    if !matches!(result, TryInsertResult::Inserted(rows) if rows > 0) {
+                        continue;
+                    }
+                }
+            }
+
+            return self.rate_limit_entry(key).await?.ok_or_else(|| {
+                StorageError::NotFound("RateLimitEntry".to_owned(), key.to_owned())
+            });
+        }
+    }
+
+    async fn save_rate_limit_entry(&self, entry: RateLimitEntry) -> Result<(), StorageError> {
+        let existing = rate_limit_entry::Entity::find()
+            .filter(rate_limit_entry::Column::Key.eq(&entry.key))
+            .one(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        let attempts = i32::try_from(entry.attempts)
+            .map_err(|err| StorageError::Validation(err.to_string()))?;
+
+        match existing {
+            Some(existing) => {
+                let mut active_model: rate_limit_entry::ActiveModel = existing.into();
+
+                active_model.attempts = ActiveValue::Set(attempts);
+                active_model.window_started_at =
+                    ActiveValue::Set(entry.window_started_at.fixed_offset());
+                active_model.last_attempted_at =
+                    ActiveValue::Set(entry.last_attempted_at.fixed_offset());
+                active_model.locked_until = ActiveValue::Set(
+                    entry
+                        .locked_until
+                        .map(|locked_until| locked_until.fixed_offset()),
+                );
+
+                active_model
+                    .update(&self.database)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+            }
+            None => {
+                let active_model = rate_limit_entry::ActiveModel {
+                    key: ActiveValue::Set(entry.key),
+                    attempts: ActiveValue::Set(attempts),
+                    window_started_at: ActiveValue::Set(entry.window_started_at.fixed_offset()),
+                    last_attempted_at: ActiveValue::Set(entry.last_attempted_at.fixed_offset()),
+                    locked_until: ActiveValue::Set(
+                        entry
+                            .locked_until
+                            .map(|locked_until| locked_until.fixed_offset()),
+                    ),
+                    ..Default::default()
+                };
+
+                active_model
+                    .insert(&self.database)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_rate_limit_entry(&self, key: &str) -> Result<(), StorageError> {
+        rate_limit_entry::Entity::delete_many()
+            .filter(rate_limit_entry::Column::Key.eq(key))
+            .exec(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+        Ok(())
+    }
+}
+
+impl From<rate_limit_entry::Model> for RateLimitEntry {
+    fn from(value: rate_limit_entry::Model) -> Self {
+        RateLimitEntry {
+            key: value.key,
+            attempts: u32::try_from(value.attempts).unwrap_or_default(),
+            window_started_at: value.window_started_at.to_utc(),
+            last_attempted_at: value.last_attempted_at.to_utc(),
+            locked_until: value.locked_until.map(|locked_until| locked_until.to_utc()),
+        }
+    }
+}
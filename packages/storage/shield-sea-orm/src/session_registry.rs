@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter};
+use shield::{
+    CreateRegisteredSession, RegisteredSession, RegisteredSessionFilter, SessionError,
+    SessionRegistry, UpdateRegisteredSession,
+};
+
+use crate::{entities::registered_session, storage::SeaOrmStorage};
+
+#[async_trait]
+impl SessionRegistry for SeaOrmStorage {
+    async fn registered_session_by_id(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<RegisteredSession>, SessionError> {
+        registered_session::Entity::find_by_id(parse_uuid(session_id)?)
+            .one(&self.database)
+            .await
+            .map_err(|err| SessionError::Engine(err.to_string()))
+            .map(|registered_session| registered_session.map(RegisteredSession::from))
+    }
+
+    async fn registered_sessions(
+        &self,
+        filter: &RegisteredSessionFilter,
+    ) -> Result<Vec<RegisteredSession>, SessionError> {
+        let mut query = registered_session::Entity::find();
+
+        if let Some(user_id) = &filter.user_id {
+            query = query.filter(registered_session::Column::UserId.eq(parse_uuid(user_id)?));
+        }
+        if let Some(method_id) = &filter.method_id {
+            query = query.filter(registered_session::Column::MethodId.eq(method_id));
+        }
+        if let Some(provider_id) = &filter.provider_id {
+            query = query.filter(registered_session::Column::ProviderId.eq(provider_id));
+        }
+        if let Some(provider_session_id) = &filter.provider_session_id {
+            query =
+                query.filter(registered_session::Column::ProviderSessionId.eq(provider_session_id));
+        }
+
+        query
+            .all(&self.database)
+            .await
+            .map_err(|err| SessionError::Engine(err.to_string()))
+            .map(|registered_sessions| {
+                registered_sessions
+                    .into_iter()
+                    .map(RegisteredSession::from)
+                    .collect()
+            })
+    }
+
+    async fn create_registered_session(
+        &self,
+        session: CreateRegisteredSession,
+    ) -> Result<RegisteredSession, SessionError> {
+        let active_model = registered_session::ActiveModel {
+            method_id: ActiveValue::Set(session.method_id),
+            provider_id: ActiveValue::Set(session.provider_id),
+            provider_session_id: ActiveValue::Set(session.provider_session_id),
+            ip_address: ActiveValue::Set(session.ip_address),
+            user_agent: ActiveValue::Set(session.user_agent),
+            last_seen_at: ActiveValue::Set(Utc::now().fixed_offset()),
+            user_id: ActiveValue::Set(parse_uuid(&session.user_id)?),
+            ..Default::default()
+        };
+
+        active_model
+            .insert(&self.database)
+            .await
+            .map_err(|err| SessionError::Engine(err.to_string()))
+            .map(RegisteredSession::from)
+    }
+
+    async fn update_registered_session(
+        &self,
+        session: UpdateRegisteredSession,
+    ) -> Result<RegisteredSession, SessionError> {
+        let registered_session = registered_session::Entity::find_by_id(parse_uuid(&session.id)?)
+            .one(&self.database)
+            .await
+            .map_err(|err| SessionError::Engine(err.to_string()))?
+            .ok_or_else(|| SessionError::Engine(format!("Session `{}` not found.", session.id)))?;
+
+        let mut active_model: registered_session::ActiveModel = registered_session.into();
+
+        if let Some(ip_address) = session.ip_address {
+            active_model.ip_address = ActiveValue::Set(ip_address);
+        }
+        if let Some(user_agent) = session.user_agent {
+            active_model.user_agent = ActiveValue::Set(user_agent);
+        }
+        if let Some(last_seen_at) = session.last_seen_at {
+            active_model.last_seen_at = ActiveValue::Set(last_seen_at.fixed_offset());
+        }
+
+        active_model
+            .update(&self.database)
+            .await
+            .map_err(|err| SessionError::Engine(err.to_string()))
+            .map(RegisteredSession::from)
+    }
+
+    async fn delete_registered_session(&self, session_id: &str) -> Result<(), SessionError> {
+        registered_session::Entity::delete_by_id(parse_uuid(session_id)?)
+            .exec(&self.database)
+            .await
+            .map_err(|err| SessionError::Engine(err.to_string()))
+            .map(|_| ())
+    }
+}
+
+fn parse_uuid(uuid: &str) -> Result<sea_orm::prelude::Uuid, SessionError> {
+    SeaOrmStorage::parse_uuid(uuid).map_err(|err| SessionError::Engine(err.to_string()))
+}
+
+impl From<registered_session::Model> for RegisteredSession {
+    fn from(value: registered_session::Model) -> Self {
+        RegisteredSession {
+            id: value.id.to_string(),
+            user_id: value.user_id.to_string(),
+            method_id: value.method_id,
+            provider_id: value.provider_id,
+            provider_session_id: value.provider_session_id,
+            ip_address: value.ip_address,
+            user_agent: value.user_agent,
+            created_at: value.created_at.to_utc(),
+            last_seen_at: value.last_seen_at.to_utc(),
+        }
+    }
+}
@@ -104,6 +104,21 @@ impl shield::User for User {
         }
     }
 
+    #[cfg(feature = "method-sms")]
+    async fn phone_numbers(&self) -> Result<Vec<shield::PhoneNumber>, StorageError> {
+        self.user
+            .find_related(crate::entities::phone_number::Entity)
+            .all(&self.database)
+            .await
+            .map_err(|err| StorageError::Engine(err.to_string()))
+            .map(|phone_numbers| {
+                phone_numbers
+                    .into_iter()
+                    .map(shield::PhoneNumber::from)
+                    .collect()
+            })
+    }
+
     fn additional(&self) -> Option<impl Serialize> {
         Some(Additional {
             #[cfg(feature = "entity")]
@@ -34,3 +34,22 @@ async fn migrations() {
             .unwrap_or_else(|err| panic!("Cleanup for backend `{backend}` failed: {err}"));
     }
 }
+
+#[tokio::test]
+async fn sqlite_migrations() {
+    let database = Database::connect("sqlite::memory:")
+        .await
+        .expect("Connect to in-memory SQLite should succeed.");
+
+    Migrator::fresh(&database)
+        .await
+        .expect("Up migrations should succeed.");
+
+    Migrator::refresh(&database)
+        .await
+        .expect("Down migrations should succeed.");
+
+    Migrator::reset(&database)
+        .await
+        .expect("Cleanup should succeed.");
+}
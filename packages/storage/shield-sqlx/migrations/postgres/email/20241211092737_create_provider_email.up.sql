@@ -0,0 +1,9 @@
+CREATE TABLE email_auth_token (
+    id UUID NOT NULL PRIMARY KEY,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    email VARCHAR(254) NOT NULL,
+    token VARCHAR(32) NOT NULL,
+    expired_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT unique_email_token UNIQUE (email, token)
+);
@@ -1 +1,13 @@
+#![cfg_attr(
+    not(any(feature = "postgres", feature = "sqlite")),
+    allow(dead_code, unused_macros)
+)]
 
+mod methods;
+pub mod migrations;
+pub mod models;
+mod storage;
+mod user;
+
+pub use storage::*;
+pub use user::*;
@@ -0,0 +1,104 @@
+use shield_email::EmailAuthToken;
+
+use crate::{models::email_auth_token, storage::for_each_database};
+
+macro_rules! impl_email_storage {
+    ($database:ty) => {
+        const _: () = {
+            use async_trait::async_trait;
+            use chrono::Utc;
+            use shield::StorageError;
+            use shield_email::{CreateEmailAuthToken, EmailStorage};
+            use uuid::Uuid;
+
+            use crate::{storage::SqlxStorage, user::User};
+
+            #[async_trait]
+            impl EmailStorage<User> for SqlxStorage<$database> {
+                async fn email_auth_token(
+                    &self,
+                    email: &str,
+                    token: &str,
+                ) -> Result<Option<EmailAuthToken>, StorageError> {
+                    sqlx::query_as::<_, email_auth_token::Model>(
+                        "SELECT * FROM email_auth_token
+                        WHERE email = $1 AND token = $2 AND expired_at > $3",
+                    )
+                    .bind(email)
+                    .bind(token)
+                    .bind(Utc::now())
+                    .fetch_optional(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))
+                    .map(|email_auth_token| email_auth_token.map(EmailAuthToken::from))
+                }
+
+                async fn create_email_auth_token(
+                    &self,
+                    email_auth_token: CreateEmailAuthToken,
+                ) -> Result<EmailAuthToken, StorageError> {
+                    let now = Utc::now();
+
+                    let model = email_auth_token::Model {
+                        id: Uuid::new_v4(),
+                        created_at: now,
+                        updated_at: now,
+                        email: email_auth_token.email,
+                        token: email_auth_token.token,
+                        expired_at: email_auth_token.expired_at.to_utc(),
+                    };
+
+                    sqlx::query(
+                        "INSERT INTO email_auth_token (
+                            id, created_at, updated_at, email, token, expired_at
+                        ) VALUES ($1, $2, $3, $4, $5, $6)",
+                    )
+                    .bind(model.id)
+                    .bind(model.created_at)
+                    .bind(model.updated_at)
+                    .bind(&model.email)
+                    .bind(&model.token)
+                    .bind(model.expired_at)
+                    .execute(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))
+                    .map(|_| EmailAuthToken::from(model))
+                }
+
+                async fn delete_email_auth_token(
+                    &self,
+                    email_auth_token_id: &str,
+                ) -> Result<(), StorageError> {
+                    sqlx::query("DELETE FROM email_auth_token WHERE id = $1")
+                        .bind(Self::parse_uuid(email_auth_token_id)?)
+                        .execute(&self.pool)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                }
+
+                async fn delete_expired_email_auth_tokens(&self) -> Result<(), StorageError> {
+                    sqlx::query("DELETE FROM email_auth_token WHERE expired_at <= $1")
+                        .bind(Utc::now())
+                        .execute(&self.pool)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                }
+            }
+        };
+    };
+}
+
+for_each_database!(impl_email_storage);
+
+impl From<email_auth_token::Model> for EmailAuthToken {
+    fn from(value: email_auth_token::Model) -> Self {
+        EmailAuthToken {
+            id: value.id.to_string(),
+            email: value.email,
+            token: value.token,
+            expired_at: value.expired_at.fixed_offset(),
+        }
+    }
+}
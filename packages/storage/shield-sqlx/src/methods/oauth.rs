@@ -0,0 +1,305 @@
+use shield::StorageError;
+use shield_oauth::{
+    OauthConnection, OauthProvider, OauthProviderPkceCodeChallenge, OauthProviderVisibility,
+};
+
+use crate::{
+    models::{oauth_provider, oauth_provider_connection},
+    storage::for_each_database,
+};
+
+macro_rules! impl_oauth_storage {
+    ($database:ty) => {
+        const _: () = {
+            use async_trait::async_trait;
+            use chrono::Utc;
+            use secrecy::ExposeSecret;
+            use shield_oauth::{CreateOauthConnection, OauthStorage, UpdateOauthConnection};
+            use uuid::Uuid;
+
+            use crate::{storage::SqlxStorage, user::User};
+
+            #[async_trait]
+            impl OauthStorage<User> for SqlxStorage<$database> {
+                async fn oauth_providers(&self) -> Result<Vec<OauthProvider>, StorageError> {
+                    sqlx::query_as::<_, oauth_provider::Model>("SELECT * FROM oauth_provider")
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .and_then(|providers| {
+                            providers.into_iter().map(OauthProvider::try_from).collect()
+                        })
+                }
+
+                async fn oauth_provider_by_id_or_slug(
+                    &self,
+                    provider_id: &str,
+                ) -> Result<Option<OauthProvider>, StorageError> {
+                    let query = match Self::parse_uuid(provider_id) {
+                        Ok(provider_id) => sqlx::query_as::<_, oauth_provider::Model>(
+                            "SELECT * FROM oauth_provider WHERE id = $1",
+                        )
+                        .bind(provider_id),
+                        Err(_) => sqlx::query_as::<_, oauth_provider::Model>(
+                            "SELECT * FROM oauth_provider WHERE slug = $1",
+                        )
+                        .bind(provider_id.to_lowercase()),
+                    };
+
+                    query
+                        .fetch_optional(&self.pool)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .and_then(|provider| match provider {
+                            Some(provider) => OauthProvider::try_from(provider).map(Option::Some),
+                            None => Ok(None),
+                        })
+                }
+
+                async fn oauth_connection_by_id(
+                    &self,
+                    connection_id: &str,
+                ) -> Result<Option<OauthConnection>, StorageError> {
+                    sqlx::query_as::<_, oauth_provider_connection::Model>(
+                        "SELECT * FROM oauth_provider_connection WHERE id = $1",
+                    )
+                    .bind(Self::parse_uuid(connection_id)?)
+                    .fetch_optional(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))
+                    .map(|connection| connection.map(OauthConnection::from))
+                }
+
+                async fn oauth_connection_by_identifier(
+                    &self,
+                    provider_id: &str,
+                    identifier: &str,
+                ) -> Result<Option<OauthConnection>, StorageError> {
+                    sqlx::query_as::<_, oauth_provider_connection::Model>(
+                        "SELECT * FROM oauth_provider_connection
+                        WHERE provider_id = $1 AND identifier = $2",
+                    )
+                    .bind(Self::parse_uuid(provider_id)?)
+                    .bind(identifier)
+                    .fetch_optional(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))
+                    .map(|connection| connection.map(OauthConnection::from))
+                }
+
+                async fn create_oauth_connection(
+                    &self,
+                    connection: CreateOauthConnection,
+                ) -> Result<OauthConnection, StorageError> {
+                    let now = Utc::now();
+
+                    let model = oauth_provider_connection::Model {
+                        id: Uuid::new_v4(),
+                        created_at: now,
+                        updated_at: now,
+                        identifier: connection.identifier,
+                        token_type: connection.token_type,
+                        access_token: connection.access_token.expose_secret().to_owned(),
+                        refresh_token: connection
+                            .refresh_token
+                            .map(|refresh_token| refresh_token.expose_secret().to_owned()),
+                        expired_at: connection.expired_at.map(|expired_at| expired_at.to_utc()),
+                        scopes: connection.scopes.map(|scopes| scopes.join(",")),
+                        provider_id: Self::parse_uuid(&connection.provider_id)?,
+                        user_id: Self::parse_uuid(&connection.user_id)?,
+                    };
+
+                    sqlx::query(
+                        "INSERT INTO oauth_provider_connection (
+                            id, created_at, updated_at, identifier, token_type, access_token,
+                            refresh_token, expired_at, scopes, provider_id, user_id
+                        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+                    )
+                    .bind(model.id)
+                    .bind(model.created_at)
+                    .bind(model.updated_at)
+                    .bind(&model.identifier)
+                    .bind(&model.token_type)
+                    .bind(&model.access_token)
+                    .bind(&model.refresh_token)
+                    .bind(model.expired_at)
+                    .bind(&model.scopes)
+                    .bind(model.provider_id)
+                    .bind(model.user_id)
+                    .execute(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))
+                    .map(|_| OauthConnection::from(model))
+                }
+
+                async fn update_oauth_connection(
+                    &self,
+                    connection: UpdateOauthConnection,
+                ) -> Result<OauthConnection, StorageError> {
+                    let mut model = sqlx::query_as::<_, oauth_provider_connection::Model>(
+                        "SELECT * FROM oauth_provider_connection WHERE id = $1",
+                    )
+                    .bind(Self::parse_uuid(&connection.id)?)
+                    .fetch_optional(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?
+                    .ok_or_else(|| {
+                        StorageError::NotFound("OauthConnection".to_owned(), connection.id)
+                    })?;
+
+                    model.updated_at = Utc::now();
+                    if let Some(token_type) = connection.token_type {
+                        model.token_type = token_type;
+                    }
+                    if let Some(access_token) = connection.access_token {
+                        model.access_token = access_token.expose_secret().to_owned();
+                    }
+                    if let Some(refresh_token) = connection.refresh_token {
+                        model.refresh_token = refresh_token
+                            .map(|refresh_token| refresh_token.expose_secret().to_owned());
+                    }
+                    if let Some(expired_at) = connection.expired_at {
+                        model.expired_at = expired_at.map(|expired_at| expired_at.to_utc());
+                    }
+                    if let Some(scopes) = connection.scopes {
+                        model.scopes = scopes.map(|scopes| scopes.join(","));
+                    }
+
+                    sqlx::query(
+                        "UPDATE oauth_provider_connection SET
+                            updated_at = $1, token_type = $2, access_token = $3,
+                            refresh_token = $4, expired_at = $5, scopes = $6
+                        WHERE id = $7",
+                    )
+                    .bind(model.updated_at)
+                    .bind(&model.token_type)
+                    .bind(&model.access_token)
+                    .bind(&model.refresh_token)
+                    .bind(model.expired_at)
+                    .bind(&model.scopes)
+                    .bind(model.id)
+                    .execute(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))
+                    .map(|_| OauthConnection::from(model))
+                }
+
+                async fn delete_oauth_connection(
+                    &self,
+                    connection_id: &str,
+                ) -> Result<(), StorageError> {
+                    sqlx::query("DELETE FROM oauth_provider_connection WHERE id = $1")
+                        .bind(Self::parse_uuid(connection_id)?)
+                        .execute(&self.pool)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                }
+
+                async fn user_oauth_connections(
+                    &self,
+                    user_id: &str,
+                    provider_id: Option<&str>,
+                ) -> Result<Vec<OauthConnection>, StorageError> {
+                    let query = match provider_id {
+                        Some(provider_id) => {
+                            sqlx::query_as::<_, oauth_provider_connection::Model>(
+                                "SELECT * FROM oauth_provider_connection
+                                WHERE user_id = $1 AND provider_id = $2",
+                            )
+                            .bind(Self::parse_uuid(user_id)?)
+                            .bind(Self::parse_uuid(provider_id)?)
+                        }
+                        None => sqlx::query_as::<_, oauth_provider_connection::Model>(
+                            "SELECT * FROM oauth_provider_connection WHERE user_id = $1",
+                        )
+                        .bind(Self::parse_uuid(user_id)?),
+                    };
+
+                    query
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|connections| {
+                            connections.into_iter().map(OauthConnection::from).collect()
+                        })
+                }
+            }
+        };
+    };
+}
+
+for_each_database!(impl_oauth_storage);
+
+fn parse_visibility(value: &str) -> Result<OauthProviderVisibility, StorageError> {
+    match value {
+        "public" => Ok(OauthProviderVisibility::Public),
+        "unlisted" => Ok(OauthProviderVisibility::Unlisted),
+        value => Err(StorageError::Validation(format!(
+            "Invalid OAuth provider visibility `{value}`."
+        ))),
+    }
+}
+
+fn parse_pkce_code_challenge(value: &str) -> Result<OauthProviderPkceCodeChallenge, StorageError> {
+    match value {
+        "none" => Ok(OauthProviderPkceCodeChallenge::None),
+        "plain" => Ok(OauthProviderPkceCodeChallenge::Plain),
+        "s256" => Ok(OauthProviderPkceCodeChallenge::S256),
+        value => Err(StorageError::Validation(format!(
+            "Invalid OAuth provider PKCE code challenge `{value}`."
+        ))),
+    }
+}
+
+impl TryFrom<oauth_provider::Model> for OauthProvider {
+    type Error = StorageError;
+
+    fn try_from(value: oauth_provider::Model) -> Result<Self, Self::Error> {
+        Ok(OauthProvider {
+            id: value.id.to_string(),
+            name: value.name,
+            slug: value.slug,
+            icon_url: value.icon_url,
+            visibility: parse_visibility(&value.visibility)?,
+            client_id: value.client_id,
+            client_secret: value.client_secret.map(Into::into),
+            scopes: value
+                .scopes
+                .map(|scopes| scopes.split(',').map(|s| s.to_string()).collect()),
+            redirect_url: value.redirect_url,
+            authorization_url: value.authorization_url,
+            authorization_url_params: value.authorization_url_params,
+            token_url: value.token_url,
+            token_url_params: value.token_url_params,
+            introspection_url: value.introspection_url,
+            introspection_url_params: value.introspection_url_params,
+            revocation_url: value.revocation_url,
+            revocation_url_params: value.revocation_url_params,
+            pkce_code_challenge: parse_pkce_code_challenge(&value.pkce_code_challenge)?,
+            user_url: value.user_url,
+            user_path: value.user_path,
+            user_id_path: value.user_id_path.unwrap_or("id".to_owned()),
+            user_email_path: value.user_email_path.unwrap_or("email".to_owned()),
+            user_name_path: value.user_name_path.unwrap_or("name".to_owned()),
+        })
+    }
+}
+
+impl From<oauth_provider_connection::Model> for OauthConnection {
+    fn from(value: oauth_provider_connection::Model) -> Self {
+        OauthConnection {
+            id: value.id.to_string(),
+            identifier: value.identifier,
+            token_type: value.token_type,
+            access_token: value.access_token.into(),
+            refresh_token: value.refresh_token.map(Into::into),
+            expired_at: value.expired_at.map(|expired_at| expired_at.fixed_offset()),
+            scopes: value
+                .scopes
+                .map(|scopes| scopes.split(',').map(|s| s.to_string()).collect()),
+            provider_id: value.provider_id.to_string(),
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
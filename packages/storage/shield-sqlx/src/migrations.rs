@@ -0,0 +1,10 @@
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+use sqlx::migrate::Migrator;
+
+pub trait Migrations {
+    fn migrators() -> Vec<Migrator>;
+}
@@ -0,0 +1,17 @@
+use sqlx::{Postgres, migrate::Migrator};
+
+use crate::migrations::Migrations;
+
+impl Migrations for Postgres {
+    fn migrators() -> Vec<Migrator> {
+        Vec::from([
+            sqlx::migrate!("migrations/postgres/core"),
+            #[cfg(feature = "method-email")]
+            sqlx::migrate!("migrations/postgres/email"),
+            #[cfg(feature = "method-oauth")]
+            sqlx::migrate!("migrations/postgres/oauth"),
+            #[cfg(feature = "method-oidc")]
+            sqlx::migrate!("migrations/postgres/oidc"),
+        ])
+    }
+}
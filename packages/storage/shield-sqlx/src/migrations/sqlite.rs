@@ -0,0 +1,17 @@
+use sqlx::{Sqlite, migrate::Migrator};
+
+use crate::migrations::Migrations;
+
+impl Migrations for Sqlite {
+    fn migrators() -> Vec<Migrator> {
+        Vec::from([
+            sqlx::migrate!("migrations/sqlite/core"),
+            #[cfg(feature = "method-email")]
+            sqlx::migrate!("migrations/sqlite/email"),
+            #[cfg(feature = "method-oauth")]
+            sqlx::migrate!("migrations/sqlite/oauth"),
+            #[cfg(feature = "method-oidc")]
+            sqlx::migrate!("migrations/sqlite/oidc"),
+        ])
+    }
+}
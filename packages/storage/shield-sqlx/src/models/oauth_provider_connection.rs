@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct Model {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub identifier: String,
+    pub token_type: String,
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expired_at: Option<DateTime<Utc>>,
+    pub scopes: Option<String>,
+    pub provider_id: Uuid,
+    pub user_id: Uuid,
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, types::Json};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct Model {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub name: String,
+    pub slug: Option<String>,
+    #[sqlx(rename = "type")]
+    pub r#type: String,
+    pub visibility: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub scopes: Option<String>,
+    pub redirect_url: Option<String>,
+    pub discovery_url: Option<String>,
+    pub issuer_url: Option<String>,
+    pub authorization_url: Option<String>,
+    pub authorization_url_params: Option<String>,
+    pub token_url: Option<String>,
+    pub token_url_params: Option<String>,
+    pub introspection_url: Option<String>,
+    pub introspection_url_params: Option<String>,
+    pub revocation_url: Option<String>,
+    pub revocation_url_params: Option<String>,
+    pub user_info_url: Option<String>,
+    pub json_web_key_set_url: Option<String>,
+    pub json_web_key_set: Option<Json<Value>>,
+    pub pkce_code_challenge: String,
+    pub icon_url: Option<String>,
+}
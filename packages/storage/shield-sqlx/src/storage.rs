@@ -0,0 +1,247 @@
+use shield::StorageError;
+use sqlx::{Database, Pool, migrate::Migrate};
+use uuid::Uuid;
+
+use crate::migrations::Migrations;
+
+pub const SQLX_STORAGE_ID: &str = "sqlx";
+
+#[derive(Debug)]
+pub struct SqlxStorage<DB: Database> {
+    pub(crate) pool: Pool<DB>,
+}
+
+impl<DB: Database> SqlxStorage<DB> {
+    pub fn new(pool: Pool<DB>) -> Self {
+        Self { pool }
+    }
+
+    pub(crate) fn parse_uuid(uuid: &str) -> Result<Uuid, StorageError> {
+        Uuid::parse_str(uuid).map_err(|err| StorageError::Validation(err.to_string()))
+    }
+}
+
+impl<DB> SqlxStorage<DB>
+where
+    DB: Database + Migrations,
+    DB::Connection: Migrate,
+{
+    pub async fn run_pending_migrations(&self) -> Result<(), StorageError> {
+        for mut migrator in DB::migrators() {
+            migrator
+                .set_ignore_missing(true)
+                .run(&self.pool)
+                .await
+                .map_err(|err| StorageError::Engine(err.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<DB: Database> Clone for SqlxStorage<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+macro_rules! for_each_database {
+    ($macro:ident) => {
+        #[cfg(feature = "postgres")]
+        $macro!(sqlx::Postgres);
+
+        #[cfg(feature = "sqlite")]
+        $macro!(sqlx::Sqlite);
+    };
+}
+
+pub(crate) use for_each_database;
+
+macro_rules! impl_storage {
+    ($database:ty) => {
+        const _: () = {
+            use async_trait::async_trait;
+            use chrono::Utc;
+            use shield::{CreateEmailAddress, CreateUser, Storage, StorageError, UpdateUser};
+            use uuid::Uuid;
+
+            use crate::{
+                models::{email_address, user},
+                storage::{SQLX_STORAGE_ID, SqlxStorage},
+                user::User,
+            };
+
+            impl SqlxStorage<$database> {
+                async fn user_with_email_addresses(
+                    &self,
+                    user: user::Model,
+                ) -> Result<User, StorageError> {
+                    let email_addresses = sqlx::query_as::<_, email_address::Model>(
+                        "SELECT * FROM email_address WHERE user_id = $1",
+                    )
+                    .bind(user.id)
+                    .fetch_all(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    Ok(User::new(user, email_addresses))
+                }
+            }
+
+            #[async_trait]
+            impl Storage<User> for SqlxStorage<$database> {
+                fn id(&self) -> String {
+                    SQLX_STORAGE_ID.to_owned()
+                }
+
+                async fn user_by_id(&self, user_id: &str) -> Result<Option<User>, StorageError> {
+                    let user =
+                        sqlx::query_as::<_, user::Model>(r#"SELECT * FROM "user" WHERE id = $1"#)
+                            .bind(Self::parse_uuid(user_id)?)
+                            .fetch_optional(&self.pool)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    match user {
+                        Some(user) => self.user_with_email_addresses(user).await.map(Some),
+                        None => Ok(None),
+                    }
+                }
+
+                async fn user_by_email(&self, email: &str) -> Result<Option<User>, StorageError> {
+                    let user = sqlx::query_as::<_, user::Model>(
+                        r#"SELECT "user".* FROM "user"
+                        INNER JOIN email_address ON email_address.user_id = "user".id
+                        WHERE email_address.email = $1"#,
+                    )
+                    .bind(email)
+                    .fetch_optional(&self.pool)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    match user {
+                        Some(user) => self.user_with_email_addresses(user).await.map(Some),
+                        None => Ok(None),
+                    }
+                }
+
+                async fn create_user(
+                    &self,
+                    user: CreateUser,
+                    email_address: CreateEmailAddress,
+                ) -> Result<User, StorageError> {
+                    let now = Utc::now();
+
+                    let user = user::Model {
+                        id: Uuid::new_v4(),
+                        created_at: now,
+                        updated_at: now,
+                        name: user.name.unwrap_or_default(),
+                    };
+
+                    let email_address = email_address::Model {
+                        id: Uuid::new_v4(),
+                        created_at: now,
+                        updated_at: now,
+                        email: email_address.email,
+                        is_primary: email_address.is_primary,
+                        is_verified: email_address.is_verified,
+                        verification_token: email_address.verification_token,
+                        verification_token_expired_at: email_address
+                            .verification_token_expired_at
+                            .map(|expired_at| expired_at.to_utc()),
+                        verified_at: email_address
+                            .verified_at
+                            .map(|verified_at| verified_at.to_utc()),
+                        user_id: user.id,
+                    };
+
+                    let mut transaction = self
+                        .pool
+                        .begin()
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    sqlx::query(
+                        r#"INSERT INTO "user" (id, created_at, updated_at, name)
+                        VALUES ($1, $2, $3, $4)"#,
+                    )
+                    .bind(user.id)
+                    .bind(user.created_at)
+                    .bind(user.updated_at)
+                    .bind(&user.name)
+                    .execute(&mut *transaction)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    sqlx::query(
+                        "INSERT INTO email_address (
+                            id, created_at, updated_at, email, is_primary, is_verified,
+                            verification_token, verification_token_expired_at, verified_at, user_id
+                        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+                    )
+                    .bind(email_address.id)
+                    .bind(email_address.created_at)
+                    .bind(email_address.updated_at)
+                    .bind(&email_address.email)
+                    .bind(email_address.is_primary)
+                    .bind(email_address.is_verified)
+                    .bind(&email_address.verification_token)
+                    .bind(email_address.verification_token_expired_at)
+                    .bind(email_address.verified_at)
+                    .bind(email_address.user_id)
+                    .execute(&mut *transaction)
+                    .await
+                    .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    transaction
+                        .commit()
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))?;
+
+                    Ok(User::new(user, vec![email_address]))
+                }
+
+                async fn update_user(&self, user: UpdateUser) -> Result<User, StorageError> {
+                    let mut user_model =
+                        sqlx::query_as::<_, user::Model>(r#"SELECT * FROM "user" WHERE id = $1"#)
+                            .bind(Self::parse_uuid(&user.id)?)
+                            .fetch_optional(&self.pool)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))?
+                            .ok_or_else(|| {
+                                StorageError::NotFound("User".to_owned(), user.id.clone())
+                            })?;
+
+                    if let Some(Some(name)) = user.name {
+                        user_model.name = name;
+                        user_model.updated_at = Utc::now();
+
+                        sqlx::query(r#"UPDATE "user" SET name = $1, updated_at = $2 WHERE id = $3"#)
+                            .bind(&user_model.name)
+                            .bind(user_model.updated_at)
+                            .bind(user_model.id)
+                            .execute(&self.pool)
+                            .await
+                            .map_err(|err| StorageError::Engine(err.to_string()))?;
+                    }
+
+                    self.user_with_email_addresses(user_model).await
+                }
+
+                async fn delete_user(&self, user_id: &str) -> Result<(), StorageError> {
+                    sqlx::query(r#"DELETE FROM "user" WHERE id = $1"#)
+                        .bind(Self::parse_uuid(user_id)?)
+                        .execute(&self.pool)
+                        .await
+                        .map_err(|err| StorageError::Engine(err.to_string()))
+                        .map(|_| ())
+                }
+            }
+        };
+    };
+}
+
+self::for_each_database!(impl_storage);
@@ -0,0 +1,73 @@
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use serde::Serialize;
+use shield::{EmailAddress, StorageError};
+
+use crate::models::{email_address, user};
+
+#[derive(Clone, Debug)]
+pub struct User {
+    user: user::Model,
+    email_addresses: Vec<email_address::Model>,
+}
+
+impl User {
+    pub(crate) fn new(user: user::Model, email_addresses: Vec<email_address::Model>) -> Self {
+        Self {
+            user,
+            email_addresses,
+        }
+    }
+}
+
+impl Deref for User {
+    type Target = user::Model;
+
+    fn deref(&self) -> &Self::Target {
+        &self.user
+    }
+}
+
+#[async_trait]
+impl shield::User for User {
+    fn id(&self) -> String {
+        self.user.id.to_string()
+    }
+
+    fn name(&self) -> Option<String> {
+        Some(self.user.name.clone())
+    }
+
+    async fn email_addresses(&self) -> Result<Vec<EmailAddress>, StorageError> {
+        Ok(self
+            .email_addresses
+            .iter()
+            .cloned()
+            .map(EmailAddress::from)
+            .collect())
+    }
+
+    fn additional(&self) -> Option<impl Serialize> {
+        None::<()>
+    }
+}
+
+impl From<email_address::Model> for EmailAddress {
+    fn from(value: email_address::Model) -> Self {
+        Self {
+            id: value.id.to_string(),
+            email: value.email,
+            is_primary: value.is_primary,
+            is_verified: value.is_verified,
+            verification_token: value.verification_token,
+            verification_token_expired_at: value
+                .verification_token_expired_at
+                .map(|expired_at| expired_at.fixed_offset()),
+            verified_at: value
+                .verified_at
+                .map(|verified_at| verified_at.fixed_offset()),
+            user_id: value.user_id.to_string(),
+        }
+    }
+}
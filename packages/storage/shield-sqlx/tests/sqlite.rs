@@ -0,0 +1,283 @@
+use std::path::PathBuf;
+
+use chrono::{Duration, Utc};
+use secrecy::ExposeSecret;
+use shield::{CreateEmailAddress, CreateUser, Storage, UpdateUser, User as _};
+use shield_email::{CreateEmailAuthToken, EmailStorage};
+use shield_oauth::{
+    CreateOauthConnection, OauthProviderVisibility, OauthStorage, UpdateOauthConnection,
+};
+use shield_sqlx::{SqlxStorage, User};
+use sqlx::{
+    Sqlite, SqlitePool,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+use uuid::Uuid;
+
+struct TestDatabase {
+    path: PathBuf,
+    pool: SqlitePool,
+    storage: SqlxStorage<Sqlite>,
+}
+
+impl TestDatabase {
+    async fn new() -> Self {
+        let path = std::env::temp_dir().join(format!("shield-sqlx-{}.sqlite", Uuid::new_v4()));
+
+        let pool = SqlitePoolOptions::new()
+            .connect_with(
+                SqliteConnectOptions::new()
+                    .filename(&path)
+                    .create_if_missing(true),
+            )
+            .await
+            .expect("Pool should connect.");
+
+        let storage = SqlxStorage::new(pool.clone());
+        storage
+            .run_pending_migrations()
+            .await
+            .expect("Migrations should run.");
+
+        Self {
+            path,
+            pool,
+            storage,
+        }
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+async fn create_user(storage: &SqlxStorage<Sqlite>, email: &str) -> User {
+    storage
+        .create_user(
+            CreateUser {
+                name: Some("Alice".to_owned()),
+            },
+            CreateEmailAddress {
+                email: email.to_owned(),
+                is_primary: true,
+                is_verified: false,
+                verification_token: None,
+                verification_token_expired_at: None,
+                verified_at: None,
+            },
+        )
+        .await
+        .expect("User should be created.")
+}
+
+#[tokio::test]
+async fn migrations() {
+    let database = TestDatabase::new().await;
+
+    database
+        .storage
+        .run_pending_migrations()
+        .await
+        .expect("Migrations should be idempotent.");
+}
+
+#[tokio::test]
+async fn users() {
+    let database = TestDatabase::new().await;
+    let storage = &database.storage;
+
+    let user = create_user(storage, "alice@example.com").await;
+    assert_eq!(user.name(), Some("Alice".to_owned()));
+
+    let email_addresses = user.email_addresses().await.unwrap();
+    assert_eq!(email_addresses.len(), 1);
+    assert_eq!(email_addresses[0].email, "alice@example.com");
+    assert_eq!(email_addresses[0].user_id, user.id());
+
+    let found = storage.user_by_id(&user.id()).await.unwrap().unwrap();
+    assert_eq!(found.id(), user.id());
+
+    let found = storage
+        .user_by_email("alice@example.com")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), user.id());
+
+    assert!(
+        storage
+            .user_by_email("bob@example.com")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let updated = storage
+        .update_user(UpdateUser {
+            id: user.id(),
+            name: Some(Some("Alice Liddell".to_owned())),
+        })
+        .await
+        .unwrap();
+    assert_eq!(updated.name(), Some("Alice Liddell".to_owned()));
+    assert_eq!(updated.email_addresses().await.unwrap().len(), 1);
+
+    storage.delete_user(&user.id()).await.unwrap();
+    assert!(storage.user_by_id(&user.id()).await.unwrap().is_none());
+    assert!(
+        storage
+            .user_by_email("alice@example.com")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn email_auth_tokens() {
+    let database = TestDatabase::new().await;
+    let storage = &database.storage;
+
+    let token = storage
+        .create_email_auth_token(CreateEmailAuthToken {
+            email: "alice@example.com".to_owned(),
+            token: "token".to_owned(),
+            expired_at: (Utc::now() + Duration::minutes(5)).fixed_offset(),
+        })
+        .await
+        .unwrap();
+
+    storage
+        .create_email_auth_token(CreateEmailAuthToken {
+            email: "alice@example.com".to_owned(),
+            token: "expired".to_owned(),
+            expired_at: (Utc::now() - Duration::minutes(5)).fixed_offset(),
+        })
+        .await
+        .unwrap();
+
+    let found = storage
+        .email_auth_token("alice@example.com", "token")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id, token.id);
+
+    assert!(
+        storage
+            .email_auth_token("alice@example.com", "expired")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    storage.delete_expired_email_auth_tokens().await.unwrap();
+    storage.delete_email_auth_token(&token.id).await.unwrap();
+    assert!(
+        storage
+            .email_auth_token("alice@example.com", "token")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn oauth_connections() {
+    let database = TestDatabase::new().await;
+    let storage = &database.storage;
+
+    let user = create_user(storage, "alice@example.com").await;
+
+    let provider_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO oauth_provider (
+            id, name, slug, type, visibility, client_id, scopes, user_url, pkce_code_challenge
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+    )
+    .bind(provider_id)
+    .bind("Example")
+    .bind("example")
+    .bind("custom")
+    .bind("public")
+    .bind("client")
+    .bind("openid,email")
+    .bind("https://example.com/user")
+    .bind("s256")
+    .execute(&database.pool)
+    .await
+    .unwrap();
+
+    let provider = storage
+        .oauth_provider_by_id_or_slug("Example")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(provider.id, provider_id.to_string());
+    assert_eq!(provider.visibility, OauthProviderVisibility::Public);
+    assert_eq!(
+        provider.scopes,
+        Some(vec!["openid".to_owned(), "email".to_owned()])
+    );
+    assert_eq!(storage.oauth_providers().await.unwrap().len(), 1);
+
+    let connection = storage
+        .create_oauth_connection(CreateOauthConnection {
+            identifier: "alice".to_owned(),
+            token_type: "Bearer".to_owned(),
+            access_token: "access".into(),
+            refresh_token: None,
+            expired_at: None,
+            scopes: None,
+            provider_id: provider.id.clone(),
+            user_id: user.id(),
+        })
+        .await
+        .unwrap();
+
+    let updated = storage
+        .update_oauth_connection(UpdateOauthConnection {
+            id: connection.id.clone(),
+            token_type: None,
+            access_token: Some("refreshed".into()),
+            refresh_token: Some(Some("refresh".into())),
+            expired_at: None,
+            scopes: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(updated.access_token.expose_secret(), "refreshed");
+    assert_eq!(
+        updated
+            .refresh_token
+            .as_ref()
+            .map(|refresh_token| refresh_token.expose_secret()),
+        Some("refresh")
+    );
+
+    let found = storage
+        .oauth_connection_by_identifier(&provider.id, "alice")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id, connection.id);
+    assert_eq!(
+        storage
+            .user_oauth_connections(&user.id(), Some(&provider.id))
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    storage.delete_user(&user.id()).await.unwrap();
+    assert!(
+        storage
+            .oauth_connection_by_id(&connection.id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
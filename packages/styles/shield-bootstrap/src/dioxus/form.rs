@@ -15,6 +15,7 @@ pub struct FormProps {
 #[component]
 pub fn Form(props: FormProps) -> Element {
     let navigator = navigator();
+    let mut data = use_signal(|| None::<String>);
 
     rsx! {
         form {
@@ -26,7 +27,7 @@ pub fn Form(props: FormProps) -> Element {
 
                     async move {
                         info!("{:?}", event);
-                        let data = serde_json::to_value(
+                        let form_data = serde_json::to_value(
                             // TODO: Support inputs with `multiple` attribute.
                             event
                                 .data()
@@ -39,7 +40,7 @@ pub fn Form(props: FormProps) -> Element {
                                 .collect::<HashMap<String, String>>()
                         ).expect("TODO: handle error");
 
-                        let result = call(action_id, data).await;
+                        let result = call(action_id, form_data).await;
 
                         match result {
                             Ok(response) => {
@@ -47,6 +48,9 @@ pub fn Form(props: FormProps) -> Element {
 
                                 match response {
                                     ResponseType::Default => {},
+                                    ResponseType::Data(value) => {
+                                        data.set(serde_json::to_string_pretty(&value).ok());
+                                    },
                                     ResponseType::Redirect(to) => {
                                         navigator.push(to);
                                     },
@@ -70,5 +74,9 @@ pub fn Form(props: FormProps) -> Element {
                 }
             }
         }
+
+        if let Some(data) = data() {
+            pre { "{data}" }
+        }
     }
 }
@@ -17,6 +17,7 @@ pub struct MethodFormProps {
 #[component]
 pub fn MethodForm(props: MethodFormProps) -> Element {
     let navigator = navigator();
+    let mut data = use_signal(|| None::<String>);
 
     rsx! {
         form {
@@ -30,7 +31,7 @@ pub fn MethodForm(props: MethodFormProps) -> Element {
 
                     async move {
                         info!("{:?}", event);
-                        let data = serde_json::to_value(
+                        let form_data = serde_json::to_value(
                             // TODO: Support inputs with `multiple` attribute.
                             event
                                 .data()
@@ -43,7 +44,7 @@ pub fn MethodForm(props: MethodFormProps) -> Element {
                                 .collect::<HashMap<String, String>>()
                         ).expect("TODO: handle error");
 
-                        let result = call_method(action_id, method_id, provider_id, data).await;
+                        let result = call_method(action_id, method_id, provider_id, form_data).await;
 
                         match result {
                             Ok(response) => {
@@ -51,6 +52,9 @@ pub fn MethodForm(props: MethodFormProps) -> Element {
 
                                 match response {
                                     ResponseType::Default => {},
+                                    ResponseType::Data(value) => {
+                                        data.set(serde_json::to_string_pretty(&value).ok());
+                                    },
                                     ResponseType::Redirect(to) => {
                                         navigator.push(to);
                                     },
@@ -74,5 +78,9 @@ pub fn MethodForm(props: MethodFormProps) -> Element {
                 }
             }
         }
+
+        if let Some(data) = data() {
+            pre { "{data}" }
+        }
     }
 }
@@ -15,5 +15,11 @@ pub fn Form(action_id: String, form: shield::Form) -> impl IntoView {
                 <FormInput input={input} />
             }).collect_view()}
         </ActionForm>
+
+        {move || {
+            call.value().get().and_then(|result| result.ok().flatten()).map(|data| view! {
+                <pre>{serde_json::to_string_pretty(&data).unwrap_or_default()}</pre>
+            })
+        }}
     }
 }
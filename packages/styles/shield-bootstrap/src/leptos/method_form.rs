@@ -22,5 +22,11 @@ pub fn MethodForm(
                 <FormInput input={input} />
             }).collect_view()}
         </ActionForm>
+
+        {move || {
+            call_method.value().get().and_then(|result| result.ok().flatten()).map(|data| view! {
+                <pre>{serde_json::to_string_pretty(&data).unwrap_or_default()}</pre>
+            })
+        }}
     }
 }